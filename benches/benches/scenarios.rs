@@ -0,0 +1,81 @@
+//! Benchmarks for trees with representative shapes, measuring the initial layout, relayout of an unchanged tree (which
+//! should be served from the cache) and relayout after dirtying a single leaf.
+//!
+//! To measure the overhead of Taffy's profiling instrumentation, save a baseline with
+//! `cargo bench -p taffy_benchmarks --bench scenarios -- --save-baseline plain` and then compare against it with
+//! `cargo bench -p taffy_benchmarks --bench scenarios --features profile -- --baseline plain`.
+use std::fmt::Display;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use taffy_benchmarks::scenarios::{
+    block_document, grid_dashboard, nested, wide_flex_row, wrapped_flex_grid, NestedParams, Scenario, StyleMix,
+};
+
+/// Benchmark the initial layout, cached relayout and single-dirty relayout of the trees built by `build`
+fn bench_scenario(
+    c: &mut Criterion,
+    name: &str,
+    params: impl IntoIterator<Item = (impl Display, impl Fn() -> Scenario)>,
+) {
+    let mut group = c.benchmark_group(format!("scenarios/{name}"));
+    group.sample_size(10);
+    for (param, build) in params {
+        let param = param.to_string();
+        group.bench_function(BenchmarkId::new("initial", &param), |b| {
+            b.iter_batched(&build, |mut scenario| scenario.compute_layout(), BatchSize::LargeInput)
+        });
+
+        let mut scenario = build();
+        scenario.compute_layout();
+        group.bench_function(BenchmarkId::new("cached_relayout", &param), |b| b.iter(|| scenario.compute_layout()));
+
+        let mut leaf_index = 0;
+        group.bench_function(BenchmarkId::new("single_dirty_relayout", &param), |b| {
+            b.iter(|| {
+                // Step through the leaves with a stride so that successive iterations dirty different subtrees
+                leaf_index += 7;
+                scenario.mark_leaf_dirty(leaf_index);
+                scenario.compute_layout()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn scenario_benchmarks(c: &mut Criterion) {
+    for (style_mix_name, style_mix) in [
+        ("flexbox", StyleMix::Flexbox),
+        ("grid", StyleMix::Grid),
+        ("block", StyleMix::Block),
+        ("mixed", StyleMix::Mixed),
+    ] {
+        for measured_leaves in [false, true] {
+            let leaves = if measured_leaves { "text" } else { "fixed" };
+            bench_scenario(
+                c,
+                &format!("nested/{style_mix_name}/{leaves}"),
+                [(4, 6), (10, 2), (100, 1)].map(|(branching, depth)| {
+                    let params = NestedParams { depth, branching, style_mix, measured_leaves };
+                    (format!("{branching}^{depth}"), move || nested(params))
+                }),
+            );
+        }
+    }
+    bench_scenario(c, "wide_flex_row", [100, 1_000, 10_000].map(|count| (count, move || wide_flex_row(count))));
+    bench_scenario(c, "wrapped_flex_grid", [100, 1_000].map(|count| (count, move || wrapped_flex_grid(count))));
+    bench_scenario(
+        c,
+        "grid_dashboard",
+        [(4, 3), (20, 6)].map(|(rows, columns)| (format!("{rows}x{columns}"), move || grid_dashboard(rows, columns))),
+    );
+    bench_scenario(
+        c,
+        "block_document",
+        [(10, 5), (100, 10)].map(|(sections, paragraphs)| {
+            (format!("{sections}x{paragraphs}"), move || block_document(sections, paragraphs))
+        }),
+    );
+}
+
+criterion_group!(benches, scenario_benchmarks);
+criterion_main!(benches);
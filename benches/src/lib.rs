@@ -2,6 +2,7 @@
 // of false positives for this lint. So let's just disable it for this code.
 #![allow(dead_code)]
 
+pub mod scenarios;
 pub mod taffy_helpers;
 pub use taffy_helpers::TaffyTreeBuilder;
 
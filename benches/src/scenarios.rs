@@ -0,0 +1,286 @@
+//! Builders for trees with the shapes that are commonly laid out in practice: deep nesting, wide flexbox rows,
+//! wrapped flexbox grids, CSS grid dashboards and block documents with lots of text.
+//!
+//! Each builder returns a [`Scenario`], which can be laid out repeatedly and exposes its leaves so that benchmarks can
+//! dirty a single node between layouts.
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use taffy::prelude::*;
+
+use super::STANDARD_RNG_SEED;
+
+/// The width of each character (and the height of each line) of text measured by [`measure_text`]
+pub const CHAR_SIZE: Scalar = 8.0;
+
+/// The node context of a text leaf
+#[derive(Debug, Clone, Copy)]
+pub struct TextContext {
+    /// The number of characters in the text
+    pub char_count: usize,
+    /// The number of characters in the longest word, which determines the min-content width
+    pub longest_word: usize,
+}
+
+impl TextContext {
+    /// Text made of `words` words of `word_length` characters each, separated by spaces
+    pub fn words(words: usize, word_length: usize) -> Self {
+        Self { char_count: words * (word_length + 1) - 1, longest_word: word_length }
+    }
+}
+
+/// Measures text of fixed-width characters, wrapping it onto as many lines as needed to fit the available width
+pub fn measure_text(
+    known_dimensions: Size<Option<Scalar>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    context: Option<&mut TextContext>,
+    _style: &Style,
+) -> Size<Scalar> {
+    if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+        return Size { width, height };
+    }
+    let Some(text) = context else { return Size::ZERO };
+
+    let min_content_width = text.longest_word as Scalar * CHAR_SIZE;
+    let max_content_width = text.char_count as Scalar * CHAR_SIZE;
+    let width = known_dimensions.width.unwrap_or(match available_space.width {
+        AvailableSpace::MinContent => min_content_width,
+        AvailableSpace::MaxContent => max_content_width,
+        AvailableSpace::Definite(width) => width.min(max_content_width).max(min_content_width),
+    });
+    let chars_per_line = ((width / CHAR_SIZE).floor() as usize).max(1);
+    let line_count = text.char_count.div_ceil(chars_per_line);
+    Size { width, height: known_dimensions.height.unwrap_or(line_count as Scalar * CHAR_SIZE) }
+}
+
+/// A definite available width with an indefinite available height, as when laying out a scrollable page
+fn definite_width(width: Scalar) -> Size<AvailableSpace> {
+    Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent }
+}
+
+/// A tree to be laid out by a benchmark
+pub struct Scenario {
+    /// The tree
+    pub tree: TaffyTree<TextContext>,
+    /// The root of the tree
+    pub root: NodeId,
+    /// The leaves of the tree, in the order they were created
+    pub leaves: Vec<NodeId>,
+    /// The space available to the root
+    pub available_space: Size<AvailableSpace>,
+}
+
+impl Scenario {
+    /// Lay out the tree
+    pub fn compute_layout(&mut self) {
+        self.tree.compute_layout_with_measure(self.root, self.available_space, measure_text).unwrap();
+    }
+
+    /// Mark the leaf with the specified index (modulo the number of leaves) as dirty
+    pub fn mark_leaf_dirty(&mut self, index: usize) {
+        let leaf = self.leaves[index % self.leaves.len()];
+        self.tree.mark_dirty(leaf).unwrap();
+    }
+}
+
+/// The layout algorithms used by the containers of a [`nested`] tree
+#[derive(Debug, Clone, Copy)]
+pub enum StyleMix {
+    /// Every container is a flexbox container
+    Flexbox,
+    /// Every container is a grid container
+    Grid,
+    /// Every container is a block container
+    Block,
+    /// Containers cycle through flexbox, grid and block at each level of the tree
+    Mixed,
+}
+
+impl StyleMix {
+    /// The display mode of a container at the specified depth in the tree
+    fn display(self, depth: u32) -> Display {
+        match self {
+            StyleMix::Flexbox => Display::Flex,
+            StyleMix::Grid => Display::Grid,
+            StyleMix::Block => Display::Block,
+            StyleMix::Mixed => [Display::Flex, Display::Grid, Display::Block][depth as usize % 3],
+        }
+    }
+}
+
+/// The parameters of a [`nested`] tree
+#[derive(Debug, Clone, Copy)]
+pub struct NestedParams {
+    /// The number of levels of containers
+    pub depth: u32,
+    /// The number of children of each container
+    pub branching: u32,
+    /// The layout algorithms used by the containers
+    pub style_mix: StyleMix,
+    /// Whether the leaves contain text that must be measured, rather than having a fixed size
+    pub measured_leaves: bool,
+}
+
+/// A tree in which every container has the same number of children, down to a fixed depth
+pub fn nested(params: NestedParams) -> Scenario {
+    /// Recursively build the children of a container at the specified depth
+    fn build_children(
+        tree: &mut TaffyTree<TextContext>,
+        leaves: &mut Vec<NodeId>,
+        params: NestedParams,
+        depth: u32,
+    ) -> Vec<NodeId> {
+        (0..params.branching)
+            .map(|_| {
+                if depth == params.depth {
+                    let leaf = if params.measured_leaves {
+                        tree.new_leaf_with_context(Style::default(), TextContext::words(4, 5)).unwrap()
+                    } else {
+                        tree.new_leaf(Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() }).unwrap()
+                    };
+                    leaves.push(leaf);
+                    leaf
+                } else {
+                    let children = build_children(tree, leaves, params, depth + 1);
+                    let style = Style {
+                        display: params.style_mix.display(depth),
+                        flex_grow: 1.0,
+                        padding: Rect::length(2.0),
+                        ..Default::default()
+                    };
+                    tree.new_with_children(style, &children).unwrap()
+                }
+            })
+            .collect()
+    }
+
+    let mut tree = TaffyTree::new();
+    let mut leaves = Vec::new();
+    let children = build_children(&mut tree, &mut leaves, params, 1);
+    let style = Style { display: params.style_mix.display(0), ..Default::default() };
+    let root = tree.new_with_children(style, &children).unwrap();
+    Scenario { tree, root, leaves, available_space: definite_width(1920.0) }
+}
+
+/// A single flexbox row with many children, half of which contain text
+pub fn wide_flex_row(child_count: usize) -> Scenario {
+    let mut tree = TaffyTree::new();
+    let leaves: Vec<NodeId> = (0..child_count)
+        .map(|index| {
+            if index % 2 == 0 {
+                tree.new_leaf_with_context(Style { flex_shrink: 1.0, ..Default::default() }, TextContext::words(2, 6))
+                    .unwrap()
+            } else {
+                let style = Style { flex_basis: length(40.0), flex_grow: 1.0, ..Default::default() };
+                tree.new_leaf(style).unwrap()
+            }
+        })
+        .collect();
+    let style = Style { gap: Size::length(4.0), ..Default::default() };
+    let root = tree.new_with_children(style, &leaves).unwrap();
+    Scenario { tree, root, leaves, available_space: definite_width(1920.0) }
+}
+
+/// A wrapping flexbox container of cards, each of which is a column containing a title and some body text
+pub fn wrapped_flex_grid(card_count: usize) -> Scenario {
+    let mut tree = TaffyTree::new();
+    let mut leaves = Vec::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(STANDARD_RNG_SEED);
+    let cards: Vec<NodeId> = (0..card_count)
+        .map(|_| {
+            let title = tree.new_leaf_with_context(Style::default(), TextContext::words(3, 6)).unwrap();
+            let body =
+                tree.new_leaf_with_context(Style::default(), TextContext::words(rng.random_range(5..40), 6)).unwrap();
+            leaves.extend([title, body]);
+            let style = Style {
+                flex_direction: FlexDirection::Column,
+                flex_basis: length(200.0),
+                flex_grow: 1.0,
+                padding: Rect::length(8.0),
+                gap: Size::length(4.0),
+                ..Default::default()
+            };
+            tree.new_with_children(style, &[title, body]).unwrap()
+        })
+        .collect();
+    let style = Style { flex_wrap: FlexWrap::Wrap, gap: Size::length(8.0), ..Default::default() };
+    let root = tree.new_with_children(style, &cards).unwrap();
+    Scenario { tree, root, leaves, available_space: definite_width(1280.0) }
+}
+
+/// A CSS grid of panels, some spanning multiple tracks, each of which is a flexbox column with a header and a list
+pub fn grid_dashboard(rows: usize, columns: usize) -> Scenario {
+    let mut tree = TaffyTree::new();
+    let mut leaves = Vec::new();
+    let mut panels = Vec::new();
+    for row in 0..rows {
+        let mut column = 0;
+        while column < columns {
+            // Every third panel spans two columns
+            let column_span = if (row + column) % 3 == 0 && column + 1 < columns { 2 } else { 1 };
+            let header = tree.new_leaf_with_context(Style::default(), TextContext::words(2, 8)).unwrap();
+            leaves.push(header);
+            let items: Vec<NodeId> = (0..5)
+                .map(|_| {
+                    let label = tree.new_leaf_with_context(Style::default(), TextContext::words(3, 5)).unwrap();
+                    let value = tree.new_leaf_with_context(Style::default(), TextContext::words(1, 4)).unwrap();
+                    leaves.extend([label, value]);
+                    let style = Style { justify_content: Some(JustifyContent::SpaceBetween), ..Default::default() };
+                    tree.new_with_children(style, &[label, value]).unwrap()
+                })
+                .collect();
+            let list = tree
+                .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &items)
+                .unwrap();
+            let style = Style {
+                flex_direction: FlexDirection::Column,
+                grid_column: Line { start: auto(), end: span(column_span as u16) },
+                padding: Rect::length(12.0),
+                ..Default::default()
+            };
+            panels.push(tree.new_with_children(style, &[header, list]).unwrap());
+            column += column_span;
+        }
+    }
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![fr(1.0); columns],
+        grid_auto_rows: vec![minmax(length(100.0), auto())],
+        gap: Size::length(16.0),
+        ..Default::default()
+    };
+    let root = tree.new_with_children(style, &panels).unwrap();
+    Scenario { tree, root, leaves, available_space: definite_width(1920.0) }
+}
+
+/// A block document made of sections, each of which has a heading followed by paragraphs of varying length
+pub fn block_document(section_count: usize, paragraphs_per_section: usize) -> Scenario {
+    let mut tree = TaffyTree::new();
+    let mut leaves = Vec::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(STANDARD_RNG_SEED);
+    let block = |margin: f32| Style {
+        display: Display::Block,
+        margin: Rect { left: zero(), right: zero(), top: length(margin), bottom: length(margin) },
+        ..Default::default()
+    };
+    let sections: Vec<NodeId> = (0..section_count)
+        .map(|_| {
+            let mut children = Vec::with_capacity(paragraphs_per_section + 1);
+            children.push(tree.new_leaf_with_context(block(16.0), TextContext::words(5, 7)).unwrap());
+            for _ in 0..paragraphs_per_section {
+                let paragraph = TextContext::words(rng.random_range(20..200), rng.random_range(3..9));
+                children.push(tree.new_leaf_with_context(block(8.0), paragraph).unwrap());
+            }
+            leaves.extend_from_slice(&children);
+            tree.new_with_children(block(24.0), &children).unwrap()
+        })
+        .collect();
+    let style = Style {
+        display: Display::Block,
+        max_size: Size { width: length(800.0), height: auto() },
+        padding: Rect::length(24.0),
+        ..Default::default()
+    };
+    let root = tree.new_with_children(style, &sections).unwrap();
+    Scenario { tree, root, leaves, available_space: definite_width(1280.0) }
+}
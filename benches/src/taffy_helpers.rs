@@ -27,7 +27,10 @@ impl<R: Rng, G: GenStyle<TaffyStyle>> BuildTree<R, G> for TaffyTreeBuilder<R, G>
     }
 
     fn compute_layout_inner(&mut self, available_width: Option<f32>, available_height: Option<f32>) {
-        let available_space = taffy::geometry::Size { width: available_width.into(), height: available_height.into() };
+        let available_space = taffy::geometry::Size {
+            width: available_width.map(|width| width as taffy::Scalar).into(),
+            height: available_height.map(|height| height as taffy::Scalar).into(),
+        };
         self.tree.compute_layout(self.root, available_space).unwrap();
     }
 
@@ -191,7 +191,7 @@ fn apply_taffy_style(node: &mut yg::Node, style: &tf::Style) {
         tf::Display::None => yg::Display::None,
         tf::Display::Flex => yg::Display::Flex,
         tf::Display::Grid => panic!("Yoga does not support CSS Grid layout"),
-        tf::Display::Block => panic!("Yoga does not support CSS Block layout"),
+        tf::Display::Block | tf::Display::FlowRoot => panic!("Yoga does not support CSS Block layout"),
     });
 
     // box_sizing
@@ -1,14 +1,14 @@
-use taffy::geometry::Size;
+use taffy::geometry::{Scalar, Size};
 
 pub struct ImageContext {
-    pub width: f32,
-    pub height: f32,
+    pub width: Scalar,
+    pub height: Scalar,
 }
 
 pub fn image_measure_function(
-    known_dimensions: taffy::geometry::Size<Option<f32>>,
+    known_dimensions: taffy::geometry::Size<Option<Scalar>>,
     image_context: &ImageContext,
-) -> taffy::geometry::Size<f32> {
+) -> taffy::geometry::Size<Scalar> {
     match (known_dimensions.width, known_dimensions.height) {
         (Some(width), Some(height)) => Size { width, height },
         (Some(width), None) => Size { width, height: (width / image_context.width) * image_context.height },
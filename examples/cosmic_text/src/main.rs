@@ -17,19 +17,21 @@ impl CosmicTextContext {
         Self { buffer }
     }
 
+    // `taffy::Scalar` is the same type as cosmic-text's `f32` unless taffy's `f64` feature is enabled
+    #[allow(clippy::unnecessary_cast)]
     fn measure(
         &mut self,
-        known_dimensions: taffy::Size<Option<f32>>,
+        known_dimensions: taffy::Size<Option<taffy::Scalar>>,
         available_space: taffy::Size<taffy::AvailableSpace>,
         font_system: &mut FontSystem,
-    ) -> taffy::Size<f32> {
+    ) -> taffy::Size<taffy::Scalar> {
         // Set width constraint
         let width_constraint = known_dimensions.width.or(match available_space.width {
             AvailableSpace::MinContent => Some(0.0),
             AvailableSpace::MaxContent => None,
             AvailableSpace::Definite(width) => Some(width),
         });
-        self.buffer.set_size(font_system, width_constraint, None);
+        self.buffer.set_size(font_system, width_constraint.map(|width| width as f32), None);
 
         // Compute layout
         self.buffer.shape_until_scroll(font_system, false);
@@ -41,10 +43,11 @@ impl CosmicTextContext {
             .fold((0.0, 0usize), |(width, total_lines), run| (run.line_w.max(width), total_lines + 1));
         let height = total_lines as f32 * self.buffer.metrics().line_height;
 
-        taffy::Size { width, height }
+        taffy::Size { width: width as taffy::Scalar, height: height as taffy::Scalar }
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 enum NodeContext {
     Text(CosmicTextContext),
     Image(ImageContext),
@@ -57,17 +60,17 @@ impl NodeContext {
     }
 
     /// Constructor for an image node context
-    fn image(width: f32, height: f32) -> Self {
+    fn image(width: Scalar, height: Scalar) -> Self {
         NodeContext::Image(ImageContext { width, height })
     }
 }
 
 fn measure_function(
-    known_dimensions: taffy::Size<Option<f32>>,
+    known_dimensions: taffy::Size<Option<Scalar>>,
     available_space: taffy::Size<taffy::AvailableSpace>,
     node_context: Option<&mut NodeContext>,
     font_system: &mut FontSystem,
-) -> Size<f32> {
+) -> Size<Scalar> {
     if let Size { width: Some(width), height: Some(height) } = known_dimensions {
         return Size { width, height };
     }
@@ -0,0 +1,142 @@
+//! Prints the `tracing` spans and events emitted while laying out a small tree, as an indented outline.
+//!
+//! Run with `cargo run --example layout_tracing --features tracing`. In an application, a subscriber from a crate such as
+//! `tracing-subscriber` or `tracing-tree` would usually be installed instead of the minimal one implemented here.
+use std::collections::HashMap;
+use std::fmt::{Debug, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use taffy::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+/// Formats the fields of a span or event as `name=value` pairs, with the event's message first
+#[derive(Default)]
+struct FieldFormatter(String);
+
+impl Visit for FieldFormatter {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        match field.name() {
+            "message" => write!(self.0, "{value:?}").unwrap(),
+            name => write!(self.0, "{name}={value:?}").unwrap(),
+        }
+    }
+}
+
+/// A subscriber that prints each span when it's exited, indented by the number of spans it's nested within, and each
+/// event when it occurs
+#[derive(Default)]
+struct OutlineSubscriber {
+    /// The id given to the next span that's created
+    next_id: AtomicU64,
+    /// The metadata, formatted fields and number of open handles of each span that hasn't been closed
+    spans: Mutex<HashMap<u64, (&'static Metadata<'static>, FieldFormatter, usize)>>,
+    /// The spans that are currently entered, from outermost to innermost. The innermost is the current span, which
+    /// Taffy records the layout algorithm used for each node on.
+    entered: Mutex<Vec<Id>>,
+}
+
+impl OutlineSubscriber {
+    /// Prints a line indented by the current depth
+    fn print(&self, line: std::fmt::Arguments) {
+        let indent = self.entered.lock().unwrap().len() * 2;
+        println!("{:indent$}{line}", "");
+    }
+}
+
+impl Subscriber for OutlineSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut fields = FieldFormatter::default();
+        span.record(&mut fields);
+        self.spans.lock().unwrap().insert(id, (span.metadata(), fields, 1));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        if let Some((_, fields, _)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            values.record(fields);
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = FieldFormatter::default();
+        event.record(&mut fields);
+        self.print(format_args!("- {}", fields.0));
+    }
+
+    fn enter(&self, span: &Id) {
+        let spans = self.spans.lock().unwrap();
+        let (metadata, fields, _) = &spans[&span.into_u64()];
+        // The algorithm is recorded after the span is entered, so it's printed when the span is exited
+        self.print(format_args!("{} {}", metadata.name(), fields.0));
+        self.entered.lock().unwrap().push(span.clone());
+    }
+
+    fn exit(&self, span: &Id) {
+        self.entered.lock().unwrap().pop();
+        let spans = self.spans.lock().unwrap();
+        let (metadata, fields, _) = &spans[&span.into_u64()];
+        self.print(format_args!("end {} {}", metadata.name(), fields.0));
+    }
+
+    fn current_span(&self) -> Current {
+        match self.entered.lock().unwrap().last() {
+            Some(id) => Current::new(id.clone(), self.spans.lock().unwrap()[&id.into_u64()].0),
+            None => Current::none(),
+        }
+    }
+
+    fn clone_span(&self, span: &Id) -> Id {
+        if let Some((_, _, handles)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            *handles += 1;
+        }
+        span.clone()
+    }
+
+    fn try_close(&self, span: Id) -> bool {
+        let mut spans = self.spans.lock().unwrap();
+        let Some((_, _, handles)) = spans.get_mut(&span.into_u64()) else { return false };
+        *handles -= 1;
+        let closed = *handles == 0;
+        if closed {
+            spans.remove(&span.into_u64());
+        }
+        closed
+    }
+}
+
+fn main() -> Result<(), taffy::TaffyError> {
+    tracing::subscriber::set_global_default(OutlineSubscriber::default()).expect("no subscriber is installed yet");
+
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() })?;
+    let text = taffy.new_leaf_with_context(Style { flex_grow: 1.0, ..Default::default() }, ())?;
+    let node = taffy.new_with_children(
+        Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() },
+        &[child, text],
+    )?;
+
+    taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, |known_dimensions, _, _, context, _| match context {
+        Some(()) => Size { width: known_dimensions.width.unwrap_or(40.0), height: 15.0 },
+        None => Size::ZERO,
+    })?;
+
+    // Laying out the tree again is served from the layout caches, which is visible as cache hits in the trace
+    println!("\nRelayout:");
+    taffy.compute_layout(node, Size::MAX_CONTENT)?;
+
+    Ok(())
+}
@@ -12,28 +12,28 @@ enum NodeContext {
 }
 
 fn measure_function(
-    known_dimensions: taffy::geometry::Size<Option<f32>>,
+    known_dimensions: taffy::geometry::Size<Option<Scalar>>,
     available_space: taffy::geometry::Size<taffy::style::AvailableSpace>,
     node_context: Option<&mut NodeContext>,
     font_metrics: &FontMetrics,
-) -> Size<f32> {
+) -> taffy::MeasureOutput {
     if let Size { width: Some(width), height: Some(height) } = known_dimensions {
-        return Size { width, height };
+        return Size { width, height }.into();
     }
 
     match node_context {
-        None => Size::ZERO,
+        None => taffy::MeasureOutput::ZERO,
         Some(NodeContext::Text(text_context)) => {
             text_measure_function(known_dimensions, available_space, &*text_context, font_metrics)
         }
-        Some(NodeContext::Image(image_context)) => image_measure_function(known_dimensions, image_context),
+        Some(NodeContext::Image(image_context)) => image_measure_function(known_dimensions, image_context).into(),
     }
 }
 
 fn main() -> Result<(), taffy::TaffyError> {
     let mut taffy: TaffyTree<NodeContext> = TaffyTree::new();
 
-    let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0 };
+    let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0, ascent: 8.0 };
 
     let text_node = taffy.new_leaf_with_context(
         Style::default(),
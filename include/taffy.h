@@ -0,0 +1,704 @@
+#ifndef TAFFY_H
+#define TAFFY_H
+
+/* This file is generated by cbindgen from src/ffi.rs. Don't edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * See [`AlignContent`]. Used for the `align-content` and `justify-content` properties.
+ */
+typedef enum TaffyAlignContent {
+  /**
+   * The property isn't set (`None` in a [`Style`])
+   */
+  TaffyAlignContent_Normal,
+  /**
+   * See [`AlignContent::Start`]
+   */
+  TaffyAlignContent_Start,
+  /**
+   * See [`AlignContent::End`]
+   */
+  TaffyAlignContent_End,
+  /**
+   * See [`AlignContent::FlexStart`]
+   */
+  TaffyAlignContent_FlexStart,
+  /**
+   * See [`AlignContent::FlexEnd`]
+   */
+  TaffyAlignContent_FlexEnd,
+  /**
+   * See [`AlignContent::Center`]
+   */
+  TaffyAlignContent_Center,
+  /**
+   * See [`AlignContent::Stretch`]
+   */
+  TaffyAlignContent_Stretch,
+  /**
+   * See [`AlignContent::SpaceBetween`]
+   */
+  TaffyAlignContent_SpaceBetween,
+  /**
+   * See [`AlignContent::SpaceEvenly`]
+   */
+  TaffyAlignContent_SpaceEvenly,
+  /**
+   * See [`AlignContent::SpaceAround`]
+   */
+  TaffyAlignContent_SpaceAround,
+} TaffyAlignContent;
+
+/**
+ * See [`AlignItems`]. Used for the `align-items`, `align-self`, `justify-items` and `justify-self` properties.
+ */
+typedef enum TaffyAlignItems {
+  /**
+   * The property isn't set (`None` in a [`Style`])
+   */
+  TaffyAlignItems_Normal,
+  /**
+   * See [`AlignItems::Start`]
+   */
+  TaffyAlignItems_Start,
+  /**
+   * See [`AlignItems::End`]
+   */
+  TaffyAlignItems_End,
+  /**
+   * See [`AlignItems::FlexStart`]
+   */
+  TaffyAlignItems_FlexStart,
+  /**
+   * See [`AlignItems::FlexEnd`]
+   */
+  TaffyAlignItems_FlexEnd,
+  /**
+   * See [`AlignItems::Center`]
+   */
+  TaffyAlignItems_Center,
+  /**
+   * See [`AlignItems::Baseline`]
+   */
+  TaffyAlignItems_Baseline,
+  /**
+   * See [`AlignItems::Stretch`]
+   */
+  TaffyAlignItems_Stretch,
+} TaffyAlignItems;
+
+/**
+ * The kind of a [`TaffyAvailableSpace`]
+ */
+typedef enum TaffyAvailableSpaceKind {
+  /**
+   * A definite amount of space, given by the value
+   */
+  TaffyAvailableSpaceKind_Definite,
+  /**
+   * The space available when sizing under a min-content constraint
+   */
+  TaffyAvailableSpaceKind_MinContent,
+  /**
+   * The space available when sizing under a max-content constraint
+   */
+  TaffyAvailableSpaceKind_MaxContent,
+} TaffyAvailableSpaceKind;
+
+/**
+ * See [`BoxSizing`]
+ */
+typedef enum TaffyBoxSizing {
+  /**
+   * See [`BoxSizing::BorderBox`]
+   */
+  TaffyBoxSizing_BorderBox,
+  /**
+   * See [`BoxSizing::ContentBox`]
+   */
+  TaffyBoxSizing_ContentBox,
+} TaffyBoxSizing;
+
+/**
+ * See [`Display`]
+ */
+typedef enum TaffyDisplay {
+  /**
+   * See [`Display::Block`]
+   */
+  TaffyDisplay_Block,
+  /**
+   * See [`Display::Flex`]
+   */
+  TaffyDisplay_Flex,
+  /**
+   * See [`Display::Grid`]
+   */
+  TaffyDisplay_Grid,
+  /**
+   * See [`Display::None`]
+   */
+  TaffyDisplay_None,
+} TaffyDisplay;
+
+/**
+ * See [`FlexDirection`]
+ */
+typedef enum TaffyFlexDirection {
+  /**
+   * See [`FlexDirection::Row`]
+   */
+  TaffyFlexDirection_Row,
+  /**
+   * See [`FlexDirection::Column`]
+   */
+  TaffyFlexDirection_Column,
+  /**
+   * See [`FlexDirection::RowReverse`]
+   */
+  TaffyFlexDirection_RowReverse,
+  /**
+   * See [`FlexDirection::ColumnReverse`]
+   */
+  TaffyFlexDirection_ColumnReverse,
+} TaffyFlexDirection;
+
+/**
+ * See [`FlexWrap`]
+ */
+typedef enum TaffyFlexWrap {
+  /**
+   * See [`FlexWrap::NoWrap`]
+   */
+  TaffyFlexWrap_NoWrap,
+  /**
+   * See [`FlexWrap::Wrap`]
+   */
+  TaffyFlexWrap_Wrap,
+  /**
+   * See [`FlexWrap::WrapReverse`]
+   */
+  TaffyFlexWrap_WrapReverse,
+} TaffyFlexWrap;
+
+/**
+ * See [`Overflow`]
+ */
+typedef enum TaffyOverflow {
+  /**
+   * See [`Overflow::Visible`]
+   */
+  TaffyOverflow_Visible,
+  /**
+   * See [`Overflow::Clip`]
+   */
+  TaffyOverflow_Clip,
+  /**
+   * See [`Overflow::Hidden`]
+   */
+  TaffyOverflow_Hidden,
+  /**
+   * See [`Overflow::Scroll`]
+   */
+  TaffyOverflow_Scroll,
+} TaffyOverflow;
+
+/**
+ * See [`Position`]
+ */
+typedef enum TaffyPosition {
+  /**
+   * See [`Position::Relative`]
+   */
+  TaffyPosition_Relative,
+  /**
+   * See [`Position::Absolute`]
+   */
+  TaffyPosition_Absolute,
+} TaffyPosition;
+
+/**
+ * The result of a function that can fail
+ */
+typedef enum TaffyReturnCode {
+  /**
+   * The function succeeded
+   */
+  TaffyReturnCode_Ok,
+  /**
+   * A pointer argument was null
+   */
+  TaffyReturnCode_NullPointer,
+  /**
+   * A node argument isn't a node in the tree
+   */
+  TaffyReturnCode_InvalidNode,
+  /**
+   * A child index argument is greater than the number of children of the parent
+   */
+  TaffyReturnCode_ChildIndexOutOfBounds,
+  /**
+   * A child node argument isn't a child of the parent
+   */
+  TaffyReturnCode_NotAChild,
+  /**
+   * A style has a value that isn't valid for its property, such as `auto` padding
+   */
+  TaffyReturnCode_InvalidStyle,
+} TaffyReturnCode;
+
+/**
+ * The unit of a [`TaffyDimension`]
+ */
+typedef enum TaffyUnit {
+  /**
+   * The value is `auto`, and ignored
+   */
+  TaffyUnit_Auto,
+  /**
+   * The value is a length in pixels
+   */
+  TaffyUnit_Length,
+  /**
+   * The value is a fraction of the size of the containing block
+   */
+  TaffyUnit_Percent,
+} TaffyUnit;
+
+/**
+ * An opaque handle to a tree of nodes
+ */
+typedef struct TaffyTree TaffyTree;
+
+/**
+ * The id of a node in a [`TaffyTree`]
+ */
+typedef uint64_t TaffyNodeId;
+
+/**
+ * The space available to a node in an axis. See [`AvailableSpace`].
+ */
+typedef struct TaffyAvailableSpace {
+  /**
+   * The kind of space
+   */
+  TaffyAvailableSpaceKind kind;
+  /**
+   * The amount of definite space, which is ignored for other kinds of space
+   */
+  float value;
+} TaffyAvailableSpace;
+
+/**
+ * A length, percentage or `auto` value of a style property
+ */
+typedef struct TaffyDimension {
+  /**
+   * The unit of the value
+   */
+  TaffyUnit unit;
+  /**
+   * The value
+   */
+  float value;
+} TaffyDimension;
+
+/**
+ * A [`TaffyDimension`] for each edge
+ */
+typedef struct TaffyDimensionRect {
+  /**
+   * The value of the left edge
+   */
+  TaffyDimension left;
+  /**
+   * The value of the right edge
+   */
+  TaffyDimension right;
+  /**
+   * The value of the top edge
+   */
+  TaffyDimension top;
+  /**
+   * The value of the bottom edge
+   */
+  TaffyDimension bottom;
+} TaffyDimensionRect;
+
+/**
+ * A [`TaffyDimension`] for each axis
+ */
+typedef struct TaffyDimensionSize {
+  /**
+   * The horizontal value
+   */
+  TaffyDimension width;
+  /**
+   * The vertical value
+   */
+  TaffyDimension height;
+} TaffyDimensionSize;
+
+/**
+ * A length for each edge of a box
+ */
+typedef struct TaffyEdges {
+  /**
+   * The length of the left edge
+   */
+  float left;
+  /**
+   * The length of the right edge
+   */
+  float right;
+  /**
+   * The length of the top edge
+   */
+  float top;
+  /**
+   * The length of the bottom edge
+   */
+  float bottom;
+} TaffyEdges;
+
+/**
+ * The computed layout of a node. See [`Layout`](crate::Layout).
+ */
+typedef struct TaffyLayout {
+  /**
+   * The horizontal position of the node relative to its parent's border box
+   */
+  float x;
+  /**
+   * The vertical position of the node relative to its parent's border box
+   */
+  float y;
+  /**
+   * The width of the node
+   */
+  float width;
+  /**
+   * The height of the node
+   */
+  float height;
+  /**
+   * The width of the node's content, which may overflow it
+   */
+  float content_width;
+  /**
+   * The height of the node's content, which may overflow it
+   */
+  float content_height;
+  /**
+   * The widths of the node's borders
+   */
+  TaffyEdges border;
+  /**
+   * The widths of the node's padding
+   */
+  TaffyEdges padding;
+  /**
+   * The node's margins, as used by the layout of its parent
+   */
+  TaffyEdges margin;
+} TaffyLayout;
+
+/**
+ * A width and a height
+ */
+typedef struct TaffySize {
+  /**
+   * The width
+   */
+  float width;
+  /**
+   * The height
+   */
+  float height;
+} TaffySize;
+
+/**
+ * The style of a node. See [`Style`] for the meaning of each property.
+ *
+ * `padding`, `border` and `gap` can't be `auto`. An `aspect_ratio` of NaN means that the node has no aspect ratio.
+ */
+typedef struct TaffyStyle {
+  /**
+   * See [`Style::display`]
+   */
+  TaffyDisplay display;
+  /**
+   * See [`Style::box_sizing`]
+   */
+  TaffyBoxSizing box_sizing;
+  /**
+   * See [`Style::position`]
+   */
+  TaffyPosition position;
+  /**
+   * The horizontal value of [`Style::overflow`]
+   */
+  TaffyOverflow overflow_x;
+  /**
+   * The vertical value of [`Style::overflow`]
+   */
+  TaffyOverflow overflow_y;
+  /**
+   * See [`Style::scrollbar_width`]
+   */
+  float scrollbar_width;
+  /**
+   * See [`Style::inset`]
+   */
+  TaffyDimensionRect inset;
+  /**
+   * See [`Style::size`]
+   */
+  TaffyDimensionSize size;
+  /**
+   * See [`Style::min_size`]
+   */
+  TaffyDimensionSize min_size;
+  /**
+   * See [`Style::max_size`]
+   */
+  TaffyDimensionSize max_size;
+  /**
+   * See [`Style::aspect_ratio`]
+   */
+  float aspect_ratio;
+  /**
+   * See [`Style::margin`]
+   */
+  TaffyDimensionRect margin;
+  /**
+   * See [`Style::padding`]
+   */
+  TaffyDimensionRect padding;
+  /**
+   * See [`Style::border`]
+   */
+  TaffyDimensionRect border;
+  /**
+   * See [`Style::align_items`]
+   */
+  TaffyAlignItems align_items;
+  /**
+   * See [`Style::align_self`]
+   */
+  TaffyAlignItems align_self;
+  /**
+   * See [`Style::justify_items`]
+   */
+  TaffyAlignItems justify_items;
+  /**
+   * See [`Style::justify_self`]
+   */
+  TaffyAlignItems justify_self;
+  /**
+   * See [`Style::align_content`]
+   */
+  TaffyAlignContent align_content;
+  /**
+   * See [`Style::justify_content`]
+   */
+  TaffyAlignContent justify_content;
+  /**
+   * See [`Style::gap`]
+   */
+  TaffyDimensionSize gap;
+  /**
+   * See [`Style::flex_direction`]
+   */
+  TaffyFlexDirection flex_direction;
+  /**
+   * See [`Style::flex_wrap`]
+   */
+  TaffyFlexWrap flex_wrap;
+  /**
+   * See [`Style::flex_basis`]
+   */
+  TaffyDimension flex_basis;
+  /**
+   * See [`Style::flex_grow`]
+   */
+  float flex_grow;
+  /**
+   * See [`Style::flex_shrink`]
+   */
+  float flex_shrink;
+} TaffyStyle;
+
+/**
+ * A function that measures the content of a leaf node
+ *
+ * It is passed the user data given to [`taffy_node_set_measure`], the node, the known width and height of the node's
+ * content box (each of which is NaN if it isn't known) and the space available in each axis, and returns the size of
+ * the node's content.
+ */
+typedef TaffySize (*TaffyMeasureFunction)(void *user_data, TaffyNodeId node, float known_width, float known_height, TaffyAvailableSpace available_width, TaffyAvailableSpace available_height);
+
+/**
+ * Computes the layout of the subtree rooted at `node` in the given available space, measuring leaves with the
+ * functions set with [`taffy_node_set_measure`]
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and the measure functions of
+ * its nodes must be safe to call with their user data.
+ */
+TaffyReturnCode taffy_compute_layout(TaffyTree *tree,
+                                     TaffyNodeId node,
+                                     TaffyAvailableSpace available_width,
+                                     TaffyAvailableSpace available_height);
+
+/**
+ * Appends `child` to the children of `parent`
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_node_add_child(TaffyTree *tree, TaffyNodeId parent, TaffyNodeId child);
+
+/**
+ * Writes the id of the child of a node at `index` to `child`
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `child` must be null or
+ * valid for writes.
+ */
+TaffyReturnCode taffy_node_child_at_index(TaffyTree *tree,
+                                          TaffyNodeId node,
+                                          size_t index,
+                                          TaffyNodeId *child);
+
+/**
+ * Writes the number of children of a node to `count`
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `count` must be null or
+ * valid for writes.
+ */
+TaffyReturnCode taffy_node_child_count(TaffyTree *tree, TaffyNodeId node, size_t *count);
+
+/**
+ * Writes the computed layout of a node to `layout`
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `layout` must be null or
+ * valid for writes.
+ */
+TaffyReturnCode taffy_node_get_layout(TaffyTree *tree, TaffyNodeId node, TaffyLayout *layout);
+
+/**
+ * Inserts `child` into the children of `parent` at `index`, which may be the number of children to append it
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_node_insert_child_at_index(TaffyTree *tree,
+                                                 TaffyNodeId parent,
+                                                 size_t index,
+                                                 TaffyNodeId child);
+
+/**
+ * Marks the layout of a node and its ancestors as outdated, for example because the content of a measured leaf has
+ * changed
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_node_mark_dirty(TaffyTree *tree, TaffyNodeId node);
+
+/**
+ * Creates a new node without children, and writes its id to `node`
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, `style` must be null or point
+ * to a valid style, and `node` must be null or valid for writes.
+ */
+TaffyReturnCode taffy_node_new(TaffyTree *tree, const TaffyStyle *style, TaffyNodeId *node);
+
+/**
+ * Removes a node from the tree. Its children are detached from it, but aren't removed.
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_node_remove(TaffyTree *tree, TaffyNodeId node);
+
+/**
+ * Detaches `child` from `parent`, without removing it from the tree
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_node_remove_child(TaffyTree *tree, TaffyNodeId parent, TaffyNodeId child);
+
+/**
+ * Sets the function that measures a leaf node, and the user data passed to it, or removes it if `measure` is null
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed. `measure` must be safe to call
+ * with `user_data` whenever the layout of the tree is computed.
+ */
+TaffyReturnCode taffy_node_set_measure(TaffyTree *tree,
+                                       TaffyNodeId node,
+                                       TaffyMeasureFunction measure,
+                                       void *user_data);
+
+/**
+ * Sets the style of a node
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `style` must be null or
+ * point to a valid style.
+ */
+TaffyReturnCode taffy_node_set_style(TaffyTree *tree, TaffyNodeId node, const TaffyStyle *style);
+
+/**
+ * Returns the default style, which should be modified to create the style of a node
+ */
+TaffyStyle taffy_style_default(void);
+
+/**
+ * Frees a tree and all of its nodes. Does nothing if `tree` is null.
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+void taffy_tree_free(TaffyTree *tree);
+
+/**
+ * Creates a new, empty tree. It must be freed with [`taffy_tree_free`].
+ */
+TaffyTree *taffy_tree_new(void);
+
+/**
+ * Sets whether the layout of the tree is rounded to whole pixels. Rounding is enabled by default.
+ *
+ * # Safety
+ *
+ * `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+ */
+TaffyReturnCode taffy_tree_set_rounding(TaffyTree *tree, bool enabled);
+
+#endif  /* TAFFY_H */
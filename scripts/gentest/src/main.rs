@@ -222,7 +222,7 @@ fn generate_test(name: impl AsRef<str>, description: &Value) -> TokenStream {
         #[allow(non_snake_case)]
         fn #name() {
             #[allow(unused_imports)]
-            use taffy::{Layout, prelude::*};
+            use taffy::{assert_layout, prelude::*};
             let mut taffy = crate::new_test_tree();
             #set_rounding_mode
             #node_description
@@ -275,39 +275,21 @@ fn generate_assertions(ident: &str, node: &Value, use_rounding: bool) -> TokenSt
     let scroll_assertions = if is_scroll_container {
         quote!(
             #[cfg(feature = "content_size")]
-            assert_eq!(layout.scroll_width(), #scroll_width, "scroll_width of node {:?}. Expected {}. Actual {}", #ident, #scroll_width, layout.scroll_width());
-            #[cfg(feature = "content_size")]
-            assert_eq!(layout.scroll_height(), #scroll_height, "scroll_height of node {:?}. Expected {}. Actual {}", #ident, #scroll_height, layout.scroll_height());
+            assert_layout!(taffy, #ident, scroll_width: #scroll_width, scroll_height: #scroll_height);
         )
     } else {
         quote!()
     };
 
-    if use_rounding {
-        quote!(
-            let layout = taffy.layout(#ident).unwrap();
-            let Layout { size, location, .. } = layout;
-            assert_eq!(size.width, #width, "width of node {:?}. Expected {}. Actual {}", #ident,  #width, size.width);
-            assert_eq!(size.height, #height, "height of node {:?}. Expected {}. Actual {}", #ident,  #height, size.height);
-            assert_eq!(location.x, #x, "x of node {:?}. Expected {}. Actual {}", #ident,  #x, location.x);
-            assert_eq!(location.y, #y, "y of node {:?}. Expected {}. Actual {}", #ident,  #y, location.y);
-            #scroll_assertions
-
-            #children
-        )
-    } else {
-        quote!(
-            let layout = taffy.layout(#ident).unwrap();
-            let Layout { size, location, .. } = layout;
-            assert!((size.width - #width).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", #ident, #width, size.width);
-            assert!((size.height - #height).abs() < 0.1, "height of node {:?}. Expected {}. Actual {}", #ident, #height, size.height);
-            assert!((location.x - #x).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", #ident, #x, location.x);
-            assert!((location.y - #y).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", #ident, #y, location.y);
-            #scroll_assertions
-
-            #children
-        )
-    }
+    // Unrounded layouts are compared with a small tolerance, as the browser's layout has limited precision
+    let tolerance = if use_rounding { quote!() } else { quote!(tolerance: 0.1,) };
+
+    quote!(
+        assert_layout!(taffy, #ident, #tolerance x: #x, y: #y, width: #width, height: #height);
+        #scroll_assertions
+
+        #children
+    )
 }
 
 fn generate_node(ident: &str, node: &Value) -> TokenStream {
@@ -389,6 +371,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         Value::String(ref value) => match value.as_ref() {
             "none" => quote!(display: taffy::style::Display::None,),
             "block" => quote!(display: taffy::style::Display::Block,),
+            "flow-root" => quote!(display: taffy::style::Display::FlowRoot,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
@@ -783,7 +766,7 @@ fn generate_available_space(dimen: &serde_json::Map<String, Value>) -> TokenStre
             "min-content" => quote!(taffy::style::AvailableSpace::MaxContent),
             "px" => {
                 let value = value();
-                quote!(taffy::style::AvailableSpace::Definite(#value))
+                quote!(taffy::style::AvailableSpace::from_length(#value))
             }
             _ => unreachable!(),
         },
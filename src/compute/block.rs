@@ -1,18 +1,23 @@
 //! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
-use crate::geometry::{Line, Point, Rect, Size};
+use crate::geometry::{Line, Point, Rect, Scalar, Size};
 use crate::style::{AvailableSpace, CoreStyle, LengthPercentageAuto, Overflow, Position};
 use crate::style_helpers::TaffyMaxContent;
 use crate::tree::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
-use crate::util::debug::debug_log;
-use crate::util::sys::f32_max;
+use crate::util::debug::{debug_log, debug_record_algorithm};
 use crate::util::sys::Vec;
+use crate::util::sys::{scalar_max, scalar_min};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
-use crate::{BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, LayoutBlockContainer, TextAlign};
+use crate::{
+    BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, Clear, Float, LayoutBlockContainer, TextAlign,
+};
 
 #[cfg(feature = "content_size")]
-use super::common::content_size::compute_content_size_contribution;
+use super::common::content_size::{compute_content_size_contribution, compute_margin_box_content_size_contribution};
+use super::scratch::{release_buffer, take_buffer, ScratchItem};
+#[cfg(feature = "std")]
+use super::scratch::{shrink_pool_to_limit, LayoutScratch};
 
 /// Per-child data that is accumulated and modified over the course of the layout algorithm
 struct BlockItem {
@@ -25,47 +30,77 @@ struct BlockItem {
 
     /// Items that are tables don't have stretch sizing applied to them
     is_table: bool,
+    /// Whether the item is an in-flow inline-level item, which is laid out on a line alongside adjacent inline-level items
+    is_inline: bool,
 
     /// The base size of this item
-    size: Size<Option<f32>>,
+    size: Size<Option<Scalar>>,
     /// The minimum allowable size of this item
-    min_size: Size<Option<f32>>,
+    min_size: Size<Option<Scalar>>,
     /// The maximum allowable size of this item
-    max_size: Size<Option<f32>>,
+    max_size: Size<Option<Scalar>>,
 
     /// The overflow style of the item
     overflow: Point<Overflow>,
     /// The width of the item's scrollbars (if it has scrollbars)
-    scrollbar_width: f32,
+    scrollbar_width: Scalar,
 
     /// The position style of the item
     position: Position,
+    /// Whether the item is floated (absolutely positioned items are never floated)
+    float: Float,
+    /// Which preceding floats the item must be placed below
+    clear: Clear,
     /// The final offset of this item
     inset: Rect<LengthPercentageAuto>,
     /// The margin of this item
     margin: Rect<LengthPercentageAuto>,
     /// The margin of this item
-    padding: Rect<f32>,
+    padding: Rect<Scalar>,
     /// The margin of this item
-    border: Rect<f32>,
+    border: Rect<Scalar>,
     /// The sum of padding and border for this item
-    padding_border_sum: Size<f32>,
+    padding_border_sum: Size<Scalar>,
 
     /// The computed border box size of this item
-    computed_size: Size<f32>,
+    computed_size: Size<Scalar>,
     /// The computed "static position" of this item. The static position is the position
     /// taking into account padding, border, margins, and scrollbar_gutters but not inset
-    static_position: Point<f32>,
+    static_position: Point<Scalar>,
     /// Whether margins can be collapsed through this item
     can_be_collapsed_through: bool,
 }
 
+/// The retained block item buffers
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(super) struct BlockScratch {
+    /// Block item buffers that are available for reuse
+    items: Vec<Vec<BlockItem>>,
+}
+
+#[cfg(feature = "std")]
+impl BlockScratch {
+    /// Frees the retained buffers whose capacity exceeds `limit`
+    pub(super) fn shrink_to_limit(&mut self, limit: usize) {
+        shrink_pool_to_limit(&mut self.items, limit);
+    }
+}
+
+impl ScratchItem for BlockItem {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.block.items
+    }
+}
+
 /// Computes the layout of [`LayoutPartialTree`] according to the block layout algorithm
 pub fn compute_block_layout(
     tree: &mut impl LayoutBlockContainer,
     node_id: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    debug_record_algorithm!("block");
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_block_container_style(node_id);
 
@@ -172,12 +207,14 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     // Determine margin collapsing behaviour
     let own_margins_collapse_with_children = Line {
         start: vertical_margins_are_collapsible.start
+            && !style.is_flow_root()
             && !style.overflow().x.is_scroll_container()
             && !style.overflow().y.is_scroll_container()
             && style.position() == Position::Relative
             && padding.top == 0.0
             && border.top == 0.0,
         end: vertical_margins_are_collapsible.end
+            && !style.is_flow_root()
             && !style.overflow().x.is_scroll_container()
             && !style.overflow().y.is_scroll_container()
             && style.position() == Position::Relative
@@ -186,6 +223,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
             && size.height.is_none(),
     };
     let has_styles_preventing_being_collapsed_through = !style.is_block()
+        || style.is_flow_root()
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
         || style.position() == Position::Absolute
@@ -196,12 +234,25 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         || matches!(size.height, Some(h) if h > 0.0)
         || matches!(min_size.height, Some(h) if h > 0.0);
 
+    // Floats are contained by (and contribute to the height of) nodes that establish a new block formatting context.
+    // Nodes whose vertical margins can't collapse with their parent's are not in their parent's block formatting context.
+    let contains_floats = !vertical_margins_are_collapsible.start
+        || style.is_flow_root()
+        || style.overflow().x.is_scroll_container()
+        || style.overflow().y.is_scroll_container()
+        || style.position() == Position::Absolute;
+
+    // The baselines of scroll containers are synthesized from their border box by the parent rather than being taken
+    // from their content
+    let is_scroll_container = style.overflow().x.is_scroll_container() || style.overflow().y.is_scroll_container();
+
     let text_align = style.text_align();
 
     drop(style);
 
     // 1. Generate items
-    let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    let mut items = take_buffer(tree);
+    generate_item_list(tree, node_id, container_content_box_size, &mut items);
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
@@ -213,6 +264,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
 
     // Short-circuit if computing size and both dimensions known
     if let (RunMode::ComputeSize, Some(container_outer_height)) = (run_mode, known_dimensions.height) {
+        release_buffer(tree, items);
         return LayoutOutput::from_outer_size(Size { width: container_outer_width, height: container_outer_height });
     }
 
@@ -220,16 +272,23 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let resolved_padding = raw_padding.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
     let resolved_border = raw_border.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
     let resolved_content_box_inset = resolved_padding + resolved_border + scrollbar_gutter;
-    let (inflow_content_size, intrinsic_outer_height, first_child_top_margin_set, last_child_bottom_margin_set) =
-        perform_final_layout_on_in_flow_children(
-            tree,
-            &mut items,
-            container_outer_width,
-            content_box_inset,
-            resolved_content_box_inset,
-            text_align,
-            own_margins_collapse_with_children,
-        );
+    let (
+        inflow_content_size,
+        intrinsic_outer_height,
+        first_child_top_margin_set,
+        last_child_bottom_margin_set,
+        inflow_baselines,
+    ) = perform_final_layout_on_in_flow_children(
+        tree,
+        &mut items,
+        container_outer_width,
+        container_content_box_size.height,
+        content_box_inset,
+        resolved_content_box_inset,
+        text_align,
+        own_margins_collapse_with_children,
+        contains_floats,
+    );
     let container_outer_height = known_dimensions
         .height
         .unwrap_or(intrinsic_outer_height.maybe_clamp(min_size.height, max_size.height))
@@ -238,47 +297,66 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
 
     // Short-circuit if computing size
     if run_mode == RunMode::ComputeSize {
+        release_buffer(tree, items);
         return LayoutOutput::from_outer_size(final_outer_size);
     }
 
     // 4. Layout absolutely positioned children
+    // The containing block is the padding box, which browsers place inside the scrollbar gutter. So an inset of 0
+    // aligns an absolutely positioned child with the scrollbar rather than the outer border edge.
     let absolute_position_inset = resolved_border + scrollbar_gutter;
     let absolute_position_area = final_outer_size - absolute_position_inset.sum_axes();
     let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
-    let absolute_content_size =
-        perform_absolute_layout_on_absolute_children(tree, &items, absolute_position_area, absolute_position_offset);
-
-    // 5. Perform hidden layout on hidden children
+    let container_inner_width = container_outer_width - resolved_content_box_inset.horizontal_axis_sum();
+    let absolute_content_size = perform_absolute_layout_on_absolute_children(
+        tree,
+        &items,
+        absolute_position_area,
+        absolute_position_offset,
+        container_inner_width,
+        text_align,
+    );
+
+    // 5. Perform hidden layout on hidden children. Hidden children are the only children without an item, so this
+    // is skipped in the common case where there are none.
     let len = tree.child_count(node_id);
-    for order in 0..len {
-        let child = tree.get_child_id(node_id, order);
-        if tree.get_block_child_style(child).box_generation_mode() == BoxGenerationMode::None {
-            tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
-            tree.perform_child_layout(
-                child,
-                Size::NONE,
-                Size::NONE,
-                Size::MAX_CONTENT,
-                SizingMode::InherentSize,
-                Line::FALSE,
-            );
+    if items.len() < len {
+        for order in 0..len {
+            let child = tree.get_child_id(node_id, order);
+            if tree.get_block_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+                tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
+            }
         }
     }
 
     // 7. Determine whether this node can be collapsed through
-    let all_in_flow_children_can_be_collapsed_through =
-        items.iter().all(|item| item.position == Position::Absolute || item.can_be_collapsed_through);
+    let all_in_flow_children_can_be_collapsed_through = items
+        .iter()
+        .all(|item| item.position == Position::Absolute || item.float != Float::None || item.can_be_collapsed_through);
     let can_be_collapsed_through =
         !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
 
+    release_buffer(tree, items);
+
     #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
     let content_size = inflow_content_size.f32_max(absolute_content_size);
 
+    let baselines = if is_scroll_container { Line { start: None, end: None } } else { inflow_baselines };
+
     LayoutOutput {
         size: final_outer_size,
         #[cfg(feature = "content_size")]
         content_size,
-        first_baselines: Point::NONE,
+        first_baselines: Point { x: None, y: baselines.start },
+        last_baselines: Point { x: None, y: baselines.end },
         top_margin: if own_margins_collapse_with_children.start {
             first_child_top_margin_set
         } else {
@@ -296,72 +374,98 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     }
 }
 
-/// Create a `Vec` of `BlockItem` structs where each item in the `Vec` represents a child of the current node
+/// Fill `items` with `BlockItem` structs where each item represents a child of the current node
 #[inline]
 fn generate_item_list(
     tree: &impl LayoutBlockContainer,
     node: NodeId,
-    node_inner_size: Size<Option<f32>>,
-) -> Vec<BlockItem> {
-    tree.child_ids(node)
-        .map(|child_node_id| (child_node_id, tree.get_block_child_style(child_node_id)))
-        .filter(|(_, style)| style.box_generation_mode() != BoxGenerationMode::None)
-        .enumerate()
-        .map(|(order, (child_node_id, child_style))| {
-            let aspect_ratio = child_style.aspect_ratio();
-            let padding = child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
-            let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
-            let pb_sum = (padding + border).sum_axes();
-            let box_sizing_adjustment =
-                if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
-            BlockItem {
-                node_id: child_node_id,
-                order: order as u32,
-                is_table: child_style.is_table(),
-                size: child_style
-                    .size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
-                min_size: child_style
-                    .min_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
-                max_size: child_style
-                    .max_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
-                overflow: child_style.overflow(),
-                scrollbar_width: child_style.scrollbar_width(),
-                position: child_style.position(),
-                inset: child_style.inset(),
-                margin: child_style.margin(),
-                padding,
-                border,
-                padding_border_sum: pb_sum,
-
-                // Fields to be computed later (for now we initialise with dummy values)
-                computed_size: Size::zero(),
-                static_position: Point::zero(),
-                can_be_collapsed_through: false,
-            }
-        })
-        .collect()
+    node_inner_size: Size<Option<Scalar>>,
+    items: &mut Vec<BlockItem>,
+) {
+    items.extend(
+        tree.child_ids(node)
+            .map(|child_node_id| (child_node_id, tree.get_block_child_style(child_node_id)))
+            // Enumerate before filtering so that the order of each item matches its index in the list of children. Hidden
+            // children are assigned the order matching their index in step 5 of `compute_inner`.
+            .enumerate()
+            .filter(|(_, (_, style))| style.box_generation_mode() != BoxGenerationMode::None)
+            .map(|(order, (child_node_id, child_style))| {
+                let aspect_ratio = child_style.aspect_ratio();
+                let padding =
+                    child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
+                let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
+                let pb_sum = (padding + border).sum_axes();
+                let box_sizing_adjustment =
+                    if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+                BlockItem {
+                    node_id: child_node_id,
+                    order: order as u32,
+                    is_table: child_style.is_table(),
+                    // Floated and absolutely positioned items are blockified
+                    is_inline: child_style.is_inline()
+                        && child_style.position() != Position::Absolute
+                        && child_style.float() == Float::None,
+                    size: child_style
+                        .size()
+                        .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+                    min_size: child_style
+                        .min_size()
+                        .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+                    max_size: child_style
+                        .max_size()
+                        .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+                    overflow: child_style.overflow(),
+                    scrollbar_width: child_style.scrollbar_width(),
+                    position: child_style.position(),
+                    float: if child_style.position() == Position::Absolute { Float::None } else { child_style.float() },
+                    clear: child_style.clear(),
+                    inset: child_style.inset(),
+                    margin: child_style.margin(),
+                    padding,
+                    border,
+                    padding_border_sum: pb_sum,
+
+                    // Fields to be computed later (for now we initialise with dummy values)
+                    computed_size: Size::zero(),
+                    static_position: Point::zero(),
+                    can_be_collapsed_through: false,
+                }
+            }),
+    );
 }
 
-/// Compute the content-based width in the case that the width of the container is not known
+/// Compute the content-based width in the case that the width of the container is not known.
+///
+/// The container's width constraint is passed through to each child, so under a min-content constraint this is the
+/// largest min-content contribution of the children and under a max-content constraint it is the largest max-content
+/// contribution. Under a definite constraint each child is sized to fit within the available width.
 #[inline]
 fn determine_content_based_container_width(
     tree: &mut impl LayoutPartialTree,
     items: &[BlockItem],
     available_width: AvailableSpace,
-) -> f32 {
+) -> Scalar {
     let available_space = Size { width: available_width, height: AvailableSpace::MinContent };
 
     let mut max_child_width = 0.0;
-    for item in items.iter().filter(|item| item.position != Position::Absolute) {
+    // The combined width of a run of consecutive floats, which are placed side by side unless the width is
+    // constrained to the min-content size
+    let mut float_run_width = 0.0;
+    // The combined width of a run of consecutive inline-level items, which are placed on a single line unless the
+    // width is constrained
+    let mut inline_run_width = 0.0;
+    for item in items.iter() {
+        // Absolutely positioned items don't contribute to the width, but they do end a run of inline-level items
+        if item.position == Position::Absolute {
+            inline_run_width = 0.0;
+            continue;
+        }
         let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
 
         let width = known_dimensions.width.unwrap_or_else(|| {
@@ -369,38 +473,64 @@ fn determine_content_based_container_width(
                 .margin
                 .resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis))
                 .horizontal_axis_sum();
+            // Note: this performs a full layout of the child (rather than only computing its size). The final layout
+            // pass lays out each child with its width as a known dimension, and the final layout cache entry is
+            // reused when that width matches the width computed here, so children that end up at their content-based
+            // width are only laid out once.
             let size_and_baselines = tree.perform_child_layout(
                 item.node_id,
                 known_dimensions,
                 Size::NONE,
                 available_space.map_width(|w| w.maybe_sub(item_x_margin_sum)),
                 SizingMode::InherentSize,
-                Line::TRUE,
+                if item.float == Float::None && !item.is_inline { Line::TRUE } else { Line::FALSE },
             );
 
             size_and_baselines.size.width + item_x_margin_sum
         });
-        let width = f32_max(width, item.padding_border_sum.width);
+        let width = scalar_max(width, item.padding_border_sum.width);
+
+        let width = if item.float != Float::None && available_width != AvailableSpace::MinContent {
+            float_run_width = if item.clear == Clear::None { float_run_width + width } else { width };
+            inline_run_width = 0.0;
+            float_run_width
+        } else if item.is_inline && available_width != AvailableSpace::MinContent {
+            // Inline-level items wrap onto a new line rather than overflowing a definite width
+            float_run_width = 0.0;
+            inline_run_width = scalar_max((inline_run_width + width).maybe_min(available_width.into_option()), width);
+            inline_run_width
+        } else {
+            float_run_width = 0.0;
+            inline_run_width = 0.0;
+            width
+        };
 
-        max_child_width = f32_max(max_child_width, width);
+        max_child_width = scalar_max(max_child_width, width);
     }
 
     max_child_width
 }
 
-/// Compute each child's final size and position
+/// Compute each child's final size and position.
+///
+/// The returned baselines are the first baseline of the first in-flow child that has one and the last baseline of the
+/// last in-flow child that has one, relative to the container's border box (ignoring the children's relative offsets).
 #[inline]
+#[allow(clippy::too_many_arguments)]
 fn perform_final_layout_on_in_flow_children(
     tree: &mut impl LayoutPartialTree,
     items: &mut [BlockItem],
-    container_outer_width: f32,
-    content_box_inset: Rect<f32>,
-    resolved_content_box_inset: Rect<f32>,
+    container_outer_width: Scalar,
+    container_inner_height: Option<Scalar>,
+    content_box_inset: Rect<Scalar>,
+    resolved_content_box_inset: Rect<Scalar>,
     text_align: TextAlign,
     own_margins_collapse_with_children: Line<bool>,
-) -> (Size<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet) {
+    contains_floats: bool,
+) -> (Size<Scalar>, Scalar, CollapsibleMarginSet, CollapsibleMarginSet, Line<Option<Scalar>>) {
     // Resolve container_inner_width for sizing child nodes using initial content_box_inset
     let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
+    let container_inner_size = Size { width: Some(container_inner_width), height: container_inner_height };
     let parent_size = Size { width: Some(container_outer_width), height: None };
     let available_space =
         Size { width: AvailableSpace::Definite(container_inner_width), height: AvailableSpace::MinContent };
@@ -412,9 +542,121 @@ fn perform_final_layout_on_in_flow_children(
     let mut first_child_top_margin_set = CollapsibleMarginSet::ZERO;
     let mut active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
     let mut is_collapsing_with_first_margin_set = true;
-    for item in items.iter_mut() {
+    let mut baselines = Line { start: None, end: None };
+    let mut floats =
+        FloatContext::new(resolved_content_box_inset.left, resolved_content_box_inset.left + container_inner_width);
+    let mut item_index = 0;
+    while let Some(item) = items.get_mut(item_index) {
+        item_index += 1;
         if item.position == Position::Absolute {
             item.static_position = Point { x: resolved_content_box_inset.left, y: y_offset_for_absolute }
+        } else if item.is_inline {
+            // Consecutive inline-level items are wrapped in an anonymous block box which holds the lines that they are
+            // placed on. The anonymous box has no margins of its own, and can't be collapsed through.
+            let run_start = item_index - 1;
+            let run_len = items[run_start..].iter().take_while(|item| item.is_inline).count();
+            item_index = run_start + run_len;
+
+            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                0.0
+            } else {
+                active_collapsible_margin_set.resolve()
+            };
+            let run_top = committed_y_offset + y_margin_offset;
+            let run_output = perform_final_layout_on_inline_run(
+                tree,
+                &mut items[run_start..item_index],
+                container_outer_width,
+                container_inner_size,
+                Point { x: resolved_content_box_inset.left, y: run_top },
+                text_align,
+            );
+
+            if baselines.start.is_none() {
+                baselines.start = run_output.baselines.start;
+            }
+            if run_output.baselines.end.is_some() {
+                baselines.end = run_output.baselines.end;
+            }
+
+            #[cfg(feature = "content_size")]
+            {
+                inflow_content_size = inflow_content_size.f32_max(run_output.content_size);
+            }
+
+            is_collapsing_with_first_margin_set = false;
+            committed_y_offset = run_top + run_output.height;
+            active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
+            y_offset_for_absolute = committed_y_offset;
+        } else if item.float != Float::None {
+            let item_margin = item
+                .margin
+                .map(|margin| margin.resolve_to_option(container_outer_width, |val, basis| tree.calc(val, basis)))
+                .map(|margin| margin.unwrap_or(0.0));
+            let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
+
+            // Floats are sized to fit their content within the container and their margins never collapse
+            let item_layout = tree.perform_child_layout(
+                item.node_id,
+                known_dimensions,
+                parent_size,
+                available_space.map_width(|w| w.maybe_sub(item_margin.horizontal_axis_sum())),
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+
+            // A float may not be placed higher than the position of the next in-flow item, or higher than any
+            // preceding float that it clears
+            let flow_position = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                committed_y_offset
+            } else {
+                committed_y_offset + active_collapsible_margin_set.resolve()
+            };
+            let min_y =
+                floats.clearance_position(item.clear).map_or(flow_position, |pos| scalar_max(pos, flow_position));
+            let outer_position = floats.place(item.float, item_layout.size + item_margin.sum_axes(), min_y);
+
+            let inset_offset = resolve_relative_offset(tree, item.inset, container_inner_size);
+            let location = Point {
+                x: outer_position.x + item_margin.left + inset_offset.x,
+                y: outer_position.y + item_margin.top + inset_offset.y,
+            };
+
+            item.computed_size = item_layout.size;
+            item.static_position = location;
+
+            let scrollbar_size = Size {
+                width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+                height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+            };
+
+            tree.set_unrounded_layout(
+                item.node_id,
+                &Layout {
+                    order: item.order,
+                    size: item_layout.size,
+                    #[cfg(feature = "content_size")]
+                    content_size: item_layout.content_size,
+                    scrollbar_size,
+                    location,
+                    padding: item.padding,
+                    border: item.border,
+                    margin: item_margin,
+                    first_baseline: item_layout.first_baselines.y,
+                    last_baseline: item_layout.last_baselines.y,
+                },
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                inflow_content_size = inflow_content_size.f32_max(compute_margin_box_content_size_contribution(
+                    location,
+                    item_layout.size,
+                    item_layout.content_size,
+                    item.overflow,
+                    item_margin,
+                ));
+            }
         } else {
             let item_margin = item
                 .margin
@@ -428,6 +670,8 @@ fn perform_final_layout_on_in_flow_children(
                     .map_width(|width| {
                         // TODO: Allow stretch-sizing to be conditional, as there are exceptions.
                         // e.g. Table children of blocks do not stretch fit
+                        // The height of an item with an aspect ratio is derived from this stretched width when the
+                        // item itself is laid out
                         Some(
                             width
                                 .unwrap_or(container_inner_width - item_non_auto_x_margin_sum)
@@ -453,37 +697,41 @@ fn perform_final_layout_on_in_flow_children(
             // Expand auto margins to fill available space
             // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
             // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
-            let free_x_space = f32_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
+            let free_x_space = scalar_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
             let x_axis_auto_margin_size = {
                 let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
                 if auto_margin_count > 0 {
-                    free_x_space / auto_margin_count as f32
+                    free_x_space / auto_margin_count as Scalar
                 } else {
                     0.0
                 }
             };
-            let resolved_margin = Rect {
+            let mut resolved_margin = Rect {
                 left: item_margin.left.unwrap_or(x_axis_auto_margin_size),
                 right: item_margin.right.unwrap_or(x_axis_auto_margin_size),
                 top: top_margin_set.resolve(),
                 bottom: bottom_margin_set.resolve(),
             };
 
-            // Resolve item inset
-            let inset = item.inset.zip_size(Size { width: container_inner_width, height: 0.0 }, |p, s| {
-                p.maybe_resolve(s, |val, basis| tree.calc(val, basis))
-            });
-            let inset_offset = Point {
-                x: inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0),
-                y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
-            };
+            let inset_offset = resolve_relative_offset(tree, item.inset, container_inner_size);
 
-            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+            let mut y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start
+            {
                 0.0
             } else {
                 active_collapsible_margin_set.collapse_with_margin(resolved_margin.top).resolve()
             };
 
+            // If the item clears a preceding float that extends below its top border edge, then clearance is
+            // introduced to move it below the float. Clearance prevents the item's margins collapsing through it.
+            let clearance_position = floats
+                .clearance_position(item.clear)
+                .filter(|&position| position > committed_y_offset + y_margin_offset);
+            if let Some(position) = clearance_position {
+                y_margin_offset = position - committed_y_offset;
+            }
+            let has_clearance = clearance_position.is_some();
+
             item.computed_size = item_layout.size;
             item.can_be_collapsed_through = item_layout.margins_can_collapse_through;
             item.static_position = Point {
@@ -495,20 +743,25 @@ fn perform_final_layout_on_in_flow_children(
                 y: committed_y_offset + inset_offset.y + y_margin_offset,
             };
 
+            let item_y_offset = committed_y_offset + y_margin_offset;
+            if baselines.start.is_none() {
+                baselines.start = item_layout.first_baselines.y.map(|baseline| item_y_offset + baseline);
+            }
+            if let Some(baseline) = item_layout.last_baselines.y {
+                baselines.end = Some(item_y_offset + baseline);
+            }
+
             // Apply alignment
             let item_outer_width = item_layout.size.width + resolved_margin.horizontal_axis_sum();
-            if item_outer_width < container_inner_width {
-                match text_align {
-                    TextAlign::Auto => {
-                        // Do nothing
-                    }
-                    TextAlign::LegacyLeft => {
-                        // Do nothing. Left aligned by default.
-                    }
-                    TextAlign::LegacyRight => location.x += container_inner_width - item_outer_width,
-                    TextAlign::LegacyCenter => location.x += (container_inner_width - item_outer_width) / 2.0,
-                }
-            }
+            let alignment_offset = legacy_text_align_offset(text_align, container_inner_width - item_outer_width);
+            location.x += alignment_offset;
+
+            // The horizontal margins, border, padding and width must sum to the width of the containing block. When
+            // they don't (the item is over-constrained, or its width was clamped), the end margin is ignored and its
+            // used value is whatever space remains after the item.
+            // See: https://www.w3.org/TR/CSS21/visudet.html#blockwidth
+            // TODO: ignore the start margin instead once the `direction` property is supported
+            resolved_margin.right = container_inner_width - final_size.width - resolved_margin.left - alignment_offset;
 
             let scrollbar_size = Size {
                 width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
@@ -527,22 +780,27 @@ fn perform_final_layout_on_in_flow_children(
                     padding: item.padding,
                     border: item.border,
                     margin: resolved_margin,
+                    first_baseline: item_layout.first_baselines.y,
+                    last_baseline: item_layout.last_baselines.y,
                 },
             );
 
             #[cfg(feature = "content_size")]
             {
-                inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
+                inflow_content_size = inflow_content_size.f32_max(compute_margin_box_content_size_contribution(
                     location,
                     final_size,
                     item_layout.content_size,
                     item.overflow,
+                    resolved_margin,
                 ));
             }
 
             // Update first_child_top_margin_set
             if is_collapsing_with_first_margin_set {
-                if item.can_be_collapsed_through {
+                if has_clearance {
+                    is_collapsing_with_first_margin_set = false;
+                } else if item.can_be_collapsed_through {
                     first_child_top_margin_set = first_child_top_margin_set
                         .collapse_with_set(top_margin_set)
                         .collapse_with_set(bottom_margin_set);
@@ -553,7 +811,7 @@ fn perform_final_layout_on_in_flow_children(
             }
 
             // Update active_collapsible_margin_set
-            if item.can_be_collapsed_through {
+            if item.can_be_collapsed_through && !has_clearance {
                 active_collapsible_margin_set = active_collapsible_margin_set
                     .collapse_with_set(top_margin_set)
                     .collapse_with_set(bottom_margin_set);
@@ -570,19 +828,298 @@ fn perform_final_layout_on_in_flow_children(
     let bottom_y_margin_offset =
         if own_margins_collapse_with_children.end { 0.0 } else { last_child_bottom_margin_set.resolve() };
 
-    committed_y_offset += resolved_content_box_inset.bottom + bottom_y_margin_offset;
-    let content_height = f32_max(0.0, committed_y_offset);
-    (inflow_content_size, content_height, first_child_top_margin_set, last_child_bottom_margin_set)
+    committed_y_offset += bottom_y_margin_offset;
+    if contains_floats {
+        if let Some(floats_bottom) = floats.bottom() {
+            committed_y_offset = scalar_max(committed_y_offset, floats_bottom);
+        }
+    }
+    committed_y_offset += resolved_content_box_inset.bottom;
+    let content_height = scalar_max(0.0, committed_y_offset);
+    (inflow_content_size, content_height, first_child_top_margin_set, last_child_bottom_margin_set, baselines)
+}
+
+/// The result of laying out a run of consecutive inline-level items
+struct InlineRunOutput {
+    /// The combined height of the line boxes
+    height: Scalar,
+    /// The baselines of the first and last line boxes, relative to the container's border box
+    baselines: Line<Option<Scalar>>,
+    /// The size of the content of the items, relative to the container's border box
+    #[cfg(feature = "content_size")]
+    content_size: Size<Scalar>,
+}
+
+/// Lay out a run of consecutive inline-level items on lines, starting at the specified position.
+///
+/// This is a minimal inline formatting context: each item is laid out as an atomic box that is sized to fit within the
+/// container, and items are placed side by side until the next item doesn't fit, at which point a new line is started.
+/// Items on a line are aligned by their baselines (or by their bottom margin edge if they don't have a baseline), and
+/// lines are stacked vertically. Items are never split across lines, and lines don't flow around floats.
+#[inline]
+fn perform_final_layout_on_inline_run(
+    tree: &mut impl LayoutPartialTree,
+    items: &mut [BlockItem],
+    container_outer_width: Scalar,
+    container_inner_size: Size<Option<Scalar>>,
+    start: Point<Scalar>,
+    text_align: TextAlign,
+) -> InlineRunOutput {
+    let container_inner_width = container_inner_size.width.unwrap_or(0.0);
+    let parent_size = Size { width: Some(container_outer_width), height: None };
+
+    let item_layouts: Vec<(LayoutOutput, Rect<Scalar>)> = items
+        .iter()
+        .map(|item| {
+            let item_margin =
+                item.margin.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
+            let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
+            let available_space = Size {
+                width: AvailableSpace::Definite(container_inner_width - item_margin.horizontal_axis_sum()),
+                height: AvailableSpace::MinContent,
+            };
+            let item_layout = tree.perform_child_layout(
+                item.node_id,
+                known_dimensions,
+                parent_size,
+                available_space,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+            (item_layout, item_margin)
+        })
+        .collect();
+
+    // The distance from the top margin edge of an item to its baseline. Items without a baseline are aligned by their
+    // bottom margin edge.
+    let ascent = |(item_layout, item_margin): &(LayoutOutput, Rect<Scalar>)| {
+        item_margin.top + item_layout.last_baselines.y.unwrap_or(item_layout.size.height + item_margin.bottom)
+    };
+
+    let mut baselines = Line { start: None, end: None };
+    #[cfg(feature = "content_size")]
+    let mut content_size = Size::ZERO;
+    let mut line_top = start.y;
+    let mut line_start = 0;
+    while line_start < items.len() {
+        // Fill the line until the next item doesn't fit. Each line holds at least one item.
+        let mut line_width = 0.0;
+        let mut line_end = line_start;
+        while let Some((item_layout, item_margin)) = item_layouts.get(line_end) {
+            let item_outer_width = item_layout.size.width + item_margin.horizontal_axis_sum();
+            if line_end > line_start && line_width + item_outer_width > container_inner_width {
+                break;
+            }
+            line_width += item_outer_width;
+            line_end += 1;
+        }
+
+        let line_layouts = &item_layouts[line_start..line_end];
+        let line_ascent = line_layouts.iter().map(ascent).fold(0.0, scalar_max);
+        let line_descent = line_layouts
+            .iter()
+            .map(|layout| layout.0.size.height + layout.1.vertical_axis_sum() - ascent(layout))
+            .fold(0.0, scalar_max);
+        let line_baseline = line_top + line_ascent;
+
+        let mut x = start.x + legacy_text_align_offset(text_align, container_inner_width - line_width);
+        for (item, layout) in items[line_start..line_end].iter_mut().zip(line_layouts) {
+            let (item_layout, item_margin) = layout;
+            let inset_offset = resolve_relative_offset(tree, item.inset, container_inner_size);
+            let location = Point {
+                x: x + item_margin.left + inset_offset.x,
+                y: line_baseline - ascent(layout) + item_margin.top + inset_offset.y,
+            };
+            x += item_layout.size.width + item_margin.horizontal_axis_sum();
+
+            item.computed_size = item_layout.size;
+            item.static_position = location;
+            item.can_be_collapsed_through = false;
+
+            let scrollbar_size = Size {
+                width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+                height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+            };
+
+            tree.set_unrounded_layout(
+                item.node_id,
+                &Layout {
+                    order: item.order,
+                    size: item_layout.size,
+                    #[cfg(feature = "content_size")]
+                    content_size: item_layout.content_size,
+                    scrollbar_size,
+                    location,
+                    padding: item.padding,
+                    border: item.border,
+                    margin: *item_margin,
+                    first_baseline: item_layout.first_baselines.y,
+                    last_baseline: item_layout.last_baselines.y,
+                },
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                content_size = content_size.f32_max(compute_margin_box_content_size_contribution(
+                    location,
+                    item_layout.size,
+                    item_layout.content_size,
+                    item.overflow,
+                    *item_margin,
+                ));
+            }
+        }
+
+        if baselines.start.is_none() {
+            baselines.start = Some(line_baseline);
+        }
+        baselines.end = Some(line_baseline);
+        line_top = line_baseline + line_descent;
+        line_start = line_end;
+    }
+
+    InlineRunOutput {
+        height: line_top - start.y,
+        baselines,
+        #[cfg(feature = "content_size")]
+        content_size,
+    }
+}
+
+/// Resolve the offset of a relatively positioned item from its inset
+#[inline]
+///
+/// Percentage insets are resolved against the container's content box. If the container's height is indefinite then
+/// percentage `top` and `bottom` insets are treated as `auto`.
+fn resolve_relative_offset(
+    tree: &impl LayoutPartialTree,
+    inset: Rect<LengthPercentageAuto>,
+    container_inner_size: Size<Option<Scalar>>,
+) -> Point<Scalar> {
+    let inset = inset.zip_size(container_inner_size, |p, s| p.maybe_resolve(s, |val, basis| tree.calc(val, basis)));
+    Point {
+        x: inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0),
+        y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
+    }
+}
+
+/// A float that has been placed within a block container. The edges are the outer (margin box) edges of the float,
+/// relative to the container's border box.
+#[derive(Debug, Clone, Copy)]
+struct PlacedFloat {
+    /// Which side of the container the float is placed against
+    float: Float,
+    /// The top outer edge of the float
+    top: Scalar,
+    /// The bottom outer edge of the float
+    bottom: Scalar,
+    /// The left outer edge of the float
+    left: Scalar,
+    /// The right outer edge of the float
+    right: Scalar,
+}
+
+/// The floats that have been placed within a block container
+/// See <https://www.w3.org/TR/CSS21/visuren.html#float-position>
+struct FloatContext {
+    /// The left edge of the container's content box
+    content_left: Scalar,
+    /// The right edge of the container's content box
+    content_right: Scalar,
+    /// The floats that have been placed so far, in the order they were placed
+    floats: Vec<PlacedFloat>,
+}
+
+impl FloatContext {
+    /// Create an empty float context for a container with the given content box edges
+    fn new(content_left: Scalar, content_right: Scalar) -> Self {
+        Self { content_left, content_right, floats: Vec::new() }
+    }
+
+    /// The lowest bottom outer edge of the placed floats that are cleared by `clear` (if any)
+    fn clearance_position(&self, clear: Clear) -> Option<Scalar> {
+        self.floats.iter().filter(|float| clear.clears(float.float)).map(|float| float.bottom).reduce(scalar_max)
+    }
+
+    /// The lowest bottom outer edge of all of the placed floats (if any)
+    fn bottom(&self) -> Option<Scalar> {
+        self.floats.iter().map(|float| float.bottom).reduce(scalar_max)
+    }
+
+    /// Place a float with the given outer size as high as possible (but no higher than `min_y`) and as far to the left
+    /// or right as possible. Returns the position of the float's top-left outer edge.
+    fn place(&mut self, float: Float, outer_size: Size<Scalar>, min_y: Scalar) -> Point<Scalar> {
+        // The top of a float may not be higher than the top of any preceding float
+        let mut y = self.floats.iter().map(|float| float.top).fold(min_y, scalar_max);
+
+        loop {
+            let overlapping = self
+                .floats
+                .iter()
+                .filter(|float| float.bottom > y && (float.top < y + outer_size.height || float.top <= y));
+            let left_edge = overlapping
+                .clone()
+                .filter(|float| float.float == Float::Left)
+                .map(|float| float.right)
+                .fold(self.content_left, scalar_max);
+            let right_edge = overlapping
+                .clone()
+                .filter(|float| float.float == Float::Right)
+                .map(|float| float.left)
+                .fold(self.content_right, scalar_min);
+
+            // If the float doesn't fit beside the floats that it overlaps, then try again below the first of them to end
+            match overlapping.map(|float| float.bottom).reduce(scalar_min) {
+                Some(next_y) if right_edge - left_edge < outer_size.width => y = next_y,
+                _ => {
+                    let x = match float {
+                        Float::Right => right_edge - outer_size.width,
+                        _ => left_edge,
+                    };
+                    let placed = PlacedFloat {
+                        float,
+                        top: y,
+                        bottom: y + outer_size.height,
+                        left: x,
+                        right: x + outer_size.width,
+                    };
+                    self.floats.push(placed);
+                    return Point { x, y };
+                }
+            }
+        }
+    }
+}
+
+/// Compute the horizontal offset of a block-level child within its container's content box due to the container's
+/// legacy `text-align` behaviour, given the free space remaining after the child's margin box is placed.
+#[inline]
+fn legacy_text_align_offset(text_align: TextAlign, free_space: Scalar) -> Scalar {
+    if free_space <= 0.0 {
+        return 0.0;
+    }
+    match text_align {
+        TextAlign::Auto => 0.0,
+        // Left aligned by default
+        TextAlign::LegacyLeft => 0.0,
+        TextAlign::LegacyRight => free_space,
+        TextAlign::LegacyCenter => free_space / 2.0,
+    }
 }
 
 /// Perform absolute layout on all absolutely positioned children.
+///
+/// Children without a horizontal inset are placed at their static position, which takes into account the container's
+/// legacy `text-align` behaviour in the same way as it is applied to in-flow children.
 #[inline]
 fn perform_absolute_layout_on_absolute_children(
     tree: &mut impl LayoutBlockContainer,
     items: &[BlockItem],
-    area_size: Size<f32>,
-    area_offset: Point<f32>,
-) -> Size<f32> {
+    area_size: Size<Scalar>,
+    area_offset: Point<Scalar>,
+    container_inner_width: Scalar,
+    text_align: TextAlign,
+) -> Size<Scalar> {
     let area_width = area_size.width;
     let area_height = area_size.height;
 
@@ -640,7 +1177,7 @@ fn perform_absolute_layout_on_absolute_children(
         //   - Item has both left and right inset properties set
         if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
             let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
-            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+            known_dimensions.width = Some(scalar_max(new_width_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
         }
 
@@ -649,7 +1186,7 @@ fn perform_absolute_layout_on_absolute_children(
         //   - Item has both top and bottom inset properties set
         if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
             let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
-            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+            known_dimensions.height = Some(scalar_max(new_height_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
         }
 
@@ -671,7 +1208,7 @@ fn perform_absolute_layout_on_absolute_children(
             left: if left.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
             right: if right.is_some() { margin.right.unwrap_or(0.0) } else { 0.0 },
             top: if top.is_some() { margin.top.unwrap_or(0.0) } else { 0.0 },
-            bottom: if bottom.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
+            bottom: if bottom.is_some() { margin.bottom.unwrap_or(0.0) } else { 0.0 },
         };
 
         // Expand auto margins to fill available space
@@ -705,7 +1242,7 @@ fn perform_absolute_layout_on_absolute_children(
                     {
                         0.0
                     } else if auto_margin_count > 0 {
-                        free_space.width / auto_margin_count as f32
+                        free_space.width / auto_margin_count as Scalar
                     } else {
                         0.0
                     }
@@ -717,7 +1254,7 @@ fn perform_absolute_layout_on_absolute_children(
                     {
                         0.0
                     } else if auto_margin_count > 0 {
-                        free_space.height / auto_margin_count as f32
+                        free_space.height / auto_margin_count as Scalar
                     } else {
                         0.0
                     }
@@ -744,7 +1281,10 @@ fn perform_absolute_layout_on_absolute_children(
                 .map(|left| left + resolved_margin.left)
                 .or(right.map(|right| area_size.width - final_size.width - right - resolved_margin.right))
                 .maybe_add(area_offset.x)
-                .unwrap_or(item.static_position.x + resolved_margin.left),
+                .unwrap_or_else(|| {
+                    let free_space = container_inner_width - final_size.width - resolved_margin.horizontal_axis_sum();
+                    item.static_position.x + resolved_margin.left + legacy_text_align_offset(text_align, free_space)
+                }),
             y: top
                 .map(|top| top + resolved_margin.top)
                 .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
@@ -770,6 +1310,8 @@ fn perform_absolute_layout_on_absolute_children(
                 padding,
                 border,
                 margin: resolved_margin,
+                first_baseline: layout_output.first_baselines.y,
+                last_baseline: layout_output.last_baselines.y,
             },
         );
 
@@ -786,3 +1328,134 @@ fn perform_absolute_layout_on_absolute_children(
 
     absolute_content_size
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Line, Point, Rect, Size};
+    use crate::style::{Display, Overflow, Position, Style};
+    use crate::style_helpers::{auto, length, zero, TaffyMaxContent};
+    use crate::tree::{LayoutOutput, LayoutPartialTreeExt, NodeId, SizingMode};
+    use crate::TaffyTree;
+
+    /// A block-level style with the given fixed size
+    fn block(width: f32, height: f32) -> Style {
+        Style { display: Display::Block, size: Size::from_lengths(width, height), ..Default::default() }
+    }
+
+    /// Lay out a block container whose children are two flex containers of the given heights, and return its output
+    #[cfg(feature = "flexbox")]
+    fn layout_two_line_block(overflow: Overflow) -> LayoutOutput {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let lines: [NodeId; 2] = [15.0, 25.0].map(|height| {
+            let leaf = taffy.new_leaf(Style { size: Size::from_lengths(20.0, height), ..Default::default() }).unwrap();
+            taffy.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[leaf]).unwrap()
+        });
+        let style =
+            Style { display: Display::Block, overflow: Point { x: overflow, y: overflow }, ..Default::default() };
+        let root = taffy.new_with_children(style, &lines).unwrap();
+
+        let output = taffy.as_layout_tree().perform_child_layout(
+            root,
+            Size::NONE,
+            Size::NONE,
+            Size::MAX_CONTENT,
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+        output
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn first_and_last_baselines_come_from_first_and_last_children() {
+        let output = layout_two_line_block(Overflow::Visible);
+        assert_eq!(output.first_baselines.y, Some(15.0));
+        assert_eq!(output.last_baselines.y, Some(40.0));
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn scroll_container_has_no_baselines() {
+        let output = layout_two_line_block(Overflow::Hidden);
+        assert_eq!(output.first_baselines.y, None);
+        assert_eq!(output.last_baselines.y, None);
+    }
+
+    #[test]
+    fn sibling_and_parent_margins_collapse() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let first = taffy
+            .new_leaf(Style {
+                margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(10.0) },
+                ..block(50.0, 10.0)
+            })
+            .unwrap();
+        let second = taffy
+            .new_leaf(Style {
+                margin: Rect { left: zero(), right: zero(), top: length(20.0), bottom: zero() },
+                ..block(50.0, 10.0)
+            })
+            .unwrap();
+        let parent = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { left: zero(), right: zero(), top: length(8.0), bottom: zero() },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(block(100.0, 100.0), &[parent]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The first child's top margin collapses with the parent's, and the sibling margins collapse with each other
+        assert_eq!(taffy.layout(parent).unwrap().location.y, 8.0);
+        assert_eq!(taffy.layout(first).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(second).unwrap().location.y, 30.0);
+        assert_eq!(taffy.layout(parent).unwrap().size.height, 40.0);
+    }
+
+    #[test]
+    fn over_constrained_end_margin_is_ignored() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                margin: Rect { left: length(30.0), right: length(30.0), top: zero(), bottom: zero() },
+                ..block(100.0, 10.0)
+            })
+            .unwrap();
+        let root = taffy.new_with_children(block(120.0, 10.0), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.location.x, 30.0);
+        assert_eq!(layout.margin.left, 30.0);
+        assert_eq!(layout.margin.right, -10.0);
+    }
+
+    #[test]
+    fn absolute_children_are_positioned_against_padding_box() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let in_flow = taffy.new_leaf(block(50.0, 10.0)).unwrap();
+        let absolute = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                inset: Rect { left: auto(), right: length(10.0), top: auto(), bottom: length(10.0) },
+                ..block(20.0, 20.0)
+            })
+            .unwrap();
+        let static_absolute = taffy.new_leaf(Style { position: Position::Absolute, ..block(20.0, 20.0) }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { border: length(5.0), ..block(100.0, 100.0) },
+                &[in_flow, absolute, static_absolute],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: 65.0, y: 65.0 });
+        // Absolutely positioned children without insets are placed at their static position
+        assert_eq!(taffy.layout(static_absolute).unwrap().location, Point { x: 5.0, y: 15.0 });
+    }
+}
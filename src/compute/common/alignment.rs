@@ -1,4 +1,5 @@
 //! Generic CSS alignment code that is shared between both the Flexbox and CSS Grid algorithms.
+use crate::geometry::Scalar;
 use crate::style::AlignContent;
 
 /// Implement fallback alignment.
@@ -6,14 +7,15 @@ use crate::style::AlignContent;
 /// In addition to the spec at https://www.w3.org/TR/css-align-3/ this implementation follows
 /// the resolution of https://github.com/w3c/csswg-drafts/issues/10154
 pub(crate) fn apply_alignment_fallback(
-    free_space: f32,
+    free_space: Scalar,
     num_items: usize,
     mut alignment_mode: AlignContent,
     mut is_safe: bool,
 ) -> AlignContent {
     // Fallback occurs in two cases:
 
-    // 1. If there is only a single item being aligned and alignment is a distributed alignment keyword
+    // 1. If there is only a single item being aligned (or there is no positive free space to distribute) and
+    //    alignment is a distributed alignment keyword, then the keyword's fallback alignment is used
     //    https://www.w3.org/TR/css-align-3/#distribution-values
     if num_items <= 1 || free_space <= 0.0 {
         (alignment_mode, is_safe) = match alignment_mode {
@@ -40,13 +42,13 @@ pub(crate) fn apply_alignment_fallback(
 /// CSS Grid does not apply gaps as part of alignment, so the gap parameter should
 /// always be set to zero for CSS Grid.
 pub(crate) fn compute_alignment_offset(
-    free_space: f32,
+    free_space: Scalar,
     num_items: usize,
-    gap: f32,
+    gap: Scalar,
     alignment_mode: AlignContent,
     layout_is_flex_reversed: bool,
     is_first: bool,
-) -> f32 {
+) -> Scalar {
     if is_first {
         match alignment_mode {
             AlignContent::Start => 0.0,
@@ -70,14 +72,14 @@ pub(crate) fn compute_alignment_offset(
             AlignContent::SpaceBetween => 0.0,
             AlignContent::SpaceAround => {
                 if free_space >= 0.0 {
-                    (free_space / num_items as f32) / 2.0
+                    (free_space / num_items as Scalar) / 2.0
                 } else {
                     free_space / 2.0
                 }
             }
             AlignContent::SpaceEvenly => {
                 if free_space >= 0.0 {
-                    free_space / (num_items + 1) as f32
+                    free_space / (num_items + 1) as Scalar
                 } else {
                     free_space / 2.0
                 }
@@ -92,9 +94,9 @@ pub(crate) fn compute_alignment_offset(
             AlignContent::FlexEnd => 0.0,
             AlignContent::Center => 0.0,
             AlignContent::Stretch => 0.0,
-            AlignContent::SpaceBetween => free_space / (num_items - 1) as f32,
-            AlignContent::SpaceAround => free_space / num_items as f32,
-            AlignContent::SpaceEvenly => free_space / (num_items + 1) as f32,
+            AlignContent::SpaceBetween => free_space / (num_items - 1) as Scalar,
+            AlignContent::SpaceAround => free_space / num_items as Scalar,
+            AlignContent::SpaceEvenly => free_space / (num_items + 1) as Scalar,
         }
     }
 }
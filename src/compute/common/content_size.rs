@@ -1,23 +1,23 @@
 //! Generic CSS content size code that is shared between all CSS algorithms.
-use crate::geometry::{Point, Size};
+use crate::geometry::{Point, Rect, Scalar, Size};
 use crate::style::Overflow;
-use crate::util::sys::f32_max;
+use crate::util::sys::scalar_max;
 
 #[inline(always)]
 /// Determine how much width/height a given node contributes to it's parent's content size
 pub(crate) fn compute_content_size_contribution(
-    location: Point<f32>,
-    size: Size<f32>,
-    content_size: Size<f32>,
+    location: Point<Scalar>,
+    size: Size<Scalar>,
+    content_size: Size<Scalar>,
     overflow: Point<Overflow>,
-) -> Size<f32> {
+) -> Size<Scalar> {
     let size_content_size_contribution = Size {
         width: match overflow.x {
-            Overflow::Visible => f32_max(size.width, content_size.width),
+            Overflow::Visible => scalar_max(size.width, content_size.width),
             _ => size.width,
         },
         height: match overflow.y {
-            Overflow::Visible => f32_max(size.height, content_size.height),
+            Overflow::Visible => scalar_max(size.height, content_size.height),
             _ => size.height,
         },
     };
@@ -30,3 +30,25 @@ pub(crate) fn compute_content_size_contribution(
         Size::ZERO
     }
 }
+
+#[inline(always)]
+/// Determine how much width/height a given in-flow node contributes to it's parent's content size when its margin box
+/// (not just its border box) contributes to the parent's scrollable overflow. Negative end margins can't shrink the
+/// contribution below that of the node's border box.
+pub(crate) fn compute_margin_box_content_size_contribution(
+    location: Point<Scalar>,
+    size: Size<Scalar>,
+    content_size: Size<Scalar>,
+    overflow: Point<Overflow>,
+    margin: Rect<Scalar>,
+) -> Size<Scalar> {
+    let contribution = compute_content_size_contribution(location, size, content_size, overflow);
+    if size.has_non_zero_area() {
+        contribution.f32_max(Size {
+            width: location.x + size.width + scalar_max(margin.right, 0.0),
+            height: location.y + size.height + scalar_max(margin.bottom, 0.0),
+        })
+    } else {
+        contribution
+    }
+}
@@ -0,0 +1,295 @@
+//! Layout of absolutely positioned children of a flex container
+//! <https://www.w3.org/TR/css-flexbox-1/#abspos-items>
+use crate::geometry::{Line, Point, Rect, Scalar, Size};
+use crate::style::{AlignSelf, AvailableSpace, JustifyContent, Overflow, Position};
+use crate::style::{CoreStyle, FlexboxItemStyle};
+use crate::tree::{Layout, SizingMode};
+use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt, NodeId};
+use crate::util::sys::scalar_max;
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::{BoxGenerationMode, BoxSizing};
+
+use super::AlgoConstants;
+
+/// Perform absolute layout on all absolutely positioned children.
+#[inline]
+pub(super) fn perform_absolute_layout_on_absolute_children(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    constants: &AlgoConstants,
+) -> Size<Scalar> {
+    let container_width = constants.container_size.width;
+    let container_height = constants.container_size.height;
+    let inset_relative_size =
+        constants.container_size - constants.border.sum_axes() - constants.scrollbar_gutter.into();
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut content_size = Size::ZERO;
+
+    for order in 0..tree.child_count(node) {
+        let child = tree.get_child_id(node, order);
+        let child_style = tree.get_flexbox_child_style(child);
+
+        // Skip items that are display:none or are not position:absolute
+        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
+        {
+            continue;
+        }
+
+        let overflow = child_style.overflow();
+        let scrollbar_width = child_style.scrollbar_width();
+        let aspect_ratio = child_style.aspect_ratio();
+        let align_self = child_style.align_self().unwrap_or(constants.align_items);
+        let margin = child_style
+            .margin()
+            .map(|margin| margin.resolve_to_option(inset_relative_size.width, |val, basis| tree.calc(val, basis)));
+        let padding =
+            child_style.padding().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
+        let border =
+            child_style.border().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
+        let padding_border_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment =
+            if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+        // Resolve inset
+        // Insets are resolved against the container size minus border
+        let left =
+            child_style.inset().left.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
+        let right =
+            child_style.inset().right.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
+        let top = child_style.inset().top.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
+        let bottom =
+            child_style.inset().bottom.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
+
+        // Compute known dimensions from min/max/inherent size styles
+        let style_size = child_style
+            .size()
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let min_size = child_style
+            .min_size()
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .or(padding_border_sum.map(Some))
+            .maybe_max(padding_border_sum);
+        let max_size = child_style
+            .max_size()
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+        drop(child_style);
+
+        // Fill in width from left/right and reapply aspect ratio if:
+        //   - Width is not already known
+        //   - Item has both left and right inset properties set
+        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+            let new_width_raw = inset_relative_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+            known_dimensions.width = Some(scalar_max(new_width_raw, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+
+        // Fill in height from top/bottom and reapply aspect ratio if:
+        //   - Height is not already known
+        //   - Item has both top and bottom inset properties set
+        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+            let new_height_raw =
+                inset_relative_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+            known_dimensions.height = Some(scalar_max(new_height_raw, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+        let layout_output = tree.perform_child_layout(
+            child,
+            known_dimensions,
+            constants.node_inner_size,
+            Size {
+                width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
+                height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
+            },
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+        let measured_size = layout_output.size;
+        let final_size = known_dimensions.unwrap_or(measured_size).maybe_clamp(min_size, max_size);
+
+        let non_auto_margin = margin.map(|m| m.unwrap_or(0.0));
+
+        let free_space = Size {
+            width: constants.container_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
+            height: constants.container_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
+        }
+        .f32_max(Size::ZERO);
+
+        // Expand auto margins to fill available space
+        let resolved_margin = {
+            let auto_margin_size = Size {
+                width: {
+                    let auto_margin_count = margin.left.is_none() as u8 + margin.right.is_none() as u8;
+                    if auto_margin_count > 0 {
+                        free_space.width / auto_margin_count as Scalar
+                    } else {
+                        0.0
+                    }
+                },
+                height: {
+                    let auto_margin_count = margin.top.is_none() as u8 + margin.bottom.is_none() as u8;
+                    if auto_margin_count > 0 {
+                        free_space.height / auto_margin_count as Scalar
+                    } else {
+                        0.0
+                    }
+                },
+            };
+
+            Rect {
+                left: margin.left.unwrap_or(auto_margin_size.width),
+                right: margin.right.unwrap_or(auto_margin_size.width),
+                top: margin.top.unwrap_or(auto_margin_size.height),
+                bottom: margin.bottom.unwrap_or(auto_margin_size.height),
+            }
+        };
+
+        // Determine flex-relative insets
+        let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
+        let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
+
+        // Apply main-axis alignment
+        // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
+        let offset_main = if let Some(start) = start_main {
+            start + constants.border.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
+        } else if let Some(end) = end_main {
+            constants.container_size.main(constants.dir)
+                - constants.border.main_end(constants.dir)
+                - constants.scrollbar_gutter.main(constants.dir)
+                - final_size.main(constants.dir)
+                - end
+                - resolved_margin.main_end(constants.dir)
+        } else {
+            // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
+            // treat it as if it wasn't set (and thus we default to FlexStart behaviour).
+            // The flex-relative values are mirrored for reversed flex directions (the main-start edge
+            // of a `row-reverse` container is its right edge).
+            match (constants.justify_content.unwrap_or(JustifyContent::FlexStart), constants.dir.is_reverse()) {
+                (JustifyContent::Start, _)
+                | (JustifyContent::SpaceBetween, false)
+                | (JustifyContent::Stretch, false)
+                | (JustifyContent::FlexStart, false)
+                | (JustifyContent::FlexEnd, true) => {
+                    constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
+                }
+                (JustifyContent::End, _)
+                | (JustifyContent::FlexEnd, false)
+                | (JustifyContent::FlexStart, true)
+                | (JustifyContent::SpaceBetween, true)
+                | (JustifyContent::Stretch, true) => {
+                    constants.container_size.main(constants.dir)
+                        - constants.content_box_inset.main_end(constants.dir)
+                        - final_size.main(constants.dir)
+                        - resolved_margin.main_end(constants.dir)
+                }
+                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                    (constants.container_size.main(constants.dir)
+                        + constants.content_box_inset.main_start(constants.dir)
+                        - constants.content_box_inset.main_end(constants.dir)
+                        - final_size.main(constants.dir)
+                        + resolved_margin.main_start(constants.dir)
+                        - resolved_margin.main_end(constants.dir))
+                        / 2.0
+                }
+            }
+        };
+
+        // Apply cross-axis alignment
+        // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
+        let offset_cross = if let Some(start) = start_cross {
+            start + constants.border.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
+        } else if let Some(end) = end_cross {
+            constants.container_size.cross(constants.dir)
+                - constants.border.cross_end(constants.dir)
+                - constants.scrollbar_gutter.cross(constants.dir)
+                - final_size.cross(constants.dir)
+                - end
+                - resolved_margin.cross_end(constants.dir)
+        } else {
+            match (align_self, constants.is_wrap_reverse) {
+                // Stretch alignment does not apply to absolutely positioned items
+                // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
+                // Note: Stretch should be FlexStart not Start when we support both
+                (AlignSelf::Start, _)
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, false)
+                | (AlignSelf::FlexEnd, true) => {
+                    constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
+                }
+                (AlignSelf::End, _)
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, true)
+                | (AlignSelf::FlexEnd, false) => {
+                    constants.container_size.cross(constants.dir)
+                        - constants.content_box_inset.cross_end(constants.dir)
+                        - final_size.cross(constants.dir)
+                        - resolved_margin.cross_end(constants.dir)
+                }
+                (AlignSelf::Center, _) => {
+                    (constants.container_size.cross(constants.dir)
+                        + constants.content_box_inset.cross_start(constants.dir)
+                        - constants.content_box_inset.cross_end(constants.dir)
+                        - final_size.cross(constants.dir)
+                        + resolved_margin.cross_start(constants.dir)
+                        - resolved_margin.cross_end(constants.dir))
+                        / 2.0
+                }
+            }
+        };
+
+        let location = match constants.is_row {
+            true => Point { x: offset_main, y: offset_cross },
+            false => Point { x: offset_cross, y: offset_main },
+        };
+        let scrollbar_size = Size {
+            width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
+            height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
+        };
+        tree.set_unrounded_layout(
+            child,
+            &Layout {
+                order: order as u32,
+                size: final_size,
+                #[cfg(feature = "content_size")]
+                content_size: layout_output.content_size,
+                scrollbar_size,
+                location,
+                padding,
+                border,
+                margin: resolved_margin,
+                first_baseline: layout_output.first_baselines.y,
+                last_baseline: layout_output.last_baselines.y,
+            },
+        );
+
+        #[cfg(feature = "content_size")]
+        {
+            let size_content_size_contribution = Size {
+                width: match overflow.x {
+                    Overflow::Visible => scalar_max(final_size.width, layout_output.content_size.width),
+                    _ => final_size.width,
+                },
+                height: match overflow.y {
+                    Overflow::Visible => scalar_max(final_size.height, layout_output.content_size.height),
+                    _ => final_size.height,
+                },
+            };
+            if size_content_size_contribution.has_non_zero_area() {
+                let content_size_contribution = Size {
+                    width: location.x + size_content_size_contribution.width,
+                    height: location.y + size_content_size_contribution.height,
+                };
+                content_size = content_size.f32_max(content_size_contribution);
+            }
+        }
+    }
+
+    content_size
+}
@@ -0,0 +1,415 @@
+//! Main and cross axis alignment of flex items and flex lines, and the final positioning of each item
+//! <https://www.w3.org/TR/css-flexbox-1/#alignment>
+use crate::compute::common::alignment::apply_alignment_fallback;
+use crate::compute::common::alignment::compute_alignment_offset;
+#[cfg(feature = "content_size")]
+use crate::compute::common::content_size::compute_margin_box_content_size_contribution;
+use crate::geometry::{Line, Point, Rect, Scalar, Size};
+use crate::style::FlexDirection;
+use crate::style::{AlignSelf, JustifyContent, Overflow};
+use crate::tree::LayoutFlexboxContainer;
+use crate::tree::{Layout, LayoutOutput, SizingMode};
+
+use super::{sum_axis_gaps, AlgoConstants, FlexItem, FlexLine};
+
+/// Distribute any remaining free space.
+///
+/// # [9.5. Main-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#main-alignment)
+///
+/// - [**Distribute any remaining free space**](https://www.w3.org/TR/css-flexbox-1/#algo-main-align). For each flex line:
+///
+///   1. If the remaining free space is positive and at least one main-axis margin on this line is `auto`, distribute the free space equally among these margins.
+///      Otherwise, set all `auto` margins to zero.
+///
+///   2. Align the items along the main-axis per `justify-content`.
+#[inline]
+pub(super) fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+    for line in flex_lines {
+        let total_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+        let used_space: Scalar = total_main_axis_gap
+            + line.items.iter().map(|child| child.outer_target_size.main(constants.dir)).sum::<Scalar>();
+        let free_space = constants.inner_container_size.main(constants.dir) - used_space;
+        let mut num_auto_margins = 0;
+
+        for child in line.items.iter_mut() {
+            if child.margin_is_auto.main_start(constants.dir) {
+                num_auto_margins += 1;
+            }
+            if child.margin_is_auto.main_end(constants.dir) {
+                num_auto_margins += 1;
+            }
+        }
+
+        if free_space > 0.0 && num_auto_margins > 0 {
+            let margin = free_space / num_auto_margins as Scalar;
+
+            for child in line.items.iter_mut() {
+                if child.margin_is_auto.main_start(constants.dir) {
+                    if constants.is_row {
+                        child.margin.left = margin;
+                    } else {
+                        child.margin.top = margin;
+                    }
+                }
+                if child.margin_is_auto.main_end(constants.dir) {
+                    if constants.is_row {
+                        child.margin.right = margin;
+                    } else {
+                        child.margin.bottom = margin;
+                    }
+                }
+            }
+        } else {
+            let num_items = line.items.len();
+            let layout_reverse = constants.dir.is_reverse();
+            let gap = constants.gap.main(constants.dir);
+            let is_safe = false; // TODO: Implement safe alignment
+            let raw_justify_content_mode = constants.justify_content.unwrap_or(JustifyContent::FlexStart);
+            let justify_content_mode =
+                apply_alignment_fallback(free_space, num_items, raw_justify_content_mode, is_safe);
+
+            let justify_item = |(i, child): (usize, &mut FlexItem)| {
+                child.offset_main =
+                    compute_alignment_offset(free_space, num_items, gap, justify_content_mode, layout_reverse, i == 0);
+            };
+
+            if layout_reverse {
+                line.items.iter_mut().rev().enumerate().for_each(justify_item);
+            } else {
+                line.items.iter_mut().enumerate().for_each(justify_item);
+            }
+        }
+    }
+}
+
+/// Resolve cross-axis `auto` margins.
+///
+/// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
+///
+/// - [**Resolve cross-axis `auto` margins**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-margins).
+///   If a flex item has auto cross-axis margins:
+///
+///   - If its outer cross size (treating those auto margins as zero) is less than the cross size of its flex line,
+///     distribute the difference in those sizes equally to the auto margins.
+///
+///   - Otherwise, if the block-start or inline-start margin (whichever is in the cross axis) is auto, set it to zero.
+///     Set the opposite margin so that the outer cross size of the item equals the cross size of its flex line.
+#[inline]
+pub(super) fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+    for line in flex_lines {
+        let line_cross_size = line.cross_size;
+        let max_baseline: Scalar = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+        // The largest distance between a baseline-aligned item's baseline and its outer cross-end (bottom) edge. This is
+        // needed to place the baseline-sharing group flush against the cross-start edge of lines in wrap-reverse containers.
+        let max_baseline_descent: Scalar = line
+            .items
+            .iter()
+            .filter(|child| child.align_self == AlignSelf::Baseline)
+            .map(|child| child.outer_target_size.cross(constants.dir) - child.baseline)
+            .fold(0.0, |acc, x| acc.max(x));
+
+        for child in line.items.iter_mut() {
+            let free_space = line_cross_size - child.outer_target_size.cross(constants.dir);
+
+            if child.margin_is_auto.cross_start(constants.dir) && child.margin_is_auto.cross_end(constants.dir) {
+                if constants.is_row {
+                    child.margin.top = free_space / 2.0;
+                    child.margin.bottom = free_space / 2.0;
+                } else {
+                    child.margin.left = free_space / 2.0;
+                    child.margin.right = free_space / 2.0;
+                }
+            } else if child.margin_is_auto.cross_start(constants.dir) {
+                if constants.is_row {
+                    child.margin.top = free_space;
+                } else {
+                    child.margin.left = free_space;
+                }
+            } else if child.margin_is_auto.cross_end(constants.dir) {
+                if constants.is_row {
+                    child.margin.bottom = free_space;
+                } else {
+                    child.margin.right = free_space;
+                }
+            } else {
+                // 14. Align all flex items along the cross-axis.
+                child.offset_cross =
+                    align_flex_items_along_cross_axis(child, free_space, max_baseline, max_baseline_descent, constants);
+            }
+        }
+    }
+}
+
+/// Align all flex items along the cross-axis.
+///
+/// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
+///
+/// - [**Align all flex items along the cross-axis**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-align) per `align-self`,
+///   if neither of the item's cross-axis margins are `auto`.
+#[inline]
+fn align_flex_items_along_cross_axis(
+    child: &FlexItem,
+    free_space: Scalar,
+    max_baseline: Scalar,
+    max_baseline_descent: Scalar,
+    constants: &AlgoConstants,
+) -> Scalar {
+    match child.align_self {
+        AlignSelf::Start => 0.0,
+        AlignSelf::FlexStart => {
+            if constants.is_wrap_reverse {
+                free_space
+            } else {
+                0.0
+            }
+        }
+        AlignSelf::End => free_space,
+        AlignSelf::FlexEnd => {
+            if constants.is_wrap_reverse {
+                0.0
+            } else {
+                free_space
+            }
+        }
+        AlignSelf::Center => free_space / 2.0,
+        AlignSelf::Baseline => {
+            if constants.is_row && constants.is_wrap_reverse {
+                // The cross-start edge of the line is its bottom edge, so the baseline-sharing group is aligned such that
+                // the item with the largest descent is placed flush against it.
+                free_space + child.outer_target_size.cross(constants.dir) - child.baseline - max_baseline_descent
+            } else if constants.is_row {
+                max_baseline - child.baseline
+            } else {
+                // Until we support vertical writing modes, baseline alignment only makes sense if
+                // the constants.direction is row, so we treat it as flex-start alignment in columns.
+                if constants.is_wrap_reverse {
+                    free_space
+                } else {
+                    0.0
+                }
+            }
+        }
+        AlignSelf::Stretch => {
+            if constants.is_wrap_reverse {
+                free_space
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+/// Align all flex lines per `align-content`.
+///
+/// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
+///
+/// - [**Align all flex lines**](https://www.w3.org/TR/css-flexbox-1/#algo-line-align) per `align-content`.
+#[inline]
+pub(super) fn align_flex_lines_per_align_content(
+    flex_lines: &mut [FlexLine],
+    constants: &AlgoConstants,
+    total_cross_size: Scalar,
+) {
+    let num_lines = flex_lines.len();
+    let gap = constants.gap.cross(constants.dir);
+    let total_cross_axis_gap = sum_axis_gaps(gap, num_lines);
+    let free_space = constants.inner_container_size.cross(constants.dir) - total_cross_size - total_cross_axis_gap;
+    let is_safe = false; // TODO: Implement safe alignment
+
+    let align_content_mode = apply_alignment_fallback(free_space, num_lines, constants.align_content, is_safe);
+
+    let align_line = |(i, line): (usize, &mut FlexLine)| {
+        line.offset_cross =
+            compute_alignment_offset(free_space, num_lines, gap, align_content_mode, constants.is_wrap_reverse, i == 0);
+    };
+
+    if constants.is_wrap_reverse {
+        flex_lines.iter_mut().rev().enumerate().for_each(align_line);
+    } else {
+        flex_lines.iter_mut().enumerate().for_each(align_line);
+    }
+}
+
+/// Calculates the layout for a flex-item
+#[allow(clippy::too_many_arguments)]
+fn calculate_flex_item(
+    tree: &mut impl LayoutFlexboxContainer,
+    item: &mut FlexItem,
+    total_offset_main: &mut Scalar,
+    total_offset_cross: Scalar,
+    line_offset_cross: Scalar,
+    #[cfg(feature = "content_size")] total_content_size: &mut Size<Scalar>,
+    container_size: Size<Scalar>,
+    node_inner_size: Size<Option<Scalar>>,
+    direction: FlexDirection,
+) {
+    let layout_output = item.perform_layout(
+        tree,
+        item.target_size.map(|s| s.into()),
+        node_inner_size,
+        container_size.map(|s| s.into()),
+        SizingMode::ContentSize,
+        Line::FALSE,
+    );
+    let LayoutOutput {
+        size,
+        #[cfg(feature = "content_size")]
+        content_size,
+        ..
+    } = layout_output;
+
+    let offset_main = *total_offset_main
+        + item.offset_main
+        + item.margin.main_start(direction)
+        + (item.inset.main_start(direction).or(item.inset.main_end(direction).map(|pos| -pos)).unwrap_or(0.0));
+
+    let offset_cross = total_offset_cross
+        + item.offset_cross
+        + line_offset_cross
+        + item.margin.cross_start(direction)
+        + (item.inset.cross_start(direction).or(item.inset.cross_end(direction).map(|pos| -pos)).unwrap_or(0.0));
+
+    // Store the position of the item's first baseline relative to the container's border box (ignoring relative
+    // positioning) so that it can be used to compute the container's own baseline.
+    let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
+    item.baseline = if direction.is_row() {
+        total_offset_cross + line_offset_cross + item.offset_cross + item.margin.cross_start(direction) + inner_baseline
+    } else {
+        *total_offset_main + item.offset_main + item.margin.main_start(direction) + inner_baseline
+    };
+
+    let location = match direction.is_row() {
+        true => Point { x: offset_main, y: offset_cross },
+        false => Point { x: offset_cross, y: offset_main },
+    };
+    let scrollbar_size = Size {
+        width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+        height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
+    };
+
+    tree.set_unrounded_layout(
+        item.node,
+        &Layout {
+            order: item.order,
+            size,
+            #[cfg(feature = "content_size")]
+            content_size,
+            scrollbar_size,
+            location,
+            padding: item.padding,
+            border: item.border,
+            margin: item.margin,
+            first_baseline: layout_output.first_baselines.y,
+            last_baseline: layout_output.last_baselines.y,
+        },
+    );
+
+    *total_offset_main += item.offset_main + item.margin.main_axis_sum(direction) + size.main(direction);
+
+    #[cfg(feature = "content_size")]
+    {
+        // Flex items contribute their margin box (not just their border box) to the container's scrollable overflow.
+        // Relative offsets are already included in `location`, so they are accounted for here too.
+        let contribution =
+            compute_margin_box_content_size_contribution(location, size, content_size, item.overflow, item.margin);
+        *total_content_size = total_content_size.f32_max(contribution);
+    }
+}
+
+/// Calculates the layout line
+#[allow(clippy::too_many_arguments)]
+fn calculate_layout_line(
+    tree: &mut impl LayoutFlexboxContainer,
+    line: &mut FlexLine,
+    total_offset_cross: &mut Scalar,
+    #[cfg(feature = "content_size")] content_size: &mut Size<Scalar>,
+    container_size: Size<Scalar>,
+    node_inner_size: Size<Option<Scalar>>,
+    padding_border: Rect<Scalar>,
+    direction: FlexDirection,
+) {
+    let mut total_offset_main = padding_border.main_start(direction);
+    let line_offset_cross = line.offset_cross;
+
+    if direction.is_reverse() {
+        for item in line.items.iter_mut().rev() {
+            calculate_flex_item(
+                tree,
+                item,
+                &mut total_offset_main,
+                *total_offset_cross,
+                line_offset_cross,
+                #[cfg(feature = "content_size")]
+                content_size,
+                container_size,
+                node_inner_size,
+                direction,
+            );
+        }
+    } else {
+        for item in line.items.iter_mut() {
+            calculate_flex_item(
+                tree,
+                item,
+                &mut total_offset_main,
+                *total_offset_cross,
+                line_offset_cross,
+                #[cfg(feature = "content_size")]
+                content_size,
+                container_size,
+                node_inner_size,
+                direction,
+            );
+        }
+    }
+
+    *total_offset_cross += line_offset_cross + line.cross_size;
+}
+
+/// Do a final layout pass and collect the resulting layouts.
+#[inline]
+pub(super) fn final_layout_pass(
+    tree: &mut impl LayoutFlexboxContainer,
+    flex_lines: &mut [FlexLine],
+    constants: &AlgoConstants,
+) -> Size<Scalar> {
+    let mut total_offset_cross = constants.content_box_inset.cross_start(constants.dir);
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut content_size = Size::ZERO;
+
+    if constants.is_wrap_reverse {
+        for line in flex_lines.iter_mut().rev() {
+            calculate_layout_line(
+                tree,
+                line,
+                &mut total_offset_cross,
+                #[cfg(feature = "content_size")]
+                &mut content_size,
+                constants.container_size,
+                constants.node_inner_size,
+                constants.content_box_inset,
+                constants.dir,
+            );
+        }
+    } else {
+        for line in flex_lines.iter_mut() {
+            calculate_layout_line(
+                tree,
+                line,
+                &mut total_offset_cross,
+                #[cfg(feature = "content_size")]
+                &mut content_size,
+                constants.container_size,
+                constants.node_inner_size,
+                constants.content_box_inset,
+                constants.dir,
+            );
+        }
+    }
+
+    content_size.width += constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.x;
+    content_size.height += constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.y;
+
+    content_size
+}
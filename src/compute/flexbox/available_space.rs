@@ -0,0 +1,44 @@
+//! Determining the space available to the items of a flex container
+//! <https://www.w3.org/TR/css-flexbox-1/#line-sizing>
+use crate::geometry::{Scalar, Size};
+use crate::style::AvailableSpace;
+use crate::util::MaybeMath;
+
+use super::AlgoConstants;
+
+/// Determine the available main and cross space for the flex items.
+///
+/// # [9.2. Line Length Determination](https://www.w3.org/TR/css-flexbox-1/#line-sizing)
+///
+/// - [**Determine the available main and cross space for the flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-available).
+///
+/// For each dimension, if that dimension of the flex container’s content box is a definite size, use that;
+/// if that dimension of the flex container is being sized under a min or max-content constraint, the available space in that dimension is that constraint;
+/// otherwise, subtract the flex container’s margin, border, and padding from the space available to the flex container in that dimension and use that value.
+/// **This might result in an infinite value**.
+#[inline]
+#[must_use]
+pub(super) fn determine_available_space(
+    known_dimensions: Size<Option<Scalar>>,
+    outer_available_space: Size<AvailableSpace>,
+    constants: &AlgoConstants,
+) -> Size<AvailableSpace> {
+    // Note: min/max/preferred size styles have already been applied to known_dimensions in the `compute` function above
+    let width = match known_dimensions.width {
+        Some(node_width) => AvailableSpace::Definite(node_width - constants.content_box_inset.horizontal_axis_sum()),
+        None => outer_available_space
+            .width
+            .maybe_sub(constants.margin.horizontal_axis_sum())
+            .maybe_sub(constants.content_box_inset.horizontal_axis_sum()),
+    };
+
+    let height = match known_dimensions.height {
+        Some(node_height) => AvailableSpace::Definite(node_height - constants.content_box_inset.vertical_axis_sum()),
+        None => outer_available_space
+            .height
+            .maybe_sub(constants.margin.vertical_axis_sum())
+            .maybe_sub(constants.content_box_inset.vertical_axis_sum()),
+    };
+
+    Size { width, height }
+}
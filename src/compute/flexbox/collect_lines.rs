@@ -0,0 +1,369 @@
+//! Breaking flex items into lines and determining the main size of the flex container
+//! <https://www.w3.org/TR/css-flexbox-1/#main-sizing>
+use crate::geometry::{Line, Scalar, Size};
+use crate::style::AvailableSpace;
+use crate::tree::LayoutFlexboxContainer;
+use crate::tree::SizingMode;
+use crate::util::debug::debug_log;
+use crate::util::sys::{scalar_max, ChildrenVec};
+use crate::util::MaybeMath;
+
+use super::{sum_axis_gaps, AlgoConstants, FlexItem, FlexLine};
+
+/// Collect flex items into flex lines.
+///
+/// # [9.3. Main Size Determination](https://www.w3.org/TR/css-flexbox-1/#main-sizing)
+///
+/// - [**Collect flex items into flex lines**](https://www.w3.org/TR/css-flexbox-1/#algo-line-break):
+///
+///     - If the flex container is single-line, collect all the flex items into a single flex line.
+///
+///     - Otherwise, starting from the first uncollected item, collect consecutive items one by one until the first time that the next collected item would not fit into the flex container’s inner main size
+///       (or until a forced break is encountered, see [§10 Fragmenting Flex Layout](https://www.w3.org/TR/css-flexbox-1/#pagination)).
+///       If the very first uncollected item wouldn't fit, collect just it into the line.
+///
+///       For this step, the size of a flex item is its outer hypothetical main size. (**Note: This can be negative**.)
+///
+///       Repeat until all flex items have been collected into flex lines.
+///
+///       **Note that the "collect as many" line will collect zero-sized flex items onto the end of the previous line even if the last non-zero item exactly "filled up" the line**.
+///
+/// The lines are collected into `lines`, which should be empty.
+#[inline]
+pub(super) fn collect_flex_lines<'a>(
+    constants: &AlgoConstants,
+    available_space: Size<AvailableSpace>,
+    flex_items: &'a mut ChildrenVec<FlexItem>,
+    mut lines: ChildrenVec<FlexLine<'a>>,
+) -> ChildrenVec<FlexLine<'a>> {
+    if !constants.is_wrap {
+        lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+        lines
+    } else {
+        // Note: a max-content constraint is treated as infinite available space regardless of the container's max size.
+        // The max size is applied to the container's main size after line collection, and the lines are then collected
+        // again against the clamped size (see `compute_preliminary`).
+        let main_axis_available_space =
+            match (available_space.main(constants.dir), constants.max_size.main(constants.dir)) {
+                (AvailableSpace::MaxContent, _) => AvailableSpace::MaxContent,
+                (available_space, Some(max_size)) => AvailableSpace::Definite(
+                    available_space.into_option().unwrap_or(max_size).maybe_max(constants.min_size.main(constants.dir)),
+                ),
+                (available_space, None) => available_space,
+            };
+
+        match main_axis_available_space {
+            // If we're sizing under a max-content constraint then the flex items will never wrap
+            // (at least for now - future extensions to the CSS spec may add provisions for forced wrap points)
+            AvailableSpace::MaxContent => {
+                lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+                lines
+            }
+            // If flex-wrap is Wrap and we're sizing under a min-content constraint, then we take every possible wrapping opportunity
+            // and place each item in it's own line
+            AvailableSpace::MinContent => {
+                #[cfg(any(feature = "std", feature = "alloc"))]
+                lines.reserve(flex_items.len());
+                let mut items = &mut flex_items[..];
+                while !items.is_empty() {
+                    let (line_items, rest) = items.split_at_mut(1);
+                    lines.push(FlexLine { items: line_items, cross_size: 0.0, offset_cross: 0.0 });
+                    items = rest;
+                }
+                lines
+            }
+            AvailableSpace::Definite(main_axis_available_space) => {
+                let mut flex_items = &mut flex_items[..];
+                let main_axis_gap = constants.gap.main(constants.dir);
+
+                while !flex_items.is_empty() {
+                    // Find index of the first item in the next line
+                    // (or the last item if all remaining items are in the current line)
+                    let mut line_length = 0.0;
+                    let index = flex_items
+                        .iter()
+                        .enumerate()
+                        .find(|&(idx, child)| {
+                            // Gaps only occur between items (not before the first one or after the last one)
+                            // So first item in the line does not contribute a gap to the line length
+                            let gap_contribution = if idx == 0 { 0.0 } else { main_axis_gap };
+                            line_length += child.hypothetical_outer_size.main(constants.dir) + gap_contribution;
+                            line_length > main_axis_available_space && idx != 0
+                        })
+                        .map(|(idx, _)| idx)
+                        .unwrap_or(flex_items.len());
+
+                    let (items, rest) = flex_items.split_at_mut(index);
+                    lines.push(FlexLine { items, cross_size: 0.0, offset_cross: 0.0 });
+                    flex_items = rest;
+                }
+                lines
+            }
+        }
+    }
+}
+
+/// Determine the container's main size (if not already known)
+pub(super) fn determine_container_main_size(
+    tree: &mut impl LayoutFlexboxContainer,
+    available_space: Size<AvailableSpace>,
+    lines: &mut [FlexLine<'_>],
+    constants: &mut AlgoConstants,
+) {
+    let dir = constants.dir;
+    let main_content_box_inset = constants.content_box_inset.main_axis_sum(constants.dir);
+
+    let outer_main_size: Scalar = constants.node_outer_size.main(constants.dir).unwrap_or_else(|| {
+        match available_space.main(dir) {
+            AvailableSpace::Definite(main_axis_available_space) => {
+                let longest_line_length: Scalar = lines
+                    .iter()
+                    .map(|line| {
+                        let line_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+                        let total_target_size = line
+                            .items
+                            .iter()
+                            .map(|child| {
+                                let padding_border_sum = (child.padding + child.border).main_axis_sum(constants.dir);
+                                (child.flex_basis.maybe_max(child.min_size.main(constants.dir))
+                                    + child.margin.main_axis_sum(constants.dir))
+                                .max(padding_border_sum)
+                            })
+                            .sum::<Scalar>();
+                        total_target_size + line_main_axis_gap
+                    })
+                    .max_by(|a, b| a.total_cmp(b))
+                    .unwrap_or(0.0);
+                let size = longest_line_length + main_content_box_inset;
+                if lines.len() > 1 {
+                    scalar_max(size, main_axis_available_space)
+                } else {
+                    size
+                }
+            }
+            AvailableSpace::MinContent if constants.is_wrap => {
+                let longest_line_length: Scalar = lines
+                    .iter()
+                    .map(|line| {
+                        let line_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+                        let total_target_size = line
+                            .items
+                            .iter()
+                            .map(|child| {
+                                let padding_border_sum = (child.padding + child.border).main_axis_sum(constants.dir);
+                                (child.flex_basis.maybe_max(child.min_size.main(constants.dir))
+                                    + child.margin.main_axis_sum(constants.dir))
+                                .max(padding_border_sum)
+                            })
+                            .sum::<Scalar>();
+                        total_target_size + line_main_axis_gap
+                    })
+                    .max_by(|a, b| a.total_cmp(b))
+                    .unwrap_or(0.0);
+                longest_line_length + main_content_box_inset
+            }
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => {
+                // Define a base main_size variable. This is mutated once for iteration over the outer
+                // loop over the flex lines as:
+                //   "The flex container’s max-content size is the largest sum of the afore-calculated sizes of all items within a single line."
+                let mut main_size = 0.0;
+
+                for line in lines.iter_mut() {
+                    for item in line.items.iter_mut() {
+                        let style_min = item.min_size.main(constants.dir);
+                        let style_preferred = item.size.main(constants.dir);
+                        let style_max = item.max_size.main(constants.dir);
+
+                        // The spec seems a bit unclear on this point (my initial reading was that the `.maybe_max(style_preferred)` should
+                        // not be included here), however this matches both Chrome and Firefox as of 9th March 2023.
+                        //
+                        // Spec: https://www.w3.org/TR/css-flexbox-1/#intrinsic-item-contributions
+                        // Spec modification: https://www.w3.org/TR/css-flexbox-1/#change-2016-max-contribution
+                        // Issue: https://github.com/w3c/csswg-drafts/issues/1435
+                        // Gentest: xpadding_border_overrides_size_flex_basis_0.html (disabled as Chrome does not use the chosen flex fraction)
+                        let clamping_basis = Some(item.flex_basis).maybe_max(style_preferred);
+                        let flex_basis_min = clamping_basis.filter(|_| item.flex_shrink == 0.0);
+                        let flex_basis_max = clamping_basis.filter(|_| item.flex_grow == 0.0);
+
+                        let min_main_size = style_min
+                            .maybe_max(flex_basis_min)
+                            .or(flex_basis_min)
+                            .unwrap_or(item.resolved_minimum_main_size)
+                            .max(item.resolved_minimum_main_size);
+                        let max_main_size =
+                            style_max.maybe_min(flex_basis_max).or(flex_basis_max).unwrap_or(Scalar::INFINITY);
+
+                        let content_contribution = match (min_main_size, style_preferred, max_main_size) {
+                            // If the clamping values are such that max <= min, then we can avoid the expensive step of computing the content size
+                            // as we know that the clamping values will override it anyway
+                            (min, Some(pref), max) if max <= min || max <= pref => {
+                                pref.min(max).max(min) + item.margin.main_axis_sum(constants.dir)
+                            }
+                            (min, _, max) if max <= min => min + item.margin.main_axis_sum(constants.dir),
+
+                            // Else compute the min- or -max content size and apply the full formula for computing the
+                            // min- or max- content contribution
+                            _ if item.is_scroll_container() => {
+                                item.flex_basis + item.margin.main_axis_sum(constants.dir)
+                            }
+                            _ => {
+                                // Parent size for child sizing
+                                let cross_axis_parent_size = constants.node_inner_size.cross(dir);
+
+                                // Available space for child sizing
+                                let cross_axis_margin_sum = constants.margin.cross_axis_sum(dir);
+                                let child_min_cross = item.min_size.cross(dir).maybe_add(cross_axis_margin_sum);
+                                let child_max_cross = item.max_size.cross(dir).maybe_add(cross_axis_margin_sum);
+                                let cross_axis_available_space: AvailableSpace = available_space
+                                    .cross(dir)
+                                    .map_definite_value(|val| cross_axis_parent_size.unwrap_or(val))
+                                    .maybe_clamp(child_min_cross, child_max_cross);
+
+                                let child_available_space = available_space.with_cross(dir, cross_axis_available_space);
+
+                                // Known dimensions for child sizing
+                                let child_known_dimensions = {
+                                    let mut ckd = item.size.with_main(dir, None);
+                                    if item.is_stretched_in_cross_axis(dir) && ckd.cross(dir).is_none() {
+                                        ckd.set_cross(
+                                            dir,
+                                            cross_axis_available_space
+                                                .into_option()
+                                                .maybe_sub(item.margin.cross_axis_sum(dir)),
+                                        );
+                                    }
+                                    ckd
+                                };
+
+                                // Either the min- or max- content size depending on which constraint we are sizing under.
+                                // TODO: Optimise by using already computed values where available
+                                debug_log!("COMPUTE CHILD BASE SIZE (for intrinsic main size):");
+                                let content_main_size = item.measure_size(
+                                    tree,
+                                    child_known_dimensions,
+                                    constants.node_inner_size,
+                                    child_available_space,
+                                    SizingMode::InherentSize,
+                                    dir.main_axis(),
+                                    Line::FALSE,
+                                ) + item.margin.main_axis_sum(constants.dir);
+
+                                // This is somewhat bizarre in that it's asymmetrical depending whether the flex container is a column or a row.
+                                //
+                                // I *think* this might relate to https://drafts.csswg.org/css-flexbox-1/#algo-main-container:
+                                //
+                                //    "The automatic block size of a block-level flex container is its max-content size."
+                                //
+                                // Which could suggest that flex-basis defining a vertical size does not shrink because it is in the block axis, and the automatic size
+                                // in the block axis is a MAX content size. Whereas a flex-basis defining a horizontal size does shrink because the automatic size in
+                                // inline axis is MIN content size (although I don't have a reference for that).
+                                //
+                                // Ultimately, this was not found by reading the spec, but by trial and error fixing tests to align with Webkit/Firefox output.
+                                // (see the `flex_basis_unconstraint_row` and `flex_basis_uncontraint_column` generated tests which demonstrate this)
+                                if constants.is_row {
+                                    content_main_size.maybe_clamp(style_min, style_max).max(main_content_box_inset)
+                                } else {
+                                    content_main_size
+                                        .max(item.flex_basis)
+                                        .maybe_clamp(style_min, style_max)
+                                        .max(main_content_box_inset)
+                                }
+                            }
+                        };
+                        // Subtract the item's outer flex base size from its content contribution. If the result is positive,
+                        // divide it by the item's flex grow factor if the flex grow factor is >= 1, or multiply it by the flex
+                        // grow factor if the flex grow factor is < 1. If the result is negative, divide it by the item's scaled
+                        // flex shrink factor. This is the item's desired flex fraction.
+                        item.content_flex_fraction = {
+                            let diff =
+                                content_contribution - item.flex_basis - item.margin.main_axis_sum(constants.dir);
+                            if diff > 0.0 {
+                                if item.flex_grow >= 1.0 {
+                                    diff / item.flex_grow
+                                } else {
+                                    diff * item.flex_grow
+                                }
+                            } else if diff < 0.0 {
+                                let scaled_shrink_factor = item.flex_shrink * item.inner_flex_basis;
+                                if scaled_shrink_factor > 0.0 {
+                                    diff / scaled_shrink_factor
+                                } else {
+                                    Scalar::NEG_INFINITY
+                                }
+                            } else {
+                                // We are assuming that diff is 0.0 here and that we haven't accidentally introduced a NaN
+                                0.0
+                            }
+                        };
+                        item.content_contribution_clamp = (min_main_size, max_main_size);
+                    }
+
+                    // Within each line, find the greatest (most positive) desired flex fraction among all the flex items.
+                    // This is the line's chosen flex fraction.
+                    let mut line_flex_fraction = line
+                        .items
+                        .iter()
+                        .map(|item| item.content_flex_fraction)
+                        .max_by(|a, b| a.total_cmp(b))
+                        .unwrap_or(0.0); // Unwrap case never gets hit because there is always at least one item a line
+
+                    // If the chosen flex fraction is positive, and the sum of the line's flex grow factors is less than 1,
+                    // divide the chosen flex fraction by that sum. If the chosen flex fraction is negative, and the sum of the
+                    // line's flex shrink factors is less than 1, multiply the chosen flex fraction by that sum.
+                    if line_flex_fraction > 0.0 {
+                        let flex_grow_sum: Scalar = line.items.iter().map(|item| item.flex_grow).sum();
+                        if flex_grow_sum < 1.0 && flex_grow_sum > 0.0 {
+                            line_flex_fraction /= flex_grow_sum;
+                        }
+                    } else if line_flex_fraction < 0.0 {
+                        let flex_shrink_sum: Scalar = line.items.iter().map(|item| item.flex_shrink).sum();
+                        if flex_shrink_sum < 1.0 {
+                            line_flex_fraction *= flex_shrink_sum;
+                        }
+                    }
+
+                    // Add each item’s flex base size to the product of:
+                    //   - its flex grow factor (or scaled flex shrink factor, if the chosen flex fraction was negative)
+                    //   - the chosen flex fraction
+                    // then clamp that result by the max main size floored by the min main size.
+                    //
+                    // The flex container’s max-content size is the largest sum of the afore-calculated sizes of all items within a single line.
+                    let item_main_size_sum = line
+                        .items
+                        .iter_mut()
+                        .map(|item| {
+                            let flex_contribution = if line_flex_fraction > 0.0 {
+                                item.flex_grow * line_flex_fraction
+                            } else if line_flex_fraction < 0.0 && line_flex_fraction.is_finite() {
+                                item.flex_shrink * item.inner_flex_basis * line_flex_fraction
+                            } else {
+                                0.0
+                            };
+                            let (min_main_size, max_main_size) = item.content_contribution_clamp;
+                            let size = (item.flex_basis + flex_contribution).min(max_main_size).max(min_main_size)
+                                + item.margin.main_axis_sum(constants.dir);
+                            item.outer_target_size.set_main(constants.dir, size);
+                            item.target_size.set_main(constants.dir, size);
+                            size
+                        })
+                        .sum::<Scalar>();
+
+                    let gap_sum = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+                    main_size = scalar_max(main_size, item_main_size_sum + gap_sum)
+                }
+
+                main_size + main_content_box_inset
+            }
+        }
+    });
+
+    let outer_main_size = outer_main_size
+        .maybe_clamp(constants.min_size.main(constants.dir), constants.max_size.main(constants.dir))
+        .max(main_content_box_inset - constants.scrollbar_gutter.main(constants.dir));
+
+    // let outer_main_size = inner_main_size + constants.padding_border.main_axis_sum(constants.dir);
+    let inner_main_size = scalar_max(outer_main_size - main_content_box_inset, 0.0);
+    constants.container_size.set_main(constants.dir, outer_main_size);
+    constants.inner_container_size.set_main(constants.dir, inner_main_size);
+    constants.node_inner_size.set_main(constants.dir, Some(inner_main_size));
+}
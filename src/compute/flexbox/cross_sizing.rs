@@ -0,0 +1,344 @@
+//! Determining the cross size of flex items, flex lines and the flex container
+//! <https://www.w3.org/TR/css-flexbox-1/#cross-sizing>
+use crate::geometry::{Line, Scalar, Size};
+use crate::style::CoreStyle;
+use crate::style::{AlignContent, AlignSelf, AvailableSpace};
+use crate::tree::SizingMode;
+use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt};
+use crate::util::sys::scalar_max;
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::BoxSizing;
+
+use super::{sum_axis_gaps, AlgoConstants, FlexLine};
+
+/// Determine the hypothetical cross size of each item.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Determine the hypothetical cross size of each item**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-item)
+///   by performing layout with the used main size and the available space, treating auto as fit-content.
+#[inline]
+pub(super) fn determine_hypothetical_cross_size(
+    tree: &mut impl LayoutFlexboxContainer,
+    line: &mut FlexLine,
+    constants: &AlgoConstants,
+    available_space: Size<AvailableSpace>,
+) {
+    for child in line.items.iter_mut() {
+        let padding_border_sum = (child.padding + child.border).cross_axis_sum(constants.dir);
+
+        let child_known_main = constants.container_size.main(constants.dir).into();
+
+        let child_cross = child
+            .size
+            .cross(constants.dir)
+            .maybe_clamp(child.min_size.cross(constants.dir), child.max_size.cross(constants.dir))
+            .maybe_max(padding_border_sum);
+
+        let child_available_cross = available_space
+            .cross(constants.dir)
+            .maybe_clamp(child.min_size.cross(constants.dir), child.max_size.cross(constants.dir))
+            .maybe_max(padding_border_sum);
+
+        let child_inner_cross = child_cross.unwrap_or_else(|| {
+            child
+                .measure_size(
+                    tree,
+                    Size {
+                        width: if constants.is_row { child.target_size.width.into() } else { child_cross },
+                        height: if constants.is_row { child_cross } else { child.target_size.height.into() },
+                    },
+                    constants.node_inner_size,
+                    Size {
+                        width: if constants.is_row { child_known_main } else { child_available_cross },
+                        height: if constants.is_row { child_available_cross } else { child_known_main },
+                    },
+                    SizingMode::ContentSize,
+                    constants.dir.cross_axis(),
+                    Line::FALSE,
+                )
+                .maybe_clamp(child.min_size.cross(constants.dir), child.max_size.cross(constants.dir))
+                .max(padding_border_sum)
+        });
+        let child_outer_cross = child_inner_cross + child.margin.cross_axis_sum(constants.dir);
+
+        child.hypothetical_inner_size.set_cross(constants.dir, child_inner_cross);
+        child.hypothetical_outer_size.set_cross(constants.dir, child_outer_cross);
+    }
+}
+
+/// Calculate the base lines of the children.
+#[inline]
+pub(super) fn calculate_children_base_lines(
+    tree: &mut impl LayoutFlexboxContainer,
+    node_size: Size<Option<Scalar>>,
+    available_space: Size<AvailableSpace>,
+    flex_lines: &mut [FlexLine],
+    constants: &AlgoConstants,
+) {
+    // Only compute baselines for flex rows because we only support baseline alignment in the cross axis
+    // where that axis is also the inline axis
+    // TODO: this may need revisiting if/when we support vertical writing modes
+    if !constants.is_row {
+        return;
+    }
+
+    for line in flex_lines {
+        // If a flex line has one or zero items participating in baseline alignment then baseline alignment is a no-op so we skip
+        let line_baseline_child_count =
+            line.items.iter().filter(|child| child.align_self == AlignSelf::Baseline).count();
+        if line_baseline_child_count <= 1 {
+            continue;
+        }
+
+        for child in line.items.iter_mut() {
+            // Only calculate baselines for children participating in baseline alignment
+            if child.align_self != AlignSelf::Baseline {
+                continue;
+            }
+
+            let measured_size_and_baselines = child.perform_layout(
+                tree,
+                Size {
+                    width: if constants.is_row {
+                        child.target_size.width.into()
+                    } else {
+                        child.hypothetical_inner_size.width.into()
+                    },
+                    height: if constants.is_row {
+                        child.hypothetical_inner_size.height.into()
+                    } else {
+                        child.target_size.height.into()
+                    },
+                },
+                constants.node_inner_size,
+                Size {
+                    width: if constants.is_row {
+                        constants.container_size.width.into()
+                    } else {
+                        available_space.width.maybe_set(node_size.width)
+                    },
+                    height: if constants.is_row {
+                        available_space.height.maybe_set(node_size.height)
+                    } else {
+                        constants.container_size.height.into()
+                    },
+                },
+                SizingMode::ContentSize,
+                Line::FALSE,
+            );
+
+            let baseline = measured_size_and_baselines.first_baselines.y;
+            let height = measured_size_and_baselines.size.height;
+
+            child.baseline = baseline.unwrap_or(height) + child.margin.top;
+        }
+    }
+}
+
+/// Calculate the cross size of each flex line.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Calculate the cross size of each flex line**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-line).
+#[inline]
+pub(super) fn calculate_cross_size(
+    flex_lines: &mut [FlexLine],
+    node_size: Size<Option<Scalar>>,
+    constants: &AlgoConstants,
+) {
+    // If the flex container is single-line and has a definite cross size,
+    // the cross size of the flex line is the flex container’s inner cross size.
+    if !constants.is_wrap && node_size.cross(constants.dir).is_some() {
+        let cross_axis_padding_border = constants.content_box_inset.cross_axis_sum(constants.dir);
+        let cross_min_size = constants.min_size.cross(constants.dir);
+        let cross_max_size = constants.max_size.cross(constants.dir);
+        flex_lines[0].cross_size = node_size
+            .cross(constants.dir)
+            .maybe_clamp(cross_min_size, cross_max_size)
+            .maybe_sub(cross_axis_padding_border)
+            .maybe_max(0.0)
+            .unwrap_or(0.0);
+    } else {
+        // Otherwise, for each flex line:
+        //
+        //    1. Collect all the flex items whose inline-axis is parallel to the main-axis, whose
+        //       align-self is baseline, and whose cross-axis margins are both non-auto. Find the
+        //       largest of the distances between each item’s baseline and its hypothetical outer
+        //       cross-start edge, and the largest of the distances between each item’s baseline
+        //       and its hypothetical outer cross-end edge, and sum these two values.
+
+        //    2. Among all the items not collected by the previous step, find the largest
+        //       outer hypothetical cross size.
+
+        //    3. The used cross-size of the flex line is the largest of the numbers found in the
+        //       previous two steps and zero.
+        for line in flex_lines.iter_mut() {
+            let max_baseline: Scalar = line.items.iter().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+            line.cross_size = line
+                .items
+                .iter()
+                .map(|child| {
+                    if child.align_self == AlignSelf::Baseline
+                        && !child.margin_is_auto.cross_start(constants.dir)
+                        && !child.margin_is_auto.cross_end(constants.dir)
+                    {
+                        max_baseline - child.baseline + child.hypothetical_outer_size.cross(constants.dir)
+                    } else {
+                        child.hypothetical_outer_size.cross(constants.dir)
+                    }
+                })
+                .fold(0.0, |acc, x| acc.max(x));
+        }
+
+        // If the flex container is single-line, then clamp the line’s cross-size to be within the container’s computed min and max cross sizes.
+        // Note that if CSS 2.1’s definition of min/max-width/height applied more generally, this behavior would fall out automatically.
+        if !constants.is_wrap {
+            let cross_axis_padding_border = constants.content_box_inset.cross_axis_sum(constants.dir);
+            let cross_min_size = constants.min_size.cross(constants.dir);
+            let cross_max_size = constants.max_size.cross(constants.dir);
+            flex_lines[0].cross_size = flex_lines[0].cross_size.maybe_clamp(
+                cross_min_size.maybe_sub(cross_axis_padding_border),
+                cross_max_size.maybe_sub(cross_axis_padding_border),
+            );
+        }
+
+        // Multi-line containers never clamp (or squash) their lines to the container's min/max cross size: lines keep their
+        // content-based cross size. Any leftover space is distributed by `align-content` and any excess overflows the container.
+    }
+}
+
+/// Handle 'align-content: stretch'.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Handle 'align-content: stretch'**](https://www.w3.org/TR/css-flexbox-1/#algo-line-stretch). If the flex container has a definite cross size, align-content is stretch,
+///   and the sum of the flex lines' cross sizes is less than the flex container’s inner cross size,
+///   increase the cross size of each flex line by equal amounts such that the sum of their cross sizes exactly equals the flex container’s inner cross size.
+#[inline]
+pub(super) fn handle_align_content_stretch(
+    flex_lines: &mut [FlexLine],
+    node_size: Size<Option<Scalar>>,
+    constants: &AlgoConstants,
+) {
+    if constants.align_content == AlignContent::Stretch {
+        let cross_axis_padding_border = constants.content_box_inset.cross_axis_sum(constants.dir);
+        let cross_min_size = constants.min_size.cross(constants.dir);
+        let cross_max_size = constants.max_size.cross(constants.dir);
+        let container_min_inner_cross = node_size
+            .cross(constants.dir)
+            .or(cross_min_size)
+            .maybe_clamp(cross_min_size, cross_max_size)
+            .maybe_sub(cross_axis_padding_border)
+            .maybe_max(0.0)
+            .unwrap_or(0.0);
+
+        let total_cross_axis_gap = sum_axis_gaps(constants.gap.cross(constants.dir), flex_lines.len());
+        let lines_total_cross: Scalar =
+            flex_lines.iter().map(|line| line.cross_size).sum::<Scalar>() + total_cross_axis_gap;
+
+        if lines_total_cross < container_min_inner_cross {
+            let remaining = container_min_inner_cross - lines_total_cross;
+            let addition = remaining / flex_lines.len() as Scalar;
+            flex_lines.iter_mut().for_each(|line| line.cross_size += addition);
+        }
+    }
+}
+
+/// Determine the used cross size of each flex item.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Determine the used cross size of each flex item**](https://www.w3.org/TR/css-flexbox-1/#algo-stretch). If a flex item has align-self: stretch, its computed cross size property is auto,
+///   and neither of its cross-axis margins are auto, the used outer cross size is the used cross size of its flex line, clamped according to the item’s used min and max cross sizes.
+///   Otherwise, the used cross size is the item’s hypothetical cross size.
+///
+///   If the flex item has align-self: stretch, redo layout for its contents, treating this used size as its definite cross size so that percentage-sized children can be resolved.
+///
+///   **Note that this step does not affect the main size of the flex item, even if it has an intrinsic aspect ratio**.
+#[inline]
+pub(super) fn determine_used_cross_size(
+    tree: &impl LayoutFlexboxContainer,
+    flex_lines: &mut [FlexLine],
+    constants: &AlgoConstants,
+) {
+    for line in flex_lines {
+        let line_cross_size = line.cross_size;
+
+        for child in line.items.iter_mut() {
+            let child_style = tree.get_flexbox_child_style(child.node);
+            child.target_size.set_cross(
+                constants.dir,
+                if child.is_stretched_in_cross_axis(constants.dir) && child_style.size().cross(constants.dir).is_auto()
+                {
+                    // For some reason this particular usage of max_width is an exception to the rule that max_width's transfer
+                    // using the aspect_ratio (if set). Both Chrome and Firefox agree on this. And reading the spec, it seems like
+                    // a reasonable interpretation. Although it seems to me that the spec *should* apply aspect_ratio here.
+                    let padding = child_style
+                        .padding()
+                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+                    let border = child_style
+                        .border()
+                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+                    let pb_sum = (padding + border).sum_axes();
+                    let box_sizing_adjustment =
+                        if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+
+                    let max_size_ignoring_aspect_ratio = child_style
+                        .max_size()
+                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_add(box_sizing_adjustment);
+
+                    (line_cross_size - child.margin.cross_axis_sum(constants.dir)).maybe_clamp(
+                        child.min_size.cross(constants.dir),
+                        max_size_ignoring_aspect_ratio.cross(constants.dir),
+                    )
+                } else {
+                    child.hypothetical_inner_size.cross(constants.dir)
+                },
+            );
+
+            child.outer_target_size.set_cross(
+                constants.dir,
+                child.target_size.cross(constants.dir) + child.margin.cross_axis_sum(constants.dir),
+            );
+        }
+    }
+}
+
+/// Determine the flex container’s used cross size.
+///
+/// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
+///
+/// - [**Determine the flex container’s used cross size**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-container):
+///
+///     - If the cross size property is a definite size, use that, clamped by the used min and max cross sizes of the flex container.
+///
+///     - Otherwise, use the sum of the flex lines' cross sizes, clamped by the used min and max cross sizes of the flex container.
+#[inline]
+#[must_use]
+pub(super) fn determine_container_cross_size(
+    flex_lines: &[FlexLine],
+    node_size: Size<Option<Scalar>>,
+    constants: &mut AlgoConstants,
+) -> Scalar {
+    let total_cross_axis_gap = sum_axis_gaps(constants.gap.cross(constants.dir), flex_lines.len());
+    let total_line_cross_size: Scalar = flex_lines.iter().map(|line| line.cross_size).sum::<Scalar>();
+
+    let padding_border_sum = constants.content_box_inset.cross_axis_sum(constants.dir);
+    let cross_scrollbar_gutter = constants.scrollbar_gutter.cross(constants.dir);
+    let min_cross_size = constants.min_size.cross(constants.dir);
+    let max_cross_size = constants.max_size.cross(constants.dir);
+    let outer_container_size = node_size
+        .cross(constants.dir)
+        .unwrap_or(total_line_cross_size + total_cross_axis_gap + padding_border_sum)
+        .maybe_clamp(min_cross_size, max_cross_size)
+        .max(padding_border_sum - cross_scrollbar_gutter);
+    let inner_container_size = scalar_max(outer_container_size - padding_border_sum, 0.0);
+
+    constants.container_size.set_cross(constants.dir, outer_container_size);
+    constants.inner_container_size.set_cross(constants.dir, inner_container_size);
+
+    total_line_cross_size
+}
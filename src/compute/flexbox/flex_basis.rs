@@ -0,0 +1,273 @@
+//! Determining the flex base size and hypothetical main size of each flex item
+//! <https://www.w3.org/TR/css-flexbox-1/#algo-main-item>
+use crate::geometry::{Line, Scalar, Size};
+use crate::style::{AvailableSpace, Overflow};
+use crate::style::{CoreStyle, FlexboxItemStyle};
+use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
+use crate::tree::SizingMode;
+use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt};
+use crate::util::debug::debug_log;
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::BoxSizing;
+
+use super::{AlgoConstants, FlexItem};
+
+/// Determine the flex base size and hypothetical main size of each item.
+///
+/// # [9.2. Line Length Determination](https://www.w3.org/TR/css-flexbox-1/#line-sizing)
+///
+/// - [**Determine the flex base size and hypothetical main size of each item:**](https://www.w3.org/TR/css-flexbox-1/#algo-main-item)
+///
+///     - A. If the item has a definite used flex basis, that’s the flex base size.
+///
+///     - B. If the flex item has ...
+///
+///         - an intrinsic aspect ratio,
+///         - a used flex basis of content, and
+///         - a definite cross size,
+///
+///       then the flex base size is calculated from its inner cross size and the flex item’s intrinsic aspect ratio.
+///
+///     - C. If the used flex basis is content or depends on its available space, and the flex container is being sized under a min-content
+///       or max-content constraint (e.g. when performing automatic table layout \[CSS21\]), size the item under that constraint.
+///       The flex base size is the item’s resulting main size.
+///
+///     - E. Otherwise, size the item into the available space using its used flex basis in place of its main size, treating a value of content as max-content.
+///       If a cross size is needed to determine the main size (e.g. when the flex item’s main size is in its block axis) and the flex item’s cross size is auto and not definite,
+///       in this calculation use fit-content as the flex item’s cross size. The flex base size is the item’s resulting main size.
+///
+///   When determining the flex base size, the item’s min and max main sizes are ignored (no clamping occurs).
+///   Furthermore, the sizing calculations that floor the content box size at zero when applying box-sizing are also ignored.
+///   (For example, an item with a specified size of zero, positive padding, and box-sizing: border-box will have an outer flex base size of zero—and hence a negative inner flex base size.)
+#[inline]
+pub(super) fn determine_flex_base_size(
+    tree: &mut impl LayoutFlexboxContainer,
+    constants: &AlgoConstants,
+    available_space: Size<AvailableSpace>,
+    flex_items: &mut [FlexItem],
+) {
+    let dir = constants.dir;
+
+    for child in flex_items.iter_mut() {
+        let child_style = tree.get_flexbox_child_style(child.node);
+
+        // Parent size for child sizing
+        let cross_axis_parent_size = constants.node_inner_size.cross(dir);
+        let child_parent_size = Size::from_cross(dir, cross_axis_parent_size);
+
+        // Available space for child sizing
+        let cross_axis_margin_sum = constants.margin.cross_axis_sum(dir);
+        let child_min_cross = child.min_size.cross(dir).maybe_add(cross_axis_margin_sum);
+        let child_max_cross = child.max_size.cross(dir).maybe_add(cross_axis_margin_sum);
+
+        // Clamp available space by min- and max- size
+        let cross_axis_available_space: AvailableSpace = match available_space.cross(dir) {
+            AvailableSpace::Definite(val) => AvailableSpace::Definite(
+                cross_axis_parent_size.unwrap_or(val).maybe_clamp(child_min_cross, child_max_cross),
+            ),
+            AvailableSpace::MinContent => match child_min_cross {
+                Some(min) => AvailableSpace::Definite(min),
+                None => AvailableSpace::MinContent,
+            },
+            AvailableSpace::MaxContent => match child_max_cross {
+                Some(max) => AvailableSpace::Definite(max),
+                None => AvailableSpace::MaxContent,
+            },
+        };
+
+        // Known dimensions for child sizing
+        let child_known_dimensions = {
+            let mut ckd = child.size.with_main(dir, None);
+            if child.is_stretched_in_cross_axis(dir) && ckd.cross(dir).is_none() {
+                ckd.set_cross(
+                    dir,
+                    cross_axis_available_space.into_option().maybe_sub(child.margin.cross_axis_sum(dir)),
+                );
+            }
+            ckd
+        };
+
+        let container_width = constants.node_inner_size.main(dir);
+        let box_sizing_adjustment = if child_style.box_sizing() == BoxSizing::ContentBox {
+            let padding = child_style.padding().resolve_or_zero(container_width, |val, basis| tree.calc(val, basis));
+            let border = child_style.border().resolve_or_zero(container_width, |val, basis| tree.calc(val, basis));
+            (padding + border).sum_axes()
+        } else {
+            Size::ZERO
+        };
+        let flex_basis = child_style
+            .flex_basis()
+            .maybe_resolve(container_width, |val, basis| tree.calc(val, basis))
+            .maybe_add(box_sizing_adjustment.main(dir));
+
+        // The size suggestions used to compute the automatic minimum size of the item
+        // See https://www.w3.org/TR/css-flexbox-1/#min-size-auto
+        let aspect_ratio = child_style.aspect_ratio();
+        let style_size =
+            child_style.size().maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+        let style_min_cross = child_style
+            .min_size()
+            .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+            .cross(dir);
+        let style_max_cross = child_style
+            .max_size()
+            .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+            .cross(dir);
+        let transfer_to_main = |cross: Scalar| {
+            let main = Size::from_cross(dir, Some(cross)).maybe_apply_aspect_ratio(aspect_ratio).main(dir);
+            main.maybe_add(box_sizing_adjustment.main(dir))
+        };
+
+        // If the item’s preferred main size is definite, then the specified size suggestion is that size.
+        let specified_size_suggestion = style_size.main(dir).maybe_add(box_sizing_adjustment.main(dir));
+
+        // If the item has a preferred aspect ratio and its preferred cross size is definite, then the transferred
+        // size suggestion is that size (clamped by its min and max cross sizes if they are definite), converted
+        // through the aspect ratio.
+        let transferred_size_suggestion = aspect_ratio
+            .and(style_size.cross(dir))
+            .and_then(|cross| transfer_to_main(cross.maybe_clamp(style_min_cross, style_max_cross)));
+
+        // The min and max cross sizes of the item converted through the aspect ratio (if it has one)
+        let transferred_min_main = aspect_ratio.and(style_min_cross).and_then(transfer_to_main);
+        let transferred_max_main = aspect_ratio.and(style_max_cross).and_then(transfer_to_main);
+
+        drop(child_style);
+
+        child.flex_basis = 'flex_basis: {
+            // A. If the item has a definite used flex basis, that’s the flex base size.
+
+            // B. If the flex item has an intrinsic aspect ratio,
+            //    a used flex basis of content, and a definite cross size,
+            //    then the flex base size is calculated from its inner
+            //    cross size and the flex item’s intrinsic aspect ratio.
+
+            // Note: `child.size` has already been resolved against aspect_ratio in generate_anonymous_flex_items
+            // So B will just work here by using main_size without special handling for aspect_ratio
+            let main_size = child.size.main(dir);
+            if let Some(flex_basis) = flex_basis.or(main_size) {
+                break 'flex_basis flex_basis;
+            };
+
+            // C. If the used flex basis is content or depends on its available space,
+            //    and the flex container is being sized under a min-content or max-content
+            //    constraint (e.g. when performing automatic table layout [CSS21]),
+            //    size the item under that constraint. The flex base size is the item’s
+            //    resulting main size.
+
+            // This is covered by the implementation of E below, which passes the available_space constraint
+            // through to the child size computation. It may need a separate implementation if/when D is implemented.
+
+            // D. Otherwise, if the used flex basis is content or depends on its
+            //    available space, the available main size is infinite, and the flex item’s
+            //    inline axis is parallel to the main axis, lay the item out using the rules
+            //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
+            //    is the item’s max-content main size.
+
+            // TODO if/when vertical writing modes are supported
+
+            // E. Otherwise, size the item into the available space using its used flex basis
+            //    in place of its main size, treating a value of content as max-content.
+            //    If a cross size is needed to determine the main size (e.g. when the
+            //    flex item’s main size is in its block axis) and the flex item’s cross size
+            //    is auto and not definite, in this calculation use fit-content as the
+            //    flex item’s cross size. The flex base size is the item’s resulting main size.
+
+            let child_available_space = Size::MAX_CONTENT
+                .with_main(
+                    dir,
+                    // Map AvailableSpace::Definite to AvailableSpace::MaxContent
+                    if available_space.main(dir) == AvailableSpace::MinContent {
+                        AvailableSpace::MinContent
+                    } else {
+                        AvailableSpace::MaxContent
+                    },
+                )
+                .with_cross(dir, cross_axis_available_space);
+
+            debug_log!("COMPUTE CHILD BASE SIZE:");
+            break 'flex_basis child.measure_size(
+                tree,
+                child_known_dimensions,
+                child_parent_size,
+                child_available_space,
+                SizingMode::ContentSize,
+                dir.main_axis(),
+                Line::FALSE,
+            );
+        };
+
+        // Floor flex-basis by the padding_border_sum (floors inner_flex_basis at zero)
+        // This seems to be in violation of the spec which explicitly states that the content box should not be floored at zero
+        // (like it usually is) when calculating the flex-basis. But including this matches both Chrome and Firefox's behaviour.
+        //
+        // TODO: resolve spec violation
+        // Spec: https://www.w3.org/TR/css-flexbox-1/#intrinsic-item-contributions
+        // Spec: https://www.w3.org/TR/css-flexbox-1/#change-2016-max-contribution
+        let padding_border_sum = child.padding.main_axis_sum(constants.dir) + child.border.main_axis_sum(constants.dir);
+        child.flex_basis = child.flex_basis.max(padding_border_sum);
+
+        // The hypothetical main size is the item’s flex base size clamped according to its
+        // used min and max main sizes (and flooring the content box size at zero).
+
+        child.inner_flex_basis =
+            child.flex_basis - child.padding.main_axis_sum(constants.dir) - child.border.main_axis_sum(constants.dir);
+
+        let padding_border_axes_sums = (child.padding + child.border).sum_axes().map(Some);
+
+        // Note that it is important that the `parent_size` parameter in the main axis is not set for this
+        // function call as it used for resolving percentages, and percentage size in an axis should not contribute
+        // to a min-content contribution in that same axis. However the `parent_size` and `available_space` *should*
+        // be set to their usual values in the cross axis so that wrapping content can wrap correctly.
+        //
+        // See https://drafts.csswg.org/css-sizing-3/#min-percentage-contribution
+        let style_min_main_size =
+            child.min_size.or(child.overflow.map(Overflow::maybe_into_automatic_min_size).into()).main(dir);
+
+        child.resolved_minimum_main_size = style_min_main_size.unwrap_or({
+            let min_content_main_size = {
+                let child_available_space = Size::MIN_CONTENT.with_cross(dir, cross_axis_available_space);
+
+                debug_log!("COMPUTE CHILD MIN SIZE:");
+                child.measure_size(
+                    tree,
+                    child_known_dimensions,
+                    child_parent_size,
+                    child_available_space,
+                    SizingMode::ContentSize,
+                    dir.main_axis(),
+                    Line::FALSE,
+                )
+            };
+
+            // 4.5. Automatic Minimum Size of Flex Items
+            // https://www.w3.org/TR/css-flexbox-1/#min-size-auto
+            //
+            // The content size suggestion is the min-content size in the main axis, clamped, if it has a preferred
+            // aspect ratio, by any definite min and max cross sizes converted through the aspect ratio, and then
+            // further clamped by the max main size if that is definite.
+            let content_size_suggestion = min_content_main_size
+                .maybe_clamp(transferred_min_main, transferred_max_main)
+                .maybe_min(child.max_size.main(dir));
+
+            // The content-based minimum size is the smaller of the content size suggestion and the specified size
+            // suggestion if it exists, or otherwise the smaller of the content size suggestion and the transferred
+            // size suggestion if that exists. Otherwise it is the content size suggestion.
+            let content_based_minimum_size =
+                content_size_suggestion.maybe_min(specified_size_suggestion.or(transferred_size_suggestion));
+
+            // The automatic minimum size is the content-based minimum size, further clamped by the max main size
+            content_based_minimum_size.maybe_min(child.max_size.main(dir)).maybe_max(padding_border_axes_sums.main(dir))
+        });
+
+        let hypothetical_inner_min_main =
+            child.resolved_minimum_main_size.maybe_max(padding_border_axes_sums.main(constants.dir));
+        let hypothetical_inner_size =
+            child.flex_basis.maybe_clamp(Some(hypothetical_inner_min_main), child.max_size.main(constants.dir));
+        let hypothetical_outer_size = hypothetical_inner_size + child.margin.main_axis_sum(constants.dir);
+
+        child.hypothetical_inner_size.set_main(constants.dir, hypothetical_inner_size);
+        child.hypothetical_outer_size.set_main(constants.dir, hypothetical_outer_size);
+    }
+}
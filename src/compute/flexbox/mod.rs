@@ -0,0 +1,738 @@
+//! Computes the [flexbox](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) layout algorithm on [`TaffyTree`](crate::TaffyTree) according to the [spec](https://www.w3.org/TR/css-flexbox-1/)
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Scalar, Size};
+use crate::style::{
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, FlexWrap, JustifyContent, LengthPercentageAuto, Overflow,
+    Position,
+};
+use crate::style::{CoreStyle, FlexDirection, FlexboxContainerStyle, FlexboxItemStyle};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
+use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt, NodeId};
+use crate::util::debug::{debug_log, debug_record_algorithm};
+use crate::util::sys::ChildrenVec;
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::{BoxGenerationMode, BoxSizing};
+
+use abspos::perform_absolute_layout_on_absolute_children;
+use alignment::{
+    align_flex_lines_per_align_content, distribute_remaining_free_space, final_layout_pass,
+    resolve_cross_axis_auto_margins,
+};
+use available_space::determine_available_space;
+use collect_lines::{collect_flex_lines, determine_container_main_size};
+use cross_sizing::{
+    calculate_children_base_lines, calculate_cross_size, determine_container_cross_size,
+    determine_hypothetical_cross_size, determine_used_cross_size, handle_align_content_stretch,
+};
+use flex_basis::determine_flex_base_size;
+use resolve_flexible_lengths::resolve_flexible_lengths;
+#[cfg(feature = "std")]
+pub(super) use scratch::FlexboxScratch;
+use scratch::{relabel_lines, release_items, release_lines, take_items, take_lines};
+
+mod abspos;
+mod alignment;
+mod available_space;
+mod collect_lines;
+mod cross_sizing;
+mod flex_basis;
+mod resolve_flexible_lengths;
+mod scratch;
+
+/// The intermediate results of a flexbox calculation for a single item
+struct FlexItem {
+    /// The identifier for the associated node
+    node: NodeId,
+
+    /// The order of the node relative to it's siblings
+    order: u32,
+
+    /// The base size of this item
+    size: Size<Option<Scalar>>,
+    /// The minimum allowable size of this item
+    min_size: Size<Option<Scalar>>,
+    /// The maximum allowable size of this item
+    max_size: Size<Option<Scalar>>,
+    /// The cross-alignment of this item
+    align_self: AlignSelf,
+
+    /// The overflow style of the item
+    overflow: Point<Overflow>,
+    /// The width of the scrollbars (if it has any)
+    scrollbar_width: Scalar,
+    /// The flex shrink style of the item
+    flex_shrink: Scalar,
+    /// The flex grow style of the item
+    flex_grow: Scalar,
+
+    /// The minimum size of the item. This differs from min_size above because it also
+    /// takes into account content based automatic minimum sizes
+    resolved_minimum_main_size: Scalar,
+
+    /// The final offset of this item
+    inset: Rect<Option<Scalar>>,
+    /// The margin of this item
+    margin: Rect<Scalar>,
+    /// Whether each margin is an auto margin or not
+    margin_is_auto: Rect<bool>,
+    /// The padding of this item
+    padding: Rect<Scalar>,
+    /// The border of this item
+    border: Rect<Scalar>,
+
+    /// The default size of this item
+    flex_basis: Scalar,
+    /// The default size of this item, minus padding and border
+    inner_flex_basis: Scalar,
+    /// The amount by which this item has deviated from its target size
+    violation: Scalar,
+    /// Is the size of this item locked
+    frozen: bool,
+
+    /// Either the max- or min- content flex fraction
+    /// See https://www.w3.org/TR/css-flexbox-1/#intrinsic-main-sizes
+    content_flex_fraction: Scalar,
+    /// The min and max main sizes used to clamp the item's contribution to the container's intrinsic main size
+    content_contribution_clamp: (Scalar, Scalar),
+
+    /// The proposed inner size of this item
+    hypothetical_inner_size: Size<Scalar>,
+    /// The proposed outer size of this item
+    hypothetical_outer_size: Size<Scalar>,
+    /// The size that this item wants to be
+    target_size: Size<Scalar>,
+    /// The size that this item wants to be, plus any padding and border
+    outer_target_size: Size<Scalar>,
+
+    /// The position of the bottom edge of this item
+    baseline: Scalar,
+
+    /// The inputs and output of the most recent layout of this item. Used to avoid laying out the item again
+    /// with identical inputs within a single invocation of the flexbox algorithm.
+    layout_memo: Option<(LayoutInput, LayoutOutput)>,
+
+    /// A temporary value for the main offset
+    ///
+    /// Offset is the relative position from the item's natural flow position based on
+    /// relative position values, alignment, and justification. Does not include margin/padding/border.
+    offset_main: Scalar,
+    /// A temporary value for the cross offset
+    ///
+    /// Offset is the relative position from the item's natural flow position based on
+    /// relative position values, alignment, and justification. Does not include margin/padding/border.
+    offset_cross: Scalar,
+}
+
+impl FlexItem {
+    /// Returns true if the item is a <https://www.w3.org/TR/css-overflow-3/#scroll-container>
+    fn is_scroll_container(&self) -> bool {
+        self.overflow.x.is_scroll_container() | self.overflow.y.is_scroll_container()
+    }
+
+    /// Returns true if the item should be stretched in the cross axis. Auto margins in the cross axis absorb any free
+    /// space before alignment is applied, so an item with `align-self: stretch` and an auto cross-axis margin is not stretched.
+    #[inline(always)]
+    fn is_stretched_in_cross_axis(&self, dir: FlexDirection) -> bool {
+        self.align_self == AlignSelf::Stretch
+            && !self.margin_is_auto.cross_start(dir)
+            && !self.margin_is_auto.cross_end(dir)
+    }
+
+    /// Lay out the item, reusing the output of the item's previous layout if it was computed from the same inputs
+    ///
+    /// A memoized `PerformLayout` output can satisfy any request with otherwise identical inputs. A memoized
+    /// `ComputeSize` output can only satisfy a `ComputeSize` request for the same axis (or if it computed both axes).
+    fn compute_layout(&mut self, tree: &mut impl LayoutFlexboxContainer, inputs: LayoutInput) -> LayoutOutput {
+        if let Some((memo_inputs, memo_output)) = self.layout_memo {
+            let inputs_match = memo_inputs.known_dimensions == inputs.known_dimensions
+                && memo_inputs.parent_size == inputs.parent_size
+                && memo_inputs.available_space == inputs.available_space
+                && memo_inputs.sizing_mode == inputs.sizing_mode
+                && memo_inputs.vertical_margins_are_collapsible == inputs.vertical_margins_are_collapsible;
+            let memo_is_sufficient = match (memo_inputs.run_mode, inputs.run_mode) {
+                (RunMode::PerformLayout, _) => true,
+                (RunMode::ComputeSize, RunMode::ComputeSize) => {
+                    memo_inputs.axis == RequestedAxis::Both || memo_inputs.axis == inputs.axis
+                }
+                _ => false,
+            };
+            if inputs_match && memo_is_sufficient {
+                return memo_output;
+            }
+        }
+
+        let output = tree.compute_child_layout(self.node, inputs);
+        self.layout_memo = Some((inputs, output));
+        output
+    }
+
+    /// Measure the item's size in a single axis. See [`FlexItem::compute_layout`].
+    #[allow(clippy::too_many_arguments)]
+    fn measure_size(
+        &mut self,
+        tree: &mut impl LayoutFlexboxContainer,
+        known_dimensions: Size<Option<Scalar>>,
+        parent_size: Size<Option<Scalar>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        axis: AbsoluteAxis,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> Scalar {
+        let inputs = LayoutInput {
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            axis: axis.into(),
+            run_mode: RunMode::ComputeSize,
+            vertical_margins_are_collapsible,
+        };
+        self.compute_layout(tree, inputs).size.get_abs(axis)
+    }
+
+    /// Perform a full layout of the item. See [`FlexItem::compute_layout`].
+    fn perform_layout(
+        &mut self,
+        tree: &mut impl LayoutFlexboxContainer,
+        known_dimensions: Size<Option<Scalar>>,
+        parent_size: Size<Option<Scalar>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> LayoutOutput {
+        let inputs = LayoutInput {
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            axis: RequestedAxis::Both,
+            run_mode: RunMode::PerformLayout,
+            vertical_margins_are_collapsible,
+        };
+        self.compute_layout(tree, inputs)
+    }
+}
+
+/// A line of [`FlexItem`] used for intermediate computation
+struct FlexLine<'a> {
+    /// The slice of items to iterate over during computation of this line
+    items: &'a mut [FlexItem],
+    /// The dimensions of the cross-axis
+    cross_size: Scalar,
+    /// The relative offset of the cross-axis
+    offset_cross: Scalar,
+}
+
+/// Values that can be cached during the flexbox algorithm
+struct AlgoConstants {
+    /// The direction of the current segment being laid out
+    dir: FlexDirection,
+    /// Is this segment a row
+    is_row: bool,
+    /// Is this segment a column
+    is_column: bool,
+    /// Is wrapping enabled (in either direction)
+    is_wrap: bool,
+    /// Is the wrap direction inverted
+    is_wrap_reverse: bool,
+
+    /// The item's min_size style
+    min_size: Size<Option<Scalar>>,
+    /// The item's max_size style
+    max_size: Size<Option<Scalar>>,
+    /// The margin of this section
+    margin: Rect<Scalar>,
+    /// The border of this section
+    border: Rect<Scalar>,
+    /// The space between the content box and the border box.
+    /// This consists of padding + border + scrollbar_gutter.
+    content_box_inset: Rect<Scalar>,
+    /// The size reserved for scrollbar gutters in each axis
+    scrollbar_gutter: Point<Scalar>,
+    /// The gap of this section
+    gap: Size<Scalar>,
+    /// The align_items property of this node
+    align_items: AlignItems,
+    /// The align_content property of this node
+    align_content: AlignContent,
+    /// The justify_content property of this node
+    justify_content: Option<JustifyContent>,
+
+    /// The border-box size of the node being laid out (if known)
+    node_outer_size: Size<Option<Scalar>>,
+    /// The content-box size of the node being laid out (if known)
+    node_inner_size: Size<Option<Scalar>>,
+
+    /// The size of the virtual container containing the flex items.
+    container_size: Size<Scalar>,
+    /// The size of the internal container
+    inner_container_size: Size<Scalar>,
+}
+
+/// Computes the layout of a box according to the flexbox algorithm
+pub fn compute_flexbox_layout(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+) -> LayoutOutput {
+    debug_record_algorithm!("flexbox");
+    let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
+    let style = tree.get_flexbox_container_style(node);
+
+    // Pull these out earlier to avoid borrowing issues
+    let aspect_ratio = style.aspect_ratio();
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding_border_sum = padding.sum_axes() + border.sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+    let min_size = style
+        .min_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
+        style
+            .size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size)
+    } else {
+        Size::NONE
+    };
+
+    // If both min and max in a given axis are set and max <= min then this determines the size in that axis
+    let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
+        (Some(min), Some(max)) if max <= min => Some(min),
+        _ => None,
+    });
+
+    // The size of the container should be floored by the padding and border
+    let styled_based_known_dimensions =
+        known_dimensions.or(min_max_definite_size.or(clamped_style_size).maybe_max(padding_border_sum));
+
+    // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
+    // is ComputeSize (and thus the container's size is all that we're interested in)
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = styled_based_known_dimensions {
+            return LayoutOutput::from_outer_size(Size { width, height });
+        }
+    }
+
+    debug_log!("FLEX:", dbg:style.flex_direction());
+    drop(style);
+
+    compute_preliminary(tree, node, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+}
+
+/// Compute a preliminary size for an item
+fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+
+    // Define some general constants we will need for the remainder of the algorithm.
+    let mut constants = compute_constants(tree, tree.get_flexbox_container_style(node), known_dimensions, parent_size);
+
+    // 9. Flex Layout Algorithm
+
+    // 9.1. Initial Setup
+
+    // 1. Generate anonymous flex items as described in §4 Flex Items.
+    debug_log!("generate_anonymous_flex_items");
+    let mut flex_items = take_items(tree);
+    generate_anonymous_flex_items(tree, node, &constants, &mut flex_items);
+    // Every child that isn't a flex item is either absolutely positioned or hidden
+    let has_out_of_flow_children = flex_items.len() < tree.child_count(node);
+
+    // 9.2. Line Length Determination
+
+    // 2. Determine the available main and cross space for the flex items
+    debug_log!("determine_available_space");
+    let available_space = determine_available_space(known_dimensions, available_space, &constants);
+
+    // 3. Determine the flex base size and hypothetical main size of each item.
+    debug_log!("determine_flex_base_size");
+    determine_flex_base_size(tree, &constants, available_space, &mut flex_items);
+
+    #[cfg(any(feature = "debug", feature = "tracing"))]
+    for item in flex_items.iter() {
+        debug_log!("item.flex_basis", item.flex_basis);
+        debug_log!("item.inner_flex_basis", item.inner_flex_basis);
+        debug_log!("item.hypothetical_outer_size", dbg:item.hypothetical_outer_size);
+        debug_log!("item.hypothetical_inner_size", dbg:item.hypothetical_inner_size);
+        debug_log!("item.resolved_minimum_main_size", dbg:item.resolved_minimum_main_size);
+    }
+
+    // 4. Determine the main size of the flex container
+    // This has already been done as part of compute_constants. The inner size is exposed as constants.node_inner_size.
+
+    // 9.3. Main Size Determination
+
+    // 5. Collect flex items into flex lines.
+    debug_log!("collect_flex_lines");
+    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items, take_lines(tree));
+
+    // If container size is undefined, determine the container's main size
+    // and then re-resolve gaps based on newly determined size
+    debug_log!("determine_container_main_size");
+    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
+        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
+        constants.inner_container_size.set_main(constants.dir, inner_main_size);
+        constants.container_size.set_main(constants.dir, outer_main_size);
+    } else {
+        // Sets constants.container_size and constants.outer_container_size
+        determine_container_main_size(tree, available_space, &mut flex_lines, &mut constants);
+        constants.node_inner_size.set_main(constants.dir, Some(constants.inner_container_size.main(constants.dir)));
+        constants.node_outer_size.set_main(constants.dir, Some(constants.container_size.main(constants.dir)));
+
+        debug_log!("constants.node_outer_size", dbg:constants.node_outer_size);
+        debug_log!("constants.node_inner_size", dbg:constants.node_inner_size);
+
+        // Re-resolve percentage gaps in the main axis now that the container's main size is known
+        if constants.is_row {
+            constants.gap.width = resolve_column_gap(tree, node, constants.inner_container_size.width);
+        }
+
+        // A wrapping container sized under a max-content constraint collects its items into a single line. If the
+        // resulting main size was clamped by the container's max size then the items must be broken into lines again
+        // using the clamped size.
+        if constants.is_wrap
+            && available_space.main(constants.dir) == AvailableSpace::MaxContent
+            && constants.max_size.main(constants.dir).is_some()
+        {
+            let inner_main_size = AvailableSpace::Definite(constants.inner_container_size.main(constants.dir));
+            let available_space = available_space.with_main(constants.dir, inner_main_size);
+            let line_buffer = relabel_lines(flex_lines);
+            flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items, line_buffer);
+        }
+    }
+
+    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
+    debug_log!("resolve_flexible_lengths");
+    for line in flex_lines.iter_mut() {
+        resolve_flexible_lengths(line, &constants);
+    }
+
+    // 9.4. Cross Size Determination
+
+    // 7. Determine the hypothetical cross size of each item.
+    debug_log!("determine_hypothetical_cross_size");
+    for line in flex_lines.iter_mut() {
+        determine_hypothetical_cross_size(tree, line, &constants, available_space);
+    }
+
+    // Calculate child baselines. This function is internally smart and only computes child baselines
+    // if they are necessary.
+    debug_log!("calculate_children_base_lines");
+    calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, &constants);
+
+    // 8. Calculate the cross size of each flex line.
+    debug_log!("calculate_cross_size");
+    calculate_cross_size(&mut flex_lines, known_dimensions, &constants);
+
+    // 9. Handle 'align-content: stretch'.
+    debug_log!("handle_align_content_stretch");
+    handle_align_content_stretch(&mut flex_lines, known_dimensions, &constants);
+
+    // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
+    //     note the cross size of the line they’re in as the item’s strut size, and restart
+    //     layout from the beginning.
+    //
+    //     In this second layout round, when collecting items into lines, treat the collapsed
+    //     items as having zero main size. For the rest of the algorithm following that step,
+    //     ignore the collapsed items entirely (as if they were display:none) except that after
+    //     calculating the cross size of the lines, if any line’s cross size is less than the
+    //     largest strut size among all the collapsed items in the line, set its cross size to
+    //     that strut size.
+    //
+    //     Skip this step in the second layout round.
+
+    // TODO implement once (if ever) we support visibility:collapse
+
+    // 11. Determine the used cross size of each flex item.
+    debug_log!("determine_used_cross_size");
+    determine_used_cross_size(tree, &mut flex_lines, &constants);
+
+    // 9.5. Main-Axis Alignment
+
+    // 12. Distribute any remaining free space.
+    debug_log!("distribute_remaining_free_space");
+    distribute_remaining_free_space(&mut flex_lines, &constants);
+
+    // 9.6. Cross-Axis Alignment
+
+    // 13. Resolve cross-axis auto margins (also includes 14).
+    debug_log!("resolve_cross_axis_auto_margins");
+    resolve_cross_axis_auto_margins(&mut flex_lines, &constants);
+
+    // 15. Determine the flex container’s used cross size.
+    debug_log!("determine_container_cross_size");
+    let total_line_cross_size = determine_container_cross_size(&flex_lines, known_dimensions, &mut constants);
+
+    // Re-resolve percentage gaps in the cross axis now that the container's cross size is known
+    if constants.is_column && known_dimensions.width.is_none() {
+        constants.gap.width = resolve_column_gap(tree, node, constants.inner_container_size.width);
+    }
+
+    // We have the container size.
+    // If our caller does not care about performing layout we are done now.
+    if run_mode == RunMode::ComputeSize {
+        release_lines(tree, flex_lines);
+        release_items(tree, flex_items);
+        return LayoutOutput::from_outer_size(constants.container_size);
+    }
+
+    // 16. Align all flex lines per align-content.
+    debug_log!("align_flex_lines_per_align_content");
+    align_flex_lines_per_align_content(&mut flex_lines, &constants, total_line_cross_size);
+
+    // Do a final layout pass and gather the resulting layouts
+    debug_log!("final_layout_pass");
+    let inflow_content_size = final_layout_pass(tree, &mut flex_lines, &constants);
+
+    // Before returning we perform absolute layout on all absolutely positioned children, and hidden layout on all
+    // hidden children. Both passes are skipped in the common case where every child is a flex item.
+    let mut absolute_content_size = Size::ZERO;
+    if has_out_of_flow_children {
+        debug_log!("perform_absolute_layout_on_absolute_children");
+        absolute_content_size = perform_absolute_layout_on_absolute_children(tree, node, &constants);
+
+        debug_log!("hidden_layout");
+        let len = tree.child_count(node);
+        for order in 0..len {
+            let child = tree.get_child_id(node, order);
+            if tree.get_flexbox_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+                tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
+            }
+        }
+    }
+
+    // 8.5. Flex Container Baselines: calculate the flex container's first baseline
+    // See https://www.w3.org/TR/css-flexbox-1/#flex-baselines
+    let first_vertical_baseline = if flex_lines.is_empty() {
+        None
+    } else {
+        flex_lines[0]
+            .items
+            .iter()
+            .find(|item| constants.is_column || item.align_self == AlignSelf::Baseline)
+            .or_else(|| flex_lines[0].items.iter().next())
+            .map(|child| child.baseline)
+    };
+
+    release_lines(tree, flex_lines);
+    release_items(tree, flex_items);
+
+    LayoutOutput::from_sizes_and_baselines(
+        constants.container_size,
+        inflow_content_size.f32_max(absolute_content_size),
+        Point { x: None, y: first_vertical_baseline },
+    )
+}
+
+/// Compute constants that can be reused during the flexbox algorithm.
+#[inline]
+fn compute_constants(
+    tree: &impl LayoutFlexboxContainer,
+    style: impl FlexboxContainerStyle,
+    known_dimensions: Size<Option<Scalar>>,
+    parent_size: Size<Option<Scalar>>,
+) -> AlgoConstants {
+    let dir = style.flex_direction();
+    let is_row = dir.is_row();
+    let is_column = dir.is_column();
+    let is_wrap = matches!(style.flex_wrap(), FlexWrap::Wrap | FlexWrap::WrapReverse);
+    let is_wrap_reverse = style.flex_wrap() == FlexWrap::WrapReverse;
+
+    let aspect_ratio = style.aspect_ratio();
+    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding_border_sum = padding.sum_axes() + border.sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+    let align_items = style.align_items().unwrap_or(AlignItems::Stretch);
+    let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
+    let justify_content = style.justify_content();
+
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
+    // However, the axis are switched (transposed) because a node that scrolls vertically needs
+    // *horizontal* space to be reserved for a scrollbar
+    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
+        Overflow::Scroll => style.scrollbar_width(),
+        _ => 0.0,
+    });
+    // TODO: make side configurable based on the `direction` property
+    let mut content_box_inset = padding + border;
+    content_box_inset.right += scrollbar_gutter.x;
+    content_box_inset.bottom += scrollbar_gutter.y;
+
+    let node_outer_size = known_dimensions;
+    let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
+    let gap = style.gap().resolve_or_zero(node_inner_size.or(Size::zero()), |val, basis| tree.calc(val, basis));
+
+    let container_size = Size::zero();
+    let inner_container_size = Size::zero();
+
+    AlgoConstants {
+        dir,
+        is_row,
+        is_column,
+        is_wrap,
+        is_wrap_reverse,
+        min_size: style
+            .min_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment),
+        max_size: style
+            .max_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment),
+        margin,
+        border,
+        gap,
+        content_box_inset,
+        scrollbar_gutter,
+        align_items,
+        align_content,
+        justify_content,
+        node_outer_size,
+        node_inner_size,
+        container_size,
+        inner_container_size,
+    }
+}
+
+/// Generate anonymous flex items.
+///
+/// # [9.1. Initial Setup](https://www.w3.org/TR/css-flexbox-1/#box-manip)
+///
+/// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
+#[inline]
+fn generate_anonymous_flex_items(
+    tree: &impl LayoutFlexboxContainer,
+    node: NodeId,
+    constants: &AlgoConstants,
+    flex_items: &mut ChildrenVec<FlexItem>,
+) {
+    flex_items.extend(
+        tree.child_ids(node)
+            .enumerate()
+            .map(|(index, child)| (index, child, tree.get_flexbox_child_style(child)))
+            .filter(|(_, _, style)| style.position() != Position::Absolute)
+            .filter(|(_, _, style)| style.box_generation_mode() != BoxGenerationMode::None)
+            .map(|(index, child, child_style)| {
+                let aspect_ratio = child_style.aspect_ratio();
+                let padding = child_style
+                    .padding()
+                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
+                let border = child_style
+                    .border()
+                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
+                let pb_sum = (padding + border).sum_axes();
+                let box_sizing_adjustment =
+                    if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+                FlexItem {
+                    node: child,
+                    order: index as u32,
+                    size: child_style
+                        .size()
+                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+                    min_size: child_style
+                        .min_size()
+                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+                    max_size: child_style
+                        .max_size()
+                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_apply_aspect_ratio(aspect_ratio)
+                        .maybe_add(box_sizing_adjustment),
+
+                    inset: child_style.inset().zip_size(constants.node_inner_size, |p, s| {
+                        p.maybe_resolve(s, |val, basis| tree.calc(val, basis))
+                    }),
+                    margin: child_style
+                        .margin()
+                        .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
+                    margin_is_auto: child_style.margin().map(LengthPercentageAuto::is_auto),
+                    padding: child_style
+                        .padding()
+                        .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
+                    border: child_style
+                        .border()
+                        .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
+                    align_self: child_style.align_self().unwrap_or(constants.align_items),
+                    overflow: child_style.overflow(),
+                    scrollbar_width: child_style.scrollbar_width(),
+                    flex_grow: child_style.flex_grow(),
+                    flex_shrink: child_style.flex_shrink(),
+                    flex_basis: 0.0,
+                    inner_flex_basis: 0.0,
+                    violation: 0.0,
+                    frozen: false,
+
+                    resolved_minimum_main_size: 0.0,
+                    hypothetical_inner_size: Size::zero(),
+                    hypothetical_outer_size: Size::zero(),
+                    target_size: Size::zero(),
+                    outer_target_size: Size::zero(),
+                    content_flex_fraction: 0.0,
+                    content_contribution_clamp: (0.0, Scalar::INFINITY),
+
+                    baseline: 0.0,
+                    layout_memo: None,
+
+                    offset_main: 0.0,
+                    offset_cross: 0.0,
+                }
+            }),
+    );
+}
+
+/// Resolve the container's `column-gap` against the now-known inner width of the container.
+///
+/// Percentage gaps are resolved against the container's content box in the corresponding axis. When that size is
+/// indefinite they resolve to zero (see [`compute_constants`]). As with block-level boxes in browsers, the width of
+/// a flex container is always known by the time its contents are laid out, so a percentage `column-gap` is resolved
+/// again once the width has been determined. A percentage `row-gap` in a container with an indefinite height stays zero.
+#[inline]
+fn resolve_column_gap(tree: &impl LayoutFlexboxContainer, node: NodeId, inner_width: Scalar) -> Scalar {
+    let style = tree.get_flexbox_container_style(node);
+    style.gap().width.maybe_resolve(Some(inner_width), |val, basis| tree.calc(val, basis)).unwrap_or(0.0)
+}
+
+/// Computes the total space taken up by gaps in an axis given:
+///   - The size of each gap
+///   - The number of items (children or flex-lines) between which there are gaps
+#[inline(always)]
+fn sum_axis_gaps(gap: Scalar, num_items: usize) -> Scalar {
+    // Gaps only exist between items, so...
+    if num_items <= 1 {
+        // ...if there are less than 2 items then there are no gaps
+        0.0
+    } else {
+        // ...otherwise there are (num_items - 1) gaps
+        gap * (num_items - 1) as Scalar
+    }
+}
@@ -0,0 +1,380 @@
+//! Distributing free space within a flex line according to each item's flex factors
+//! <https://www.w3.org/TR/css-flexbox-1/#resolve-flexible-lengths>
+use crate::geometry::Scalar;
+use crate::util::MaybeMath;
+
+use super::{sum_axis_gaps, AlgoConstants, FlexLine};
+
+/// Resolve the flexible lengths of the items within a flex line.
+/// Sets the `main` component of each item's `target_size` and `outer_target_size`
+///
+/// # [9.7. Resolving Flexible Lengths](https://www.w3.org/TR/css-flexbox-1/#resolve-flexible-lengths)
+#[inline]
+pub(super) fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants) {
+    let total_main_axis_gap = sum_axis_gaps(constants.gap.main(constants.dir), line.items.len());
+
+    // 1. Determine the used flex factor. Sum the outer hypothetical main sizes of all
+    //    items on the line. If the sum is less than the flex container’s inner main size,
+    //    use the flex grow factor for the rest of this algorithm; otherwise, use the
+    //    flex shrink factor.
+
+    let total_hypothetical_outer_main_size =
+        line.items.iter().map(|child| child.hypothetical_outer_size.main(constants.dir)).sum::<Scalar>();
+    let used_flex_factor: Scalar = total_main_axis_gap + total_hypothetical_outer_main_size;
+    let growing = used_flex_factor < constants.node_inner_size.main(constants.dir).unwrap_or(0.0);
+    let shrinking = used_flex_factor > constants.node_inner_size.main(constants.dir).unwrap_or(0.0);
+    let exactly_sized = !growing & !shrinking;
+
+    // 2. Size inflexible items. Freeze, setting its target main size to its hypothetical main size
+    //    - Any item that has a flex factor of zero
+    //    - If using the flex grow factor: any item that has a flex base size
+    //      greater than its hypothetical main size
+    //    - If using the flex shrink factor: any item that has a flex base size
+    //      smaller than its hypothetical main size
+
+    for child in line.items.iter_mut() {
+        let inner_target_size = child.hypothetical_inner_size.main(constants.dir);
+        child.target_size.set_main(constants.dir, inner_target_size);
+
+        if exactly_sized
+            || (child.flex_grow == 0.0 && child.flex_shrink == 0.0)
+            || (growing && child.flex_basis > child.hypothetical_inner_size.main(constants.dir))
+            || (shrinking && child.flex_basis < child.hypothetical_inner_size.main(constants.dir))
+        {
+            child.frozen = true;
+            let outer_target_size = inner_target_size + child.margin.main_axis_sum(constants.dir);
+            child.outer_target_size.set_main(constants.dir, outer_target_size);
+        }
+    }
+
+    if exactly_sized {
+        return;
+    }
+
+    // 3. Calculate initial free space. Sum the outer sizes of all items on the line,
+    //    and subtract this from the flex container’s inner main size. For frozen items,
+    //    use their outer target main size; for other items, use their outer flex base size.
+
+    let used_space: Scalar = total_main_axis_gap
+        + line
+            .items
+            .iter()
+            .map(|child| {
+                if child.frozen {
+                    child.outer_target_size.main(constants.dir)
+                } else {
+                    child.flex_basis + child.margin.main_axis_sum(constants.dir)
+                }
+            })
+            .sum::<Scalar>();
+
+    let initial_free_space = constants.node_inner_size.main(constants.dir).maybe_sub(used_space).unwrap_or(0.0);
+
+    // 4. Loop
+
+    loop {
+        // a. Check for flexible items. If all the flex items on the line are frozen,
+        //    free space has been distributed; exit this loop.
+
+        if line.items.iter().all(|child| child.frozen) {
+            break;
+        }
+
+        // b. Calculate the remaining free space as for initial free space, above.
+        //    If the sum of the unfrozen flex items’ flex factors is less than one,
+        //    multiply the initial free space by this sum. If the magnitude of this
+        //    value is less than the magnitude of the remaining free space, use this
+        //    as the remaining free space.
+
+        let used_space: Scalar = total_main_axis_gap
+            + line
+                .items
+                .iter()
+                .map(|child| {
+                    if child.frozen {
+                        child.outer_target_size.main(constants.dir)
+                    } else {
+                        child.flex_basis + child.margin.main_axis_sum(constants.dir)
+                    }
+                })
+                .sum::<Scalar>();
+
+        let (sum_flex_grow, sum_flex_shrink): (Scalar, Scalar) =
+            line.items.iter().filter(|child| !child.frozen).fold((0.0, 0.0), |(flex_grow, flex_shrink), item| {
+                (flex_grow + item.flex_grow, flex_shrink + item.flex_shrink)
+            });
+
+        let free_space = if growing && sum_flex_grow < 1.0 {
+            (initial_free_space * sum_flex_grow - total_main_axis_gap)
+                .maybe_min(constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
+        } else if shrinking && sum_flex_shrink < 1.0 {
+            (initial_free_space * sum_flex_shrink - total_main_axis_gap)
+                .maybe_max(constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
+        } else {
+            (constants.node_inner_size.main(constants.dir).maybe_sub(used_space))
+                .unwrap_or(used_flex_factor - used_space)
+        };
+
+        // c. Distribute free space proportional to the flex factors.
+        //    - If the remaining free space is zero
+        //        Do Nothing
+        //    - If using the flex grow factor
+        //        Find the ratio of the item’s flex grow factor to the sum of the
+        //        flex grow factors of all unfrozen items on the line. Set the item’s
+        //        target main size to its flex base size plus a fraction of the remaining
+        //        free space proportional to the ratio.
+        //    - If using the flex shrink factor
+        //        For every unfrozen item on the line, multiply its flex shrink factor by
+        //        its inner flex base size, and note this as its scaled flex shrink factor.
+        //        Find the ratio of the item’s scaled flex shrink factor to the sum of the
+        //        scaled flex shrink factors of all unfrozen items on the line. Set the item’s
+        //        target main size to its flex base size minus a fraction of the absolute value
+        //        of the remaining free space proportional to the ratio. Note this may result
+        //        in a negative inner main size; it will be corrected in the next step.
+        //    - Otherwise
+        //        Do Nothing
+
+        if free_space.is_normal() {
+            if growing && sum_flex_grow > 0.0 {
+                for child in line.items.iter_mut().filter(|child| !child.frozen) {
+                    child
+                        .target_size
+                        .set_main(constants.dir, child.flex_basis + free_space * (child.flex_grow / sum_flex_grow));
+                }
+            } else if shrinking && sum_flex_shrink > 0.0 {
+                let sum_scaled_shrink_factor: Scalar = line
+                    .items
+                    .iter()
+                    .filter(|child| !child.frozen)
+                    .map(|child| child.inner_flex_basis * child.flex_shrink)
+                    .sum();
+
+                // The scaled shrink factors sum to zero if every unfrozen item has a zero inner flex basis. In that case
+                // there is nothing to distribute the negative free space by, so the items keep their hypothetical main
+                // sizes (they have no violations and are all frozen below) and the line overflows. Dividing by the sum
+                // here would otherwise produce NaN sizes.
+                if sum_scaled_shrink_factor > 0.0 {
+                    for child in line.items.iter_mut().filter(|child| !child.frozen) {
+                        let scaled_shrink_factor = child.inner_flex_basis * child.flex_shrink;
+                        child.target_size.set_main(
+                            constants.dir,
+                            child.flex_basis + free_space * (scaled_shrink_factor / sum_scaled_shrink_factor),
+                        )
+                    }
+                }
+            }
+        }
+
+        // d. Fix min/max violations. Clamp each non-frozen item’s target main size by its
+        //    used min and max main sizes and floor its content-box size at zero. If the
+        //    item’s target main size was made smaller by this, it’s a max violation.
+        //    If the item’s target main size was made larger by this, it’s a min violation.
+
+        let total_violation = line.items.iter_mut().filter(|child| !child.frozen).fold(0.0, |acc, child| -> Scalar {
+            let resolved_min_main: Option<Scalar> = child.resolved_minimum_main_size.into();
+            let max_main = child.max_size.main(constants.dir);
+            // The target size is a border-box size, so flooring the content-box size at zero means flooring the target
+            // size at the sum of the item's main axis padding and border.
+            let padding_border_main =
+                child.padding.main_axis_sum(constants.dir) + child.border.main_axis_sum(constants.dir);
+            let clamped =
+                child.target_size.main(constants.dir).maybe_clamp(resolved_min_main, max_main).max(padding_border_main);
+            child.violation = clamped - child.target_size.main(constants.dir);
+            child.target_size.set_main(constants.dir, clamped);
+            child.outer_target_size.set_main(
+                constants.dir,
+                child.target_size.main(constants.dir) + child.margin.main_axis_sum(constants.dir),
+            );
+
+            acc + child.violation
+        });
+
+        // e. Freeze over-flexed items. The total violation is the sum of the adjustments
+        //    from the previous step ∑(clamped size - unclamped size). If the total violation is:
+        //    - Zero
+        //        Freeze all items.
+        //    - Positive
+        //        Freeze all the items with min violations.
+        //    - Negative
+        //        Freeze all the items with max violations.
+
+        for child in line.items.iter_mut().filter(|child| !child.frozen) {
+            match total_violation {
+                v if v > 0.0 => child.frozen = child.violation > 0.0,
+                v if v < 0.0 => child.frozen = child.violation < 0.0,
+                _ => child.frozen = true,
+            }
+        }
+
+        // f. Return to the start of this loop.
+    }
+
+    for child in line.items.iter() {
+        debug_assert!(child.target_size.main(constants.dir).is_finite(), "flex item target main size must be finite");
+        debug_assert!(
+            child.outer_target_size.main(constants.dir).is_finite(),
+            "flex item outer target main size must be finite"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_flexible_lengths;
+    use crate::compute::flexbox::{AlgoConstants, FlexItem, FlexLine};
+    use crate::geometry::{Point, Rect, Scalar, Size};
+    use crate::style::{AlignContent, AlignItems, AlignSelf, FlexDirection, Overflow};
+    use crate::tree::NodeId;
+    use crate::util::sys::Vec;
+
+    /// Create a row flex item with the given flex basis, flex factors and min/max main sizes
+    fn flex_item(
+        flex_basis: Scalar,
+        flex_grow: Scalar,
+        flex_shrink: Scalar,
+        min: Scalar,
+        max: Option<Scalar>,
+    ) -> FlexItem {
+        // The hypothetical main size is the flex base size clamped by the min and max main sizes
+        let hypothetical_main_size = flex_basis.max(min).min(max.unwrap_or(Scalar::INFINITY));
+        FlexItem {
+            node: NodeId::new(0),
+            order: 0,
+            size: Size::NONE,
+            min_size: Size { width: Some(min), height: None },
+            max_size: Size { width: max, height: None },
+            align_self: AlignSelf::Stretch,
+            overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
+            scrollbar_width: 0.0,
+            flex_shrink,
+            flex_grow,
+            resolved_minimum_main_size: min,
+            inset: Rect { left: None, right: None, top: None, bottom: None },
+            margin: Rect::zero(),
+            margin_is_auto: Rect { left: false, right: false, top: false, bottom: false },
+            padding: Rect::zero(),
+            border: Rect::zero(),
+            flex_basis,
+            inner_flex_basis: flex_basis,
+            violation: 0.0,
+            frozen: false,
+            content_flex_fraction: 0.0,
+            content_contribution_clamp: (0.0, Scalar::INFINITY),
+            hypothetical_inner_size: Size { width: hypothetical_main_size, height: 0.0 },
+            hypothetical_outer_size: Size { width: hypothetical_main_size, height: 0.0 },
+            target_size: Size::zero(),
+            outer_target_size: Size::zero(),
+            baseline: 0.0,
+            layout_memo: None,
+            offset_main: 0.0,
+            offset_cross: 0.0,
+        }
+    }
+
+    /// Create the constants for a row flex container with the given inner width
+    fn row_constants(inner_width: Scalar) -> AlgoConstants {
+        AlgoConstants {
+            dir: FlexDirection::Row,
+            is_row: true,
+            is_column: false,
+            is_wrap: false,
+            is_wrap_reverse: false,
+            min_size: Size::NONE,
+            max_size: Size::NONE,
+            margin: Rect::zero(),
+            border: Rect::zero(),
+            content_box_inset: Rect::zero(),
+            scrollbar_gutter: Point { x: 0.0, y: 0.0 },
+            gap: Size::zero(),
+            align_items: AlignItems::Stretch,
+            align_content: AlignContent::Stretch,
+            justify_content: None,
+            node_outer_size: Size { width: Some(inner_width), height: None },
+            node_inner_size: Size { width: Some(inner_width), height: None },
+            container_size: Size { width: inner_width, height: 0.0 },
+            inner_container_size: Size { width: inner_width, height: 0.0 },
+        }
+    }
+
+    /// Run `resolve_flexible_lengths` on a single line and return the resulting target main sizes
+    fn resolve(items: &mut [FlexItem], inner_width: Scalar) -> Vec<Scalar> {
+        let mut line = FlexLine { items, cross_size: 0.0, offset_cross: 0.0 };
+        resolve_flexible_lengths(&mut line, &row_constants(inner_width));
+        assert!(line.items.iter().all(|item| item.frozen), "all items should be frozen once the loop exits");
+        line.items.iter().map(|item| item.target_size.width).collect()
+    }
+
+    #[test]
+    fn grow_freezes_max_violation_and_redistributes() {
+        let mut items = [
+            flex_item(0.0, 1.0, 1.0, 0.0, Some(50.0)),
+            flex_item(0.0, 1.0, 1.0, 0.0, None),
+            flex_item(0.0, 1.0, 1.0, 0.0, None),
+        ];
+        assert_eq!(resolve(&mut items, 300.0), [50.0, 125.0, 125.0]);
+    }
+
+    #[test]
+    fn shrink_freezes_min_violation_and_redistributes() {
+        let mut items = [flex_item(100.0, 0.0, 1.0, 80.0, None), flex_item(100.0, 0.0, 1.0, 0.0, None)];
+        assert_eq!(resolve(&mut items, 100.0), [80.0, 20.0]);
+    }
+
+    #[test]
+    fn positive_total_violation_only_freezes_min_violations() {
+        // First pass: each item is sized to 100px. The first item is clamped up to 200px (+100) and the second
+        // down to 40px (-60). The total violation is positive so only the first item is frozen, and the second
+        // item is unfrozen again for the next pass, where the remaining 100px is split between the last two items.
+        // The second item then violates its max again and is frozen, leaving the last item with the remainder.
+        let mut items = [
+            flex_item(0.0, 1.0, 1.0, 200.0, None),
+            flex_item(0.0, 1.0, 1.0, 0.0, Some(40.0)),
+            flex_item(0.0, 1.0, 1.0, 0.0, None),
+        ];
+        assert_eq!(resolve(&mut items, 300.0), [200.0, 40.0, 60.0]);
+    }
+
+    #[test]
+    fn negative_total_violation_only_freezes_max_violations() {
+        // First pass: each item is sized to 100px. The first item is clamped up to 110px (+10) and the second
+        // down to 20px (-80). The total violation is negative so only the second item is frozen. The remaining
+        // 280px is then split between the first and last items, neither of which violate their constraints.
+        let mut items = [
+            flex_item(0.0, 1.0, 1.0, 110.0, None),
+            flex_item(0.0, 1.0, 1.0, 0.0, Some(20.0)),
+            flex_item(0.0, 1.0, 1.0, 0.0, None),
+        ];
+        assert_eq!(resolve(&mut items, 300.0), [140.0, 20.0, 140.0]);
+    }
+
+    #[test]
+    fn grow_factor_sum_less_than_one_distributes_fraction_of_free_space() {
+        let mut items = [flex_item(0.0, 0.25, 1.0, 0.0, None), flex_item(0.0, 0.25, 1.0, 0.0, None)];
+        assert_eq!(resolve(&mut items, 200.0), [50.0, 50.0]);
+    }
+
+    #[test]
+    fn zero_scaled_shrink_factor_sum_leaves_items_at_hypothetical_size() {
+        // Both items are shrinkable, but the first has a zero flex basis and the second a zero flex shrink factor,
+        // so the sum of the scaled shrink factors is zero and the negative free space cannot be distributed.
+        let mut items = [flex_item(0.0, 0.0, 1.0, 0.0, None), flex_item(100.0, 1.0, 0.0, 0.0, None)];
+        assert_eq!(resolve(&mut items, 50.0), [0.0, 100.0]);
+    }
+
+    #[test]
+    fn content_box_size_is_floored_at_zero() {
+        // The first item's share of the negative free space would take its border-box size to zero, below its 30px
+        // of padding. Flooring its content box at zero is a min violation, so it is frozen at 30px and the second
+        // item absorbs the rest of the negative free space.
+        let mut items = [flex_item(40.0, 0.0, 10.0, 0.0, None), flex_item(100.0, 0.0, 1.0, 0.0, None)];
+        items[0].padding.left = 30.0;
+        items[0].inner_flex_basis = 10.0;
+        assert_eq!(resolve(&mut items, 60.0), [30.0, 30.0]);
+    }
+
+    #[test]
+    fn inflexible_items_are_frozen_at_hypothetical_size() {
+        let mut items = [flex_item(120.0, 0.0, 0.0, 0.0, None), flex_item(0.0, 1.0, 1.0, 0.0, None)];
+        assert_eq!(resolve(&mut items, 300.0), [120.0, 180.0]);
+    }
+}
@@ -0,0 +1,97 @@
+//! Scratch buffers for the flex items and flex lines of a flex container.
+//!
+//! The buffers are borrowed from the tree's [`LayoutScratch`](crate::compute::LayoutScratch). See the
+//! [`scratch`](crate::compute::scratch) module for details.
+//!
+//! The buffers are [`ChildrenVec`]s, as a container has at most one flex item and one flex line per child. Without an
+//! allocator, they are therefore fixed-capacity arrays bounded by the maximum number of children of a node.
+use crate::compute::scratch::ScratchItem;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::compute::scratch::{release_buffer, take_buffer};
+use crate::tree::LayoutPartialTree;
+use crate::util::sys::ChildrenVec;
+#[cfg(feature = "std")]
+use crate::util::sys::Vec;
+
+#[cfg(feature = "std")]
+use crate::compute::scratch::{shrink_pool_to_limit, LayoutScratch};
+
+use super::{FlexItem, FlexLine};
+
+/// The retained flex item and flex line buffers
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(in crate::compute) struct FlexboxScratch {
+    /// Flex item buffers that are available for reuse
+    items: Vec<Vec<FlexItem>>,
+    /// Flex line buffers that are available for reuse
+    lines: Vec<Vec<FlexLine<'static>>>,
+}
+
+#[cfg(feature = "std")]
+impl FlexboxScratch {
+    /// Frees the retained buffers whose capacity exceeds `limit`
+    pub(in crate::compute) fn shrink_to_limit(&mut self, limit: usize) {
+        shrink_pool_to_limit(&mut self.items, limit);
+        shrink_pool_to_limit(&mut self.lines, limit);
+    }
+}
+
+impl ScratchItem for FlexItem {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.flexbox.items
+    }
+}
+
+impl ScratchItem for FlexLine<'static> {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.flexbox.lines
+    }
+}
+
+/// Converts a vector of flex lines into an empty vector of flex lines with a different lifetime, reusing its allocation.
+/// As the vector is cleared first, no borrow is extended. `Vec`'s in-place collection means that this doesn't reallocate.
+pub(super) fn relabel_lines<'a, 'b>(mut lines: ChildrenVec<FlexLine<'a>>) -> ChildrenVec<FlexLine<'b>> {
+    lines.clear();
+    lines.into_iter().map(|_| -> FlexLine<'b> { unreachable!() }).collect()
+}
+
+/// Take an empty buffer of flex items from the tree's scratch pool
+pub(super) fn take_items(tree: &mut impl LayoutPartialTree) -> ChildrenVec<FlexItem> {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    return take_buffer(tree);
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    {
+        let _ = tree;
+        ChildrenVec::new()
+    }
+}
+
+/// Return a buffer of flex items to the tree's scratch pool
+pub(super) fn release_items(tree: &mut impl LayoutPartialTree, items: ChildrenVec<FlexItem>) {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    release_buffer(tree, items);
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    let _ = (tree, items);
+}
+
+/// Take an empty buffer of flex lines from the tree's scratch pool
+pub(super) fn take_lines<'a>(tree: &mut impl LayoutPartialTree) -> ChildrenVec<FlexLine<'a>> {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    return relabel_lines(take_buffer::<FlexLine<'static>>(tree));
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    {
+        let _ = tree;
+        ChildrenVec::new()
+    }
+}
+
+/// Return a buffer of flex lines to the tree's scratch pool
+pub(super) fn release_lines(tree: &mut impl LayoutPartialTree, lines: ChildrenVec<FlexLine<'_>>) {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    release_buffer::<FlexLine<'static>>(tree, relabel_lines(lines));
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    let _ = (tree, lines);
+}
@@ -1,10 +1,10 @@
 //! Alignment of tracks and final positioning of items
 use super::types::GridTrack;
 use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
-use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Size};
+use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Scalar, Size};
 use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Overflow, Position};
 use crate::tree::{Layout, LayoutPartialTreeExt, NodeId, SizingMode};
-use crate::util::sys::f32_max;
+use crate::util::sys::scalar_max;
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 
 #[cfg(feature = "content_size")]
@@ -15,13 +15,13 @@ use crate::{BoxSizing, LayoutGridContainer};
 /// justify-content (columns) property. This only does anything if the size of the
 /// grid is not equal to the size of the grid container in the axis being aligned.
 pub(super) fn align_tracks(
-    grid_container_content_box_size: f32,
-    padding: Line<f32>,
-    border: Line<f32>,
+    grid_container_content_box_size: Scalar,
+    padding: Line<Scalar>,
+    border: Line<Scalar>,
     tracks: &mut [GridTrack],
     track_alignment_style: AlignContent,
 ) {
-    let used_size: f32 = tracks.iter().map(|track| track.base_size).sum();
+    let used_size: Scalar = tracks.iter().map(|track| track.base_size).sum();
     let free_space = grid_container_content_box_size - used_size;
     let origin = padding.start + border.start;
 
@@ -55,15 +55,16 @@ pub(super) fn align_tracks(
     });
 }
 
-/// Align and size a grid item into it's final position
+/// Align and size a grid item into it's final position. Returns its content size contribution, its y position and height,
+/// and its first baseline (relative to its border box).
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutGridContainer,
     node: NodeId,
     order: u32,
-    grid_area: Rect<f32>,
+    grid_area: Rect<Scalar>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-    baseline_shim: f32,
-) -> (Size<f32>, f32, f32) {
+    baseline_shim: Scalar,
+) -> (Size<Scalar>, Scalar, Scalar, Option<Scalar>) {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
     let style = tree.get_grid_child_style(node);
@@ -148,7 +149,7 @@ pub(super) fn align_and_position_item(
         // positioned element being set
         if position == Position::Absolute {
             if let (Some(left), Some(right)) = (inset_horizontal.start, inset_horizontal.end) {
-                return Some(f32_max(grid_area_minus_item_margins_size.width - left - right, 0.0));
+                return Some(scalar_max(grid_area_minus_item_margins_size.width - left - right, 0.0));
             }
         }
 
@@ -173,7 +174,7 @@ pub(super) fn align_and_position_item(
     let height = height.or_else(|| {
         if position == Position::Absolute {
             if let (Some(top), Some(bottom)) = (inset_vertical.start, inset_vertical.end) {
-                return Some(f32_max(grid_area_minus_item_margins_size.height - top - bottom, 0.0));
+                return Some(scalar_max(grid_area_minus_item_margins_size.height - top - bottom, 0.0));
             }
         }
 
@@ -249,6 +250,8 @@ pub(super) fn align_and_position_item(
             padding,
             border,
             margin: resolved_margin,
+            first_baseline: layout_output.first_baselines.y,
+            last_baseline: layout_output.last_baselines.y,
         },
     );
 
@@ -258,27 +261,27 @@ pub(super) fn align_and_position_item(
     #[cfg(not(feature = "content_size"))]
     let contribution = Size::ZERO;
 
-    (contribution, y, height)
+    (contribution, y, height, layout_output.first_baselines.y)
 }
 
 /// Align and size a grid item along a single axis
 pub(super) fn align_item_within_area(
-    grid_area: Line<f32>,
+    grid_area: Line<Scalar>,
     alignment_style: AlignSelf,
-    resolved_size: f32,
+    resolved_size: Scalar,
     position: Position,
-    inset: Line<Option<f32>>,
-    margin: Line<Option<f32>>,
-    baseline_shim: f32,
-) -> (f32, Line<f32>) {
+    inset: Line<Option<Scalar>>,
+    margin: Line<Option<Scalar>>,
+    baseline_shim: Scalar,
+) -> (Scalar, Line<Scalar>) {
     // Calculate grid area dimension in the axis
     let non_auto_margin = Line { start: margin.start.unwrap_or(0.0) + baseline_shim, end: margin.end.unwrap_or(0.0) };
-    let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
-    let free_space = f32_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
+    let grid_area_size = scalar_max(grid_area.end - grid_area.start, 0.0);
+    let free_space = scalar_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
 
     // Expand auto margins to fill available space
     let auto_margin_count = margin.start.is_none() as u8 + margin.end.is_none() as u8;
-    let auto_margin_size = if auto_margin_count > 0 { free_space / auto_margin_count as f32 } else { 0.0 };
+    let auto_margin_size = if auto_margin_count > 0 { free_space / auto_margin_count as Scalar } else { 0.0 };
     let resolved_margin = Line {
         start: margin.start.unwrap_or(auto_margin_size) + baseline_shim,
         end: margin.end.unwrap_or(auto_margin_size),
@@ -0,0 +1,105 @@
+//! Human-readable dumps of intermediate grid layout state, used by the `debug` feature to log
+//! placement results and track sizes at each step of the track sizing algorithm.
+use super::types::{GridItem, GridTrack, GridTrackKind};
+use crate::geometry::AbstractAxis;
+use crate::CompactLength;
+use core::fmt::{self, Display, Write};
+
+/// Formats a track sizing function (as stored in a [`CompactLength`]) in CSS-like syntax
+struct SizingFunction(CompactLength);
+
+impl Display for SizingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0.value();
+        match self.0.tag() {
+            CompactLength::LENGTH_TAG => write!(f, "{value}px"),
+            CompactLength::PERCENT_TAG => write!(f, "{}%", value * 100.0),
+            CompactLength::AUTO_TAG => write!(f, "auto"),
+            CompactLength::FR_TAG => write!(f, "{value}fr"),
+            CompactLength::MIN_CONTENT_TAG => write!(f, "min-content"),
+            CompactLength::MAX_CONTENT_TAG => write!(f, "max-content"),
+            CompactLength::FIT_CONTENT_PX_TAG => write!(f, "fit-content({value}px)"),
+            CompactLength::FIT_CONTENT_PERCENT_TAG => write!(f, "fit-content({}%)", value * 100.0),
+            _ if self.0.is_calc() => write!(f, "calc(..)"),
+            _ => write!(f, "?"),
+        }
+    }
+}
+
+/// Dump each track's kind, min/max sizing functions, base size and growth limit (one track per line)
+pub(super) fn format_tracks(axis: AbstractAxis, tracks: &[GridTrack]) -> String {
+    let axis_name = match axis {
+        AbstractAxis::Inline => "column",
+        AbstractAxis::Block => "row",
+    };
+    let mut out = String::new();
+    for (index, track) in tracks.iter().enumerate() {
+        let kind = match track.kind {
+            GridTrackKind::Track => axis_name,
+            GridTrackKind::Gutter => "gutter",
+        };
+        let collapsed = if track.is_collapsed { " (collapsed)" } else { "" };
+        writeln!(
+            out,
+            "  [{index}] {kind}{collapsed}: minmax({}, {}) base_size={} growth_limit={}",
+            SizingFunction(track.min_track_sizing_function.0),
+            SizingFunction(track.max_track_sizing_function.0),
+            track.base_size,
+            track.growth_limit,
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Dump each item's resolved row and column lines (in origin-zero coordinates) and the
+/// corresponding indexes into the track vectors.
+pub(super) fn format_items(items: &[GridItem]) -> String {
+    let mut out = String::new();
+    for item in items {
+        writeln!(
+            out,
+            "  {:?} (source order {}): columns {}..{} (tracks {}..{}), rows {}..{} (tracks {}..{})",
+            item.node,
+            item.source_order,
+            item.column.start.0,
+            item.column.end.0,
+            item.column_indexes.start,
+            item.column_indexes.end,
+            item.row.start.0,
+            item.row.end.0,
+            item.row_indexes.start,
+            item.row_indexes.end,
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_tracks;
+    use crate::compute::grid::types::GridTrack;
+    use crate::geometry::AbstractAxis;
+    use crate::prelude::*;
+
+    #[test]
+    fn dumps_track_sizes() {
+        let mut tracks = [
+            GridTrack::gutter(LengthPercentage::ZERO),
+            GridTrack::new(auto(), auto()),
+            GridTrack::gutter(length(10.0)),
+            GridTrack::new(auto(), fr(1.0)),
+            GridTrack::gutter(LengthPercentage::ZERO),
+        ];
+        for (track, size) in tracks.iter_mut().zip([0.0, 30.0, 10.0, 160.0, 0.0]) {
+            track.base_size = size;
+            track.growth_limit = size;
+        }
+
+        let dump = format_tracks(AbstractAxis::Inline, &tracks);
+        assert!(dump.contains("[1] column: minmax(auto, auto) base_size=30 growth_limit=30"), "{dump}");
+        assert!(dump.contains("[2] gutter: minmax(10px, 10px) base_size=10 growth_limit=10"), "{dump}");
+        assert!(dump.contains("[3] column: minmax(auto, 1fr) base_size=160 growth_limit=160"), "{dump}");
+    }
+}
@@ -1,10 +1,10 @@
 //! Helper functions for initialising GridTrack's from styles
 //! This mainly consists of evaluating GridAutoTracks
 use super::types::{GridTrack, TrackCounts};
-use crate::geometry::{AbsoluteAxis, Size};
+use crate::geometry::{AbsoluteAxis, Scalar, Size};
 use crate::style::{GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, TrackSizingFunction};
 use crate::style_helpers::TaffyAuto;
-use crate::util::sys::{ceil, floor, Vec};
+use crate::util::sys::{ceil, floor, scalar_max, Vec};
 use crate::util::MaybeMath;
 use crate::util::ResolveOrZero;
 use crate::{GridContainerStyle, MaybeResolve};
@@ -13,8 +13,8 @@ use crate::{GridContainerStyle, MaybeResolve};
 pub(crate) fn compute_explicit_grid_size_in_axis(
     style: &impl GridContainerStyle,
     template: &[TrackSizingFunction],
-    inner_container_size: Size<Option<f32>>,
-    resolve_calc_value: impl Fn(u64, f32) -> f32,
+    inner_container_size: Size<Option<Scalar>>,
+    resolve_calc_value: impl Fn(u64, Scalar) -> Scalar,
     axis: AbsoluteAxis,
 ) -> u16 {
     // If template contains no tracks, then there are trivially zero explicit tracks
@@ -105,15 +105,15 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             /// otherwise, flooring the max track sizing function by the min track sizing function if both are definite
             fn track_definite_value(
                 sizing_function: &NonRepeatedTrackSizingFunction,
-                parent_size: Option<f32>,
-                calc_resolver: impl Fn(u64, f32) -> f32,
-            ) -> f32 {
+                parent_size: Option<Scalar>,
+                calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+            ) -> Scalar {
                 let max_size = sizing_function.max.definite_value(parent_size, &calc_resolver);
                 let min_size = sizing_function.min.definite_value(parent_size, &calc_resolver);
                 max_size.map(|max| max.maybe_min(min_size)).or(min_size).unwrap()
             }
 
-            let non_repeating_track_used_space: f32 = template
+            let non_repeating_track_used_space: Scalar = template
                 .iter()
                 .map(|track_def| {
                     use GridTrackRepetition::{AutoFill, AutoFit, Count};
@@ -127,8 +127,8 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                                 .map(|sizing_function| {
                                     track_definite_value(sizing_function, parent_size, &resolve_calc_value)
                                 })
-                                .sum::<f32>();
-                            sum * (*count as f32)
+                                .sum::<Scalar>();
+                            sum * (*count as Scalar)
                         }
                         TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0.0,
                     }
@@ -137,23 +137,26 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             let gap_size = style.gap().get_abs(axis).resolve_or_zero(Some(inner_container_size), &resolve_calc_value);
 
             // Compute the amount of space that a single repetition of the repeated track list takes
-            let per_repetition_track_used_space: f32 = repetition_definition
+            // The spec requires that we floor the size of each repeated track to avoid division by zero, and suggests 1px.
+            let per_repetition_track_used_space: Scalar = repetition_definition
                 .iter()
-                .map(|sizing_function| track_definite_value(sizing_function, parent_size, &resolve_calc_value))
-                .sum::<f32>();
+                .map(|sizing_function| {
+                    scalar_max(track_definite_value(sizing_function, parent_size, &resolve_calc_value), 1.0)
+                })
+                .sum::<Scalar>();
 
             // We special case the first repetition here because the number of gaps in the first repetition
             // depends on the number of non-repeating tracks in the template
             let first_repetition_and_non_repeating_tracks_used_space = non_repeating_track_used_space
                 + per_repetition_track_used_space
-                + ((non_auto_repeating_track_count + repetition_track_count).saturating_sub(1) as f32 * gap_size);
+                + ((non_auto_repeating_track_count + repetition_track_count).saturating_sub(1) as Scalar * gap_size);
 
             // If a single repetition already overflows the container then we return 1 as the repetition count
             // (the number of repetitions is floored at 1)
             if first_repetition_and_non_repeating_tracks_used_space > inner_container_size {
                 1u16
             } else {
-                let per_repetition_gap_used_space = (repetition_definition.len() as f32) * gap_size;
+                let per_repetition_gap_used_space = (repetition_definition.len() as Scalar) * gap_size;
                 let per_repetition_used_space = per_repetition_track_used_space + per_repetition_gap_used_space;
                 let num_repetition_that_fit = (inner_container_size
                     - first_repetition_and_non_repeating_tracks_used_space)
@@ -166,15 +169,15 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                 //
                 // In all cases we add the additional repetition that was already accounted for in the special-case computation above
                 if size_is_maximum {
-                    (floor(num_repetition_that_fit) as u16) + 1
+                    (floor(num_repetition_that_fit) as u16).saturating_add(1)
                 } else {
-                    (ceil(num_repetition_that_fit) as u16) + 1
+                    (ceil(num_repetition_that_fit) as u16).saturating_add(1)
                 }
             }
         }
     };
 
-    non_auto_repeating_track_count + (repetition_track_count * num_repetitions)
+    non_auto_repeating_track_count.saturating_add(repetition_track_count.saturating_mul(num_repetitions))
 }
 
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
@@ -211,6 +214,18 @@ pub(super) fn initialize_grid_tracks(
     // An explicit check against the count (rather than just relying on track_template being empty) is required here
     // because a count of zero can result from the track_template being invalid, in which case it should be ignored.
     if counts.explicit > 0 {
+        // The number of explicit tracks that are not generated by an auto-repetition. This is used to determine how many
+        // tracks were generated by the auto-repetition (if any), which must be done in terms of tracks rather than
+        // track definitions as a fixed-count repetition can contain multiple tracks.
+        let non_auto_repeated_track_count = track_template
+            .iter()
+            .map(|track_def| match track_def {
+                TrackSizingFunction::Single(_) => 1,
+                TrackSizingFunction::Repeat(GridTrackRepetition::Count(count), tracks) => count * tracks.len() as u16,
+                TrackSizingFunction::Repeat(GridTrackRepetition::AutoFit | GridTrackRepetition::AutoFill, _) => 0,
+            })
+            .sum::<u16>();
+
         track_template.iter().for_each(|track_sizing_function| {
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match track_sizing_function {
@@ -234,7 +249,7 @@ pub(super) fn initialize_grid_tracks(
                     });
                 }
                 TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks) => {
-                    let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
+                    let auto_repeated_track_count = (counts.explicit - non_auto_repeated_track_count) as usize;
                     let iter = repeated_tracks.iter().copied().cycle();
                     for track_def in iter.take(auto_repeated_track_count) {
                         let mut track =
@@ -298,7 +313,7 @@ mod test {
     #[test]
     fn explicit_grid_sizing_no_repeats() {
         let grid_style = (600.0, 600.0, 2, 4).into_grid();
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -327,7 +342,7 @@ mod test {
             grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -356,7 +371,7 @@ mod test {
             grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -443,7 +458,7 @@ mod test {
             grid_template_rows: vec![repeat(AutoFill, vec![length(20.0), length(10.0)])],
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -473,7 +488,7 @@ mod test {
             gap: length(20.0),
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -502,7 +517,7 @@ mod test {
             gap: length(20.0),
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -532,7 +547,7 @@ mod test {
             gap: length(20.0),
             ..Default::default()
         };
-        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let preferred_size = grid_style.size.map(|s| s.into_option().map(|value| value as Scalar));
         let width = compute_explicit_grid_size_in_axis(
             &grid_style,
             &grid_style.grid_template_columns,
@@ -199,7 +199,7 @@ mod tests {
         fn explicit_grid_sizing_with_children() {
             let explicit_col_count = 6;
             let explicit_row_count = 8;
-            let child_styles = vec![
+            let child_styles = [
                 (line(1), span(2), line(2), auto()).into_grid_child(),
                 (line(-4), auto(), line(-2), auto()).into_grid_child(),
             ];
@@ -217,7 +217,7 @@ mod tests {
         fn negative_implicit_grid_sizing() {
             let explicit_col_count = 4;
             let explicit_row_count = 4;
-            let child_styles = vec![
+            let child_styles = [
                 (line(-6), span(2), line(-8), auto()).into_grid_child(),
                 (line(4), auto(), line(3), auto()).into_grid_child(),
             ];
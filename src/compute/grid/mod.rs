@@ -2,12 +2,15 @@
 //! <https://www.w3.org/TR/css-grid-1>
 use core::borrow::Borrow;
 
-use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
+use crate::compute::scratch::{release_buffer, take_buffer};
+use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis, Scalar};
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{AlignItems, AlignSelf, AvailableSpace, Overflow, Position};
 use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
-use crate::util::debug::debug_log;
-use crate::util::sys::{f32_max, GridTrackVec, Vec};
+use crate::util::debug::{debug_log, debug_record_algorithm};
+use crate::util::sys::scalar_max;
+#[cfg(feature = "detailed_layout_info")]
+use crate::util::sys::Vec;
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{
@@ -18,6 +21,7 @@ use alignment::{align_and_position_item, align_tracks};
 use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
 use placement::place_grid_items;
+use scratch::release_grid_buffers;
 use track_sizing::{
     determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
 };
@@ -26,12 +30,17 @@ use types::{CellOccupancyMatrix, GridTrack};
 #[cfg(feature = "detailed_layout_info")]
 use types::{GridItem, GridTrackKind, TrackCounts};
 
+#[cfg(feature = "std")]
+pub(super) use scratch::GridScratch;
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
 
 mod alignment;
+#[cfg(any(feature = "debug", feature = "tracing"))]
+mod debug;
 mod explicit_grid;
 mod implicit_grid;
 mod placement;
+mod scratch;
 mod track_sizing;
 mod types;
 mod util;
@@ -47,8 +56,15 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     node: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    debug_record_algorithm!("grid");
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
+    // Take the scratch buffers up front, as the container's style borrows the tree until the tracks are initialized
+    let mut items = take_buffer(tree);
+    let mut columns = take_buffer(tree);
+    let mut rows = take_buffer(tree);
+    let cell_buffers = [take_buffer(tree), take_buffer(tree)];
+
     let style = tree.get_grid_container_style(node);
 
     // 1. Compute "available grid space"
@@ -98,13 +114,6 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     let align_items = style.align_items();
     let justify_items = style.justify_items();
 
-    // Note: we avoid accessing the grid rows/columns methods more than once as this can
-    // cause an expensive-ish computation
-    let grid_template_columms = style.grid_template_columns();
-    let grid_template_rows = style.grid_template_rows();
-    let grid_auto_columms = style.grid_auto_columns();
-    let grid_auto_rows = style.grid_auto_rows();
-
     let constrained_available_space = known_dimensions
         .or(preferred_size)
         .map(|size| size.map(AvailableSpace::Definite))
@@ -134,9 +143,21 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     if let (RunMode::ComputeSize, Some(width), Some(height)) = (run_mode, outer_node_size.width, outer_node_size.height)
     {
+        drop(style);
+        let [cells, spare_cells] = cell_buffers;
+        release_buffer(tree, cells);
+        release_buffer(tree, spare_cells);
+        release_grid_buffers(tree, items, columns, rows);
         return LayoutOutput::from_outer_size(Size { width, height });
     }
 
+    // Note: we avoid accessing the grid rows/columns methods more than once as this can
+    // cause an expensive-ish computation
+    let grid_template_columms = style.grid_template_columns();
+    let grid_template_rows = style.grid_template_rows();
+    let grid_auto_columms = style.grid_auto_columns();
+    let grid_auto_rows = style.grid_auto_rows();
+
     let get_child_styles_iter =
         |node| tree.child_ids(node).map(|child_node: NodeId| tree.get_grid_child_style(child_node));
     let child_styles_iter = get_child_styles_iter(node);
@@ -176,8 +197,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // 4. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
-    let mut items = Vec::with_capacity(tree.child_count(node));
-    let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+    items.reserve(tree.child_count(node));
+    let [cells, spare_cells] = cell_buffers;
+    let mut cell_occupancy_matrix =
+        CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts, cells, spare_cells);
     let in_flow_children_iter = || {
         tree.child_ids(node)
             .enumerate()
@@ -194,6 +217,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         align_items.unwrap_or(AlignItems::Stretch),
         justify_items.unwrap_or(AlignItems::Stretch),
     );
+    debug_log!("Cell occupancy after placement\n", dbg:&cell_occupancy_matrix);
 
     // Extract track counts from previous step (auto-placement can expand the number of tracks)
     let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
@@ -202,8 +226,6 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // 5. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
     // This resolves the min and max track sizing functions for all tracks and gutters
-    let mut columns = GridTrackVec::new();
-    let mut rows = GridTrackVec::new();
     initialize_grid_tracks(
         &mut columns,
         final_col_counts,
@@ -227,12 +249,17 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     drop(grid_auto_columms);
     drop(style);
 
+    let [cells, spare_cells] = cell_occupancy_matrix.into_buffers();
+    release_buffer(tree, cells);
+    release_buffer(tree, spare_cells);
+
     // 6. Track Sizing
 
     // Convert grid placements in origin-zero coordinates to indexes into the GridTrack (rows and columns) vectors
     // This computation is relatively trivial, but it requires the final number of negative (implicit) tracks in
     // each axis, and doing it up-front here means we don't have to keep repeating that calculation
     resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
+    debug_log!("Resolved item placements\n", debug::format_items(&items));
 
     // For each item, and in each axis, determine whether the item crosses any flexible (fr) tracks
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
@@ -254,12 +281,12 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         &mut columns,
         &mut rows,
         &mut items,
-        |track: &GridTrack, parent_size: Option<f32>, tree: &Tree| {
+        |track: &GridTrack, parent_size: Option<Scalar>, tree: &Tree| {
             track.max_track_sizing_function.definite_value(parent_size, |val, basis| tree.calc(val, basis))
         },
         has_baseline_aligned_item,
     );
-    let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
+    let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<Scalar>();
     inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
 
     items.iter_mut().for_each(|item| item.available_space_cache = None);
@@ -280,7 +307,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         |track: &GridTrack, _, _| Some(track.base_size),
         false, // TODO: Support baseline alignment in the vertical axis
     );
-    let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
+    let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<Scalar>();
     inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
 
     debug_log!("initial_column_sum", dbg:initial_column_sum);
@@ -303,12 +330,13 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             .max(padding_border_size.height),
     };
     let container_content_box = Size {
-        width: f32_max(0.0, container_border_box.width - content_box_inset.horizontal_axis_sum()),
-        height: f32_max(0.0, container_border_box.height - content_box_inset.vertical_axis_sum()),
+        width: scalar_max(0.0, container_border_box.width - content_box_inset.horizontal_axis_sum()),
+        height: scalar_max(0.0, container_border_box.height - content_box_inset.vertical_axis_sum()),
     };
 
     // If only the container's size has been requested
     if run_mode == RunMode::ComputeSize {
+        release_grid_buffers(tree, items, columns, rows);
         return LayoutOutput::from_outer_size(container_border_box);
     }
 
@@ -317,10 +345,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // and therefore need to be re-resolved here based on the content-sized content box of the container
     if !available_grid_space.width.is_definite() {
         for column in &mut columns {
-            let min: Option<f32> = column
+            let min: Option<Scalar> = column
                 .min_track_sizing_function
                 .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(val, basis));
-            let max: Option<f32> = column
+            let max: Option<Scalar> = column
                 .max_track_sizing_function
                 .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(val, basis));
             column.base_size = column.base_size.maybe_clamp(min, max);
@@ -328,10 +356,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     }
     if !available_grid_space.height.is_definite() {
         for row in &mut rows {
-            let min: Option<f32> = row
+            let min: Option<Scalar> = row
                 .min_track_sizing_function
                 .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(val, basis));
-            let max: Option<f32> = row
+            let max: Option<Scalar> = row
                 .max_track_sizing_function
                 .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(val, basis));
             row.base_size = row.base_size.maybe_clamp(min, max);
@@ -490,7 +518,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     let container_alignment_styles = InBothAbsAxis { horizontal: justify_items, vertical: align_items };
 
     // Position in-flow children (stored in items vector)
-    for (index, item) in items.iter_mut().enumerate() {
+    for item in items.iter_mut() {
         let grid_area = Rect {
             top: rows[item.row_indexes.start as usize + 1].offset,
             bottom: rows[item.row_indexes.end as usize].offset,
@@ -498,16 +526,17 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             right: columns[item.column_indexes.end as usize].offset,
         };
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-        let (content_size_contribution, y_position, height) = align_and_position_item(
+        let (content_size_contribution, y_position, height, first_baseline) = align_and_position_item(
             tree,
             item.node,
-            index as u32,
+            item.source_order as u32,
             grid_area,
             container_alignment_styles,
             item.baseline_shim,
         );
         item.y_position = y_position;
         item.height = height;
+        item.first_baseline = first_baseline;
 
         #[cfg(feature = "content_size")]
         {
@@ -515,94 +544,96 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         }
     }
 
-    // Position hidden and absolutely positioned children
-    let mut order = items.len() as u32;
-    (0..tree.child_count(node)).for_each(|index| {
-        let child = tree.get_child_id(node, index);
-        let child_style = tree.get_grid_child_style(child);
-
-        // Position hidden child
-        if child_style.box_generation_mode() == BoxGenerationMode::None {
-            drop(child_style);
-            tree.set_unrounded_layout(child, &Layout::with_order(order));
-            tree.perform_child_layout(
-                child,
-                Size::NONE,
-                Size::NONE,
-                Size::MAX_CONTENT,
-                SizingMode::InherentSize,
-                Line::FALSE,
-            );
-            order += 1;
-            return;
-        }
-
-        // Position absolutely positioned child
-        if child_style.position() == Position::Absolute {
-            // Convert grid-col-{start/end} into Option's of indexes into the columns vector
-            // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_col_indexes = child_style
-                .grid_column()
-                .into_origin_zero(final_col_counts.explicit)
-                .resolve_absolutely_positioned_grid_tracks()
-                .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
-                });
-            // Convert grid-row-{start/end} into Option's of indexes into the row vector
-            // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_row_indexes = child_style
-                .grid_row()
-                .into_origin_zero(final_row_counts.explicit)
-                .resolve_absolutely_positioned_grid_tracks()
-                .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
-                });
-
-            let grid_area = Rect {
-                top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(border.top),
-                bottom: maybe_row_indexes
-                    .end
-                    .map(|index| rows[index].offset)
-                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
-                left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(border.left),
-                right: maybe_col_indexes
-                    .end
-                    .map(|index| columns[index].offset)
-                    .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
-            };
-            drop(child_style);
-
-            // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
-            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-            let (content_size_contribution, _, _) =
-                align_and_position_item(tree, child, order, grid_area, container_alignment_styles, 0.0);
-            #[cfg(feature = "content_size")]
-            {
-                item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
+    // Position hidden and absolutely positioned children. The order of every child (including in-flow children) is its
+    // index in the list of children. Every child that isn't a grid item is either hidden or absolutely positioned, so
+    // this pass is skipped in the common case where there are none.
+    if items.len() < tree.child_count(node) {
+        (0..tree.child_count(node)).for_each(|index| {
+            let child = tree.get_child_id(node, index);
+            let order = index as u32;
+            let child_style = tree.get_grid_child_style(child);
+
+            // Position hidden child
+            if child_style.box_generation_mode() == BoxGenerationMode::None {
+                drop(child_style);
+                tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                tree.set_unrounded_layout(child, &Layout::with_order(order));
+                return;
             }
 
-            order += 1;
-        }
-    });
+            // Position absolutely positioned child
+            if child_style.position() == Position::Absolute {
+                // Convert grid-col-{start/end} into Option's of indexes into the columns vector
+                // The Option is None if the style property is Auto and an unresolvable Span
+                let maybe_col_indexes = child_style
+                    .grid_column()
+                    .into_origin_zero(final_col_counts.explicit)
+                    .resolve_absolutely_positioned_grid_tracks()
+                    .map(|maybe_grid_line| {
+                        maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
+                    });
+                // Convert grid-row-{start/end} into Option's of indexes into the row vector
+                // The Option is None if the style property is Auto and an unresolvable Span
+                let maybe_row_indexes = child_style
+                    .grid_row()
+                    .into_origin_zero(final_row_counts.explicit)
+                    .resolve_absolutely_positioned_grid_tracks()
+                    .map(|maybe_grid_line| {
+                        maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
+                    });
+
+                let grid_area = Rect {
+                    top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(border.top),
+                    bottom: maybe_row_indexes
+                        .end
+                        .map(|index| rows[index].offset)
+                        .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
+                    left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(border.left),
+                    right: maybe_col_indexes
+                        .end
+                        .map(|index| columns[index].offset)
+                        .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
+                };
+                drop(child_style);
+
+                // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
+                #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+                let (content_size_contribution, _, _, _) =
+                    align_and_position_item(tree, child, order, grid_area, container_alignment_styles, 0.0);
+                #[cfg(feature = "content_size")]
+                {
+                    item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
+                }
+            }
+        });
+    }
 
     // Set detailed grid information
     #[cfg(feature = "detailed_layout_info")]
     tree.set_detailed_grid_info(
         node,
         DetailedGridInfo {
-            rows: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_row_counts, rows),
-            columns: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_col_counts, columns),
+            rows: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_row_counts, &rows),
+            columns: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_col_counts, &columns),
             items: items.iter().map(DetailedGridItemsInfo::from_grid_item).collect(),
         },
     );
 
     // If there are not items then return just the container size (no baseline)
     if items.is_empty() {
+        release_grid_buffers(tree, items, columns, rows);
         return LayoutOutput::from_outer_size(container_border_box);
     }
 
     // Determine the grid container baseline(s) (currently we only compute the first baseline)
-    let grid_container_baseline: f32 = {
+    let grid_container_baseline: Scalar = {
         // Sort items by row start position so that we can iterate items in groups which are in the same row
         items.sort_by_key(|item| item.row_indexes.start);
 
@@ -621,9 +652,11 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             &first_row_items[0]
         };
 
-        item.y_position + item.baseline.unwrap_or(item.height)
+        item.y_position + item.first_baseline.unwrap_or(item.height)
     };
 
+    release_grid_buffers(tree, items, columns, rows);
+
     LayoutOutput::from_sizes_and_baselines(
         container_border_box,
         item_content_size_contribution,
@@ -655,16 +688,16 @@ pub struct DetailedGridTracksInfo {
     pub positive_implicit_tracks: u16,
 
     /// Gutters between tracks
-    pub gutters: Vec<f32>,
+    pub gutters: Vec<Scalar>,
     /// The used size of the tracks
-    pub sizes: Vec<f32>,
+    pub sizes: Vec<Scalar>,
 }
 
 #[cfg(feature = "detailed_layout_info")]
 impl DetailedGridTracksInfo {
     /// Get the base_size of [`GridTrack`] with a kind [`types::GridTrackKind`]
     #[inline(always)]
-    fn grid_track_base_size_of_kind(grid_tracks: &[GridTrack], kind: GridTrackKind) -> Vec<f32> {
+    fn grid_track_base_size_of_kind(grid_tracks: &[GridTrack], kind: GridTrackKind) -> Vec<Scalar> {
         grid_tracks
             .iter()
             .filter_map(|track| match track.kind == kind {
@@ -675,23 +708,23 @@ impl DetailedGridTracksInfo {
     }
 
     /// Get the sizes of the gutters
-    fn gutters_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<f32> {
+    fn gutters_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<Scalar> {
         DetailedGridTracksInfo::grid_track_base_size_of_kind(grid_tracks, GridTrackKind::Gutter)
     }
 
     /// Get the sizes of the tracks
-    fn sizes_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<f32> {
+    fn sizes_from_grid_track_layout(grid_tracks: &[GridTrack]) -> Vec<Scalar> {
         DetailedGridTracksInfo::grid_track_base_size_of_kind(grid_tracks, GridTrackKind::Track)
     }
 
     /// Construct DetailedGridTracksInfo from TrackCounts and GridTracks
-    fn from_grid_tracks_and_track_count(track_count: TrackCounts, grid_tracks: Vec<GridTrack>) -> Self {
+    fn from_grid_tracks_and_track_count(track_count: TrackCounts, grid_tracks: &[GridTrack]) -> Self {
         DetailedGridTracksInfo {
             negative_implicit_tracks: track_count.negative_implicit,
             explicit_tracks: track_count.explicit,
             positive_implicit_tracks: track_count.positive_implicit,
-            gutters: DetailedGridTracksInfo::gutters_from_grid_track_layout(&grid_tracks),
-            sizes: DetailedGridTracksInfo::sizes_from_grid_track_layout(&grid_tracks),
+            gutters: DetailedGridTracksInfo::gutters_from_grid_track_layout(grid_tracks),
+            sizes: DetailedGridTracksInfo::sizes_from_grid_track_layout(grid_tracks),
         }
     }
 }
@@ -12,6 +12,10 @@ use crate::GridItemStyle;
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
 ///
+/// Within each step of the algorithm items are processed in "order-modified document order". As Taffy does not
+/// implement the `order` property this is simply the order of the children as returned by `children_iter`, which
+/// means that the resulting placement is deterministic and stable with respect to the child order.
+///
 /// [Specification](https://www.w3.org/TR/css-grid-2/#auto-placement-algo)
 pub(super) fn place_grid_items<'a, S, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
@@ -231,8 +235,6 @@ fn place_indefinitely_positioned_item(
     let has_definite_primary_axis_position = primary_placement_style.is_definite();
     let primary_axis_grid_start_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_start_line();
     let primary_axis_grid_end_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_end_line();
-    let secondary_axis_grid_start_line =
-        cell_occupancy_matrix.track_counts(primary_axis.other_axis()).implicit_start_line();
 
     let line_area_is_occupied = |primary_span, secondary_span| {
         !cell_occupancy_matrix.line_area_is_unoccupied(primary_axis, primary_span, secondary_span)
@@ -244,13 +246,13 @@ fn place_indefinitely_positioned_item(
         let definite_primary_placement = primary_placement_style.resolve_definite_grid_lines();
         let defined_primary_idx = definite_primary_placement.start;
 
-        // Compute starting position for search
-        if defined_primary_idx < primary_idx && secondary_idx != secondary_axis_grid_start_line {
-            secondary_idx = secondary_axis_grid_start_line;
-            primary_idx = defined_primary_idx + 1;
-        } else {
-            primary_idx = defined_primary_idx;
+        // Compute starting position for search: set the primary axis position of the cursor to the item's
+        // start line. If this is less than the previous primary axis position of the cursor, then move the
+        // cursor on to the next track in the secondary axis.
+        if defined_primary_idx < primary_idx {
+            secondary_idx += 1;
         }
+        primary_idx = defined_primary_idx;
 
         // Item has fixed primary axis position: so we simply increment the secondary axis position
         // until we find a space that the item fits in
@@ -374,7 +376,7 @@ mod tests {
             let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
-                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
+                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1, Vec::new(), Vec::new());
 
             // Run placement algorithm
             place_grid_items(
@@ -0,0 +1,68 @@
+//! Scratch buffers for the items, tracks and cell occupancy matrix of a grid container.
+//!
+//! The buffers are borrowed from the tree's [`LayoutScratch`](crate::compute::LayoutScratch). See the
+//! [`scratch`](crate::compute::scratch) module for details.
+use crate::compute::scratch::{release_buffer, ScratchItem};
+use crate::tree::LayoutPartialTree;
+use crate::util::sys::Vec;
+
+#[cfg(feature = "std")]
+use crate::compute::scratch::{shrink_pool_to_limit, LayoutScratch};
+
+use super::types::{CellOccupancyState, GridItem, GridTrack};
+
+/// The retained grid item, grid track and cell occupancy buffers
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(in crate::compute) struct GridScratch {
+    /// Grid item buffers that are available for reuse
+    items: Vec<Vec<GridItem>>,
+    /// Grid track buffers that are available for reuse. Each grid container uses two: one for its rows and one for
+    /// its columns.
+    tracks: Vec<Vec<GridTrack>>,
+    /// Cell occupancy buffers that are available for reuse
+    cells: Vec<Vec<CellOccupancyState>>,
+}
+
+#[cfg(feature = "std")]
+impl GridScratch {
+    /// Frees the retained buffers whose capacity exceeds `limit`
+    pub(in crate::compute) fn shrink_to_limit(&mut self, limit: usize) {
+        shrink_pool_to_limit(&mut self.items, limit);
+        shrink_pool_to_limit(&mut self.tracks, limit);
+        shrink_pool_to_limit(&mut self.cells, limit);
+    }
+}
+
+impl ScratchItem for GridItem {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.grid.items
+    }
+}
+
+impl ScratchItem for GridTrack {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.grid.tracks
+    }
+}
+
+impl ScratchItem for CellOccupancyState {
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>> {
+        &mut scratch.grid.cells
+    }
+}
+
+/// Return the item and track buffers of a grid container to the tree's scratch pool
+pub(super) fn release_grid_buffers(
+    tree: &mut impl LayoutPartialTree,
+    items: Vec<GridItem>,
+    columns: Vec<GridTrack>,
+    rows: Vec<GridTrack>,
+) {
+    release_buffer(tree, rows);
+    release_buffer(tree, columns);
+    release_buffer(tree, items);
+}
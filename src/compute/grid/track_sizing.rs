@@ -1,15 +1,24 @@
 //! Implements the track sizing algorithm
 //! <https://www.w3.org/TR/css-grid-1/#layout-algorithm>
 use super::types::{GridItem, GridTrack, TrackCounts};
-use crate::geometry::{AbstractAxis, Line, Size};
+use crate::geometry::{AbstractAxis, Line, Scalar, Size};
 use crate::style::{AlignContent, AlignSelf, AvailableSpace};
 use crate::style_helpers::TaffyMinContent;
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, SizingMode};
-use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::debug::debug_log;
+use crate::util::sys::{scalar_max, scalar_min, Vec};
 use crate::util::{MaybeMath, ResolveOrZero};
 use crate::CompactLength;
 use core::cmp::Ordering;
 
+/// Log the sizing functions, base sizes and growth limits of the tracks being sized after a step of the
+/// track sizing algorithm. Compiles to nothing unless the `debug` feature is enabled.
+macro_rules! debug_log_tracks {
+    ($step:literal, $axis:expr, $tracks:expr) => {
+        debug_log!($step, format_args!("({:?})\n{}", $axis, super::debug::format_tracks($axis, $tracks)));
+    };
+}
+
 /// Takes an axis, and a list of grid items sorted firstly by whether they cross a flex track
 /// in the specified axis (items that don't cross a flex track first) and then by the number
 /// of tracks they cross in specified axis (ascending order).
@@ -67,7 +76,7 @@ impl ItemBatcher {
 struct IntrisicSizeMeasurer<'tree, 'oat, Tree, EstimateFunction>
 where
     Tree: LayoutPartialTree,
-    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    EstimateFunction: Fn(&GridTrack, Option<Scalar>, &Tree) -> Option<Scalar>,
 {
     /// The layout tree
     tree: &'tree mut Tree,
@@ -79,20 +88,20 @@ where
     /// The axis we are currently sizing
     axis: AbstractAxis,
     /// The available grid space
-    inner_node_size: Size<Option<f32>>,
+    inner_node_size: Size<Option<Scalar>>,
 }
 
 impl<Tree, EstimateFunction> IntrisicSizeMeasurer<'_, '_, Tree, EstimateFunction>
 where
     Tree: LayoutPartialTree,
-    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    EstimateFunction: Fn(&GridTrack, Option<Scalar>, &Tree) -> Option<Scalar>,
 {
     /// Compute the available_space to be passed to the child sizing functions
     /// These are estimates based on either the max track sizing function or the provisional base size in the opposite
     /// axis to the one currently being sized.
     /// https://www.w3.org/TR/css-grid-1/#algo-overview
     #[inline(always)]
-    fn available_space(&self, item: &mut GridItem) -> Size<Option<f32>> {
+    fn available_space(&self, item: &mut GridItem) -> Size<Option<Scalar>> {
         item.available_space_cached(
             self.axis,
             self.other_axis_tracks,
@@ -104,19 +113,19 @@ where
     /// Compute the item's resolved margins for size contributions. Horizontal percentage margins always resolve
     /// to zero if the container size is indefinite as otherwise this would introduce a cyclic dependency.
     #[inline(always)]
-    fn margins_axis_sums_with_baseline_shims(&self, item: &GridItem) -> Size<f32> {
+    fn margins_axis_sums_with_baseline_shims(&self, item: &GridItem) -> Size<Scalar> {
         item.margins_axis_sums_with_baseline_shims(self.inner_node_size.width, self.tree)
     }
 
     /// Simple pass-through function to `LayoutPartialTreeExt::calc`
     #[inline(always)]
-    fn calc(&self, val: u64, basis: f32) -> f32 {
+    fn calc(&self, val: u64, basis: Scalar) -> Scalar {
         self.tree.calc(val, basis)
     }
 
     /// Retrieve the item's min content contribution from the cache or compute it using the provided parameters
     #[inline(always)]
-    fn min_content_contribution(&mut self, item: &mut GridItem) -> f32 {
+    fn min_content_contribution(&mut self, item: &mut GridItem) -> Scalar {
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -126,7 +135,7 @@ where
 
     /// Retrieve the item's max content contribution from the cache or compute it using the provided parameters
     #[inline(always)]
-    fn max_content_contribution(&mut self, item: &mut GridItem) -> f32 {
+    fn max_content_contribution(&mut self, item: &mut GridItem) -> Scalar {
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -142,7 +151,7 @@ where
     ///
     /// Because the minimum contribution often depends on the size of the item’s content, it is considered a type of intrinsic size contribution.
     #[inline(always)]
-    fn minimum_contribution(&mut self, item: &mut GridItem, axis_tracks: &[GridTrack]) -> f32 {
+    fn minimum_contribution(&mut self, item: &mut GridItem, axis_tracks: &[GridTrack]) -> Scalar {
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -180,10 +189,10 @@ pub(super) fn cmp_by_cross_flex_then_span_then_start(
 #[inline(always)]
 pub(super) fn compute_alignment_gutter_adjustment(
     alignment: AlignContent,
-    axis_inner_node_size: Option<f32>,
-    get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
+    axis_inner_node_size: Option<Scalar>,
+    get_track_size_estimate: impl Fn(&GridTrack, Option<Scalar>) -> Option<Scalar>,
     tracks: &[GridTrack],
-) -> f32 {
+) -> Scalar {
     if tracks.len() <= 1 {
         return 0.0;
     }
@@ -219,17 +228,19 @@ pub(super) fn compute_alignment_gutter_adjustment(
     }
 
     if let Some(axis_inner_node_size) = axis_inner_node_size {
+        // Free space is floored at zero: when the tracks overflow the container the distributed alignment keywords fall
+        // back to start/center alignment (see `apply_alignment_fallback`), neither of which adds space to inner gutters.
         let free_space = tracks
             .iter()
             .map(|track| get_track_size_estimate(track, Some(axis_inner_node_size)))
-            .sum::<Option<f32>>()
-            .map(|track_size_sum| f32_max(0.0, axis_inner_node_size - track_size_sum))
+            .sum::<Option<Scalar>>()
+            .map(|track_size_sum| scalar_max(0.0, axis_inner_node_size - track_size_sum))
             .unwrap_or(0.0);
 
         let weighted_track_count =
             (((tracks.len() - 3) / 2) * inner_gutter_weight as usize) + (2 * outer_gutter_weight as usize);
 
-        return (free_space / weighted_track_count as f32) * inner_gutter_weight as f32;
+        return (free_space / weighted_track_count as Scalar) * inner_gutter_weight as Scalar;
     }
 
     0.0
@@ -269,21 +280,22 @@ pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
 pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     tree: &mut Tree,
     axis: AbstractAxis,
-    axis_min_size: Option<f32>,
-    axis_max_size: Option<f32>,
+    axis_min_size: Option<Scalar>,
+    axis_max_size: Option<Scalar>,
     axis_alignment: AlignContent,
     other_axis_alignment: AlignContent,
     available_grid_space: Size<AvailableSpace>,
-    inner_node_size: Size<Option<f32>>,
+    inner_node_size: Size<Option<Scalar>>,
     axis_tracks: &mut [GridTrack],
     other_axis_tracks: &mut [GridTrack],
     items: &mut [GridItem],
-    get_track_size_estimate: fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    get_track_size_estimate: fn(&GridTrack, Option<Scalar>, &Tree) -> Option<Scalar>,
     has_baseline_aligned_item: bool,
 ) {
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
     initialize_track_sizes(tree, axis_tracks, inner_node_size.get(axis));
+    debug_log_tracks!("Tracks after initialise", axis, axis_tracks);
 
     // 11.5.1 Shim item baselines
     if has_baseline_aligned_item {
@@ -325,10 +337,12 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
         inner_node_size,
         get_track_size_estimate,
     );
+    debug_log_tracks!("Tracks after resolve intrinsic", axis, axis_tracks);
 
     // 11.6. Maximise Tracks
     // Distributes free space (if any) to tracks with FINITE growth limits, up to their limits.
     maximise_tracks(axis_tracks, inner_node_size.get(axis), available_grid_space.get(axis));
+    debug_log_tracks!("Tracks after maximise", axis, axis_tracks);
 
     // For the purpose of the final two expansion steps ("Expand Flexible Tracks" and "Stretch auto Tracks"), we only want to expand
     // into space generated by the grid container's size (as defined by either it's preferred size style or by it's parent node through
@@ -355,11 +369,13 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
         axis_available_space_for_expansion,
         inner_node_size,
     );
+    debug_log_tracks!("Tracks after expand flexible", axis, axis_tracks);
 
     // 11.8. Stretch auto Tracks
     // This step expands tracks that have an auto max track sizing function by dividing any remaining positive, definite free space equally amongst them.
     if axis_alignment == AlignContent::Stretch {
         stretch_auto_tracks(axis_tracks, axis_min_size, axis_available_space_for_expansion);
+        debug_log_tracks!("Tracks after stretch auto", axis, axis_tracks);
     }
 }
 
@@ -390,7 +406,7 @@ fn flush_planned_base_size_increases(tracks: &mut [GridTrack]) {
 fn flush_planned_growth_limit_increases(tracks: &mut [GridTrack], set_infinitely_growable: bool) {
     for track in tracks {
         if track.growth_limit_planned_increase > 0.0 {
-            track.growth_limit = if track.growth_limit == f32::INFINITY {
+            track.growth_limit = if track.growth_limit == Scalar::INFINITY {
                 track.base_size + track.growth_limit_planned_increase
             } else {
                 track.growth_limit + track.growth_limit_planned_increase
@@ -409,7 +425,7 @@ fn flush_planned_growth_limit_increases(tracks: &mut [GridTrack], set_infinitely
 fn initialize_track_sizes(
     tree: &impl LayoutPartialTree,
     axis_tracks: &mut [GridTrack],
-    axis_inner_node_size: Option<f32>,
+    axis_inner_node_size: Option<Scalar>,
 ) {
     for track in axis_tracks.iter_mut() {
         // For each track, if the track’s min track sizing function is:
@@ -433,7 +449,7 @@ fn initialize_track_sizes(
         track.growth_limit = track
             .max_track_sizing_function
             .definite_value(axis_inner_node_size, |val, basis| tree.calc(val, basis))
-            .unwrap_or(f32::INFINITY);
+            .unwrap_or(Scalar::INFINITY);
 
         // In all cases, if the growth limit is less than the base size, increase the growth limit to match the base size.
         if track.growth_limit < track.base_size {
@@ -447,7 +463,7 @@ fn resolve_item_baselines(
     tree: &mut impl LayoutPartialTree,
     axis: AbstractAxis,
     items: &mut [GridItem],
-    inner_node_size: Size<Option<f32>>,
+    inner_node_size: Size<Option<Scalar>>,
 ) {
     // Sort items by track in the other axis (row) start position so that we can iterate items in groups which
     // are in the same track in the other axis (row)
@@ -526,8 +542,8 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
     other_axis_tracks: &[GridTrack],
     items: &mut [GridItem],
     axis_available_grid_space: AvailableSpace,
-    inner_node_size: Size<Option<f32>>,
-    get_track_size_estimate: impl Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    inner_node_size: Size<Option<Scalar>>,
+    get_track_size_estimate: impl Fn(&GridTrack, Option<Scalar>, &Tree) -> Option<Scalar>,
 ) {
     // Step 1. Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
 
@@ -552,7 +568,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
     // Also, minimum contribution <= min-content contribution <= max-content contribution.
 
     let axis_inner_node_size = inner_node_size.get(axis);
-    let flex_factor_sum = axis_tracks.iter().map(|track| track.flex_factor()).sum::<f32>();
+    let flex_factor_sum = axis_tracks.iter().map(|track| track.flex_factor()).sum::<Scalar>();
     let mut item_sizer =
         IntrisicSizeMeasurer { tree, other_axis_tracks, axis, inner_node_size, get_track_size_estimate };
 
@@ -569,19 +585,19 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                 // Handle base sizes
                 let new_base_size = match track.min_track_sizing_function.0.tag() {
                     CompactLength::MIN_CONTENT_TAG => {
-                        f32_max(track.base_size, item_sizer.min_content_contribution(item))
+                        scalar_max(track.base_size, item_sizer.min_content_contribution(item))
                     }
                     // If the container size is indefinite and has not yet been resolved then percentage sized
                     // tracks should be treated as min-content (this matches Chrome's behaviour and seems sensible)
                     CompactLength::PERCENT_TAG => {
                         if axis_inner_node_size.is_none() {
-                            f32_max(track.base_size, item_sizer.min_content_contribution(item))
+                            scalar_max(track.base_size, item_sizer.min_content_contribution(item))
                         } else {
                             track.base_size
                         }
                     }
                     CompactLength::MAX_CONTENT_TAG => {
-                        f32_max(track.base_size, item_sizer.max_content_contribution(item))
+                        scalar_max(track.base_size, item_sizer.max_content_contribution(item))
                     }
                     CompactLength::AUTO_TAG => {
                         let space = match axis_available_grid_space {
@@ -605,7 +621,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                             }
                             _ => item_sizer.minimum_contribution(item, axis_tracks),
                         };
-                        f32_max(track.base_size, space)
+                        scalar_max(track.base_size, space)
                     }
                     CompactLength::LENGTH_TAG => {
                         // Do nothing as it's not an intrinsic track sizing function
@@ -614,7 +630,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                     // Handle calc() like percentage
                     _ if track.min_track_sizing_function.0.is_calc() => {
                         if axis_inner_node_size.is_none() {
-                            f32_max(track.base_size, item_sizer.min_content_contribution(item))
+                            scalar_max(track.base_size, item_sizer.min_content_contribution(item))
                         } else {
                             track.base_size
                         }
@@ -631,35 +647,35 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                     if !item.overflow.get(axis).is_scroll_container() {
                         let min_content_contribution = item_sizer.min_content_contribution(item);
                         track.growth_limit_planned_increase =
-                            f32_max(track.growth_limit_planned_increase, min_content_contribution);
+                            scalar_max(track.growth_limit_planned_increase, min_content_contribution);
                     }
 
                     // Always increase the growth limit to at least the size of the *fit-content limited*
                     // max-cotent contribution
                     let fit_content_limit = track.fit_content_limit(axis_inner_node_size);
                     let max_content_contribution =
-                        f32_min(item_sizer.max_content_contribution(item), fit_content_limit);
+                        scalar_min(item_sizer.max_content_contribution(item), fit_content_limit);
                     track.growth_limit_planned_increase =
-                        f32_max(track.growth_limit_planned_increase, max_content_contribution);
+                        scalar_max(track.growth_limit_planned_increase, max_content_contribution);
                 } else if track.max_track_sizing_function.is_max_content_alike()
                     || track.max_track_sizing_function.uses_percentage() && axis_inner_node_size.is_none()
                 {
                     // If the container size is indefinite and has not yet been resolved then percentage sized
                     // tracks should be treated as auto (this matches Chrome's behaviour and seems sensible)
                     track.growth_limit_planned_increase =
-                        f32_max(track.growth_limit_planned_increase, item_sizer.max_content_contribution(item));
+                        scalar_max(track.growth_limit_planned_increase, item_sizer.max_content_contribution(item));
                 } else if track.max_track_sizing_function.is_intrinsic() {
                     track.growth_limit_planned_increase =
-                        f32_max(track.growth_limit_planned_increase, item_sizer.min_content_contribution(item));
+                        scalar_max(track.growth_limit_planned_increase, item_sizer.min_content_contribution(item));
                 }
             }
 
             for track in axis_tracks.iter_mut() {
                 if track.growth_limit_planned_increase > 0.0 {
-                    track.growth_limit = if track.growth_limit == f32::INFINITY {
+                    track.growth_limit = if track.growth_limit == Scalar::INFINITY {
                         track.growth_limit_planned_increase
                     } else {
-                        f32_max(track.growth_limit, track.growth_limit_planned_increase)
+                        scalar_max(track.growth_limit, track.growth_limit_planned_increase)
                     };
                 }
                 track.infinitely_growable = false;
@@ -824,7 +840,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                             space,
                             tracks,
                             has_max_content_min_track_sizing_function,
-                            |_| f32::INFINITY,
+                            |_| Scalar::INFINITY,
                             IntrinsicContributionType::Maximum,
                         );
                     } else {
@@ -927,7 +943,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
     // NOTE: this step is super-important to ensure that the "Maximise Tracks" step doesn't affect flexible tracks
     axis_tracks
         .iter_mut()
-        .filter(|track| track.growth_limit == f32::INFINITY)
+        .filter(|track| track.growth_limit == Scalar::INFINITY)
         .for_each(|track| track.growth_limit = track.base_size);
 }
 
@@ -937,10 +953,10 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
 fn distribute_item_space_to_base_size(
     is_flex: bool,
     use_flex_factor_for_distribution: bool,
-    space: f32,
+    space: Scalar,
     tracks: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
-    track_limit: impl Fn(&GridTrack) -> f32,
+    track_limit: impl Fn(&GridTrack) -> Scalar,
     intrinsic_contribution_type: IntrinsicContributionType,
 ) {
     if is_flex {
@@ -978,11 +994,11 @@ fn distribute_item_space_to_base_size(
     /// Inner function that doesn't account for differences due to distributing to flex items
     /// This difference is handled by the closure passed in above
     fn distribute_item_space_to_base_size_inner(
-        space: f32,
+        space: Scalar,
         tracks: &mut [GridTrack],
         track_is_affected: impl Fn(&GridTrack) -> bool,
-        track_distribution_proportion: impl Fn(&GridTrack) -> f32,
-        track_limit: impl Fn(&GridTrack) -> f32,
+        track_distribution_proportion: impl Fn(&GridTrack) -> Scalar,
+        track_limit: impl Fn(&GridTrack) -> Scalar,
         intrinsic_contribution_type: IntrinsicContributionType,
     ) {
         // Skip this distribution if there is either
@@ -997,8 +1013,8 @@ fn distribute_item_space_to_base_size(
         let get_base_size = |track: &GridTrack| track.base_size;
 
         // 1. Find the space to distribute
-        let track_sizes: f32 = tracks.iter().map(|track| track.base_size).sum();
-        let extra_space: f32 = f32_max(0.0, space - track_sizes);
+        let track_sizes: Scalar = tracks.iter().map(|track| track.base_size).sum();
+        let extra_space: Scalar = scalar_max(0.0, space - track_sizes);
 
         // 2. Distribute space up to limits:
         // Note: there are two exit conditions to this loop:
@@ -1007,7 +1023,7 @@ fn distribute_item_space_to_base_size(
 
         /// Define a small constant to avoid infinite loops due to rounding errors. Rather than stopping distributing
         /// extra space when it gets to exactly zero, we will stop when it falls below this amount
-        const THRESHOLD: f32 = 0.000001;
+        const THRESHOLD: Scalar = 0.000001;
 
         let extra_space = distribute_space_up_to_limits(
             extra_space,
@@ -1070,10 +1086,10 @@ fn distribute_item_space_to_base_size(
 /// This is simplified (and faster) version of the algorithm for growth limits
 /// https://www.w3.org/TR/css-grid-1/#extra-space
 fn distribute_item_space_to_growth_limit(
-    space: f32,
+    space: Scalar,
     tracks: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
-    axis_inner_node_size: Option<f32>,
+    axis_inner_node_size: Option<Scalar>,
 ) {
     // Skip this distribution if there is either
     //   - no space to distribute
@@ -1083,11 +1099,11 @@ fn distribute_item_space_to_growth_limit(
     }
 
     // 1. Find the space to distribute
-    let track_sizes: f32 = tracks
+    let track_sizes: Scalar = tracks
         .iter()
-        .map(|track| if track.growth_limit == f32::INFINITY { track.base_size } else { track.growth_limit })
+        .map(|track| if track.growth_limit == Scalar::INFINITY { track.base_size } else { track.growth_limit })
         .sum();
-    let extra_space: f32 = f32_max(0.0, space - track_sizes);
+    let extra_space: Scalar = scalar_max(0.0, space - track_sizes);
 
     // 2. Distribute space up to limits:
     // For growth limits the limit is either Infinity, or the growth limit itself. Which means that:
@@ -1097,13 +1113,15 @@ fn distribute_item_space_to_growth_limit(
         .iter()
         .filter(|track| track_is_affected(track))
         .filter(|track| {
-            track.infinitely_growable || track.fit_content_limited_growth_limit(axis_inner_node_size) == f32::INFINITY
+            track.infinitely_growable
+                || track.fit_content_limited_growth_limit(axis_inner_node_size) == Scalar::INFINITY
         })
         .count();
     if number_of_growable_tracks > 0 {
-        let item_incurred_increase = extra_space / number_of_growable_tracks as f32;
+        let item_incurred_increase = extra_space / number_of_growable_tracks as Scalar;
         for track in tracks.iter_mut().filter(|track| track_is_affected(track)).filter(|track| {
-            track.infinitely_growable || track.fit_content_limited_growth_limit(axis_inner_node_size) == f32::INFINITY
+            track.infinitely_growable
+                || track.fit_content_limited_growth_limit(axis_inner_node_size) == Scalar::INFINITY
         }) {
             track.item_incurred_increase = item_incurred_increase;
         }
@@ -1116,7 +1134,7 @@ fn distribute_item_space_to_growth_limit(
             tracks,
             track_is_affected,
             |_| 1.0,
-            |track| if track.growth_limit == f32::INFINITY { track.base_size } else { track.growth_limit },
+            |track| if track.growth_limit == Scalar::INFINITY { track.base_size } else { track.growth_limit },
             move |track| track.fit_content_limit(axis_inner_node_size),
         );
     };
@@ -1138,12 +1156,12 @@ fn distribute_item_space_to_growth_limit(
 #[inline(always)]
 fn maximise_tracks(
     axis_tracks: &mut [GridTrack],
-    axis_inner_node_size: Option<f32>,
+    axis_inner_node_size: Option<Scalar>,
     axis_available_grid_space: AvailableSpace,
 ) {
-    let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+    let used_space: Scalar = axis_tracks.iter().map(|track| track.base_size).sum();
     let free_space = axis_available_grid_space.compute_free_space(used_space);
-    if free_space == f32::INFINITY {
+    if free_space == Scalar::INFINITY {
         axis_tracks.iter_mut().for_each(|track| track.base_size = track.growth_limit);
     } else if free_space > 0.0 {
         distribute_space_up_to_limits(
@@ -1170,10 +1188,10 @@ fn expand_flexible_tracks(
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
     items: &mut [GridItem],
-    axis_min_size: Option<f32>,
-    axis_max_size: Option<f32>,
+    axis_min_size: Option<Scalar>,
+    axis_max_size: Option<Scalar>,
     axis_available_space_for_expansion: AvailableSpace,
-    inner_node_size: Size<Option<f32>>,
+    inner_node_size: Size<Option<Scalar>>,
 ) {
     // First, find the grid’s used flex fraction:
     let flex_fraction = match axis_available_space_for_expansion {
@@ -1183,7 +1201,7 @@ fn expand_flexible_tracks(
         //   The used flex fraction is the result of finding the size of an fr using all of the grid tracks and
         //   a space to fill of the available grid space.
         AvailableSpace::Definite(available_space) => {
-            let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+            let used_space: Scalar = axis_tracks.iter().map(|track| track.base_size).sum();
             let free_space = available_space - used_space;
             if free_space <= 0.0 {
                 0.0
@@ -1196,7 +1214,7 @@ fn expand_flexible_tracks(
         // Otherwise, if the free space is an indefinite length:
         AvailableSpace::MaxContent => {
             // The used flex fraction is the maximum of:
-            let flex_fraction = f32_max(
+            let flex_fraction = scalar_max(
                 // For each flexible track, if the flexible track’s flex factor is greater than one,
                 // the result of dividing the track’s base size by its flex factor; otherwise, the track’s base size.
                 axis_tracks
@@ -1232,19 +1250,19 @@ fn expand_flexible_tracks(
             // grid container’s max-width/height), then redo this step, treating the free space as definite and the available grid space as equal
             // to the grid container’s inner size when it’s sized to its min-width/height (max-width/height).
             // (Note: min_size takes precedence over max_size)
-            let hypothetical_grid_size: f32 = axis_tracks
+            let hypothetical_grid_size: Scalar = axis_tracks
                 .iter()
                 .map(|track| {
                     if track.max_track_sizing_function.is_fr() {
-                        let track_flex_factor = track.max_track_sizing_function.0.value();
-                        f32_max(track.base_size, track_flex_factor * flex_fraction)
+                        let track_flex_factor = track.max_track_sizing_function.0.value() as Scalar;
+                        scalar_max(track.base_size, track_flex_factor * flex_fraction)
                     } else {
                         track.base_size
                     }
                 })
                 .sum();
             let axis_min_size = axis_min_size.unwrap_or(0.0);
-            let axis_max_size = axis_max_size.unwrap_or(f32::INFINITY);
+            let axis_max_size = axis_max_size.unwrap_or(Scalar::INFINITY);
             if hypothetical_grid_size < axis_min_size {
                 find_size_of_fr(axis_tracks, axis_min_size)
             } else if hypothetical_grid_size > axis_max_size {
@@ -1258,8 +1276,8 @@ fn expand_flexible_tracks(
     // For each flexible track, if the product of the used flex fraction and the track’s flex factor is greater
     // than the track’s base size, set its base size to that product.
     for track in axis_tracks.iter_mut().filter(|track| track.max_track_sizing_function.is_fr()) {
-        let track_flex_factor = track.max_track_sizing_function.0.value();
-        track.base_size = f32_max(track.base_size, track_flex_factor * flex_fraction);
+        let track_flex_factor = track.max_track_sizing_function.0.value() as Scalar;
+        track.base_size = scalar_max(track.base_size, track_flex_factor * flex_fraction);
     }
 }
 
@@ -1267,7 +1285,7 @@ fn expand_flexible_tracks(
 /// This algorithm finds the largest size that an fr unit can be without exceeding the target size.
 /// It must be called with a set of grid tracks and some quantity of space to fill.
 #[inline(always)]
-fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
+fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: Scalar) -> Scalar {
     // Handle the trivial case where there is no space to fill
     // Do not remove as otherwise the loop below will loop infinitely
     if space_to_fill == 0.0 {
@@ -1278,7 +1296,7 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
     // is less than the track’s base size, then we must restart this algorithm treating all such tracks as inflexible.
     // We therefore wrap the entire algorithm in a loop, with an hypothetical_fr_size of INFINITY such that the above
     // condition can never be true for the first iteration.
-    let mut hypothetical_fr_size = f32::INFINITY;
+    let mut hypothetical_fr_size = Scalar::INFINITY;
     let mut previous_iter_hypothetical_fr_size;
     loop {
         // Let leftover space be the space to fill minus the base sizes of the non-flexible grid tracks.
@@ -1289,15 +1307,15 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
         for track in tracks.iter() {
             // Tracks for which flex_factor * hypothetical_fr_size < track.base_size are treated as inflexible
             if track.max_track_sizing_function.is_fr()
-                && track.max_track_sizing_function.0.value() * hypothetical_fr_size >= track.base_size
+                && track.max_track_sizing_function.0.value() as Scalar * hypothetical_fr_size >= track.base_size
             {
-                naive_flex_factor_sum += track.max_track_sizing_function.0.value();
+                naive_flex_factor_sum += track.max_track_sizing_function.0.value() as Scalar;
             } else {
                 used_space += track.base_size;
             };
         }
         let leftover_space = space_to_fill - used_space;
-        let flex_factor = f32_max(naive_flex_factor_sum, 1.0);
+        let flex_factor = scalar_max(naive_flex_factor_sum, 1.0);
 
         // Let the hypothetical fr size be the leftover space divided by the flex factor sum.
         previous_iter_hypothetical_fr_size = hypothetical_fr_size;
@@ -1308,7 +1326,7 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
         // We keep track of the hypothetical_fr_size
         let hypothetical_fr_size_is_valid = tracks.iter().all(|track| {
             if track.max_track_sizing_function.is_fr() {
-                let flex_factor = track.max_track_sizing_function.0.value();
+                let flex_factor = track.max_track_sizing_function.0.value() as Scalar;
                 flex_factor * hypothetical_fr_size >= track.base_size
                     || flex_factor * previous_iter_hypothetical_fr_size < track.base_size
             } else {
@@ -1329,12 +1347,12 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
 #[inline(always)]
 fn stretch_auto_tracks(
     axis_tracks: &mut [GridTrack],
-    axis_min_size: Option<f32>,
+    axis_min_size: Option<Scalar>,
     axis_available_space_for_expansion: AvailableSpace,
 ) {
     let num_auto_tracks = axis_tracks.iter().filter(|track| track.max_track_sizing_function.is_auto()).count();
     if num_auto_tracks > 0 {
-        let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+        let used_space: Scalar = axis_tracks.iter().map(|track| track.base_size).sum();
 
         // If the free space is indefinite, but the grid container has a definite min-width/height
         // use that size to calculate the free space for this step instead.
@@ -1347,7 +1365,7 @@ fn stretch_auto_tracks(
             }
         };
         if free_space > 0.0 {
-            let extra_space_per_auto_track = free_space / num_auto_tracks as f32;
+            let extra_space_per_auto_track = free_space / num_auto_tracks as Scalar;
             axis_tracks
                 .iter_mut()
                 .filter(|track| track.max_track_sizing_function.is_auto())
@@ -1360,20 +1378,20 @@ fn stretch_auto_tracks(
 /// Used by both distribute_item_space_to_base_size and maximise_tracks steps
 #[inline(always)]
 fn distribute_space_up_to_limits(
-    space_to_distribute: f32,
+    space_to_distribute: Scalar,
     tracks: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
-    track_distribution_proportion: impl Fn(&GridTrack) -> f32,
-    track_affected_property: impl Fn(&GridTrack) -> f32,
-    track_limit: impl Fn(&GridTrack) -> f32,
-) -> f32 {
+    track_distribution_proportion: impl Fn(&GridTrack) -> Scalar,
+    track_affected_property: impl Fn(&GridTrack) -> Scalar,
+    track_limit: impl Fn(&GridTrack) -> Scalar,
+) -> Scalar {
     /// Define a small constant to avoid infinite loops due to rounding errors. Rather than stopping distributing
     /// extra space when it gets to exactly zero, we will stop when it falls below this amount
-    const THRESHOLD: f32 = 0.01;
+    const THRESHOLD: Scalar = 0.01;
 
     let mut space_to_distribute = space_to_distribute;
     while space_to_distribute > THRESHOLD {
-        let track_distribution_proportion_sum: f32 = tracks
+        let track_distribution_proportion_sum: Scalar = tracks
             .iter()
             .filter(|track| track_affected_property(track) + track.item_incurred_increase < track_limit(track))
             .filter(|track| track_is_affected(track))
@@ -1393,7 +1411,7 @@ fn distribute_space_up_to_limits(
             .min_by(|a, b| a.total_cmp(b))
             .unwrap(); // We will never pass an empty track list to this function
         let iteration_item_incurred_increase =
-            f32_min(min_increase_limit, space_to_distribute / track_distribution_proportion_sum);
+            scalar_min(min_increase_limit, space_to_distribute / track_distribution_proportion_sum);
 
         for track in tracks.iter_mut().filter(|track| track_is_affected(track)) {
             let increase = iteration_item_incurred_increase * track_distribution_proportion(track);
@@ -30,6 +30,8 @@ pub(crate) struct CellOccupancyMatrix {
     columns: TrackCounts,
     /// The counts of implicit and explicit rows
     rows: TrackCounts,
+    /// An empty buffer that the grid's data is moved into when it is expanded
+    spare: Vec<CellOccupancyState>,
 }
 
 /// Debug impl that represents the matrix in a compact 2d text format
@@ -66,8 +68,22 @@ impl Debug for CellOccupancyMatrix {
 impl CellOccupancyMatrix {
     /// Create a CellOccupancyMatrix given a set of provisional track counts. The grid can expand as needed to fit more tracks,
     /// the provisional track counts represent a best effort attempt to avoid the extra allocations this requires.
-    pub fn with_track_counts(columns: TrackCounts, rows: TrackCounts) -> Self {
-        Self { inner: Grid::new(rows.len(), columns.len()), rows, columns }
+    ///
+    /// The matrix's storage reuses the allocations of the two (empty) buffers passed in, which can be recovered with
+    /// [`CellOccupancyMatrix::into_buffers`].
+    pub fn with_track_counts(
+        columns: TrackCounts,
+        rows: TrackCounts,
+        mut buffer: Vec<CellOccupancyState>,
+        spare: Vec<CellOccupancyState>,
+    ) -> Self {
+        buffer.resize(rows.len() * columns.len(), CellOccupancyState::Unoccupied);
+        Self { inner: Grid::from_vec(buffer, columns.len()), rows, columns, spare }
+    }
+
+    /// Consumes the matrix, returning its storage so that it can be reused
+    pub fn into_buffers(self) -> [Vec<CellOccupancyState>; 2] {
+        [self.inner.into_vec(), self.spare]
     }
 
     /// Determines whether the specified area fits within the tracks currently represented by the matrix
@@ -100,7 +116,8 @@ impl CellOccupancyMatrix {
         let new_row_count = old_row_count + (req_negative_rows + req_positive_rows) as usize;
         let new_col_count = old_col_count + (req_negative_cols + req_positive_cols) as usize;
 
-        let mut data = Vec::with_capacity(new_row_count * new_col_count);
+        let mut data = core::mem::take(&mut self.spare);
+        data.reserve(new_row_count * new_col_count);
 
         // Push new negative rows
         for _ in 0..(req_negative_rows as usize * new_col_count) {
@@ -128,8 +145,9 @@ impl CellOccupancyMatrix {
             data.push(CellOccupancyState::Unoccupied);
         }
 
-        // Update self with new data
-        self.inner = Grid::from_vec(data, new_col_count);
+        // Update self with new data, keeping the old storage for the next expansion
+        self.spare = core::mem::replace(&mut self.inner, Grid::from_vec(data, new_col_count)).into_vec();
+        self.spare.clear();
         self.rows.negative_implicit += req_negative_rows as u16;
         self.rows.positive_implicit += req_positive_rows as u16;
         self.columns.negative_implicit += req_negative_cols as u16;
@@ -2,7 +2,7 @@
 use super::GridTrack;
 use crate::compute::grid::OriginZeroLine;
 use crate::geometry::AbstractAxis;
-use crate::geometry::{Line, Point, Rect, Size};
+use crate::geometry::{Line, Point, Rect, Scalar, Size};
 use crate::style::{AlignItems, AlignSelf, AvailableSpace, Dimension, LengthPercentageAuto, Overflow};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
@@ -39,7 +39,7 @@ pub(in super::super) struct GridItem {
     /// The item's max_size style
     pub max_size: Size<Dimension>,
     /// The item's aspect_ratio style
-    pub aspect_ratio: Option<f32>,
+    pub aspect_ratio: Option<Scalar>,
     /// The item's padding style
     pub padding: Rect<LengthPercentage>,
     /// The item's border style
@@ -51,10 +51,10 @@ pub(in super::super) struct GridItem {
     /// The item's justify_self property, or the parent's justify_items property is not set
     pub justify_self: AlignSelf,
     /// The items first baseline (horizontal)
-    pub baseline: Option<f32>,
+    pub baseline: Option<Scalar>,
     /// Shim for baseline alignment that acts like an extra top margin
     /// TODO: Support last baseline and vertical text baselines
-    pub baseline_shim: f32,
+    pub baseline_shim: Scalar,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -74,18 +74,20 @@ pub(in super::super) struct GridItem {
 
     // Caches for intrinsic size computation. These caches are only valid for a single run of the track-sizing algorithm.
     /// Cache for the known_dimensions input to intrinsic sizing computation
-    pub available_space_cache: Option<Size<Option<f32>>>,
+    pub available_space_cache: Option<Size<Option<Scalar>>>,
     /// Cache for the min-content size
-    pub min_content_contribution_cache: Size<Option<f32>>,
+    pub min_content_contribution_cache: Size<Option<Scalar>>,
     /// Cache for the minimum contribution
-    pub minimum_contribution_cache: Size<Option<f32>>,
+    pub minimum_contribution_cache: Size<Option<Scalar>>,
     /// Cache for the max-content size
-    pub max_content_contribution_cache: Size<Option<f32>>,
+    pub max_content_contribution_cache: Size<Option<Scalar>>,
 
     /// Final y position. Used to compute baseline alignment for the container.
-    pub y_position: f32,
+    pub y_position: Scalar,
     /// Final height. Used to compute baseline alignment for the container.
-    pub height: f32,
+    pub height: Scalar,
+    /// Final first baseline, relative to the item's border box. Used to compute baseline alignment for the container.
+    pub first_baseline: Option<Scalar>,
 }
 
 impl GridItem {
@@ -128,6 +130,7 @@ impl GridItem {
             max_content_contribution_cache: Size::NONE,
             minimum_contribution_cache: Size::NONE,
             y_position: 0.0,
+            first_baseline: None,
             height: 0.0,
         }
     }
@@ -188,15 +191,15 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
-        axis_parent_size: Option<f32>,
-        resolve_calc_value: &dyn Fn(u64, f32) -> f32,
-    ) -> Option<f32> {
+        axis_parent_size: Option<Scalar>,
+        resolve_calc_value: &dyn Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         let spanned_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
         let tracks_all_fixed = spanned_tracks.iter().all(|track| {
             track.max_track_sizing_function.definite_limit(axis_parent_size, resolve_calc_value).is_some()
         });
         if tracks_all_fixed {
-            let limit: f32 = spanned_tracks
+            let limit: Scalar = spanned_tracks
                 .iter()
                 .map(|track| {
                     track.max_track_sizing_function.definite_limit(axis_parent_size, resolve_calc_value).unwrap()
@@ -214,15 +217,15 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
-        axis_parent_size: Option<f32>,
-        resolve_calc_value: &dyn Fn(u64, f32) -> f32,
-    ) -> Option<f32> {
+        axis_parent_size: Option<Scalar>,
+        resolve_calc_value: &dyn Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         let spanned_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
         let tracks_all_fixed = spanned_tracks.iter().all(|track| {
             track.max_track_sizing_function.definite_value(axis_parent_size, resolve_calc_value).is_some()
         });
         if tracks_all_fixed {
-            let limit: f32 = spanned_tracks
+            let limit: Scalar = spanned_tracks
                 .iter()
                 .map(|track| {
                     track.max_track_sizing_function.definite_value(axis_parent_size, resolve_calc_value).unwrap()
@@ -240,9 +243,9 @@ impl GridItem {
     fn known_dimensions(
         &self,
         tree: &mut impl LayoutPartialTree,
-        inner_node_size: Size<Option<f32>>,
-        grid_area_size: Size<Option<f32>>,
-    ) -> Size<Option<f32>> {
+        inner_node_size: Size<Option<Scalar>>,
+        grid_area_size: Size<Option<Scalar>>,
+    ) -> Size<Option<Scalar>> {
         let margins = self.margins_axis_sums_with_baseline_shims(inner_node_size.width, tree);
 
         let aspect_ratio = self.aspect_ratio;
@@ -314,17 +317,17 @@ impl GridItem {
         &self,
         axis: AbstractAxis,
         other_axis_tracks: &[GridTrack],
-        other_axis_available_space: Option<f32>,
-        get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
-    ) -> Size<Option<f32>> {
-        let item_other_axis_size: Option<f32> = {
+        other_axis_available_space: Option<Scalar>,
+        get_track_size_estimate: impl Fn(&GridTrack, Option<Scalar>) -> Option<Scalar>,
+    ) -> Size<Option<Scalar>> {
+        let item_other_axis_size: Option<Scalar> = {
             other_axis_tracks[self.track_range_excluding_lines(axis.other())]
                 .iter()
                 .map(|track| {
                     get_track_size_estimate(track, other_axis_available_space)
                         .map(|size| size + track.content_alignment_adjustment)
                 })
-                .sum::<Option<f32>>()
+                .sum::<Option<Scalar>>()
         };
 
         let mut size = Size::NONE;
@@ -337,9 +340,9 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         other_axis_tracks: &[GridTrack],
-        other_axis_available_space: Option<f32>,
-        get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
-    ) -> Size<Option<f32>> {
+        other_axis_available_space: Option<Scalar>,
+        get_track_size_estimate: impl Fn(&GridTrack, Option<Scalar>) -> Option<Scalar>,
+    ) -> Size<Option<Scalar>> {
         self.available_space_cache.unwrap_or_else(|| {
             let available_spaces =
                 self.available_space(axis, other_axis_tracks, other_axis_available_space, get_track_size_estimate);
@@ -353,9 +356,9 @@ impl GridItem {
     #[inline(always)]
     pub fn margins_axis_sums_with_baseline_shims(
         &self,
-        inner_node_width: Option<f32>,
+        inner_node_width: Option<Scalar>,
         tree: &impl LayoutPartialTree,
-    ) -> Size<f32> {
+    ) -> Size<Scalar> {
         Rect {
             left: self.margin.left.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
             right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
@@ -371,9 +374,9 @@ impl GridItem {
         &self,
         axis: AbstractAxis,
         tree: &mut impl LayoutPartialTree,
-        available_space: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        available_space: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         let known_dimensions = self.known_dimensions(tree, inner_node_size, available_space);
         tree.measure_child_size(
             self.node,
@@ -395,9 +398,9 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         tree: &mut impl LayoutPartialTree,
-        available_space: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        available_space: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         self.min_content_contribution_cache.get(axis).unwrap_or_else(|| {
             let size = self.min_content_contribution(axis, tree, available_space, inner_node_size);
             self.min_content_contribution_cache.set(axis, Some(size));
@@ -410,9 +413,9 @@ impl GridItem {
         &self,
         axis: AbstractAxis,
         tree: &mut impl LayoutPartialTree,
-        available_space: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        available_space: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         let known_dimensions = self.known_dimensions(tree, inner_node_size, available_space);
         tree.measure_child_size(
             self.node,
@@ -434,9 +437,9 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         tree: &mut impl LayoutPartialTree,
-        available_space: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        available_space: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         self.max_content_contribution_cache.get(axis).unwrap_or_else(|| {
             let size = self.max_content_contribution(axis, tree, available_space, inner_node_size);
             self.max_content_contribution_cache.set(axis, Some(size));
@@ -457,9 +460,9 @@ impl GridItem {
         tree: &mut impl LayoutPartialTree,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
-        known_dimensions: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        known_dimensions: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         let padding = self.padding.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
         let border = self.border.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
         let padding_border_size = (padding + border).sum_axes();
@@ -526,9 +529,9 @@ impl GridItem {
         tree: &mut impl LayoutPartialTree,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
-        known_dimensions: Size<Option<f32>>,
-        inner_node_size: Size<Option<f32>>,
-    ) -> f32 {
+        known_dimensions: Size<Option<Scalar>>,
+        inner_node_size: Size<Option<Scalar>>,
+    ) -> Scalar {
         self.minimum_contribution_cache.get(axis).unwrap_or_else(|| {
             let size = self.minimum_contribution(tree, axis, axis_tracks, known_dimensions, inner_node_size);
             self.minimum_contribution_cache.set(axis, Some(size));
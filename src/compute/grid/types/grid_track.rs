@@ -1,8 +1,9 @@
 //! Contains GridTrack used to represent a single grid track (row/column) during layout
+use crate::geometry::Scalar;
 use crate::{
     prelude::TaffyZero,
     style::{LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction},
-    util::sys::f32_min,
+    util::sys::scalar_min,
     CompactLength,
 };
 
@@ -35,25 +36,25 @@ pub(in super::super) struct GridTrack {
     pub max_track_sizing_function: MaxTrackSizingFunction,
 
     /// The distance of the start of the track from the start of the grid container
-    pub offset: f32,
+    pub offset: Scalar,
 
     /// The size (width/height as applicable) of the track
-    pub base_size: f32,
+    pub base_size: Scalar,
 
     /// A temporary scratch value when sizing tracks
     /// Note: can be infinity
-    pub growth_limit: f32,
+    pub growth_limit: Scalar,
 
     /// A temporary scratch value when sizing tracks. Is used as an additional amount to add to the
     /// estimate for the available space in the opposite axis when content sizing items
-    pub content_alignment_adjustment: f32,
+    pub content_alignment_adjustment: Scalar,
 
     /// A temporary scratch value when "distributing space" to avoid clobbering planned increase variable
-    pub item_incurred_increase: f32,
+    pub item_incurred_increase: Scalar,
     /// A temporary scratch value when "distributing space" to avoid clobbering the main variable
-    pub base_size_planned_increase: f32,
+    pub base_size_planned_increase: Scalar,
     /// A temporary scratch value when "distributing space" to avoid clobbering the main variable
-    pub growth_limit_planned_increase: f32,
+    pub growth_limit_planned_increase: Scalar,
     /// A temporary scratch value when "distributing space"
     /// See: https://www.w3.org/TR/css3-grid-layout/#infinitely-growable
     pub infinitely_growable: bool,
@@ -127,28 +128,28 @@ impl GridTrack {
 
     #[inline]
     /// Returns true if the track is flexible (has a Flex MaxTrackSizingFunction), else false.
-    pub fn fit_content_limit(&self, axis_available_grid_space: Option<f32>) -> f32 {
+    pub fn fit_content_limit(&self, axis_available_grid_space: Option<Scalar>) -> Scalar {
         match self.max_track_sizing_function.0.tag() {
-            CompactLength::FIT_CONTENT_PX_TAG => self.max_track_sizing_function.0.value(),
+            CompactLength::FIT_CONTENT_PX_TAG => self.max_track_sizing_function.0.value() as Scalar,
             CompactLength::FIT_CONTENT_PERCENT_TAG => match axis_available_grid_space {
-                Some(space) => space * self.max_track_sizing_function.0.value(),
-                None => f32::INFINITY,
+                Some(space) => space * self.max_track_sizing_function.0.value() as Scalar,
+                None => Scalar::INFINITY,
             },
-            _ => f32::INFINITY,
+            _ => Scalar::INFINITY,
         }
     }
 
     #[inline]
     /// Returns true if the track is flexible (has a Flex MaxTrackSizingFunction), else false.
-    pub fn fit_content_limited_growth_limit(&self, axis_available_grid_space: Option<f32>) -> f32 {
-        f32_min(self.growth_limit, self.fit_content_limit(axis_available_grid_space))
+    pub fn fit_content_limited_growth_limit(&self, axis_available_grid_space: Option<Scalar>) -> Scalar {
+        scalar_min(self.growth_limit, self.fit_content_limit(axis_available_grid_space))
     }
 
     #[inline]
     /// Returns the track's flex factor if it is a flex track, else 0.
-    pub fn flex_factor(&self) -> f32 {
+    pub fn flex_factor(&self) -> Scalar {
         if self.max_track_sizing_function.is_fr() {
-            self.max_track_sizing_function.0.value()
+            self.max_track_sizing_function.0.value() as Scalar
         } else {
             0.0
         }
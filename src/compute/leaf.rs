@@ -1,26 +1,39 @@
 //! Computes size using styles and measure functions
 
-use crate::geometry::{Point, Size};
+use crate::geometry::{Point, Scalar, Size};
 use crate::style::{AvailableSpace, Overflow, Position};
 use crate::tree::{CollapsibleMarginSet, RunMode};
-use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
-use crate::util::debug::debug_log;
-use crate::util::sys::f32_max;
+use crate::tree::{LayoutInput, LayoutOutput, MeasureOutput, SizingMode};
+use crate::util::debug::{debug_log, debug_record_algorithm};
+use crate::util::sys::scalar_max;
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxSizing, CoreStyle};
 use core::unreachable;
 
 /// Compute the size of a leaf node (node with no children)
-pub fn compute_leaf_layout<MeasureFunction>(
+///
+/// The measure function measures the node's content box. The known dimensions and available space passed to it have the
+/// node's padding, border and scrollbar gutters removed, so that (for example) text wraps within the node's padding, and
+/// these are added back to the size that it returns. This applies regardless of the node's [`BoxSizing`], as the known
+/// dimensions in the [`LayoutInput`] are always those of the border box.
+///
+/// The measure function may return either a plain `Size<Scalar>` or a [`MeasureOutput`] that also contains the content's
+/// baselines and overflowing size. Baselines are reported in the node's [`LayoutOutput`] relative to its border box. The
+/// content size reported in the [`LayoutOutput`] is the measured content size (or, if there isn't one, the measured
+/// size) plus the node's padding, so content that doesn't fit in the node is recorded as overflow even when the node's
+/// size is known.
+pub fn compute_leaf_layout<MeasureFunction, MeasureResult>(
     inputs: LayoutInput,
     style: &impl CoreStyle,
-    resolve_calc_value: impl Fn(u64, f32) -> f32,
+    resolve_calc_value: impl Fn(u64, Scalar) -> Scalar,
     measure_function: MeasureFunction,
 ) -> LayoutOutput
 where
-    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
+    MeasureFunction: FnOnce(Size<Option<Scalar>>, Size<AvailableSpace>) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
 {
+    debug_record_algorithm!("leaf");
     let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, run_mode, .. } = inputs;
 
     // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
@@ -74,6 +87,7 @@ where
     content_box_inset.bottom += scrollbar_gutter.y;
 
     let has_styles_preventing_being_collapsed_through = !style.is_block()
+        || style.is_flow_root()
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
         || style.position() == Position::Absolute
@@ -100,6 +114,7 @@ where
                 #[cfg(feature = "content_size")]
                 content_size: Size::ZERO,
                 first_baselines: Point::NONE,
+                last_baselines: Point::NONE,
                 top_margin: CollapsibleMarginSet::ZERO,
                 bottom_margin: CollapsibleMarginSet::ZERO,
                 margins_can_collapse_through: false,
@@ -133,30 +148,40 @@ where
             }),
     };
 
+    // Compute the known dimensions of the content box
+    let content_box_known_dimensions = known_dimensions
+        .maybe_sub(content_box_inset.sum_axes())
+        .map(|dimension| dimension.map(|dimension| scalar_max(dimension, 0.0)));
+
     // Measure node
-    let measured_size = measure_function(
+    let measure_output: MeasureOutput = measure_function(
         match run_mode {
-            RunMode::ComputeSize => known_dimensions,
+            RunMode::ComputeSize => content_box_known_dimensions,
             RunMode::PerformLayout => Size::NONE,
             RunMode::PerformHiddenLayout => unreachable!(),
         },
         available_space,
-    );
+    )
+    .into();
+    let MeasureOutput { size: measured_size, first_baseline, last_baseline, .. } = measure_output;
+    debug_log!("measured_size", dbg:measured_size);
     let clamped_size = known_dimensions
         .or(node_size)
         .unwrap_or(measured_size + content_box_inset.sum_axes())
         .maybe_clamp(node_min_size, node_max_size);
     let size = Size {
         width: clamped_size.width,
-        height: f32_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
+        height: scalar_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
     };
     let size = size.maybe_max(padding_border.sum_axes().map(Some));
 
     LayoutOutput {
         size,
         #[cfg(feature = "content_size")]
-        content_size: measured_size + padding.sum_axes(),
-        first_baselines: Point::NONE,
+        content_size: measure_output.content_size.unwrap_or(measured_size) + padding.sum_axes(),
+        // Measured baselines are relative to the content box
+        first_baselines: Point { x: None, y: first_baseline.map(|baseline| baseline + content_box_inset.top) },
+        last_baselines: Point { x: None, y: last_baseline.map(|baseline| baseline + content_box_inset.top) },
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
@@ -23,6 +23,7 @@
 //!
 pub(crate) mod common;
 pub(crate) mod leaf;
+pub(crate) mod scratch;
 
 #[cfg(feature = "block_layout")]
 pub(crate) mod block;
@@ -34,6 +35,7 @@ pub(crate) mod flexbox;
 pub(crate) mod grid;
 
 pub use leaf::compute_leaf_layout;
+pub use scratch::LayoutScratch;
 
 #[cfg(feature = "block_layout")]
 pub use self::block::compute_block_layout;
@@ -44,17 +46,22 @@ pub use self::flexbox::compute_flexbox_layout;
 #[cfg(feature = "grid")]
 pub use self::grid::compute_grid_layout;
 
-use crate::geometry::{Line, Point, Size};
+use crate::geometry::{Line, Point, Scalar, Size};
 use crate::style::{AvailableSpace, CoreStyle, Overflow};
 use crate::tree::{
     Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
 };
-use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node};
-use crate::util::sys::round;
+use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node, debug_record_algorithm};
+use crate::util::sys::{ceil, floor, round, Vec};
 use crate::util::ResolveOrZero;
 use crate::{BoxSizing, CacheTree, MaybeMath, MaybeResolve};
+#[cfg(feature = "std")]
+use core::cell::Cell;
 
 /// Compute layout for the root node in the tree
+///
+/// The root node is laid out as if it were the only child of a block container (the initial containing block) with the
+/// specified available space. Its margins offset it from the origin and are never collapsed.
 pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, available_space: Size<AvailableSpace>) {
     let mut known_dimensions = Size::NONE;
 
@@ -139,7 +146,9 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         root,
         &Layout {
             order: 0,
-            location: Point::ZERO,
+            // The root node is positioned as if its parent were the initial containing block: its margins offset
+            // it from the origin and never collapse.
+            location: Point { x: margin.left, y: margin.top },
             size: output.size,
             #[cfg(feature = "content_size")]
             content_size: output.content_size,
@@ -148,6 +157,8 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
             border,
             // TODO: support auto margins for root node?
             margin,
+            first_baseline: output.first_baselines.y,
+            last_baseline: output.last_baselines.y,
         },
     );
 }
@@ -165,24 +176,34 @@ pub fn compute_cached_layout<Tree: CacheTree + ?Sized, ComputeFunction>(
 where
     ComputeFunction: FnMut(&mut Tree, NodeId, LayoutInput) -> LayoutOutput,
 {
-    debug_push_node!(node);
-    let LayoutInput { known_dimensions, available_space, run_mode, .. } = inputs;
-
+    debug_push_node!(node, inputs.run_mode);
     // First we check if we have a cached result for the given input
-    let cache_entry = tree.cache_get(node, known_dimensions, available_space, run_mode);
+    let cache_entry = tree.cache_get(node, &inputs);
     if let Some(cached_size_and_baselines) = cache_entry {
-        debug_log_node!(known_dimensions, inputs.parent_size, available_space, run_mode, inputs.sizing_mode);
+        debug_log_node!(
+            inputs.known_dimensions,
+            inputs.parent_size,
+            inputs.available_space,
+            inputs.run_mode,
+            inputs.sizing_mode
+        );
         debug_log!("RESULT (CACHED)", dbg:cached_size_and_baselines.size);
         debug_pop_node!();
         return cached_size_and_baselines;
     }
 
-    debug_log_node!(known_dimensions, inputs.parent_size, available_space, run_mode, inputs.sizing_mode);
+    debug_log_node!(
+        inputs.known_dimensions,
+        inputs.parent_size,
+        inputs.available_space,
+        inputs.run_mode,
+        inputs.sizing_mode
+    );
 
     let computed_size_and_baselines = compute_uncached(tree, node, inputs);
 
     // Cache result
-    tree.cache_store(node, known_dimensions, available_space, run_mode, computed_size_and_baselines);
+    tree.cache_store(node, &inputs, computed_size_and_baselines);
 
     debug_log!("RESULT", dbg:computed_size_and_baselines.size);
     debug_pop_node!();
@@ -190,6 +211,77 @@ where
     computed_size_and_baselines
 }
 
+/// The largest traversal stack capacity (in nodes) that [`round_layout_with_options`] retains for reuse
+#[cfg(feature = "std")]
+const MAX_RETAINED_ROUND_LAYOUT_STACK_CAPACITY: usize = 1024;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The traversal stack of [`round_layout_with_options`], retained between calls so that rounding doesn't need to
+    /// allocate
+    static ROUND_LAYOUT_STACK: Cell<Vec<(NodeId, Scalar, Scalar)>> = const { Cell::new(Vec::new()) };
+}
+
+/// How [`round_layout_with_options`] rounds a coordinate to a whole device pixel
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest whole pixel, rounding halfway values away from zero
+    #[default]
+    Nearest,
+    /// Round up to the next whole pixel
+    Ceil,
+    /// Round down to the previous whole pixel
+    Floor,
+    /// Round to the nearest whole pixel, rounding halfway values to the even pixel ("bankers' rounding"), so that
+    /// halfway values don't all drift in the same direction
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Rounds a value to a whole number according to this mode
+    pub fn round(self, value: Scalar) -> Scalar {
+        match self {
+            Self::Nearest => round(value),
+            Self::Ceil => ceil(value),
+            Self::Floor => floor(value),
+            Self::HalfEven => {
+                let floored = floor(value);
+                let fraction = value - floored;
+                if fraction > 0.5 || (fraction == 0.5 && floored % 2.0 != 0.0) {
+                    floored + 1.0
+                } else {
+                    floored
+                }
+            }
+        }
+    }
+}
+
+/// Options controlling how [`round_layout_with_options`] rounds layouts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundingOptions {
+    /// The number of device pixels per unit of layout (the device pixel ratio). Layout values are rounded to whole
+    /// multiples of `1.0 / scale`, so that they lie on whole device pixels once multiplied by `scale`. Defaults to `1.0`.
+    pub scale: Scalar,
+    /// How the left and top edges of each box are rounded, which determines each node's location. Defaults to
+    /// [`RoundingMode::Nearest`].
+    pub positions: RoundingMode,
+    /// How the right and bottom edges of each box are rounded, which (along with the rounded left and top edges)
+    /// determines each node's size. Defaults to [`RoundingMode::Nearest`].
+    pub sizes: RoundingMode,
+}
+
+impl RoundingOptions {
+    /// The options used by [`round_layout`], which round to the nearest whole layout unit
+    pub const DEFAULT: Self = Self { scale: 1.0, positions: RoundingMode::Nearest, sizes: RoundingMode::Nearest };
+}
+
+impl Default for RoundingOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Rounds the calculated layout to exact pixel values
 ///
 /// In order to ensure that no gaps in the layout are introduced we:
@@ -203,73 +295,112 @@ where
 /// In order to prevent innacuracies caused by rounding already-rounded values, we read from `unrounded_layout`
 /// and write to `final_layout`.
 pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
-    return round_layout_inner(tree, node_id, 0.0, 0.0);
+    round_layout_with_options(tree, node_id, RoundingOptions::DEFAULT)
+}
+
+/// Rounds the calculated layout to exact pixel values, as configured by `options`. See [`round_layout`].
+///
+/// With a `scale` other than `1.0`, values are rounded to the device pixel grid rather than to whole layout units,
+/// by rounding each absolute coordinate multiplied by the scale and dividing the result back. Edges that are rounded
+/// from the same absolute coordinate still agree, so no gaps are introduced.
+///
+/// The left and top edges of every box are rounded with the `positions` mode and the right and bottom edges with the
+/// `sizes` mode, and each location and size is the difference between rounded edges. Whatever the modes, a child
+/// whose box lies within its parent's stays within it once rounded. Boxes that touch keep touching if both modes are
+/// the same, and overlap rather than leave a gap if the `sizes` mode rounds up further than the `positions` mode
+/// (for example `Ceil` sizes with `Floor` positions, so that sizes are never rounded down).
+pub fn round_layout_with_options(tree: &mut impl RoundTree, node_id: NodeId, options: RoundingOptions) {
+    let RoundingOptions { scale, positions, sizes } = options;
+    let near_edge = |value: Scalar| positions.round(value * scale) / scale;
+    let far_edge = |value: Scalar| sizes.round(value * scale) / scale;
+
+    // The tree is traversed with an explicit stack rather than by recursion so that very deep trees can't overflow the
+    // call stack. Each entry is a node that remains to be rounded along with the cumulative position of its parent.
+    #[cfg(feature = "std")]
+    let mut stack = ROUND_LAYOUT_STACK.with(Cell::take);
+    #[cfg(not(feature = "std"))]
+    let mut stack: Vec<(NodeId, Scalar, Scalar)> = Vec::new();
+    stack.push((node_id, 0.0, 0.0));
+    while let Some((node_id, parent_x, parent_y)) = stack.pop() {
+        // Subtrees whose unrounded layouts and absolute position are unchanged since they were last rounded are skipped
+        if !tree.needs_rounding(node_id, Point { x: parent_x, y: parent_y }) {
+            continue;
+        }
 
-    /// Recursive function to apply rounding to all descendents
-    fn round_layout_inner(tree: &mut impl RoundTree, node_id: NodeId, cumulative_x: f32, cumulative_y: f32) {
         let unrounded_layout = *tree.get_unrounded_layout(node_id);
         let mut layout = unrounded_layout;
 
-        let cumulative_x = cumulative_x + unrounded_layout.location.x;
-        let cumulative_y = cumulative_y + unrounded_layout.location.y;
-
-        layout.location.x = round(unrounded_layout.location.x);
-        layout.location.y = round(unrounded_layout.location.y);
-        layout.size.width = round(cumulative_x + unrounded_layout.size.width) - round(cumulative_x);
-        layout.size.height = round(cumulative_y + unrounded_layout.size.height) - round(cumulative_y);
-        layout.scrollbar_size.width = round(unrounded_layout.scrollbar_size.width);
-        layout.scrollbar_size.height = round(unrounded_layout.scrollbar_size.height);
-        layout.border.left = round(cumulative_x + unrounded_layout.border.left) - round(cumulative_x);
-        layout.border.right = round(cumulative_x + unrounded_layout.size.width)
-            - round(cumulative_x + unrounded_layout.size.width - unrounded_layout.border.right);
-        layout.border.top = round(cumulative_y + unrounded_layout.border.top) - round(cumulative_y);
-        layout.border.bottom = round(cumulative_y + unrounded_layout.size.height)
-            - round(cumulative_y + unrounded_layout.size.height - unrounded_layout.border.bottom);
-        layout.padding.left = round(cumulative_x + unrounded_layout.padding.left) - round(cumulative_x);
-        layout.padding.right = round(cumulative_x + unrounded_layout.size.width)
-            - round(cumulative_x + unrounded_layout.size.width - unrounded_layout.padding.right);
-        layout.padding.top = round(cumulative_y + unrounded_layout.padding.top) - round(cumulative_y);
-        layout.padding.bottom = round(cumulative_y + unrounded_layout.size.height)
-            - round(cumulative_y + unrounded_layout.size.height - unrounded_layout.padding.bottom);
+        let cumulative_x = parent_x + unrounded_layout.location.x;
+        let cumulative_y = parent_y + unrounded_layout.location.y;
+
+        layout.location.x = near_edge(cumulative_x) - near_edge(parent_x);
+        layout.location.y = near_edge(cumulative_y) - near_edge(parent_y);
+        layout.size.width = far_edge(cumulative_x + unrounded_layout.size.width) - near_edge(cumulative_x);
+        layout.size.height = far_edge(cumulative_y + unrounded_layout.size.height) - near_edge(cumulative_y);
+        layout.scrollbar_size.width = sizes.round(unrounded_layout.scrollbar_size.width * scale) / scale;
+        layout.scrollbar_size.height = sizes.round(unrounded_layout.scrollbar_size.height * scale) / scale;
+        // The edges of the padding and content boxes are rounded from their cumulative positions, and the border and
+        // padding widths are computed as the differences between the rounded edges, so that the rounded width and
+        // height of the content box (`size - border - padding`) can't be negative
+        let Layout { size, border, padding, .. } = unrounded_layout;
+        let padding_box_left = cumulative_x + border.left;
+        let padding_box_right = cumulative_x + size.width - border.right;
+        let padding_box_top = cumulative_y + border.top;
+        let padding_box_bottom = cumulative_y + size.height - border.bottom;
+        layout.border.left = near_edge(padding_box_left) - near_edge(cumulative_x);
+        layout.border.right = far_edge(cumulative_x + size.width) - far_edge(padding_box_right);
+        layout.border.top = near_edge(padding_box_top) - near_edge(cumulative_y);
+        layout.border.bottom = far_edge(cumulative_y + size.height) - far_edge(padding_box_bottom);
+        layout.padding.left = near_edge(padding_box_left + padding.left) - near_edge(padding_box_left);
+        layout.padding.right = far_edge(padding_box_right) - far_edge(padding_box_right - padding.right);
+        layout.padding.top = near_edge(padding_box_top + padding.top) - near_edge(padding_box_top);
+        layout.padding.bottom = far_edge(padding_box_bottom) - far_edge(padding_box_bottom - padding.bottom);
+
+        // Baselines are rounded from their cumulative positions in the same way as the top edges of boxes
+        layout.first_baseline = unrounded_layout
+            .first_baseline
+            .map(|baseline| near_edge(cumulative_y + baseline) - near_edge(cumulative_y));
+        layout.last_baseline =
+            unrounded_layout.last_baseline.map(|baseline| near_edge(cumulative_y + baseline) - near_edge(cumulative_y));
 
         #[cfg(feature = "content_size")]
-        round_content_size(&mut layout, unrounded_layout.content_size, cumulative_x, cumulative_y);
+        {
+            layout.content_size.width =
+                far_edge(cumulative_x + unrounded_layout.content_size.width) - near_edge(cumulative_x);
+            layout.content_size.height =
+                far_edge(cumulative_y + unrounded_layout.content_size.height) - near_edge(cumulative_y);
+        }
 
         tree.set_final_layout(node_id, &layout);
 
+        // Push the children in reverse so that they are rounded in order
         let child_count = tree.child_count(node_id);
-        for index in 0..child_count {
+        for index in (0..child_count).rev() {
             let child = tree.get_child_id(node_id, index);
-            round_layout_inner(tree, child, cumulative_x, cumulative_y);
+            stack.push((child, cumulative_x, cumulative_y));
         }
     }
 
-    #[cfg(feature = "content_size")]
-    #[inline(always)]
-    /// Round content size variables.
-    /// This is split into a separate function to make it easier to feature flag.
-    fn round_content_size(
-        layout: &mut Layout,
-        unrounded_content_size: Size<f32>,
-        cumulative_x: f32,
-        cumulative_y: f32,
-    ) {
-        layout.content_size.width = round(cumulative_x + unrounded_content_size.width) - round(cumulative_x);
-        layout.content_size.height = round(cumulative_y + unrounded_content_size.height) - round(cumulative_y);
+    #[cfg(feature = "std")]
+    if stack.capacity() <= MAX_RETAINED_ROUND_LAYOUT_STACK_CAPACITY {
+        ROUND_LAYOUT_STACK.with(|retained| retained.set(stack));
     }
 }
 
 /// Creates a layout for this node and its children, recursively.
 /// Each hidden node has zero size and is placed at the origin
 pub fn compute_hidden_layout(tree: &mut (impl LayoutPartialTree + CacheTree), node: NodeId) -> LayoutOutput {
+    debug_record_algorithm!("hidden");
     // Clear cache and set zeroed-out layout for the node
     tree.cache_clear(node);
     tree.set_unrounded_layout(node, &Layout::with_order(0));
 
-    // Perform hidden layout on all children
+    // Perform hidden layout on all children. Each child's zeroed-out layout is set after it has been laid out so that
+    // its order reflects its position within this node's children.
     for index in 0..tree.child_count(node) {
         let child_id = tree.get_child_id(node, index);
         tree.compute_child_layout(child_id, LayoutInput::HIDDEN);
+        tree.set_unrounded_layout(child_id, &Layout::with_order(index as u32));
     }
 
     LayoutOutput::HIDDEN
@@ -319,5 +450,11 @@ mod tests {
             assert_eq!(layout.size, Size::zero());
             assert_eq!(layout.location, Point::zero());
         }
+
+        // Each hidden node's order should be its index within its parent's children
+        for (node, order) in [(child_00, 0), (child_01, 1), (grandchild_00, 0), (grandchild_01, 1), (grandchild_02, 0)]
+        {
+            assert_eq!(taffy.unrounded_layout(node).order, order);
+        }
     }
 }
@@ -0,0 +1,149 @@
+//! Scratch buffers for the per-container temporaries of the layout algorithms (flex items, grid tracks, block items, etc).
+//!
+//! Each algorithm takes the buffers it needs from the tree's [`LayoutScratch`] when it starts laying out a container
+//! and returns them when it finishes. Containers can be nested, so the buffers of each type are kept in a stack: a
+//! nested container takes its buffers from the top of the stack and returns them before its parent continues, and the
+//! next sibling container then reuses them. The number of buffers that have to be allocated is therefore bounded by the
+//! depth of the tree rather than by its size.
+//!
+//! Buffers are only pooled when the `std` feature is enabled. Otherwise they are plain `Vec`s that are allocated each
+//! time they are used.
+#![cfg_attr(not(any(feature = "flexbox", feature = "grid", feature = "block_layout")), allow(dead_code))]
+use core::fmt::{Debug, Formatter};
+
+use crate::tree::LayoutPartialTree;
+use crate::util::sys::Vec;
+
+/// The default value for the largest buffer capacity (in elements) that is retained for reuse
+#[cfg(feature = "std")]
+const DEFAULT_MAX_RETAINED_CAPACITY: usize = 256;
+
+/// The maximum number of buffers of each type retained for reuse. This bounds the depth of nested containers that can
+/// be laid out without allocating.
+#[cfg(feature = "std")]
+const MAX_RETAINED_BUFFERS: usize = 64;
+
+/// A pool of scratch buffers that the layout algorithms borrow for their per-container temporaries.
+///
+/// A tree makes its pool available to the algorithms through [`LayoutPartialTree::scratch`].
+/// [`TaffyTree`](crate::TaffyTree) keeps a single pool for its whole lifetime, so that the buffers warmed up by one
+/// layout are reused by the next.
+pub struct LayoutScratch {
+    /// The largest buffer capacity that is retained for reuse
+    #[cfg(feature = "std")]
+    max_retained_capacity: usize,
+    /// Buffers used by the flexbox algorithm
+    #[cfg(all(feature = "std", feature = "flexbox"))]
+    pub(super) flexbox: super::flexbox::FlexboxScratch,
+    /// Buffers used by the CSS Grid algorithm
+    #[cfg(all(feature = "std", feature = "grid"))]
+    pub(super) grid: super::grid::GridScratch,
+    /// Buffers used by the block algorithm
+    #[cfg(all(feature = "std", feature = "block_layout"))]
+    pub(super) block: super::block::BlockScratch,
+}
+
+impl LayoutScratch {
+    /// Create an empty pool
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            max_retained_capacity: DEFAULT_MAX_RETAINED_CAPACITY,
+            #[cfg(all(feature = "std", feature = "flexbox"))]
+            flexbox: Default::default(),
+            #[cfg(all(feature = "std", feature = "grid"))]
+            grid: Default::default(),
+            #[cfg(all(feature = "std", feature = "block_layout"))]
+            block: Default::default(),
+        }
+    }
+
+    /// Sets the largest number of elements for which a buffer is retained for reuse. Containers with more children
+    /// than this are still laid out correctly, but allocate new buffers each time they are laid out. Defaults to 256.
+    ///
+    /// Buffers that are already retained and exceed the new limit are freed.
+    pub fn set_capacity_limit(&mut self, limit: usize) {
+        #[cfg(feature = "std")]
+        {
+            self.max_retained_capacity = limit;
+            #[cfg(feature = "flexbox")]
+            self.flexbox.shrink_to_limit(limit);
+            #[cfg(feature = "grid")]
+            self.grid.shrink_to_limit(limit);
+            #[cfg(feature = "block_layout")]
+            self.block.shrink_to_limit(limit);
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = limit;
+    }
+}
+
+impl Default for LayoutScratch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cloning a pool creates an empty pool with the same capacity limit. Retained buffers are empty, so there is nothing
+/// to copy.
+impl Clone for LayoutScratch {
+    fn clone(&self) -> Self {
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
+        let mut scratch = Self::new();
+        #[cfg(feature = "std")]
+        {
+            scratch.max_retained_capacity = self.max_retained_capacity;
+        }
+        scratch
+    }
+}
+
+impl Debug for LayoutScratch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LayoutScratch").finish_non_exhaustive()
+    }
+}
+
+/// A type whose buffers are pooled by [`LayoutScratch`]
+#[cfg_attr(not(any(feature = "std", feature = "alloc", feature = "block_layout")), allow(dead_code))]
+pub(super) trait ScratchItem: Sized {
+    /// The stack of empty buffers of this type that are available for reuse
+    #[cfg(feature = "std")]
+    fn pool(scratch: &mut LayoutScratch) -> &mut Vec<Vec<Self>>;
+}
+
+/// Frees the retained buffers in `pool` whose capacity exceeds `limit`
+#[cfg(feature = "std")]
+pub(super) fn shrink_pool_to_limit<T>(pool: &mut Vec<Vec<T>>, limit: usize) {
+    pool.retain(|buffer| buffer.capacity() <= limit);
+}
+
+/// Take an empty buffer from the tree's scratch pool, or create a new one if the tree has no pool or the pool is empty
+#[cfg_attr(not(any(feature = "std", feature = "alloc", feature = "block_layout")), allow(dead_code))]
+pub(super) fn take_buffer<T: ScratchItem>(tree: &mut impl LayoutPartialTree) -> Vec<T> {
+    #[cfg(feature = "std")]
+    if let Some(buffer) = tree.scratch().and_then(|scratch| T::pool(scratch).pop()) {
+        return buffer;
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = tree;
+    Vec::new()
+}
+
+/// Return a buffer to the tree's scratch pool so that it can be reused by the next container that is laid out. Buffers
+/// that are too large to be retained (or that aren't returned at all) are simply dropped.
+#[cfg_attr(not(any(feature = "std", feature = "alloc", feature = "block_layout")), allow(dead_code))]
+pub(super) fn release_buffer<T: ScratchItem>(tree: &mut impl LayoutPartialTree, mut buffer: Vec<T>) {
+    #[cfg(feature = "std")]
+    if let Some(scratch) = tree.scratch() {
+        if buffer.capacity() > 0 && buffer.capacity() <= scratch.max_retained_capacity {
+            buffer.clear();
+            let pool = T::pool(scratch);
+            if pool.len() < MAX_RETAINED_BUFFERS {
+                pool.push(buffer);
+            }
+        }
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = (tree, &mut buffer);
+}
@@ -0,0 +1,941 @@
+//! A C-compatible API for using a [`TaffyTree`](crate::TaffyTree) from other languages
+//!
+//! The tree is an opaque [`TaffyTree`] handle created with [`taffy_tree_new`] and destroyed with [`taffy_tree_free`],
+//! whose nodes are identified by [`TaffyNodeId`]s. Styles are passed as a flat [`TaffyStyle`] struct (which should be
+//! initialised with [`taffy_style_default`]) that covers every style property except the CSS Grid placement and track
+//! properties. Leaf nodes can be given a [`TaffyMeasureFunction`] and a pointer to user data to measure their content.
+//! Lengths are always passed as `float`s, and are converted if the `f64` feature is enabled.
+//!
+//! Functions that can fail return a [`TaffyReturnCode`], and write their results through out-pointers. A C header for
+//! this API is in `include/taffy.h`, generated with [cbindgen](https://github.com/mozilla/cbindgen) from this module.
+//! To build a library that can be linked into a C program, compile Taffy as a `staticlib` or `cdylib` with the `ffi`
+//! feature enabled, for example with `cargo rustc --release --features ffi --crate-type staticlib`.
+#![allow(unsafe_code)]
+// The C API's `float`s are the same type as `Scalar` unless the `f64` feature is enabled
+#![cfg_attr(not(feature = "f64"), allow(clippy::unnecessary_cast))]
+
+use core::ffi::c_void;
+
+use crate::geometry::{Rect, Scalar, Size};
+use crate::style::{
+    AlignContent, AlignItems, AvailableSpace, BoxSizing, Dimension, Display, FlexDirection, FlexWrap, LengthPercentage,
+    LengthPercentageAuto, Overflow, Position, Style,
+};
+use crate::tree::{NodeId, TaffyError, TraversePartialTree};
+use crate::{geometry::Point, TaffyTree as Tree};
+
+/// The id of a node in a [`TaffyTree`]
+pub type TaffyNodeId = u64;
+
+/// An opaque handle to a tree of nodes
+pub struct TaffyTree {
+    /// The tree, whose nodes' contexts are their measure functions
+    tree: Tree<NodeMeasure>,
+}
+
+/// The measure function and user data attached to a leaf node with [`taffy_node_set_measure`]
+struct NodeMeasure {
+    /// The measure function
+    function:
+        unsafe extern "C" fn(*mut c_void, TaffyNodeId, f32, f32, TaffyAvailableSpace, TaffyAvailableSpace) -> TaffySize,
+    /// The user data passed to the measure function
+    user_data: *mut c_void,
+}
+
+/// The result of a function that can fail
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyReturnCode {
+    /// The function succeeded
+    Ok,
+    /// A pointer argument was null
+    NullPointer,
+    /// A node argument isn't a node in the tree
+    InvalidNode,
+    /// A child index argument is greater than the number of children of the parent
+    ChildIndexOutOfBounds,
+    /// A child node argument isn't a child of the parent
+    NotAChild,
+    /// A style has a value that isn't valid for its property, such as `auto` padding
+    InvalidStyle,
+}
+
+/// The unit of a [`TaffyDimension`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyUnit {
+    /// The value is `auto`, and ignored
+    Auto,
+    /// The value is a length in pixels
+    Length,
+    /// The value is a fraction of the size of the containing block
+    Percent,
+}
+
+/// A length, percentage or `auto` value of a style property
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyDimension {
+    /// The unit of the value
+    pub unit: TaffyUnit,
+    /// The value
+    pub value: f32,
+}
+
+/// A [`TaffyDimension`] for each axis
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyDimensionSize {
+    /// The horizontal value
+    pub width: TaffyDimension,
+    /// The vertical value
+    pub height: TaffyDimension,
+}
+
+/// A [`TaffyDimension`] for each edge
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyDimensionRect {
+    /// The value of the left edge
+    pub left: TaffyDimension,
+    /// The value of the right edge
+    pub right: TaffyDimension,
+    /// The value of the top edge
+    pub top: TaffyDimension,
+    /// The value of the bottom edge
+    pub bottom: TaffyDimension,
+}
+
+/// See [`Display`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyDisplay {
+    /// See [`Display::Block`]
+    Block,
+    /// See [`Display::Flex`]
+    Flex,
+    /// See [`Display::Grid`]
+    Grid,
+    /// See [`Display::None`]
+    None,
+}
+
+/// See [`Position`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyPosition {
+    /// See [`Position::Relative`]
+    Relative,
+    /// See [`Position::Absolute`]
+    Absolute,
+}
+
+/// See [`Overflow`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyOverflow {
+    /// See [`Overflow::Visible`]
+    Visible,
+    /// See [`Overflow::Clip`]
+    Clip,
+    /// See [`Overflow::Hidden`]
+    Hidden,
+    /// See [`Overflow::Scroll`]
+    Scroll,
+}
+
+/// See [`BoxSizing`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyBoxSizing {
+    /// See [`BoxSizing::BorderBox`]
+    BorderBox,
+    /// See [`BoxSizing::ContentBox`]
+    ContentBox,
+}
+
+/// See [`FlexDirection`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyFlexDirection {
+    /// See [`FlexDirection::Row`]
+    Row,
+    /// See [`FlexDirection::Column`]
+    Column,
+    /// See [`FlexDirection::RowReverse`]
+    RowReverse,
+    /// See [`FlexDirection::ColumnReverse`]
+    ColumnReverse,
+}
+
+/// See [`FlexWrap`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyFlexWrap {
+    /// See [`FlexWrap::NoWrap`]
+    NoWrap,
+    /// See [`FlexWrap::Wrap`]
+    Wrap,
+    /// See [`FlexWrap::WrapReverse`]
+    WrapReverse,
+}
+
+/// See [`AlignItems`]. Used for the `align-items`, `align-self`, `justify-items` and `justify-self` properties.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyAlignItems {
+    /// The property isn't set (`None` in a [`Style`])
+    Normal,
+    /// See [`AlignItems::Start`]
+    Start,
+    /// See [`AlignItems::End`]
+    End,
+    /// See [`AlignItems::FlexStart`]
+    FlexStart,
+    /// See [`AlignItems::FlexEnd`]
+    FlexEnd,
+    /// See [`AlignItems::Center`]
+    Center,
+    /// See [`AlignItems::Baseline`]
+    Baseline,
+    /// See [`AlignItems::Stretch`]
+    Stretch,
+}
+
+/// See [`AlignContent`]. Used for the `align-content` and `justify-content` properties.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyAlignContent {
+    /// The property isn't set (`None` in a [`Style`])
+    Normal,
+    /// See [`AlignContent::Start`]
+    Start,
+    /// See [`AlignContent::End`]
+    End,
+    /// See [`AlignContent::FlexStart`]
+    FlexStart,
+    /// See [`AlignContent::FlexEnd`]
+    FlexEnd,
+    /// See [`AlignContent::Center`]
+    Center,
+    /// See [`AlignContent::Stretch`]
+    Stretch,
+    /// See [`AlignContent::SpaceBetween`]
+    SpaceBetween,
+    /// See [`AlignContent::SpaceEvenly`]
+    SpaceEvenly,
+    /// See [`AlignContent::SpaceAround`]
+    SpaceAround,
+}
+
+/// The style of a node. See [`Style`] for the meaning of each property.
+///
+/// `padding`, `border` and `gap` can't be `auto`. An `aspect_ratio` of NaN means that the node has no aspect ratio.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyStyle {
+    /// See [`Style::display`]
+    pub display: TaffyDisplay,
+    /// See [`Style::box_sizing`]
+    pub box_sizing: TaffyBoxSizing,
+    /// See [`Style::position`]
+    pub position: TaffyPosition,
+    /// The horizontal value of [`Style::overflow`]
+    pub overflow_x: TaffyOverflow,
+    /// The vertical value of [`Style::overflow`]
+    pub overflow_y: TaffyOverflow,
+    /// See [`Style::scrollbar_width`]
+    pub scrollbar_width: f32,
+    /// See [`Style::inset`]
+    pub inset: TaffyDimensionRect,
+    /// See [`Style::size`]
+    pub size: TaffyDimensionSize,
+    /// See [`Style::min_size`]
+    pub min_size: TaffyDimensionSize,
+    /// See [`Style::max_size`]
+    pub max_size: TaffyDimensionSize,
+    /// See [`Style::aspect_ratio`]
+    pub aspect_ratio: f32,
+    /// See [`Style::margin`]
+    pub margin: TaffyDimensionRect,
+    /// See [`Style::padding`]
+    pub padding: TaffyDimensionRect,
+    /// See [`Style::border`]
+    pub border: TaffyDimensionRect,
+    /// See [`Style::align_items`]
+    pub align_items: TaffyAlignItems,
+    /// See [`Style::align_self`]
+    pub align_self: TaffyAlignItems,
+    /// See [`Style::justify_items`]
+    pub justify_items: TaffyAlignItems,
+    /// See [`Style::justify_self`]
+    pub justify_self: TaffyAlignItems,
+    /// See [`Style::align_content`]
+    pub align_content: TaffyAlignContent,
+    /// See [`Style::justify_content`]
+    pub justify_content: TaffyAlignContent,
+    /// See [`Style::gap`]
+    pub gap: TaffyDimensionSize,
+    /// See [`Style::flex_direction`]
+    pub flex_direction: TaffyFlexDirection,
+    /// See [`Style::flex_wrap`]
+    pub flex_wrap: TaffyFlexWrap,
+    /// See [`Style::flex_basis`]
+    pub flex_basis: TaffyDimension,
+    /// See [`Style::flex_grow`]
+    pub flex_grow: f32,
+    /// See [`Style::flex_shrink`]
+    pub flex_shrink: f32,
+}
+
+/// The kind of a [`TaffyAvailableSpace`]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaffyAvailableSpaceKind {
+    /// A definite amount of space, given by the value
+    Definite,
+    /// The space available when sizing under a min-content constraint
+    MinContent,
+    /// The space available when sizing under a max-content constraint
+    MaxContent,
+}
+
+/// The space available to a node in an axis. See [`AvailableSpace`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyAvailableSpace {
+    /// The kind of space
+    pub kind: TaffyAvailableSpaceKind,
+    /// The amount of definite space, which is ignored for other kinds of space
+    pub value: f32,
+}
+
+/// A width and a height
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffySize {
+    /// The width
+    pub width: f32,
+    /// The height
+    pub height: f32,
+}
+
+/// A length for each edge of a box
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyEdges {
+    /// The length of the left edge
+    pub left: f32,
+    /// The length of the right edge
+    pub right: f32,
+    /// The length of the top edge
+    pub top: f32,
+    /// The length of the bottom edge
+    pub bottom: f32,
+}
+
+/// The computed layout of a node. See [`Layout`](crate::Layout).
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TaffyLayout {
+    /// The horizontal position of the node relative to its parent's border box
+    pub x: f32,
+    /// The vertical position of the node relative to its parent's border box
+    pub y: f32,
+    /// The width of the node
+    pub width: f32,
+    /// The height of the node
+    pub height: f32,
+    /// The width of the node's content, which may overflow it
+    pub content_width: f32,
+    /// The height of the node's content, which may overflow it
+    pub content_height: f32,
+    /// The widths of the node's borders
+    pub border: TaffyEdges,
+    /// The widths of the node's padding
+    pub padding: TaffyEdges,
+    /// The node's margins, as used by the layout of its parent
+    pub margin: TaffyEdges,
+}
+
+/// A function that measures the content of a leaf node
+///
+/// It is passed the user data given to [`taffy_node_set_measure`], the node, the known width and height of the node's
+/// content box (each of which is NaN if it isn't known) and the space available in each axis, and returns the size of
+/// the node's content.
+pub type TaffyMeasureFunction = Option<
+    unsafe extern "C" fn(
+        user_data: *mut c_void,
+        node: TaffyNodeId,
+        known_width: f32,
+        known_height: f32,
+        available_width: TaffyAvailableSpace,
+        available_height: TaffyAvailableSpace,
+    ) -> TaffySize,
+>;
+
+impl From<AvailableSpace> for TaffyAvailableSpace {
+    fn from(space: AvailableSpace) -> Self {
+        match space {
+            AvailableSpace::Definite(value) => Self { kind: TaffyAvailableSpaceKind::Definite, value: value as f32 },
+            AvailableSpace::MinContent => Self { kind: TaffyAvailableSpaceKind::MinContent, value: 0.0 },
+            AvailableSpace::MaxContent => Self { kind: TaffyAvailableSpaceKind::MaxContent, value: 0.0 },
+        }
+    }
+}
+
+impl From<TaffyAvailableSpace> for AvailableSpace {
+    fn from(space: TaffyAvailableSpace) -> Self {
+        match space.kind {
+            TaffyAvailableSpaceKind::Definite => AvailableSpace::Definite(space.value as Scalar),
+            TaffyAvailableSpaceKind::MinContent => AvailableSpace::MinContent,
+            TaffyAvailableSpaceKind::MaxContent => AvailableSpace::MaxContent,
+        }
+    }
+}
+
+impl From<Rect<Scalar>> for TaffyEdges {
+    fn from(rect: Rect<Scalar>) -> Self {
+        Self { left: rect.left as f32, right: rect.right as f32, top: rect.top as f32, bottom: rect.bottom as f32 }
+    }
+}
+
+impl From<TaffyError> for TaffyReturnCode {
+    fn from(error: TaffyError) -> Self {
+        match error {
+            TaffyError::ChildIndexOutOfBounds { .. } => TaffyReturnCode::ChildIndexOutOfBounds,
+            TaffyError::InvalidParentNode(_) | TaffyError::InvalidChildNode(_) | TaffyError::InvalidInputNode(_) => {
+                TaffyReturnCode::InvalidNode
+            }
+            TaffyError::NodeCapacityExceeded { .. } | TaffyError::ChildCapacityExceeded { .. } => {
+                unreachable!("a TaffyTree's capacity is unbounded")
+            }
+        }
+    }
+}
+
+impl TaffyDimension {
+    /// Converts the value to a [`Dimension`]
+    fn to_dimension(self) -> Dimension {
+        match self.unit {
+            TaffyUnit::Auto => Dimension::auto(),
+            TaffyUnit::Length => Dimension::length(self.value),
+            TaffyUnit::Percent => Dimension::percent(self.value),
+        }
+    }
+
+    /// Converts the value to a [`LengthPercentageAuto`]
+    fn to_length_percentage_auto(self) -> LengthPercentageAuto {
+        match self.unit {
+            TaffyUnit::Auto => LengthPercentageAuto::auto(),
+            TaffyUnit::Length => LengthPercentageAuto::length(self.value),
+            TaffyUnit::Percent => LengthPercentageAuto::percent(self.value),
+        }
+    }
+
+    /// Converts the value to a [`LengthPercentage`], if it isn't `auto`
+    fn to_length_percentage(self) -> Option<LengthPercentage> {
+        match self.unit {
+            TaffyUnit::Auto => None,
+            TaffyUnit::Length => Some(LengthPercentage::length(self.value)),
+            TaffyUnit::Percent => Some(LengthPercentage::percent(self.value)),
+        }
+    }
+}
+
+impl TaffyDimensionSize {
+    /// Converts the values to [`Dimension`]s
+    fn to_dimensions(self) -> Size<Dimension> {
+        Size { width: self.width.to_dimension(), height: self.height.to_dimension() }
+    }
+
+    /// Converts the values to [`LengthPercentage`]s, if neither is `auto`
+    fn to_length_percentages(self) -> Option<Size<LengthPercentage>> {
+        Some(Size { width: self.width.to_length_percentage()?, height: self.height.to_length_percentage()? })
+    }
+}
+
+impl TaffyDimensionRect {
+    /// Converts the values to [`LengthPercentageAuto`]s
+    fn to_length_percentage_autos(self) -> Rect<LengthPercentageAuto> {
+        Rect {
+            left: self.left.to_length_percentage_auto(),
+            right: self.right.to_length_percentage_auto(),
+            top: self.top.to_length_percentage_auto(),
+            bottom: self.bottom.to_length_percentage_auto(),
+        }
+    }
+
+    /// Converts the values to [`LengthPercentage`]s, if none of them are `auto`
+    fn to_length_percentages(self) -> Option<Rect<LengthPercentage>> {
+        Some(Rect {
+            left: self.left.to_length_percentage()?,
+            right: self.right.to_length_percentage()?,
+            top: self.top.to_length_percentage()?,
+            bottom: self.bottom.to_length_percentage()?,
+        })
+    }
+}
+
+impl TaffyAlignItems {
+    /// Converts the value to an optional [`AlignItems`]
+    fn to_align_items(self) -> Option<AlignItems> {
+        match self {
+            TaffyAlignItems::Normal => None,
+            TaffyAlignItems::Start => Some(AlignItems::Start),
+            TaffyAlignItems::End => Some(AlignItems::End),
+            TaffyAlignItems::FlexStart => Some(AlignItems::FlexStart),
+            TaffyAlignItems::FlexEnd => Some(AlignItems::FlexEnd),
+            TaffyAlignItems::Center => Some(AlignItems::Center),
+            TaffyAlignItems::Baseline => Some(AlignItems::Baseline),
+            TaffyAlignItems::Stretch => Some(AlignItems::Stretch),
+        }
+    }
+}
+
+impl TaffyAlignContent {
+    /// Converts the value to an optional [`AlignContent`]
+    fn to_align_content(self) -> Option<AlignContent> {
+        match self {
+            TaffyAlignContent::Normal => None,
+            TaffyAlignContent::Start => Some(AlignContent::Start),
+            TaffyAlignContent::End => Some(AlignContent::End),
+            TaffyAlignContent::FlexStart => Some(AlignContent::FlexStart),
+            TaffyAlignContent::FlexEnd => Some(AlignContent::FlexEnd),
+            TaffyAlignContent::Center => Some(AlignContent::Center),
+            TaffyAlignContent::Stretch => Some(AlignContent::Stretch),
+            TaffyAlignContent::SpaceBetween => Some(AlignContent::SpaceBetween),
+            TaffyAlignContent::SpaceEvenly => Some(AlignContent::SpaceEvenly),
+            TaffyAlignContent::SpaceAround => Some(AlignContent::SpaceAround),
+        }
+    }
+}
+
+impl TaffyOverflow {
+    /// Converts the value to an [`Overflow`]
+    fn to_overflow(self) -> Overflow {
+        match self {
+            TaffyOverflow::Visible => Overflow::Visible,
+            TaffyOverflow::Clip => Overflow::Clip,
+            TaffyOverflow::Hidden => Overflow::Hidden,
+            TaffyOverflow::Scroll => Overflow::Scroll,
+        }
+    }
+}
+
+impl TaffyStyle {
+    /// Converts the style to a [`Style`], or returns `None` if it has an `auto` padding, border or gap
+    fn to_style(self) -> Option<Style> {
+        Some(Style {
+            display: match self.display {
+                TaffyDisplay::Block => Display::Block,
+                TaffyDisplay::Flex => Display::Flex,
+                TaffyDisplay::Grid => Display::Grid,
+                TaffyDisplay::None => Display::None,
+            },
+            box_sizing: match self.box_sizing {
+                TaffyBoxSizing::BorderBox => BoxSizing::BorderBox,
+                TaffyBoxSizing::ContentBox => BoxSizing::ContentBox,
+            },
+            position: match self.position {
+                TaffyPosition::Relative => Position::Relative,
+                TaffyPosition::Absolute => Position::Absolute,
+            },
+            overflow: Point { x: self.overflow_x.to_overflow(), y: self.overflow_y.to_overflow() },
+            scrollbar_width: self.scrollbar_width,
+            inset: self.inset.to_length_percentage_autos(),
+            size: self.size.to_dimensions(),
+            min_size: self.min_size.to_dimensions(),
+            max_size: self.max_size.to_dimensions(),
+            aspect_ratio: (!self.aspect_ratio.is_nan()).then_some(self.aspect_ratio),
+            margin: self.margin.to_length_percentage_autos(),
+            padding: self.padding.to_length_percentages()?,
+            border: self.border.to_length_percentages()?,
+            align_items: self.align_items.to_align_items(),
+            align_self: self.align_self.to_align_items(),
+            justify_items: self.justify_items.to_align_items(),
+            justify_self: self.justify_self.to_align_items(),
+            align_content: self.align_content.to_align_content(),
+            justify_content: self.justify_content.to_align_content(),
+            gap: self.gap.to_length_percentages()?,
+            flex_direction: match self.flex_direction {
+                TaffyFlexDirection::Row => FlexDirection::Row,
+                TaffyFlexDirection::Column => FlexDirection::Column,
+                TaffyFlexDirection::RowReverse => FlexDirection::RowReverse,
+                TaffyFlexDirection::ColumnReverse => FlexDirection::ColumnReverse,
+            },
+            flex_wrap: match self.flex_wrap {
+                TaffyFlexWrap::NoWrap => FlexWrap::NoWrap,
+                TaffyFlexWrap::Wrap => FlexWrap::Wrap,
+                TaffyFlexWrap::WrapReverse => FlexWrap::WrapReverse,
+            },
+            flex_basis: self.flex_basis.to_dimension(),
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            ..Style::DEFAULT
+        })
+    }
+}
+
+/// Reads a style through a pointer passed to the API
+///
+/// # Safety
+///
+/// `style` must be null or point to a valid [`TaffyStyle`].
+unsafe fn read_style(style: *const TaffyStyle) -> Result<Style, TaffyReturnCode> {
+    let style = style.as_ref().ok_or(TaffyReturnCode::NullPointer)?;
+    style.to_style().ok_or(TaffyReturnCode::InvalidStyle)
+}
+
+/// Gets the tree behind a handle passed to the API, and checks that each of `nodes` is in it
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+unsafe fn get_tree<'a>(
+    tree: *mut TaffyTree,
+    nodes: &[TaffyNodeId],
+) -> Result<&'a mut Tree<NodeMeasure>, TaffyReturnCode> {
+    let tree = &mut tree.as_mut().ok_or(TaffyReturnCode::NullPointer)?.tree;
+    if nodes.iter().all(|&node| tree.contains(NodeId::from(node))) {
+        Ok(tree)
+    } else {
+        Err(TaffyReturnCode::InvalidNode)
+    }
+}
+
+/// Converts the result of a function to a [`TaffyReturnCode`]
+fn to_return_code(result: Result<(), TaffyReturnCode>) -> TaffyReturnCode {
+    match result {
+        Ok(()) => TaffyReturnCode::Ok,
+        Err(code) => code,
+    }
+}
+
+/// Creates a new, empty tree. It must be freed with [`taffy_tree_free`].
+#[no_mangle]
+pub extern "C" fn taffy_tree_new() -> *mut TaffyTree {
+    Box::into_raw(Box::new(TaffyTree { tree: Tree::new() }))
+}
+
+/// Frees a tree and all of its nodes. Does nothing if `tree` is null.
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_tree_free(tree: *mut TaffyTree) {
+    if !tree.is_null() {
+        drop(Box::from_raw(tree));
+    }
+}
+
+/// Sets whether the layout of the tree is rounded to whole pixels. Rounding is enabled by default.
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_tree_set_rounding(tree: *mut TaffyTree, enabled: bool) -> TaffyReturnCode {
+    to_return_code(get_tree(tree, &[]).map(
+        |tree| {
+            if enabled {
+                tree.enable_rounding()
+            } else {
+                tree.disable_rounding()
+            }
+        },
+    ))
+}
+
+/// Returns the default style, which should be modified to create the style of a node
+#[no_mangle]
+pub extern "C" fn taffy_style_default() -> TaffyStyle {
+    let auto = TaffyDimension { unit: TaffyUnit::Auto, value: 0.0 };
+    let zero = TaffyDimension { unit: TaffyUnit::Length, value: 0.0 };
+    TaffyStyle {
+        display: TaffyDisplay::Flex,
+        box_sizing: TaffyBoxSizing::BorderBox,
+        position: TaffyPosition::Relative,
+        overflow_x: TaffyOverflow::Visible,
+        overflow_y: TaffyOverflow::Visible,
+        scrollbar_width: 0.0,
+        inset: TaffyDimensionRect { left: auto, right: auto, top: auto, bottom: auto },
+        size: TaffyDimensionSize { width: auto, height: auto },
+        min_size: TaffyDimensionSize { width: auto, height: auto },
+        max_size: TaffyDimensionSize { width: auto, height: auto },
+        aspect_ratio: f32::NAN,
+        margin: TaffyDimensionRect { left: zero, right: zero, top: zero, bottom: zero },
+        padding: TaffyDimensionRect { left: zero, right: zero, top: zero, bottom: zero },
+        border: TaffyDimensionRect { left: zero, right: zero, top: zero, bottom: zero },
+        align_items: TaffyAlignItems::Normal,
+        align_self: TaffyAlignItems::Normal,
+        justify_items: TaffyAlignItems::Normal,
+        justify_self: TaffyAlignItems::Normal,
+        align_content: TaffyAlignContent::Normal,
+        justify_content: TaffyAlignContent::Normal,
+        gap: TaffyDimensionSize { width: zero, height: zero },
+        flex_direction: TaffyFlexDirection::Row,
+        flex_wrap: TaffyFlexWrap::NoWrap,
+        flex_basis: auto,
+        flex_grow: 0.0,
+        flex_shrink: 1.0,
+    }
+}
+
+/// Creates a new node without children, and writes its id to `node`
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, `style` must be null or point
+/// to a valid style, and `node` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_new(
+    tree: *mut TaffyTree,
+    style: *const TaffyStyle,
+    node: *mut TaffyNodeId,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[])?;
+        let style = read_style(style)?;
+        let node = node.as_mut().ok_or(TaffyReturnCode::NullPointer)?;
+        *node = tree.new_leaf(style)?.into();
+        Ok(())
+    })())
+}
+
+/// Removes a node from the tree. Its children are detached from it, but aren't removed.
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_remove(tree: *mut TaffyTree, node: TaffyNodeId) -> TaffyReturnCode {
+    to_return_code((|| {
+        get_tree(tree, &[node])?.remove(node.into())?;
+        Ok(())
+    })())
+}
+
+/// Sets the style of a node
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `style` must be null or
+/// point to a valid style.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_set_style(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    style: *const TaffyStyle,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let style = read_style(style)?;
+        tree.set_style(node.into(), style)?;
+        Ok(())
+    })())
+}
+
+/// Sets the function that measures a leaf node, and the user data passed to it, or removes it if `measure` is null
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed. `measure` must be safe to call
+/// with `user_data` whenever the layout of the tree is computed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_set_measure(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    measure: TaffyMeasureFunction,
+    user_data: *mut c_void,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let measure = measure.map(|function| NodeMeasure { function, user_data });
+        tree.set_node_context(node.into(), measure)?;
+        Ok(())
+    })())
+}
+
+/// Marks the layout of a node and its ancestors as outdated, for example because the content of a measured leaf has
+/// changed
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_mark_dirty(tree: *mut TaffyTree, node: TaffyNodeId) -> TaffyReturnCode {
+    to_return_code((|| {
+        get_tree(tree, &[node])?.mark_dirty(node.into())?;
+        Ok(())
+    })())
+}
+
+/// Appends `child` to the children of `parent`
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_add_child(
+    tree: *mut TaffyTree,
+    parent: TaffyNodeId,
+    child: TaffyNodeId,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        get_tree(tree, &[parent, child])?.add_child(parent.into(), child.into())?;
+        Ok(())
+    })())
+}
+
+/// Inserts `child` into the children of `parent` at `index`, which may be the number of children to append it
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_insert_child_at_index(
+    tree: *mut TaffyTree,
+    parent: TaffyNodeId,
+    index: usize,
+    child: TaffyNodeId,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        get_tree(tree, &[parent, child])?.insert_child_at_index(parent.into(), index, child.into())?;
+        Ok(())
+    })())
+}
+
+/// Detaches `child` from `parent`, without removing it from the tree
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_remove_child(
+    tree: *mut TaffyTree,
+    parent: TaffyNodeId,
+    child: TaffyNodeId,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[parent, child])?;
+        if tree.parent(child.into()) != Some(parent.into()) {
+            return Err(TaffyReturnCode::NotAChild);
+        }
+        tree.remove_child(parent.into(), child.into())?;
+        Ok(())
+    })())
+}
+
+/// Writes the number of children of a node to `count`
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `count` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_child_count(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    count: *mut usize,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let count = count.as_mut().ok_or(TaffyReturnCode::NullPointer)?;
+        *count = tree.child_count(node.into());
+        Ok(())
+    })())
+}
+
+/// Writes the id of the child of a node at `index` to `child`
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `child` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_child_at_index(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    index: usize,
+    child: *mut TaffyNodeId,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let child = child.as_mut().ok_or(TaffyReturnCode::NullPointer)?;
+        *child = tree.child_at_index(node.into(), index)?.into();
+        Ok(())
+    })())
+}
+
+/// Computes the layout of the subtree rooted at `node` in the given available space, measuring leaves with the
+/// functions set with [`taffy_node_set_measure`]
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and the measure functions of
+/// its nodes must be safe to call with their user data.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_compute_layout(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    available_width: TaffyAvailableSpace,
+    available_height: TaffyAvailableSpace,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let available_space = Size { width: available_width.into(), height: available_height.into() };
+        tree.compute_layout_with_measure(
+            node.into(),
+            available_space,
+            |known_dimensions, available_space: Size<AvailableSpace>, node_id, measure, _style| {
+                let Some(measure) = measure else { return Size::ZERO };
+                // SAFETY: the caller guarantees that the measure function can be called with its user data
+                let size = unsafe {
+                    (measure.function)(
+                        measure.user_data,
+                        node_id.into(),
+                        known_dimensions.width.map_or(f32::NAN, |width| width as f32),
+                        known_dimensions.height.map_or(f32::NAN, |height| height as f32),
+                        available_space.width.into(),
+                        available_space.height.into(),
+                    )
+                };
+                Size { width: size.width as Scalar, height: size.height as Scalar }
+            },
+        )?;
+        Ok(())
+    })())
+}
+
+/// Writes the computed layout of a node to `layout`
+///
+/// # Safety
+///
+/// `tree` must be null or a handle returned by [`taffy_tree_new`] that hasn't been freed, and `layout` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn taffy_node_get_layout(
+    tree: *mut TaffyTree,
+    node: TaffyNodeId,
+    layout: *mut TaffyLayout,
+) -> TaffyReturnCode {
+    to_return_code((|| {
+        let tree = get_tree(tree, &[node])?;
+        let out = layout.as_mut().ok_or(TaffyReturnCode::NullPointer)?;
+        let layout = tree.layout(node.into())?;
+        *out = TaffyLayout {
+            x: layout.location.x as f32,
+            y: layout.location.y as f32,
+            width: layout.size.width as f32,
+            height: layout.size.height as f32,
+            content_width: layout.content_size.width as f32,
+            content_height: layout.content_size.height as f32,
+            border: layout.border.into(),
+            padding: layout.padding.into(),
+            margin: layout.margin.into(),
+        };
+        Ok(())
+    })())
+}
@@ -1,15 +1,27 @@
 //! Geometric primitives useful for layout
 
-use crate::util::sys::f32_max;
+use crate::util::sys::scalar_max;
 use crate::CompactLength;
-use crate::{style::Dimension, util::sys::f32_min};
+use crate::{style::Dimension, util::sys::scalar_min};
 use core::ops::{Add, Sub};
 
 #[cfg(feature = "flexbox")]
 use crate::style::FlexDirection;
 
+/// The floating-point type of the positions and sizes that Taffy computes: `f32`, or `f64` with the `f64` feature
+///
+/// Style values (such as lengths, percentages and `flex_grow`) are always `f32`s, and are converted to this type as
+/// they are resolved.
+#[cfg(not(feature = "f64"))]
+pub type Scalar = f32;
+/// The floating-point type of the positions and sizes that Taffy computes, which is `f64` as the `f64` feature is
+/// enabled. See the `f32` definition for details.
+#[cfg(feature = "f64")]
+pub type Scalar = f64;
+
 /// The simple absolute horizontal and vertical axis
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AbsoluteAxis {
     /// The horizontal axis
     Horizontal,
@@ -287,13 +299,13 @@ where
     }
 }
 
-impl Rect<f32> {
+impl Rect<Scalar> {
     /// Creates a new Rect with `0.0` as all parameters
-    pub const ZERO: Rect<f32> = Self { left: 0.0, right: 0.0, top: 0.0, bottom: 0.0 };
+    pub const ZERO: Rect<Scalar> = Self { left: 0.0, right: 0.0, top: 0.0, bottom: 0.0 };
 
     /// Creates a new Rect
     #[must_use]
-    pub const fn new(start: f32, end: f32, top: f32, bottom: f32) -> Self {
+    pub const fn new(start: Scalar, end: Scalar, top: Scalar, bottom: Scalar) -> Self {
         Self { left: start, right: end, top, bottom }
     }
 }
@@ -527,20 +539,20 @@ impl<T> Size<T> {
     }
 }
 
-impl Size<f32> {
+impl Size<Scalar> {
     /// A [`Size`] with zero width and height
-    pub const ZERO: Size<f32> = Self { width: 0.0, height: 0.0 };
+    pub const ZERO: Size<Scalar> = Self { width: 0.0, height: 0.0 };
 
-    /// Applies f32_max to each component separately
+    /// Takes the maximum of each component separately
     #[inline(always)]
-    pub fn f32_max(self, rhs: Size<f32>) -> Size<f32> {
-        Size { width: f32_max(self.width, rhs.width), height: f32_max(self.height, rhs.height) }
+    pub fn f32_max(self, rhs: Size<Scalar>) -> Size<Scalar> {
+        Size { width: scalar_max(self.width, rhs.width), height: scalar_max(self.height, rhs.height) }
     }
 
-    /// Applies f32_min to each component separately
+    /// Takes the minimum of each component separately
     #[inline(always)]
-    pub fn f32_min(self, rhs: Size<f32>) -> Size<f32> {
-        Size { width: f32_min(self.width, rhs.width), height: f32_min(self.height, rhs.height) }
+    pub fn f32_min(self, rhs: Size<Scalar>) -> Size<Scalar> {
+        Size { width: scalar_min(self.width, rhs.width), height: scalar_min(self.height, rhs.height) }
     }
 
     /// Return true if both width and height are greater than 0 else false
@@ -550,19 +562,19 @@ impl Size<f32> {
     }
 }
 
-impl Size<Option<f32>> {
+impl Size<Option<Scalar>> {
     /// A [`Size`] with `None` width and height
-    pub const NONE: Size<Option<f32>> = Self { width: None, height: None };
+    pub const NONE: Size<Option<Scalar>> = Self { width: None, height: None };
 
-    /// A [`Size<Option<f32>>`] with `Some(width)` and `Some(height)` as parameters
+    /// A [`Size<Option<Scalar>>`] with `Some(width)` and `Some(height)` as parameters
     #[must_use]
-    pub const fn new(width: f32, height: f32) -> Self {
+    pub const fn new(width: Scalar, height: Scalar) -> Self {
         Size { width: Some(width), height: Some(height) }
     }
 
-    /// Creates a new [`Size<Option<f32>>`] with either the width or height set based on the provided `direction`
+    /// Creates a new [`Size<Option<Scalar>>`] with either the width or height set based on the provided `direction`
     #[cfg(feature = "flexbox")]
-    pub fn from_cross(direction: FlexDirection, value: Option<f32>) -> Self {
+    pub fn from_cross(direction: FlexDirection, value: Option<Scalar>) -> Self {
         let mut new = Self::NONE;
         if direction.is_row() {
             new.height = value
@@ -577,7 +589,7 @@ impl Size<Option<f32>> {
     ///   - If height is `Some` but width is `None`, then width is computed from height and aspect_ratio
     ///
     /// If aspect_ratio is `None` then this function simply returns self.
-    pub fn maybe_apply_aspect_ratio(self, aspect_ratio: Option<f32>) -> Size<Option<f32>> {
+    pub fn maybe_apply_aspect_ratio(self, aspect_ratio: Option<Scalar>) -> Size<Option<Scalar>> {
         match aspect_ratio {
             Some(ratio) => match (self.width, self.height) {
                 (Some(width), None) => Size { width: Some(width), height: Some(width / ratio) },
@@ -633,12 +645,12 @@ pub struct Point<T> {
     pub y: T,
 }
 
-impl Point<f32> {
+impl Point<Scalar> {
     /// A [`Point`] with values (0,0), representing the origin
     pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
 }
 
-impl Point<Option<f32>> {
+impl Point<Option<Scalar>> {
     /// A [`Point`] with values (None, None)
     pub const NONE: Self = Self { x: None, y: None };
 }
@@ -80,13 +80,19 @@ extern crate alloc;
 extern crate serde;
 
 pub mod compute;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod geometry;
 pub mod prelude;
 pub mod style;
 pub mod style_helpers;
+#[cfg(feature = "json_fixtures")]
+pub mod test_utils;
 pub mod tree;
 #[macro_use]
 pub mod util;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
 
 mod readme_doctest {
     #![doc = include_str!("../README.md")]
@@ -106,6 +112,7 @@ pub use crate::compute::detailed_info::*;
 #[doc(inline)]
 pub use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    round_layout_with_options, LayoutScratch, RoundingMode, RoundingOptions,
 };
 #[doc(inline)]
 pub use crate::style::Style;
@@ -1,14 +1,14 @@
 //! Commonly used types
 
 pub use crate::{
-    geometry::{Line, Rect, Size},
+    geometry::{Line, Rect, Scalar, Size},
     style::{
         AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Display,
         JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
     },
     style_helpers::{
-        auto, fit_content, length, max_content, min_content, percent, zero, FromFr, FromLength, FromPercent, TaffyAuto,
-        TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+        auto, calc, fit_content, length, max_content, min_content, percent, zero, FromCalc, FromFr, FromLength,
+        FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
     },
     tree::{Layout, LayoutPartialTree, NodeId, PrintTree, RoundTree, TraversePartialTree, TraverseTree},
 };
@@ -1,4 +1,5 @@
 //! Style type for representing available space as a sizing constraint
+use crate::geometry::Scalar;
 use crate::{
     prelude::{FromLength, TaffyMaxContent, TaffyMinContent, TaffyZero},
     sys::abs,
@@ -8,10 +9,11 @@ use crate::{
 /// The amount of space available to a node in a given axis
 /// <https://www.w3.org/TR/css-sizing-3/#available>
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum AvailableSpace {
     /// The amount of space available is the specified number of pixels
-    Definite(f32),
+    Definite(Scalar),
     /// The amount of space available is indefinite and the node should be laid out under a min-content constraint
     MinContent,
     /// The amount of space available is indefinite and the node should be laid out under a max-content constraint
@@ -28,7 +30,7 @@ impl TaffyMinContent for AvailableSpace {
 }
 impl FromLength for AvailableSpace {
     fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Self::Definite(value.into())
+        Self::Definite(value.into() as Scalar)
     }
 }
 
@@ -40,7 +42,7 @@ impl AvailableSpace {
 
     /// Convert to Option
     /// Definite values become Some(value). Constraints become None.
-    pub fn into_option(self) -> Option<f32> {
+    pub fn into_option(self) -> Option<Scalar> {
         match self {
             AvailableSpace::Definite(value) => Some(value),
             _ => None,
@@ -48,13 +50,13 @@ impl AvailableSpace {
     }
 
     /// Return the definite value or a default value
-    pub fn unwrap_or(self, default: f32) -> f32 {
+    pub fn unwrap_or(self, default: Scalar) -> Scalar {
         self.into_option().unwrap_or(default)
     }
 
     /// Return the definite value. Panic is the value is not definite.
     #[track_caller]
-    pub fn unwrap(self) -> f32 {
+    pub fn unwrap(self) -> Scalar {
         self.into_option().unwrap()
     }
 
@@ -75,12 +77,12 @@ impl AvailableSpace {
     }
 
     /// Return the definite value or the result of the default value callback
-    pub fn unwrap_or_else(self, default_cb: impl FnOnce() -> f32) -> f32 {
+    pub fn unwrap_or_else(self, default_cb: impl FnOnce() -> Scalar) -> Scalar {
         self.into_option().unwrap_or_else(default_cb)
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
-    pub fn maybe_set(self, value: Option<f32>) -> AvailableSpace {
+    pub fn maybe_set(self, value: Option<Scalar>) -> AvailableSpace {
         match value {
             Some(value) => AvailableSpace::Definite(value),
             None => self,
@@ -88,7 +90,7 @@ impl AvailableSpace {
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
-    pub fn map_definite_value(self, map_function: impl FnOnce(f32) -> f32) -> AvailableSpace {
+    pub fn map_definite_value(self, map_function: impl FnOnce(Scalar) -> Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(value) => AvailableSpace::Definite(map_function(value)),
             _ => self,
@@ -96,20 +98,20 @@ impl AvailableSpace {
     }
 
     /// Compute free_space given the passed used_space
-    pub fn compute_free_space(&self, used_space: f32) -> f32 {
+    pub fn compute_free_space(&self, used_space: Scalar) -> Scalar {
         match self {
-            AvailableSpace::MaxContent => f32::INFINITY,
+            AvailableSpace::MaxContent => Scalar::INFINITY,
             AvailableSpace::MinContent => 0.0,
             AvailableSpace::Definite(available_space) => available_space - used_space,
         }
     }
 
     /// Compare equality with another AvailableSpace, treating definite values
-    /// that are within f32::EPSILON of each other as equal
+    /// that are within Scalar::EPSILON of each other as equal
     pub fn is_roughly_equal(self, other: AvailableSpace) -> bool {
         use AvailableSpace::*;
         match (self, other) {
-            (Definite(a), Definite(b)) => abs(a - b) < f32::EPSILON,
+            (Definite(a), Definite(b)) => abs(a - b) < Scalar::EPSILON,
             (MinContent, MinContent) => true,
             (MaxContent, MaxContent) => true,
             _ => false,
@@ -117,14 +119,14 @@ impl AvailableSpace {
     }
 }
 
-impl From<f32> for AvailableSpace {
-    fn from(value: f32) -> Self {
+impl From<Scalar> for AvailableSpace {
+    fn from(value: Scalar) -> Self {
         Self::Definite(value)
     }
 }
 
-impl From<Option<f32>> for AvailableSpace {
-    fn from(option: Option<f32>) -> Self {
+impl From<Option<Scalar>> for AvailableSpace {
+    fn from(option: Option<Scalar>) -> Self {
         match option {
             Some(value) => Self::Definite(value),
             None => Self::MaxContent,
@@ -133,13 +135,13 @@ impl From<Option<f32>> for AvailableSpace {
 }
 
 impl Size<AvailableSpace> {
-    /// Convert `Size<AvailableSpace>` into `Size<Option<f32>>`
-    pub fn into_options(self) -> Size<Option<f32>> {
+    /// Convert `Size<AvailableSpace>` into `Size<Option<Scalar>>`
+    pub fn into_options(self) -> Size<Option<Scalar>> {
         Size { width: self.width.into_option(), height: self.height.into_option() }
     }
 
     /// If passed value is Some then return AvailableSpace::Definite containing that value, else return self
-    pub fn maybe_set(self, value: Size<Option<f32>>) -> Size<AvailableSpace> {
+    pub fn maybe_set(self, value: Size<Option<Scalar>>) -> Size<AvailableSpace> {
         Size { width: self.width.maybe_set(value.width), height: self.height.maybe_set(value.height) }
     }
 }
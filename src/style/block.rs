@@ -17,11 +17,34 @@ pub trait BlockItemStyle: CoreStyle {
     fn is_table(&self) -> bool {
         false
     }
+
+    /// Whether the item is inline-level. Consecutive in-flow inline-level items are laid out on shared lines. Floated
+    /// and absolutely positioned items are always block-level.
+    #[inline(always)]
+    fn is_inline(&self) -> bool {
+        false
+    }
+
+    /// Whether the item is floated to one side of the block container
+    #[inline(always)]
+    fn float(&self) -> Float {
+        Style::DEFAULT.float
+    }
+
+    /// Whether the item must be placed below preceding floats
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        Style::DEFAULT.clear
+    }
 }
 
 /// Used by block layout to implement the legacy behaviour of `<center>` and `<div align="left | right | center">`
+///
+/// This affects the horizontal position of block children that are narrower than their container, and the static
+/// position of absolutely positioned children that have no horizontal insets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TextAlign {
     /// No special legacy text align behaviour.
     #[default]
@@ -33,3 +56,47 @@ pub enum TextAlign {
     /// Corresponds to `-webkit-center` or `-moz-center` in browsers
     LegacyCenter,
 }
+
+/// Controls whether a child of a block container is taken out of the normal flow and placed against one side of the container.
+///
+/// [Specification](https://www.w3.org/TR/CSS21/visuren.html#float-position)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Float {
+    /// The item is not floated and is laid out in normal flow
+    #[default]
+    None,
+    /// The item floats against the left edge of the container
+    Left,
+    /// The item floats against the right edge of the container
+    Right,
+}
+
+/// Controls whether a child of a block container is moved below preceding floats.
+///
+/// [Specification](https://www.w3.org/TR/CSS21/visuren.html#flow-control)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Clear {
+    /// The item may be placed next to preceding floats
+    #[default]
+    None,
+    /// The item is placed below preceding left floats
+    Left,
+    /// The item is placed below preceding right floats
+    Right,
+    /// The item is placed below all preceding floats
+    Both,
+}
+
+impl Clear {
+    /// Whether an item with this `clear` value must be placed below a preceding float with the specified `float` value
+    pub(crate) fn clears(self, float: Float) -> bool {
+        matches!(
+            (self, float),
+            (Clear::Both, Float::Left | Float::Right) | (Clear::Left, Float::Left) | (Clear::Right, Float::Right)
+        )
+    }
+}
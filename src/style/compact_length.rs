@@ -1,16 +1,81 @@
 //! A tagged-pointer abstraction that allows size styles in Taffy to be represented
 //! in just 64 bits. Wrapped by types in the `super::dimension` and `super::grid` modules.
 use super::LengthPercentage;
+use crate::geometry::Scalar;
 use crate::style_helpers::{
-    FromFr, FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+    FromCalc, FromFr, FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
 };
 use compat::{f32_from_bits, f32_to_bits};
 
 /// A representation of a length as a compact 64-bit tagged pointer
+///
+/// With the `serde` feature enabled, a `CompactLength` is serialized as an externally tagged enum with snake_case
+/// variant names: `"auto"`, `"min_content"` and `"max_content"` as strings, and `{ "length": 10.0 }`,
+/// `{ "percent": 0.5 }`, `{ "fr": 1.0 }`, `{ "fit_content_px": 10.0 }` and `{ "fit_content_percent": 0.5 }` as
+/// single-key maps. `calc()` values are rejected when serializing: the handle is only meaningful to the process that
+/// created it, and there is no representation that could let serialized data choose the pointer that is passed to
+/// the calc resolver.
 #[derive(Copy, Clone, PartialEq, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompactLength(u64);
 
+/// The serialized representation of a [`CompactLength`]
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "CompactLength", rename_all = "snake_case")]
+enum SerializedCompactLength {
+    /// See [`CompactLength::length`]
+    Length(f32),
+    /// See [`CompactLength::percent`]
+    Percent(f32),
+    /// See [`CompactLength::auto`]
+    Auto,
+    /// See [`CompactLength::fr`]
+    Fr(f32),
+    /// See [`CompactLength::min_content`]
+    MinContent,
+    /// See [`CompactLength::max_content`]
+    MaxContent,
+    /// See [`CompactLength::fit_content_px`]
+    FitContentPx(f32),
+    /// See [`CompactLength::fit_content_percent`]
+    FitContentPercent(f32),
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CompactLength {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.value();
+        let serialized = match self.tag() {
+            Self::LENGTH_TAG => SerializedCompactLength::Length(value),
+            Self::PERCENT_TAG => SerializedCompactLength::Percent(value),
+            Self::AUTO_TAG => SerializedCompactLength::Auto,
+            Self::FR_TAG => SerializedCompactLength::Fr(value),
+            Self::MIN_CONTENT_TAG => SerializedCompactLength::MinContent,
+            Self::MAX_CONTENT_TAG => SerializedCompactLength::MaxContent,
+            Self::FIT_CONTENT_PX_TAG => SerializedCompactLength::FitContentPx(value),
+            Self::FIT_CONTENT_PERCENT_TAG => SerializedCompactLength::FitContentPercent(value),
+            _ => return Err(serde::ser::Error::custom("calc() values cannot be serialized")),
+        };
+        serialized.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CompactLength {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match SerializedCompactLength::deserialize(deserializer)? {
+            SerializedCompactLength::Length(value) => Self::length(value),
+            SerializedCompactLength::Percent(value) => Self::percent(value),
+            SerializedCompactLength::Auto => Self::auto(),
+            SerializedCompactLength::Fr(value) => Self::fr(value),
+            SerializedCompactLength::MinContent => Self::min_content(),
+            SerializedCompactLength::MaxContent => Self::max_content(),
+            SerializedCompactLength::FitContentPx(value) => Self::fit_content_px(value),
+            SerializedCompactLength::FitContentPercent(value) => Self::fit_content_percent(value),
+        })
+    }
+}
+
 impl CompactLength {
     // Masks
 
@@ -237,16 +302,19 @@ impl CompactLength {
     /// Whether the track sizing functions depends on the size of the parent node
     #[inline(always)]
     pub const fn uses_percentage(self) -> bool {
-        // TODO: handle calc() values
         matches!(self.tag(), CompactLength::PERCENT_TAG | CompactLength::FIT_CONTENT_PERCENT_TAG) || self.is_calc()
     }
 
     /// Resolve percentage values against the passed parent_size, returning Some(value)
     /// Non-percentage values always return None.
     #[inline(always)]
-    pub fn resolved_percentage_size(self, parent_size: f32, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn resolved_percentage_size(
+        self,
+        parent_size: Scalar,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         match self.tag() {
-            CompactLength::PERCENT_TAG => Some(self.value() * parent_size),
+            CompactLength::PERCENT_TAG => Some(self.value() as Scalar * parent_size),
             _ if self.is_calc() => Some(calc_resolver(self.0, parent_size)),
             _ => None,
         }
@@ -275,6 +343,11 @@ impl FromPercent for CompactLength {
         Self::percent(value.into())
     }
 }
+impl FromCalc for CompactLength {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl FromFr for CompactLength {
     fn from_fr<Input: Into<f32> + Copy>(value: Input) -> Self {
         Self::fr(value.into())
@@ -286,6 +359,9 @@ impl TaffyFitContent for CompactLength {
         match lp.0.tag() {
             Self::LENGTH_TAG => Self::fit_content_px(value),
             Self::PERCENT_TAG => Self::fit_content_percent(value),
+            // A calc() handle uses all of the bits that aren't part of its tag, so there is no room to also mark it
+            // as a fit-content() argument
+            _ if lp.0.is_calc() => panic!("calc() values are not supported as the argument of fit-content()"),
             _ => unreachable!(),
         }
     }
@@ -295,6 +371,7 @@ impl TaffyFitContent for CompactLength {
 /// here we can reduce MSRV from 1.83 all the way down to 1.65 while retaining const constructors.
 mod compat {
     #![allow(unsafe_code)]
+    #![allow(unknown_lints, unnecessary_transmutes)]
 
     /// Raw transmutation from `f32` to `u32`.
     pub const fn f32_to_bits(val: f32) -> u32 {
@@ -1,7 +1,7 @@
 //! Style types for representing lengths / sizes
 use super::CompactLength;
-use crate::geometry::Rect;
-use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyZero};
+use crate::geometry::{Rect, Scalar};
+use crate::style_helpers::{FromCalc, FromLength, FromPercent, TaffyAuto, TaffyZero};
 
 /// A unit of linear measurement
 ///
@@ -22,6 +22,11 @@ impl FromPercent for LengthPercentage {
         Self::percent(value.into())
     }
 }
+impl FromCalc for LengthPercentage {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl LengthPercentage {
     /// An absolute length in some abstract units. Users of Taffy may define what they correspond
     /// to in their application (pixels, logical pixels, mm, etc) as they see fit.
@@ -83,6 +88,11 @@ impl FromPercent for LengthPercentageAuto {
         Self::percent(value.into())
     }
 }
+impl FromCalc for LengthPercentageAuto {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl From<LengthPercentage> for LengthPercentageAuto {
     fn from(input: LengthPercentage) -> Self {
         Self(input.0)
@@ -139,10 +149,10 @@ impl LengthPercentageAuto {
     ///   - Some(resolved) using the provided context for Percent variants
     ///   - None for Auto variants
     #[inline(always)]
-    pub fn resolve_to_option(self, context: f32, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn resolve_to_option(self, context: Scalar, calc_resolver: impl Fn(u64, Scalar) -> Scalar) -> Option<Scalar> {
         match self.0.tag() {
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => Some(context * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => Some(context * self.0.value() as Scalar),
             CompactLength::AUTO_TAG => None,
             _ if self.0.is_calc() => Some(calc_resolver(self.0.calc_value(), context)),
             _ => unreachable!("LengthPercentageAuto values cannot be constructed with other tags"),
@@ -178,6 +188,11 @@ impl FromPercent for Dimension {
         Self::percent(value.into())
     }
 }
+impl FromCalc for Dimension {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl From<LengthPercentage> for Dimension {
     fn from(input: LengthPercentage) -> Self {
         Self(input.0)
@@ -4,9 +4,9 @@ use super::{
     LengthPercentageAuto, Style,
 };
 use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine};
-use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, MinMax, Size};
+use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, MinMax, Scalar, Size};
 use crate::style_helpers::*;
-use crate::util::sys::GridTrackVec;
+use crate::util::sys::{scalar_max, GridTrackVec};
 use core::borrow::Borrow;
 use core::cmp::{max, min};
 use core::convert::Infallible;
@@ -131,10 +131,12 @@ pub trait GridItemStyle: CoreStyle {
 /// Defaults to [`GridAutoFlow::Row`]
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GridAutoFlow {
     /// Items are placed by filling each row in turn, adding new rows as necessary
+    #[default]
     Row,
     /// Items are placed by filling each column in turn, adding new columns as necessary.
     Column,
@@ -144,12 +146,6 @@ pub enum GridAutoFlow {
     ColumnDense,
 }
 
-impl Default for GridAutoFlow {
-    fn default() -> Self {
-        Self::Row
-    }
-}
-
 impl GridAutoFlow {
     /// Whether grid auto placement uses the sparse placement algorithm or the dense placement algorithm
     /// See: <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow#values>
@@ -179,6 +175,7 @@ impl GridAutoFlow {
 /// See [`crate::compute::grid::type::coordinates`] for documentation on the different coordinate systems.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GenericGridPlacement<LineType: GridCoordinate> {
     /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
     Auto,
@@ -221,6 +218,7 @@ impl TaffyGridSpan for Line<GridPlacement> {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for GridPlacement {
     fn default() -> Self {
         Self::Auto
@@ -390,6 +388,11 @@ impl FromPercent for MaxTrackSizingFunction {
         Self::percent(value.into())
     }
 }
+impl FromCalc for MaxTrackSizingFunction {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl TaffyFitContent for MaxTrackSizingFunction {
     fn fit_content(argument: LengthPercentage) -> Self {
         Self(CompactLength::fit_content(argument))
@@ -568,7 +571,7 @@ impl MaxTrackSizingFunction {
 
     /// Returns whether the value can be resolved using `Self::definite_value`
     #[inline(always)]
-    pub fn has_definite_value(self, parent_size: Option<f32>) -> bool {
+    pub fn has_definite_value(self, parent_size: Option<Scalar>) -> bool {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => true,
             CompactLength::PERCENT_TAG => parent_size.is_some(),
@@ -581,11 +584,16 @@ impl MaxTrackSizingFunction {
     /// the passed available_space and returns if this results in a concrete value (which it
     /// will if the available_space is `Some`). Otherwise returns None.
     #[inline(always)]
-    pub fn definite_value(self, parent_size: Option<f32>, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn definite_value(
+        self,
+        parent_size: Option<Scalar>,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         match self.0.tag() {
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() * size),
-            _ if self.0.is_calc() => parent_size.map(|size| calc_resolver(self.0.calc_value(), size)),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() as Scalar * size),
+            // Track sizes (including gutters) may not be negative
+            _ if self.0.is_calc() => parent_size.map(|size| scalar_max(calc_resolver(self.0.calc_value(), size), 0.0)),
             _ => None,
         }
     }
@@ -597,10 +605,14 @@ impl MaxTrackSizingFunction {
     ///     - A fit-content sizing function with percentage argument (with definite available space)
     /// All other kinds of track sizing function return None.
     #[inline(always)]
-    pub fn definite_limit(self, parent_size: Option<f32>, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn definite_limit(
+        self,
+        parent_size: Option<Scalar>,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         match self.0.tag() {
-            CompactLength::FIT_CONTENT_PX_TAG => Some(self.0.value()),
-            CompactLength::FIT_CONTENT_PERCENT_TAG => parent_size.map(|size| self.0.value() * size),
+            CompactLength::FIT_CONTENT_PX_TAG => Some(self.0.value() as Scalar),
+            CompactLength::FIT_CONTENT_PERCENT_TAG => parent_size.map(|size| self.0.value() as Scalar * size),
             _ => self.definite_value(parent_size, calc_resolver),
         }
     }
@@ -608,7 +620,11 @@ impl MaxTrackSizingFunction {
     /// Resolve percentage values against the passed parent_size, returning Some(value)
     /// Non-percentage values always return None.
     #[inline(always)]
-    pub fn resolved_percentage_size(self, parent_size: f32, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn resolved_percentage_size(
+        self,
+        parent_size: Scalar,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         self.0.resolved_percentage_size(parent_size, calc_resolver)
     }
 
@@ -649,6 +665,11 @@ impl FromPercent for MinTrackSizingFunction {
         Self::percent(value.into())
     }
 }
+impl FromCalc for MinTrackSizingFunction {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::calc(ptr)
+    }
+}
 impl From<LengthPercentage> for MinTrackSizingFunction {
     fn from(input: LengthPercentage) -> Self {
         Self(input.0)
@@ -764,11 +785,16 @@ impl MinTrackSizingFunction {
     /// the passed available_space and returns if this results in a concrete value (which it
     /// will if the available_space is `Some`). Otherwise returns `None`.
     #[inline(always)]
-    pub fn definite_value(self, parent_size: Option<f32>, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn definite_value(
+        self,
+        parent_size: Option<Scalar>,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         match self.0.tag() {
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() * size),
-            _ if self.0.is_calc() => parent_size.map(|size| calc_resolver(self.0.calc_value(), size)),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() as Scalar * size),
+            // Track sizes (including gutters) may not be negative
+            _ if self.0.is_calc() => parent_size.map(|size| scalar_max(calc_resolver(self.0.calc_value(), size), 0.0)),
             _ => None,
         }
     }
@@ -776,7 +802,11 @@ impl MinTrackSizingFunction {
     /// Resolve percentage values against the passed parent_size, returning Some(value)
     /// Non-percentage values always return None.
     #[inline(always)]
-    pub fn resolved_percentage_size(self, parent_size: f32, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    pub fn resolved_percentage_size(
+        self,
+        parent_size: Scalar,
+        calc_resolver: impl Fn(u64, Scalar) -> Scalar,
+    ) -> Option<Scalar> {
         self.0.resolved_percentage_size(parent_size, calc_resolver)
     }
 
@@ -835,6 +865,11 @@ impl FromPercent for NonRepeatedTrackSizingFunction {
         Self { min: MinTrackSizingFunction::from_percent(percent), max: MaxTrackSizingFunction::from_percent(percent) }
     }
 }
+impl FromCalc for NonRepeatedTrackSizingFunction {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self { min: MinTrackSizingFunction::from_calc(ptr), max: MaxTrackSizingFunction::from_calc(ptr) }
+    }
+}
 impl FromFr for NonRepeatedTrackSizingFunction {
     fn from_fr<Input: Into<f32> + Copy>(flex: Input) -> Self {
         Self { min: MinTrackSizingFunction::AUTO, max: MaxTrackSizingFunction::from_fr(flex) }
@@ -862,6 +897,7 @@ impl From<Dimension> for NonRepeatedTrackSizingFunction {
 /// and the difference between AutoFit and AutoFill.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GridTrackRepetition {
     /// Auto-repeating tracks should be generated to fit the container
     /// See: <https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fill>
@@ -872,6 +908,7 @@ pub enum GridTrackRepetition {
     /// The specified tracks should be repeated exacts N times
     Count(u16),
 }
+#[allow(unknown_lints, clippy::infallible_try_from)]
 impl TryFrom<u16> for GridTrackRepetition {
     type Error = Infallible;
     fn try_from(value: u16) -> Result<Self, Infallible> {
@@ -905,6 +942,7 @@ impl TryFrom<&str> for GridTrackRepetition {
 /// See <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns>
 #[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TrackSizingFunction {
     /// A single non-repeated track
     Single(NonRepeatedTrackSizingFunction),
@@ -945,6 +983,11 @@ impl FromPercent for TrackSizingFunction {
         Self::Single(NonRepeatedTrackSizingFunction::from_percent(percent))
     }
 }
+impl FromCalc for TrackSizingFunction {
+    fn from_calc(ptr: *const ()) -> Self {
+        Self::Single(NonRepeatedTrackSizingFunction::from_calc(ptr))
+    }
+}
 impl FromFr for TrackSizingFunction {
     fn from_fr<Input: Into<f32> + Copy>(flex: Input) -> Self {
         Self::Single(NonRepeatedTrackSizingFunction::from_fr(flex))
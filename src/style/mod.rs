@@ -1,8 +1,18 @@
 //! A typed representation of [CSS style properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust. Used as input to layout computation.
+//!
+//! ## Serialization
+//!
+//! With the `serde` feature enabled, [`Style`] and every type it is made of can be serialized and deserialized. Enums
+//! are externally tagged with snake_case variant names (e.g. `"flex"`, `{ "span": 2 }` or
+//! `{ "repeat": ["auto_fill", [...]] }`), structs are maps of their snake_case field names, and lengths are
+//! represented as described in [`CompactLength`]. Fields omitted from a serialized `Style` take their default values.
 mod alignment;
 mod available_space;
 mod compact_length;
 mod dimension;
+#[cfg(feature = "parse")]
+mod parse;
+mod summary;
 
 #[cfg(feature = "block_layout")]
 mod block;
@@ -15,9 +25,12 @@ pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, J
 pub use self::available_space::AvailableSpace;
 pub use self::compact_length::CompactLength;
 pub use self::dimension::{Dimension, LengthPercentage, LengthPercentageAuto};
+#[cfg(feature = "parse")]
+pub use self::parse::{ParseError, ParseErrorKind};
+pub use self::summary::StyleSummary;
 
 #[cfg(feature = "block_layout")]
-pub use self::block::{BlockContainerStyle, BlockItemStyle, TextAlign};
+pub use self::block::{BlockContainerStyle, BlockItemStyle, Clear, Float, TextAlign};
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
@@ -28,7 +41,7 @@ pub use self::grid::{
     MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 
-use crate::geometry::{Point, Rect, Size};
+use crate::geometry::{Point, Rect, Scalar, Size};
 use crate::style_helpers::TaffyAuto as _;
 
 #[cfg(feature = "grid")]
@@ -54,6 +67,11 @@ pub trait CoreStyle {
     fn is_block(&self) -> bool {
         false
     }
+    /// Does this node establish a new block formatting context regardless of its other styles (`display: flow-root`)?
+    #[inline(always)]
+    fn is_flow_root(&self) -> bool {
+        false
+    }
     /// Which box do size styles apply to
     #[inline(always)]
     fn box_sizing(&self) -> BoxSizing {
@@ -68,7 +86,7 @@ pub trait CoreStyle {
     }
     /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
+    fn scrollbar_width(&self) -> Scalar {
         0.0
     }
 
@@ -103,8 +121,8 @@ pub trait CoreStyle {
     /// Sets the preferred aspect ratio for the item
     /// The ratio is calculated as width divided by height.
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
-        Style::DEFAULT.aspect_ratio
+    fn aspect_ratio(&self) -> Option<Scalar> {
+        Style::DEFAULT.aspect_ratio.map(|ratio| ratio as Scalar)
     }
 
     // Spacing Properties
@@ -130,10 +148,15 @@ pub trait CoreStyle {
 /// The default values depends on on which feature flags are enabled. The order of precedence is: Flex, Grid, Block, None.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Display {
     /// The children will follow the block layout algorithm
     #[cfg(feature = "block_layout")]
     Block,
+    /// The children will follow the block layout algorithm, and the node establishes a new block formatting context.
+    /// Margins do not collapse between the node and its children, and floated children are contained in its height.
+    #[cfg(feature = "block_layout")]
+    FlowRoot,
     /// The children will follow the flexbox layout algorithm
     #[cfg(feature = "flexbox")]
     Flex,
@@ -174,6 +197,8 @@ impl core::fmt::Display for Display {
             Display::None => write!(f, "NONE"),
             #[cfg(feature = "block_layout")]
             Display::Block => write!(f, "BLOCK"),
+            #[cfg(feature = "block_layout")]
+            Display::FlowRoot => write!(f, "FLOW ROOT"),
             #[cfg(feature = "flexbox")]
             Display::Flex => write!(f, "FLEX"),
             #[cfg(feature = "grid")]
@@ -186,6 +211,7 @@ impl core::fmt::Display for Display {
 /// See: <https://www.w3.org/TR/css-display-3/#box-generation>
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BoxGenerationMode {
     /// The node generates a box in the regular way
     Normal,
@@ -213,11 +239,13 @@ impl Default for BoxGenerationMode {
 /// which can be unintuitive.
 ///
 /// [`Position::Relative`] is the default value, in contrast to the default behavior in CSS.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Position {
     /// The offset is computed relative to the final position given by the layout algorithm.
     /// Offsets do not affect the position of any other items; they are effectively a correction factor applied at the end.
+    #[default]
     Relative,
     /// The offset is computed relative to this item's closest positioned ancestor, if any.
     /// Otherwise, it is placed relative to the origin.
@@ -227,12 +255,6 @@ pub enum Position {
     Absolute,
 }
 
-impl Default for Position {
-    fn default() -> Self {
-        Self::Relative
-    }
-}
-
 /// Specifies whether size styles for this node are assigned to the node's "content box" or "border box"
 ///
 /// - The "content box" is the node's inner size excluding padding, border and margin
@@ -246,21 +268,17 @@ impl Default for Position {
 ///   - `flex_basis`
 ///
 /// See h<ttps://developer.mozilla.org/en-US/docs/Web/CSS/box-sizing>
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BoxSizing {
     /// Size styles such size, min_size, max_size specify the box's "content box" (the size excluding padding/border/margin)
+    #[default]
     BorderBox,
     /// Size styles such size, min_size, max_size specify the box's "border box" (the size excluding margin but including padding/border)
     ContentBox,
 }
 
-impl Default for BoxSizing {
-    fn default() -> Self {
-        Self::BorderBox
-    }
-}
-
 /// How children overflowing their container should affect layout
 ///
 /// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
@@ -276,6 +294,7 @@ impl Default for BoxSizing {
 /// <https://developer.mozilla.org/en-US/docs/Web/CSS/overflow>
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Overflow {
     /// The automatic minimum size of this node as a flexbox/grid item should be based on the size of its content.
     /// Content that overflows this node *should* contribute to the scroll region of its parent.
@@ -307,7 +326,7 @@ impl Overflow {
     /// Returns `Some(0.0)` if the overflow mode would cause the automatic minimum size of a Flexbox or CSS Grid item
     /// to be `0`. Else returns None.
     #[inline(always)]
-    pub(crate) fn maybe_into_automatic_min_size(self) -> Option<f32> {
+    pub(crate) fn maybe_into_automatic_min_size(self) -> Option<Scalar> {
         match self.is_scroll_container() {
             true => Some(0.0),
             false => None,
@@ -338,6 +357,9 @@ pub struct Style {
     /// Whether a child is display:table or not. This affects children of block layouts.
     /// This should really be part of `Display`, but it is currently seperate because table layout isn't implemented
     pub item_is_table: bool,
+    /// Whether a child is inline-level or not. This affects children of block layouts: consecutive inline-level children
+    /// are placed side by side on shared lines rather than being stacked vertically.
+    pub item_is_inline: bool,
     /// Should size styles apply to the content box or the border box of the node
     pub box_sizing: BoxSizing,
 
@@ -411,6 +433,14 @@ pub struct Style {
     #[cfg(feature = "block_layout")]
     pub text_align: TextAlign,
 
+    // Block item properties
+    /// Whether the item is floated to one side of its block container
+    #[cfg(feature = "block_layout")]
+    pub float: Float,
+    /// Whether the item must be placed below preceding floats in its block container
+    #[cfg(feature = "block_layout")]
+    pub clear: Clear,
+
     // Flexbox container properties
     /// Which direction does the main axis flow in?
     #[cfg(feature = "flexbox")]
@@ -465,6 +495,7 @@ impl Style {
     pub const DEFAULT: Style = Style {
         display: Display::DEFAULT,
         item_is_table: false,
+        item_is_inline: false,
         box_sizing: BoxSizing::BorderBox,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
         scrollbar_width: 0.0,
@@ -495,6 +526,10 @@ impl Style {
         // Block
         #[cfg(feature = "block_layout")]
         text_align: TextAlign::Auto,
+        #[cfg(feature = "block_layout")]
+        float: Float::None,
+        #[cfg(feature = "block_layout")]
+        clear: Clear::None,
         // Flexbox
         #[cfg(feature = "flexbox")]
         flex_direction: FlexDirection::Row,
@@ -522,6 +557,11 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
     };
+
+    /// A compact one-line summary of the style for debug output, which can be formatted with `{}`. See [`StyleSummary`].
+    pub fn summary(&self) -> StyleSummary<'_> {
+        StyleSummary(self)
+    }
 }
 
 impl Default for Style {
@@ -541,7 +581,12 @@ impl CoreStyle for Style {
     #[inline(always)]
     #[cfg(feature = "block_layout")]
     fn is_block(&self) -> bool {
-        matches!(self.display, Display::Block)
+        matches!(self.display, Display::Block | Display::FlowRoot)
+    }
+    #[inline(always)]
+    #[cfg(feature = "block_layout")]
+    fn is_flow_root(&self) -> bool {
+        matches!(self.display, Display::FlowRoot)
     }
     #[inline(always)]
     fn box_sizing(&self) -> BoxSizing {
@@ -552,8 +597,8 @@ impl CoreStyle for Style {
         self.overflow
     }
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
-        self.scrollbar_width
+    fn scrollbar_width(&self) -> Scalar {
+        self.scrollbar_width as Scalar
     }
     #[inline(always)]
     fn position(&self) -> Position {
@@ -576,8 +621,8 @@ impl CoreStyle for Style {
         self.max_size
     }
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
-        self.aspect_ratio
+    fn aspect_ratio(&self) -> Option<Scalar> {
+        self.aspect_ratio.map(|ratio| ratio as Scalar)
     }
     #[inline(always)]
     fn margin(&self) -> Rect<LengthPercentageAuto> {
@@ -603,6 +648,10 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).is_block()
     }
     #[inline(always)]
+    fn is_flow_root(&self) -> bool {
+        (*self).is_flow_root()
+    }
+    #[inline(always)]
     fn box_sizing(&self) -> BoxSizing {
         (*self).box_sizing()
     }
@@ -611,7 +660,7 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).overflow()
     }
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
+    fn scrollbar_width(&self) -> Scalar {
         (*self).scrollbar_width()
     }
     #[inline(always)]
@@ -635,7 +684,7 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).max_size()
     }
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
+    fn aspect_ratio(&self) -> Option<Scalar> {
         (*self).aspect_ratio()
     }
     #[inline(always)]
@@ -674,6 +723,18 @@ impl BlockItemStyle for Style {
     fn is_table(&self) -> bool {
         self.item_is_table
     }
+    #[inline(always)]
+    fn is_inline(&self) -> bool {
+        self.item_is_inline
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        self.float
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        self.clear
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -682,6 +743,18 @@ impl<T: BlockItemStyle> BlockItemStyle for &'_ T {
     fn is_table(&self) -> bool {
         (*self).is_table()
     }
+    #[inline(always)]
+    fn is_inline(&self) -> bool {
+        (*self).is_inline()
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        (*self).float()
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        (*self).clear()
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -747,12 +820,12 @@ impl FlexboxItemStyle for Style {
         self.flex_basis
     }
     #[inline(always)]
-    fn flex_grow(&self) -> f32 {
-        self.flex_grow
+    fn flex_grow(&self) -> Scalar {
+        self.flex_grow as Scalar
     }
     #[inline(always)]
-    fn flex_shrink(&self) -> f32 {
-        self.flex_shrink
+    fn flex_shrink(&self) -> Scalar {
+        self.flex_shrink as Scalar
     }
     #[inline(always)]
     fn align_self(&self) -> Option<AlignSelf> {
@@ -767,11 +840,11 @@ impl<T: FlexboxItemStyle> FlexboxItemStyle for &'_ T {
         (*self).flex_basis()
     }
     #[inline(always)]
-    fn flex_grow(&self) -> f32 {
+    fn flex_grow(&self) -> Scalar {
         (*self).flex_grow()
     }
     #[inline(always)]
-    fn flex_shrink(&self) -> f32 {
+    fn flex_shrink(&self) -> Scalar {
         (*self).flex_shrink()
     }
     #[inline(always)]
@@ -939,6 +1012,7 @@ mod tests {
         let old_defaults = Style {
             display: Default::default(),
             item_is_table: false,
+            item_is_inline: false,
             box_sizing: Default::default(),
             overflow: Default::default(),
             scrollbar_width: 0.0,
@@ -966,6 +1040,10 @@ mod tests {
             gap: Size::zero(),
             #[cfg(feature = "block_layout")]
             text_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            float: Default::default(),
+            #[cfg(feature = "block_layout")]
+            clear: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_grow: 0.0,
             #[cfg(feature = "flexbox")]
@@ -0,0 +1,299 @@
+//! Parsing of length and size values from strings in CSS syntax (e.g. `"12px"`, `"50%"` or `"auto"`)
+//!
+//! `calc()` values are opaque handles to a representation owned by the embedding application, so CSS math functions
+//! (`calc()`, `min()`, `max()`, `clamp()` and `round()`) can't be produced by this parser. They are reported as
+//! [`ParseErrorKind::UnsupportedFunction`] so that callers can parse them into their own calc representation instead.
+use super::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+use crate::geometry::Scalar;
+use core::str::FromStr;
+
+/// An error produced when a string can't be parsed as a length or size value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset into the input at which the problem was found
+    pub offset: usize,
+    /// What the problem was
+    pub kind: ParseErrorKind,
+}
+
+/// The kind of problem described by a [`ParseError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input ended where a value was expected
+    UnexpectedEnd,
+    /// A character that can't start a value
+    UnexpectedCharacter,
+    /// A number that isn't followed by a unit. Only `0` may be written without a unit
+    MissingUnit,
+    /// A number followed by a unit other than `px` or `%`
+    UnknownUnit,
+    /// An identifier that isn't a supported keyword
+    UnknownKeyword,
+    /// A function other than the CSS math functions
+    UnknownFunction,
+    /// A CSS math function (`calc()`, `min()`, `max()`, `clamp()` or `round()`). See the [module docs](self)
+    UnsupportedFunction,
+    /// A valid value that the target type can't represent (e.g. `auto` for a [`LengthPercentage`])
+    InvalidForType,
+    /// Input remaining after a complete value
+    TrailingInput,
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::UnexpectedEnd => "unexpected end of input",
+            ParseErrorKind::UnexpectedCharacter => "unexpected character",
+            ParseErrorKind::MissingUnit => "number is missing a unit",
+            ParseErrorKind::UnknownUnit => "unknown unit",
+            ParseErrorKind::UnknownKeyword => "unknown keyword",
+            ParseErrorKind::UnknownFunction => "unknown function",
+            ParseErrorKind::UnsupportedFunction => "CSS math functions can't be parsed into a calc() handle",
+            ParseErrorKind::InvalidForType => "value is not valid for this type",
+            ParseErrorKind::TrailingInput => "unexpected input after value",
+        };
+        write!(f, "{message} at byte offset {}", self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// A single value parsed from a string, before it is converted to a concrete style type
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    /// A length in `px`, or a unitless zero
+    Length(f32),
+    /// A percentage, already divided by 100
+    Percent(f32),
+    /// The `auto` keyword
+    Auto,
+    /// The `min-content` keyword
+    MinContent,
+    /// The `max-content` keyword
+    MaxContent,
+}
+
+/// Parse a single value surrounded by optional whitespace, returning it along with the offset at which it starts
+fn parse_value(input: &str) -> Result<(Value, usize), ParseError> {
+    let bytes = input.as_bytes();
+    let start = skip_whitespace(bytes, 0);
+    let Some(&first) = bytes.get(start) else {
+        return Err(ParseError { offset: start, kind: ParseErrorKind::UnexpectedEnd });
+    };
+
+    let (value, end) = if first.is_ascii_digit() || matches!(first, b'+' | b'-' | b'.') {
+        parse_dimension(input, start)?
+    } else if first.is_ascii_alphabetic() {
+        parse_identifier(input, start)?
+    } else {
+        return Err(ParseError { offset: start, kind: ParseErrorKind::UnexpectedCharacter });
+    };
+
+    let trailing = skip_whitespace(bytes, end);
+    if trailing != bytes.len() {
+        return Err(ParseError { offset: trailing, kind: ParseErrorKind::TrailingInput });
+    }
+    Ok((value, start))
+}
+
+/// Returns the offset of the first non-whitespace byte at or after `offset`
+fn skip_whitespace(bytes: &[u8], mut offset: usize) -> usize {
+    while matches!(bytes.get(offset), Some(byte) if byte.is_ascii_whitespace()) {
+        offset += 1;
+    }
+    offset
+}
+
+/// Returns the offset of the first byte at or after `offset` that doesn't match `predicate`
+fn skip_while(bytes: &[u8], mut offset: usize, predicate: impl Fn(u8) -> bool) -> usize {
+    while matches!(bytes.get(offset), Some(&byte) if predicate(byte)) {
+        offset += 1;
+    }
+    offset
+}
+
+/// Parse a number and its unit starting at `start`, returning the value and the offset after it
+fn parse_dimension(input: &str, start: usize) -> Result<(Value, usize), ParseError> {
+    let bytes = input.as_bytes();
+    let mut offset = start;
+    if matches!(bytes[offset], b'+' | b'-') {
+        offset += 1;
+    }
+    let integer_end = skip_while(bytes, offset, |byte| byte.is_ascii_digit());
+    let mut number_end = integer_end;
+    if bytes.get(number_end) == Some(&b'.') {
+        number_end = skip_while(bytes, number_end + 1, |byte| byte.is_ascii_digit());
+    }
+    // A number must contain at least one digit, either side of the decimal point
+    if number_end - offset <= usize::from(integer_end != number_end) {
+        let kind =
+            if offset == bytes.len() { ParseErrorKind::UnexpectedEnd } else { ParseErrorKind::UnexpectedCharacter };
+        return Err(ParseError { offset, kind });
+    }
+    // An exponent is only part of the number if it contains digits, otherwise the `e` starts the unit
+    if matches!(bytes.get(number_end), Some(b'e' | b'E')) {
+        let mut exponent_start = number_end + 1;
+        if matches!(bytes.get(exponent_start), Some(b'+' | b'-')) {
+            exponent_start += 1;
+        }
+        let exponent_end = skip_while(bytes, exponent_start, |byte| byte.is_ascii_digit());
+        if exponent_end > exponent_start {
+            number_end = exponent_end;
+        }
+    }
+    let number: f32 = input[start..number_end]
+        .parse()
+        .map_err(|_| ParseError { offset: start, kind: ParseErrorKind::UnexpectedCharacter })?;
+
+    let unit_end = skip_while(bytes, number_end, |byte| byte.is_ascii_alphabetic() || byte == b'%');
+    let unit = &input[number_end..unit_end];
+    let value = if unit.eq_ignore_ascii_case("px") {
+        Value::Length(number)
+    } else if unit == "%" {
+        Value::Percent(number / 100.0)
+    } else if unit.is_empty() {
+        if number != 0.0 {
+            return Err(ParseError { offset: number_end, kind: ParseErrorKind::MissingUnit });
+        }
+        Value::Length(0.0)
+    } else {
+        return Err(ParseError { offset: number_end, kind: ParseErrorKind::UnknownUnit });
+    };
+    Ok((value, unit_end))
+}
+
+/// Parse a keyword or function starting at `start`, returning the value and the offset after it
+fn parse_identifier(input: &str, start: usize) -> Result<(Value, usize), ParseError> {
+    let bytes = input.as_bytes();
+    let end = skip_while(bytes, start, |byte| byte.is_ascii_alphanumeric() || byte == b'-');
+    let identifier = &input[start..end];
+
+    if bytes.get(end) == Some(&b'(') {
+        let is_math_function =
+            ["calc", "min", "max", "clamp", "round"].iter().any(|name| identifier.eq_ignore_ascii_case(name));
+        let kind = if is_math_function { ParseErrorKind::UnsupportedFunction } else { ParseErrorKind::UnknownFunction };
+        return Err(ParseError { offset: start, kind });
+    }
+
+    let value = if identifier.eq_ignore_ascii_case("auto") {
+        Value::Auto
+    } else if identifier.eq_ignore_ascii_case("min-content") {
+        Value::MinContent
+    } else if identifier.eq_ignore_ascii_case("max-content") {
+        Value::MaxContent
+    } else {
+        return Err(ParseError { offset: start, kind: ParseErrorKind::UnknownKeyword });
+    };
+    Ok((value, end))
+}
+
+/// Implements `FromStr` for a style type by mapping the parsed [`Value`]s it supports
+macro_rules! impl_from_str {
+    ($ty:ty, { $($pattern:pat => $result:expr),* $(,)? }) => {
+        impl FromStr for $ty {
+            type Err = ParseError;
+
+            fn from_str(input: &str) -> Result<Self, ParseError> {
+                let (value, offset) = parse_value(input)?;
+                match value {
+                    $($pattern => Ok($result),)*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(ParseError { offset, kind: ParseErrorKind::InvalidForType }),
+                }
+            }
+        }
+    };
+}
+
+impl_from_str!(LengthPercentage, {
+    Value::Length(value) => Self::length(value),
+    Value::Percent(value) => Self::percent(value),
+});
+
+impl_from_str!(LengthPercentageAuto, {
+    Value::Length(value) => Self::length(value),
+    Value::Percent(value) => Self::percent(value),
+    Value::Auto => Self::auto(),
+});
+
+impl_from_str!(Dimension, {
+    Value::Length(value) => Self::length(value),
+    Value::Percent(value) => Self::percent(value),
+    Value::Auto => Self::auto(),
+});
+
+impl_from_str!(AvailableSpace, {
+    Value::Length(value) => Self::Definite(value as Scalar),
+    Value::MinContent => Self::MinContent,
+    Value::MaxContent => Self::MaxContent,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorKind};
+    use crate::style::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+
+    /// Shorthand for an expected error
+    fn error(offset: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { offset, kind }
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!("12px".parse(), Ok(LengthPercentage::length(12.0)));
+        assert_eq!("-1.5px".parse(), Ok(LengthPercentage::length(-1.5)));
+        assert_eq!("+.5PX".parse(), Ok(LengthPercentage::length(0.5)));
+        assert_eq!("1e2px".parse(), Ok(LengthPercentage::length(100.0)));
+        assert_eq!("0".parse(), Ok(LengthPercentage::length(0.0)));
+        assert_eq!("  8px\n".parse(), Ok(Dimension::length(8.0)));
+        assert_eq!("10px".parse(), Ok(AvailableSpace::Definite(10.0)));
+    }
+
+    #[test]
+    fn percentages() {
+        assert_eq!("50%".parse(), Ok(LengthPercentage::percent(0.5)));
+        assert_eq!("12.5%".parse(), Ok(LengthPercentageAuto::percent(0.125)));
+        assert_eq!("100%".parse(), Ok(Dimension::percent(1.0)));
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!("auto".parse(), Ok(LengthPercentageAuto::auto()));
+        assert_eq!("AUTO".parse(), Ok(Dimension::auto()));
+        assert_eq!("min-content".parse(), Ok(AvailableSpace::MinContent));
+        assert_eq!("max-content".parse(), Ok(AvailableSpace::MaxContent));
+    }
+
+    #[test]
+    fn values_invalid_for_type() {
+        assert_eq!(" auto".parse::<LengthPercentage>(), Err(error(1, ParseErrorKind::InvalidForType)));
+        assert_eq!("min-content".parse::<Dimension>(), Err(error(0, ParseErrorKind::InvalidForType)));
+        assert_eq!("50%".parse::<AvailableSpace>(), Err(error(0, ParseErrorKind::InvalidForType)));
+        assert_eq!("auto".parse::<AvailableSpace>(), Err(error(0, ParseErrorKind::InvalidForType)));
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!("".parse::<Dimension>(), Err(error(0, ParseErrorKind::UnexpectedEnd)));
+        assert_eq!("  ".parse::<Dimension>(), Err(error(2, ParseErrorKind::UnexpectedEnd)));
+        assert_eq!("-".parse::<Dimension>(), Err(error(1, ParseErrorKind::UnexpectedEnd)));
+        assert_eq!(".px".parse::<Dimension>(), Err(error(0, ParseErrorKind::UnexpectedCharacter)));
+        assert_eq!("#fff".parse::<Dimension>(), Err(error(0, ParseErrorKind::UnexpectedCharacter)));
+        assert_eq!("12".parse::<Dimension>(), Err(error(2, ParseErrorKind::MissingUnit)));
+        assert_eq!("12em".parse::<Dimension>(), Err(error(2, ParseErrorKind::UnknownUnit)));
+        assert_eq!("1e".parse::<Dimension>(), Err(error(1, ParseErrorKind::UnknownUnit)));
+        assert_eq!("inherit".parse::<Dimension>(), Err(error(0, ParseErrorKind::UnknownKeyword)));
+        assert_eq!("12px 4px".parse::<Dimension>(), Err(error(5, ParseErrorKind::TrailingInput)));
+        assert_eq!("12px;".parse::<Dimension>(), Err(error(4, ParseErrorKind::TrailingInput)));
+        assert_eq!("var(--x)".parse::<Dimension>(), Err(error(0, ParseErrorKind::UnknownFunction)));
+    }
+
+    #[test]
+    fn math_functions_are_unsupported() {
+        for input in ["calc(100% - 16px)", "min(1px, 2px)", "max(1px, 2px)", "clamp(1px, 2%, 3px)", "round(1.5px)"] {
+            assert_eq!(input.parse::<Dimension>(), Err(error(0, ParseErrorKind::UnsupportedFunction)), "{input}");
+        }
+        assert_eq!(" CALC(1px)".parse::<LengthPercentage>(), Err(error(1, ParseErrorKind::UnsupportedFunction)));
+    }
+}
@@ -0,0 +1,118 @@
+//! A compact, human-readable summary of a [`Style`] for debug output
+use super::{CompactLength, Position, Style};
+use crate::geometry::{Rect, Size};
+use core::fmt::{Display, Formatter, Result};
+
+/// A compact one-line summary of the properties of a [`Style`] that most affect the size of its box. Created with
+/// [`Style::summary`].
+///
+/// The display mode is always included. The position, size, min and max sizes, margin and padding are included when
+/// they differ from their defaults, written in CSS syntax with sizes as `width height` and the margin and padding as
+/// CSS shorthands. For example: `display: FLEX; size: 100px auto; padding: 5px 10px`.
+#[derive(Debug, Clone, Copy)]
+pub struct StyleSummary<'a>(pub(crate) &'a Style);
+
+impl Display for StyleSummary<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let style = self.0;
+        let default = &Style::DEFAULT;
+        write!(f, "display: {}", style.display)?;
+        if style.position == Position::Absolute {
+            f.write_str("; position: absolute")?;
+        }
+        for (name, size, default_size) in [
+            ("size", style.size, default.size),
+            ("min_size", style.min_size, default.min_size),
+            ("max_size", style.max_size, default.max_size),
+        ] {
+            if size != default_size {
+                write!(f, "; {name}: ")?;
+                write_size(f, size.map(|dimension| dimension.into_raw()))?;
+            }
+        }
+        if style.margin != default.margin {
+            f.write_str("; margin: ")?;
+            write_rect(f, style.margin.map(|margin| margin.into_raw()))?;
+        }
+        if style.padding != default.padding {
+            f.write_str("; padding: ")?;
+            write_rect(f, style.padding.map(|padding| padding.into_raw()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a length in CSS syntax. `calc()` values are opaque, so their expressions can't be written.
+fn write_length(f: &mut Formatter<'_>, length: CompactLength) -> Result {
+    if length.is_calc() {
+        return f.write_str("calc(..)");
+    }
+    match length.tag() {
+        CompactLength::LENGTH_TAG => write!(f, "{}px", length.value()),
+        CompactLength::PERCENT_TAG => write!(f, "{}%", length.value() * 100.0),
+        CompactLength::AUTO_TAG => f.write_str("auto"),
+        CompactLength::FR_TAG => write!(f, "{}fr", length.value()),
+        CompactLength::MIN_CONTENT_TAG => f.write_str("min-content"),
+        CompactLength::MAX_CONTENT_TAG => f.write_str("max-content"),
+        CompactLength::FIT_CONTENT_PX_TAG => write!(f, "fit-content({}px)", length.value()),
+        CompactLength::FIT_CONTENT_PERCENT_TAG => write!(f, "fit-content({}%)", length.value() * 100.0),
+        _ => f.write_str("?"),
+    }
+}
+
+/// Writes a size as its width followed by its height
+fn write_size(f: &mut Formatter<'_>, size: Size<CompactLength>) -> Result {
+    write_length(f, size.width)?;
+    f.write_str(" ")?;
+    write_length(f, size.height)
+}
+
+/// Writes the sides of a rect in the shortest form of the CSS shorthand (top, right, bottom, left)
+fn write_rect(f: &mut Formatter<'_>, rect: Rect<CompactLength>) -> Result {
+    let Rect { left, right, top, bottom } = rect;
+    let sides: &[CompactLength] = if left != right {
+        &[top, right, bottom, left]
+    } else if top != bottom {
+        &[top, right, bottom]
+    } else if top != right {
+        &[top, right]
+    } else {
+        &[top]
+    };
+    for (index, side) in sides.iter().enumerate() {
+        if index > 0 {
+            f.write_str(" ")?;
+        }
+        write_length(f, *side)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::Position;
+
+    #[test]
+    fn default_style_summary_only_includes_display() {
+        assert_eq!(Style { display: Display::Flex, ..Default::default() }.summary().to_string(), "display: FLEX");
+    }
+
+    #[test]
+    fn style_summary_uses_css_syntax_and_shorthands() {
+        let style = Style {
+            display: Display::Grid,
+            position: Position::Absolute,
+            size: Size { width: length(100.0), height: auto() },
+            min_size: Size { width: percent(0.5), height: zero() },
+            margin: Rect { left: auto(), right: auto(), top: length(5.0), bottom: length(5.0) },
+            padding: Rect { left: length(4.0), right: length(2.0), top: length(1.0), bottom: length(3.0) },
+            ..Default::default()
+        };
+        assert_eq!(
+            style.summary().to_string(),
+            "display: GRID; position: absolute; size: 100px auto; min_size: 50% 0px; margin: 5px auto; \
+             padding: 1px 2px 3px 4px"
+        );
+    }
+}
@@ -92,6 +92,10 @@ pub trait TaffyZero {
 impl TaffyZero for f32 {
     const ZERO: f32 = 0.0;
 }
+#[cfg(feature = "f64")]
+impl TaffyZero for f64 {
+    const ZERO: f64 = 0.0;
+}
 impl<T: TaffyZero> TaffyZero for Option<T> {
     const ZERO: Option<T> = Some(T::ZERO);
 }
@@ -302,6 +306,10 @@ impl<T: TaffyMaxContent> Rect<T> {
 
 /// Returns a value of the inferred type which represent a `fit-content(…)` value
 /// with the given argument.
+///
+/// # Panics
+///
+/// Panics if the argument is a `calc()` value, as these are not supported by `fit-content(…)`.
 pub fn fit_content<T: TaffyFitContent>(argument: LengthPercentage) -> T {
     T::fit_content(argument)
 }
@@ -385,6 +393,18 @@ impl FromLength for Option<f32> {
         Some(value.into())
     }
 }
+#[cfg(feature = "f64")]
+impl FromLength for f64 {
+    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+        value.into().into()
+    }
+}
+#[cfg(feature = "f64")]
+impl FromLength for Option<f64> {
+    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+        Some(value.into().into())
+    }
+}
 impl<T: FromLength> FromLength for Point<T> {
     fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
         Point { x: T::from_length(value.into()), y: T::from_length(value.into()) }
@@ -521,6 +541,65 @@ pub trait FromFr {
     fn from_fr<Input: Into<f32> + Copy>(flex: Input) -> Self;
 }
 
+/// Returns a `calc()` value of the inferred type. The handle is treated as opaque and is passed back unchanged to
+/// the layout tree when the value is resolved (see [`CompactLength::calc`](crate::style::CompactLength::calc)).
+pub fn calc<T: FromCalc>(ptr: *const ()) -> T {
+    T::from_calc(ptr)
+}
+
+/// Trait to create `calc()` values from opaque handles
+pub trait FromCalc {
+    /// Converts an opaque `calc()` handle into Self
+    fn from_calc(ptr: *const ()) -> Self;
+}
+impl<T: FromCalc> FromCalc for Point<T> {
+    fn from_calc(ptr: *const ()) -> Self {
+        Point { x: T::from_calc(ptr), y: T::from_calc(ptr) }
+    }
+}
+impl<T: FromCalc> FromCalc for Line<T> {
+    fn from_calc(ptr: *const ()) -> Self {
+        Line { start: T::from_calc(ptr), end: T::from_calc(ptr) }
+    }
+}
+impl<T: FromCalc> FromCalc for Size<T> {
+    fn from_calc(ptr: *const ()) -> Self {
+        Size { width: T::from_calc(ptr), height: T::from_calc(ptr) }
+    }
+}
+impl<T: FromCalc> FromCalc for Rect<T> {
+    fn from_calc(ptr: *const ()) -> Self {
+        Rect { left: T::from_calc(ptr), right: T::from_calc(ptr), top: T::from_calc(ptr), bottom: T::from_calc(ptr) }
+    }
+}
+
+#[cfg(test)]
+mod calc_fn_tests {
+    use super::calc;
+    use crate::geometry::Size;
+    use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto};
+
+    /// An arbitrary calc handle with the low 3 bits unset
+    const HANDLE: *const () = 0x1000 as *const ();
+
+    #[test]
+    fn test_calc_matches_calc_constructors() {
+        assert_eq!(calc::<LengthPercentage>(HANDLE), LengthPercentage::calc(HANDLE));
+        assert_eq!(calc::<LengthPercentageAuto>(HANDLE), LengthPercentageAuto::calc(HANDLE));
+        assert_eq!(calc::<Dimension>(HANDLE), Dimension::calc(HANDLE));
+        assert_eq!(
+            calc::<Size<Dimension>>(HANDLE),
+            Size { width: Dimension::calc(HANDLE), height: Dimension::calc(HANDLE) }
+        );
+    }
+
+    #[test]
+    fn test_calc_handle_round_trips() {
+        assert!(calc::<Dimension>(HANDLE).into_raw().is_calc());
+        assert_eq!(calc::<Dimension>(HANDLE).into_raw().calc_value(), HANDLE as u64);
+    }
+}
+
 #[cfg(feature = "grid")]
 #[cfg(test)]
 mod repeat_fn_tests {
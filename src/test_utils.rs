@@ -0,0 +1,243 @@
+//! Utilities for writing data-driven layout tests, in which a tree of nodes and their expected layouts are described
+//! in JSON rather than in Rust code
+//!
+//! A fixture is loaded with [`build_tree_from_json`], laid out with [`test_measure_function`], and checked with
+//! [`ExpectedLayouts::assert_matches`]:
+//!
+//! ```
+//! use taffy::test_utils::{build_tree_from_json, test_measure_function};
+//!
+//! let json = r#"{
+//!     "viewport": { "width": { "definite": 100.0 }, "height": "max_content" },
+//!     "root": {
+//!         "style": { "flex_wrap": "wrap" },
+//!         "layout": { "width": 100.0, "height": 20.0 },
+//!         "children": [
+//!             { "text": "HH\u200bHH", "layout": { "x": 0.0, "y": 0.0, "width": 40.0 } },
+//!             { "text": "HHHHHHH", "layout": { "x": 0.0, "y": 10.0, "width": 70.0 } }
+//!         ]
+//!     }
+//! }"#;
+//!
+//! let (mut taffy, root, expected) = build_tree_from_json(json);
+//! taffy.compute_layout_with_measure(root, expected.available_space, test_measure_function).unwrap();
+//! expected.assert_matches(&taffy);
+//! ```
+//!
+//! # Schema
+//!
+//! A fixture is an object with the following fields, of which only `root` is required:
+//!
+//! - `root`: the root node of the tree.
+//! - `viewport`: the [`AvailableSpace`] that the tree is laid out in, as a [`Size`] (`{ "width": ..., "height": ... }`).
+//!   Each axis is either `"min_content"`, `"max_content"` or `{ "definite": <number> }`. Defaults to max-content in
+//!   both axes.
+//! - `use_rounding`: whether the layout is rounded (see [`TaffyTree::enable_rounding`]). Defaults to `true`.
+//!
+//! Each node is an object with the following fields, all of which are optional:
+//!
+//! - `style`: the node's [`Style`], in its serde representation (see the [`style`](crate::style) module), in which
+//!   fields that are omitted take their default values. As in the HTML fixtures that the generated tests are created
+//!   from, nodes are flex containers by default.
+//! - `text`: text content for a leaf node, which is measured as if it were set in the Ahem test font, whose glyphs are
+//!   10px squares. Lines may only break at zero width spaces (`\u200b`).
+//! - `writing_mode`: the direction of the node's text, `"horizontal"` (the default) or `"vertical"`.
+//! - `layout`: the node's expected layout, as an object mapping the names of values of the layout to their expected
+//!   values. The names are those accepted by [`assert_layout!`](crate::assert_layout), such as `x`, `width` or
+//!   `padding_left`, and values that are omitted aren't checked.
+//! - `children`: the node's children.
+use std::collections::BTreeMap;
+
+use crate::geometry::{AbsoluteAxis, Scalar, Size};
+use crate::style::{AvailableSpace, Style};
+use crate::tree::{NodeId, PrintTree};
+use crate::util::assert_layout_matches;
+use crate::TaffyTree;
+
+/// The context of a node loaded by [`build_tree_from_json`]: the text content of a leaf, which is measured by
+/// [`test_measure_function`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestContext {
+    /// The text content of the node
+    pub text: String,
+    /// The direction of the text
+    pub writing_mode: WritingMode,
+}
+
+/// Whether text is horizontal or vertical
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WritingMode {
+    /// Horizontal text
+    #[default]
+    Horizontal,
+    /// Vertical text
+    Vertical,
+}
+
+/// The expected layouts of the nodes of a tree loaded by [`build_tree_from_json`], and the space that the tree should
+/// be laid out in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedLayouts {
+    /// The space that the tree is laid out in, from the fixture's `viewport`
+    pub available_space: Size<AvailableSpace>,
+    /// The names and expected values of the layout of each node that has any, with parents before their children
+    pub nodes: Vec<(NodeId, Vec<(String, Scalar)>)>,
+}
+
+impl ExpectedLayouts {
+    /// Asserts that the layout of each node matches its expected values exactly, as with
+    /// [`assert_layout!`](crate::assert_layout)
+    #[track_caller]
+    pub fn assert_matches(&self, tree: &impl PrintTree) {
+        for (node, values) in &self.nodes {
+            let values: Vec<(&str, Scalar)> = values.iter().map(|(name, value)| (name.as_str(), *value)).collect();
+            assert_layout_matches(tree, *node, 0.0, &values);
+        }
+    }
+}
+
+/// A fixture for a layout test. See the [module documentation](self) for its schema.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    /// The root node of the tree
+    root: FixtureNode,
+    /// The space that the tree is laid out in
+    #[serde(default = "max_content")]
+    viewport: Size<AvailableSpace>,
+    /// Whether the layout is rounded
+    #[serde(default = "enabled")]
+    use_rounding: bool,
+}
+
+/// A node within a [`Fixture`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureNode {
+    /// The style of the node
+    #[serde(default)]
+    style: Style,
+    /// The text content of a leaf node
+    #[serde(default)]
+    text: Option<String>,
+    /// The direction of the node's text
+    #[serde(default)]
+    writing_mode: WritingMode,
+    /// The expected values of the node's layout
+    #[serde(default)]
+    layout: BTreeMap<String, Scalar>,
+    /// The children of the node
+    #[serde(default)]
+    children: Vec<FixtureNode>,
+}
+
+/// The default viewport of a [`Fixture`]
+fn max_content() -> Size<AvailableSpace> {
+    Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent }
+}
+
+/// The default value of [`Fixture::use_rounding`]
+fn enabled() -> bool {
+    true
+}
+
+/// Builds a tree from a JSON fixture, and returns it along with its root node and the expected layouts of its nodes.
+/// See the [module documentation](self) for the fixture's schema.
+///
+/// Leaf nodes with text content have a [`TestContext`], and the tree should be laid out with [`test_measure_function`].
+///
+/// # Panics
+///
+/// Panics if `json` isn't a valid fixture.
+pub fn build_tree_from_json(json: &str) -> (TaffyTree<TestContext>, NodeId, ExpectedLayouts) {
+    let fixture: Fixture = match serde_json::from_str(json) {
+        Ok(fixture) => fixture,
+        Err(error) => panic!("invalid layout test fixture: {error}"),
+    };
+
+    let mut taffy = TaffyTree::new();
+    if !fixture.use_rounding {
+        taffy.disable_rounding();
+    }
+    let mut expected = ExpectedLayouts { available_space: fixture.viewport, nodes: Vec::new() };
+    let root = build_node(&mut taffy, fixture.root, &mut expected);
+    (taffy, root, expected)
+}
+
+/// Adds a node from a fixture and its descendants to the tree, recording their expected layouts
+fn build_node(taffy: &mut TaffyTree<TestContext>, node: FixtureNode, expected: &mut ExpectedLayouts) -> NodeId {
+    let FixtureNode { style, text, writing_mode, layout, children } = node;
+    let node_id = match text {
+        Some(text) => taffy.new_leaf_with_context(style, TestContext { text, writing_mode }).unwrap(),
+        None => taffy.new_leaf(style).unwrap(),
+    };
+    if !layout.is_empty() {
+        expected.nodes.push((node_id, layout.into_iter().collect()));
+    }
+
+    let children: Vec<NodeId> = children.into_iter().map(|child| build_node(taffy, child, expected)).collect();
+    taffy.set_children(node_id, &children).unwrap();
+    node_id
+}
+
+/// A measure function for trees loaded by [`build_tree_from_json`], which measures the text content of each leaf as
+/// if it were set in the Ahem test font (whose glyphs are 10px squares), with line breaks only allowed at zero width
+/// spaces. Leaves without text content are zero-sized.
+pub fn test_measure_function(
+    known_dimensions: Size<Option<Scalar>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    context: Option<&mut TestContext>,
+    _style: &Style,
+) -> Size<Scalar> {
+    /// The zero width space, at which lines may break
+    const ZWS: char = '\u{200B}';
+    /// The size of each glyph of the Ahem font
+    const GLYPH_SIZE: Scalar = 10.0;
+
+    if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+        return Size { width, height };
+    }
+    let Some(context) = context else { return known_dimensions.map(|dimension| dimension.unwrap_or(0.0)) };
+
+    let inline_axis = match context.writing_mode {
+        WritingMode::Horizontal => AbsoluteAxis::Horizontal,
+        WritingMode::Vertical => AbsoluteAxis::Vertical,
+    };
+    let block_axis = inline_axis.other_axis();
+    let words: Vec<usize> = context.text.split(ZWS).map(|word| word.chars().count()).collect();
+
+    let min_line_length = words.iter().copied().max().unwrap_or(0);
+    let max_line_length: usize = words.iter().sum();
+    let inline_size = known_dimensions
+        .get_abs(inline_axis)
+        .unwrap_or_else(|| match available_space.get_abs(inline_axis) {
+            AvailableSpace::MinContent => min_line_length as Scalar * GLYPH_SIZE,
+            AvailableSpace::MaxContent => max_line_length as Scalar * GLYPH_SIZE,
+            AvailableSpace::Definite(inline_size) => inline_size.min(max_line_length as Scalar * GLYPH_SIZE),
+        })
+        .max(min_line_length as Scalar * GLYPH_SIZE);
+    let block_size = known_dimensions.get_abs(block_axis).unwrap_or_else(|| {
+        // Break lines greedily, starting a new line when a word doesn't fit on the current one
+        let line_length = (inline_size / GLYPH_SIZE).floor() as usize;
+        let mut line_count = 1;
+        let mut current_line_length = 0;
+        for &word in &words {
+            if current_line_length + word > line_length {
+                if current_line_length > 0 {
+                    line_count += 1;
+                }
+                current_line_length = word;
+            } else {
+                current_line_length += word;
+            }
+        }
+        line_count as Scalar * GLYPH_SIZE
+    });
+
+    match context.writing_mode {
+        WritingMode::Horizontal => Size { width: inline_size, height: block_size },
+        WritingMode::Vertical => Size { width: block_size, height: inline_size },
+    }
+}
@@ -1,146 +1,351 @@
 //! A cache for storing the results of layout computation
-use crate::geometry::Size;
+use crate::geometry::{AbsoluteAxis, Line, Scalar, Size};
 use crate::style::AvailableSpace;
-use crate::tree::{LayoutOutput, RunMode};
+use crate::tree::{LayoutInput, LayoutOutput, MeasureOutput, RequestedAxis, RunMode, SizingMode};
+use core::cell::Cell;
 
-/// The number of cache entries for each node in the tree
-const CACHE_SIZE: usize = 9;
+/// The default number of measurement cache entries for each node in the tree
+pub const DEFAULT_CACHE_CAPACITY: usize = 9;
+
+/// The bits of a [`Scalar`]
+#[cfg(not(feature = "f64"))]
+type ScalarBits = u32;
+/// The bits of a [`Scalar`]
+#[cfg(feature = "f64")]
+type ScalarBits = u64;
+
+/// The bit pattern of a [`PackedOptionScalar`] representing `None`: a NaN with a payload that arithmetic never produces
+#[cfg(not(feature = "f64"))]
+const NONE_BITS: ScalarBits = 0x7FC0_0001;
+/// The bit pattern of a [`PackedAvailableSpace`] representing [`AvailableSpace::MinContent`]
+#[cfg(not(feature = "f64"))]
+const MIN_CONTENT_BITS: ScalarBits = 0x7FC0_0002;
+/// The bit pattern of a [`PackedAvailableSpace`] representing [`AvailableSpace::MaxContent`]
+#[cfg(not(feature = "f64"))]
+const MAX_CONTENT_BITS: ScalarBits = 0x7FC0_0003;
+/// The bit pattern of a [`PackedOptionScalar`] representing `None`: a NaN with a payload that arithmetic never produces
+#[cfg(feature = "f64")]
+const NONE_BITS: ScalarBits = 0x7FF8_0000_0000_0001;
+/// The bit pattern of a [`PackedAvailableSpace`] representing [`AvailableSpace::MinContent`]
+#[cfg(feature = "f64")]
+const MIN_CONTENT_BITS: ScalarBits = 0x7FF8_0000_0000_0002;
+/// The bit pattern of a [`PackedAvailableSpace`] representing [`AvailableSpace::MaxContent`]
+#[cfg(feature = "f64")]
+const MAX_CONTENT_BITS: ScalarBits = 0x7FF8_0000_0000_0003;
+
+/// An `Option<Scalar>` packed into the bits of a `Scalar`, halving the size of each cache key
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PackedOptionScalar(ScalarBits);
+
+impl PackedOptionScalar {
+    /// Pack an `Option<Scalar>`
+    #[inline(always)]
+    fn pack(value: Option<Scalar>) -> Self {
+        Self(value.map_or(NONE_BITS, Scalar::to_bits))
+    }
+
+    /// Unpack the `Option<Scalar>`
+    #[inline(always)]
+    fn unpack(self) -> Option<Scalar> {
+        (self.0 != NONE_BITS).then(|| Scalar::from_bits(self.0))
+    }
+}
+
+/// An [`AvailableSpace`] packed into the bits of a `Scalar`, halving the size of each cache key
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PackedAvailableSpace(ScalarBits);
+
+impl PackedAvailableSpace {
+    /// Pack an [`AvailableSpace`]
+    #[inline(always)]
+    fn pack(value: AvailableSpace) -> Self {
+        Self(match value {
+            AvailableSpace::Definite(value) => value.to_bits(),
+            AvailableSpace::MinContent => MIN_CONTENT_BITS,
+            AvailableSpace::MaxContent => MAX_CONTENT_BITS,
+        })
+    }
+
+    /// Unpack the [`AvailableSpace`]
+    #[inline(always)]
+    fn unpack(self) -> AvailableSpace {
+        match self.0 {
+            MIN_CONTENT_BITS => AvailableSpace::MinContent,
+            MAX_CONTENT_BITS => AvailableSpace::MaxContent,
+            bits => AvailableSpace::Definite(Scalar::from_bits(bits)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PackedOptionScalar {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.unpack().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PackedAvailableSpace {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.unpack().serialize(serializer)
+    }
+}
 
 /// Cached intermediate layout results
+///
+/// The inputs are stored packed (rather than as `Option<Scalar>` and [`AvailableSpace`]) as every node stores
+/// [`DEFAULT_CACHE_CAPACITY`] entries, so their size dominates the memory used by each node.
+///
+/// Of the other [`LayoutInput`]s, the sizing mode changes whether a node's own size styles are applied, and the
+/// collapsibility of vertical margins changes the size of block containers, so results are only reused when these
+/// match. The parent size isn't part of the key, as it only differs between calls with the same available space when
+/// a node is measured for a min-content contribution, which ignores percentage sizes, but it is retained so that the
+/// result can be recomputed (see [`Cache::cached_results`]). The requested axis isn't part of the key either, as
+/// results always contain both axes.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub(crate) struct CacheEntry<T> {
     /// The initial cached size of the node itself
-    known_dimensions: Size<Option<f32>>,
+    known_dimensions: Size<PackedOptionScalar>,
     /// The initial cached size of the parent's node
-    available_space: Size<AvailableSpace>,
+    available_space: Size<PackedAvailableSpace>,
+    /// The size of the parent node that percentages were resolved against
+    parent_size: Size<PackedOptionScalar>,
+    /// Whether the node's size styles were applied
+    sizing_mode: SizingMode,
+    /// Whether the node's vertical margins could collapse with those of its children
+    vertical_margins_are_collapsible: Line<bool>,
     /// The cached size and baselines of the item
     content: T,
 }
 
+impl<T> CacheEntry<T> {
+    /// Create a cache entry for the result of laying out a node with the given inputs
+    #[inline(always)]
+    fn new(inputs: &LayoutInput, content: T) -> Self {
+        Self {
+            known_dimensions: inputs.known_dimensions.map(PackedOptionScalar::pack),
+            available_space: inputs.available_space.map(PackedAvailableSpace::pack),
+            parent_size: inputs.parent_size.map(PackedOptionScalar::pack),
+            sizing_mode: inputs.sizing_mode,
+            vertical_margins_are_collapsible: inputs.vertical_margins_are_collapsible,
+            content,
+        }
+    }
+
+    /// The inputs that this entry's result was computed from. The requested axis isn't retained, so both are requested.
+    #[inline(always)]
+    fn inputs(&self, run_mode: RunMode) -> LayoutInput {
+        LayoutInput {
+            run_mode,
+            sizing_mode: self.sizing_mode,
+            axis: RequestedAxis::Both,
+            known_dimensions: self.known_dimensions.map(PackedOptionScalar::unpack),
+            parent_size: self.parent_size.map(PackedOptionScalar::unpack),
+            available_space: self.available_space.map(PackedAvailableSpace::unpack),
+            vertical_margins_are_collapsible: self.vertical_margins_are_collapsible,
+        }
+    }
+
+    /// Whether this entry's result can be reused for the given inputs, where `cached_size` is the size of the node
+    /// that this entry's result contains
+    #[inline(always)]
+    fn matches(&self, inputs: &LayoutInput, cached_size: Size<Scalar>) -> bool {
+        let LayoutInput { known_dimensions, available_space, .. } = *inputs;
+        inputs.sizing_mode == self.sizing_mode
+            && inputs.vertical_margins_are_collapsible == self.vertical_margins_are_collapsible
+            && (known_dimensions.width == self.known_dimensions.width.unpack()
+                || known_dimensions.width == Some(cached_size.width))
+            && (known_dimensions.height == self.known_dimensions.height.unpack()
+                || known_dimensions.height == Some(cached_size.height))
+            && (known_dimensions.width.is_some()
+                || self.available_space.width.unpack().is_roughly_equal(available_space.width))
+            && (known_dimensions.height.is_some()
+                || self.available_space.height.unpack().is_roughly_equal(available_space.height))
+    }
+}
+
+/// A cached measurement of a leaf node's min-content or max-content size
+///
+/// A leaf's intrinsic size in an axis depends only on the constraints in the other axis, so only those are part of the
+/// key. This allows the measurement to be reused as different ancestors size themselves under different constraints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+struct IntrinsicSizeEntry {
+    /// The axis in which the intrinsic size was measured
+    axis: AbsoluteAxis,
+    /// Whether the measurement was made while performing the node's layout (rather than while only computing its
+    /// size, when measure functions may estimate the size more cheaply)
+    is_full_layout: bool,
+    /// The known size of the node in the other axis
+    cross_size: PackedOptionScalar,
+    /// The available space in the other axis, if the intrinsic size was measured in the vertical axis
+    cross_available_space: PackedAvailableSpace,
+    /// The measured size of the node
+    size: Size<Scalar>,
+    /// The measured size of the node's content, if it overflows the node
+    content_size: Size<PackedOptionScalar>,
+    /// The measured first baseline of the node
+    first_baseline: PackedOptionScalar,
+    /// The measured last baseline of the node
+    last_baseline: PackedOptionScalar,
+}
+
+impl IntrinsicSizeEntry {
+    /// The measure function's result that this entry contains
+    #[inline(always)]
+    fn output(&self) -> MeasureOutput {
+        MeasureOutput {
+            size: self.size,
+            content_size: match self.content_size.map(PackedOptionScalar::unpack) {
+                Size { width: Some(width), height: Some(height) } => Some(Size { width, height }),
+                _ => None,
+            },
+            first_baseline: self.first_baseline.unpack(),
+            last_baseline: self.last_baseline.unpack(),
+        }
+    }
+}
+
+/// Which intrinsic size of a leaf node a measurement is of, along with the axis and the key that it's cached under
+#[derive(Clone, Copy)]
+enum IntrinsicSizeKey {
+    /// The node's min-content size in the given axis
+    MinContent(AbsoluteAxis, Option<Scalar>, AvailableSpace),
+    /// The node's max-content size in the given axis
+    MaxContent(AbsoluteAxis, Option<Scalar>, AvailableSpace),
+}
+
+impl IntrinsicSizeKey {
+    /// The key for measuring a leaf node with the given known dimensions and available space, if the measurement is
+    /// of the node's min-content or max-content size in an axis
+    fn new(known_dimensions: Size<Option<Scalar>>, available_space: Size<AvailableSpace>) -> Option<Self> {
+        let axis = if known_dimensions.width.is_none() && !available_space.width.is_definite() {
+            AbsoluteAxis::Horizontal
+        } else if known_dimensions.height.is_none() && !available_space.height.is_definite() {
+            AbsoluteAxis::Vertical
+        } else {
+            return None;
+        };
+        let cross_axis = axis.other_axis();
+        let cross_size = known_dimensions.get_abs(cross_axis);
+        // The min-content and max-content widths of a node are its widths when its height is indefinite, so they
+        // don't depend on the available height. Its min-content and max-content heights depend on the width that its
+        // content is laid out in (for example, the width that text wraps to), so they depend on the available width.
+        let cross_available_space = match axis {
+            AbsoluteAxis::Horizontal => AvailableSpace::MaxContent,
+            AbsoluteAxis::Vertical => available_space.width,
+        };
+        match available_space.get_abs(axis) {
+            AvailableSpace::MinContent => Some(Self::MinContent(axis, cross_size, cross_available_space)),
+            AvailableSpace::MaxContent => Some(Self::MaxContent(axis, cross_size, cross_available_space)),
+            AvailableSpace::Definite(_) => None,
+        }
+    }
+}
+
+/// Counts of cache operations performed during a layout pass
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups that returned a cached result
+    pub hits: u32,
+    /// The number of lookups that didn't find a cached result, causing the layout to be computed
+    pub misses: u32,
+    /// The number of computed results that were stored
+    pub stores: u32,
+    /// The number of stores that replaced a previously cached result to make room
+    pub evictions: u32,
+}
+
 /// A cache for caching the results of a sizing a Grid Item or Flexbox Item
+///
+/// `CAPACITY` is the number of preliminary size measurements that are cached for the node. When the cache is full,
+/// the least recently used measurement is evicted. Larger capacities avoid recomputation when a node is repeatedly
+/// measured under several different constraints (for example when the available space oscillates during an animation),
+/// while smaller capacities reduce memory usage.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct Cache {
+pub struct Cache<const CAPACITY: usize = DEFAULT_CACHE_CAPACITY> {
     /// The cache entry for the node's final layout
     final_layout_entry: Option<CacheEntry<LayoutOutput>>,
-    /// The cache entries for the node's preliminary size measurements
-    measure_entries: [Option<CacheEntry<Size<f32>>>; CACHE_SIZE],
+    /// The cache entries for the node's preliminary size measurements, ordered from most to least recently used.
+    ///
+    /// These are stored in a `Cell` so that lookups (which only have shared access to the cache) can update the order.
+    measure_entries: Cell<[Option<CacheEntry<Size<Scalar>>>; CAPACITY]>,
+    /// The measured min-content size of a leaf node
+    min_content_entry: Option<IntrinsicSizeEntry>,
+    /// The measured max-content size of a leaf node
+    max_content_entry: Option<IntrinsicSizeEntry>,
+    /// Whether a computed result has been evicted, replaced or not stored since the cache was last cleared
+    has_discarded_results: bool,
 }
 
-impl Default for Cache {
+#[cfg(feature = "serde")]
+impl<const CAPACITY: usize> serde::Serialize for Cache<CAPACITY> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Cache", 4)?;
+        state.serialize_field("final_layout_entry", &self.final_layout_entry)?;
+        state.serialize_field("measure_entries", self.measure_entries())?;
+        state.serialize_field("min_content_entry", &self.min_content_entry)?;
+        state.serialize_field("max_content_entry", &self.max_content_entry)?;
+        state.end()
+    }
+}
+
+impl<const CAPACITY: usize> Default for Cache<CAPACITY> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Cache {
+impl<const CAPACITY: usize> Cache<CAPACITY> {
     /// Create a new empty cache
     pub const fn new() -> Self {
-        Self { final_layout_entry: None, measure_entries: [None; CACHE_SIZE] }
-    }
-
-    /// Return the cache slot to cache the current computed result in
-    ///
-    /// ## Caching Strategy
-    ///
-    /// We need multiple cache slots, because a node's size is often queried by it's parent multiple times in the course of the layout
-    /// process, and we don't want later results to clobber earlier ones.
-    ///
-    /// The two variables that we care about when determining cache slot are:
-    ///
-    ///   - How many "known_dimensions" are set. In the worst case, a node may be called first with neither dimension known, then with one
-    ///     dimension known (either width of height - which doesn't matter for our purposes here), and then with both dimensions known.
-    ///   - Whether unknown dimensions are being sized under a min-content or a max-content available space constraint (definite available space
-    ///     shares a cache slot with max-content because a node will generally be sized under one or the other but not both).
-    ///
-    /// ## Cache slots:
-    ///
-    /// - Slot 0: Both known_dimensions were set
-    /// - Slots 1-4: 1 of 2 known_dimensions were set and:
-    ///   - Slot 1: width but not height known_dimension was set and the other dimension was either a MaxContent or Definite available space constraintraint
-    ///   - Slot 2: width but not height known_dimension was set and the other dimension was a MinContent constraint
-    ///   - Slot 3: height but not width known_dimension was set and the other dimension was either a MaxContent or Definite available space constraintable space constraint
-    ///   - Slot 4: height but not width known_dimension was set and the other dimension was a MinContent constraint
-    /// - Slots 5-8: Neither known_dimensions were set and:
-    ///   - Slot 5: x-axis available space is MaxContent or Definite and y-axis available space is MaxContent or Definite
-    ///   - Slot 6: x-axis available space is MaxContent or Definite and y-axis available space is MinContent
-    ///   - Slot 7: x-axis available space is MinContent and y-axis available space is MaxContent or Definite
-    ///   - Slot 8: x-axis available space is MinContent and y-axis available space is MinContent
-    #[inline]
-    fn compute_cache_slot(known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> usize {
-        use AvailableSpace::{Definite, MaxContent, MinContent};
-
-        let has_known_width = known_dimensions.width.is_some();
-        let has_known_height = known_dimensions.height.is_some();
-
-        // Slot 0: Both known_dimensions were set
-        if has_known_width && has_known_height {
-            return 0;
+        Self {
+            final_layout_entry: None,
+            measure_entries: Cell::new([None; CAPACITY]),
+            min_content_entry: None,
+            max_content_entry: None,
+            has_discarded_results: false,
         }
+    }
 
-        // Slot 1: width but not height known_dimension was set and the other dimension was either a MaxContent or Definite available space constraint
-        // Slot 2: width but not height known_dimension was set and the other dimension was a MinContent constraint
-        if has_known_width && !has_known_height {
-            return 1 + (available_space.height == MinContent) as usize;
-        }
+    /// The number of preliminary size measurements that can be cached before the least recently used is evicted
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
 
-        // Slot 3: height but not width known_dimension was set and the other dimension was either a MaxContent or Definite available space constraint
-        // Slot 4: height but not width known_dimension was set and the other dimension was a MinContent constraint
-        if has_known_height && !has_known_width {
-            return 3 + (available_space.width == MinContent) as usize;
-        }
+    /// The measurement cache entries, ordered from most to least recently used
+    #[inline]
+    fn measure_entries(&self) -> &[Cell<Option<CacheEntry<Size<Scalar>>>>] {
+        let entries: &Cell<[Option<CacheEntry<Size<Scalar>>>]> = &self.measure_entries;
+        entries.as_slice_of_cells()
+    }
 
-        // Slots 5-8: Neither known_dimensions were set and:
-        match (available_space.width, available_space.height) {
-            // Slot 5: x-axis available space is MaxContent or Definite and y-axis available space is MaxContent or Definite
-            (MaxContent | Definite(_), MaxContent | Definite(_)) => 5,
-            // Slot 6: x-axis available space is MaxContent or Definite and y-axis available space is MinContent
-            (MaxContent | Definite(_), MinContent) => 6,
-            // Slot 7: x-axis available space is MinContent and y-axis available space is MaxContent or Definite
-            (MinContent, MaxContent | Definite(_)) => 7,
-            // Slot 8: x-axis available space is MinContent and y-axis available space is MinContent
-            (MinContent, MinContent) => 8,
+    /// Moves the entry at `index` to the front of `entries`, shifting the entries before it back by one
+    #[inline]
+    fn move_to_front<T>(entries: &[Cell<T>], index: usize) {
+        for i in (1..=index).rev() {
+            entries[i].swap(&entries[i - 1]);
         }
     }
 
     /// Try to retrieve a cached result from the cache
     #[inline]
-    pub fn get(
-        &self,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-    ) -> Option<LayoutOutput> {
-        match run_mode {
-            RunMode::PerformLayout => self
-                .final_layout_entry
-                .filter(|entry| {
-                    let cached_size = entry.content.size;
-                    (known_dimensions.width == entry.known_dimensions.width
-                        || known_dimensions.width == Some(cached_size.width))
-                        && (known_dimensions.height == entry.known_dimensions.height
-                            || known_dimensions.height == Some(cached_size.height))
-                        && (known_dimensions.width.is_some()
-                            || entry.available_space.width.is_roughly_equal(available_space.width))
-                        && (known_dimensions.height.is_some()
-                            || entry.available_space.height.is_roughly_equal(available_space.height))
-                })
-                .map(|e| e.content),
+    pub fn get(&self, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        match inputs.run_mode {
+            RunMode::PerformLayout => {
+                self.final_layout_entry.filter(|entry| entry.matches(inputs, entry.content.size)).map(|e| e.content)
+            }
             RunMode::ComputeSize => {
-                for entry in self.measure_entries.iter().flatten() {
-                    let cached_size = entry.content;
-
-                    if (known_dimensions.width == entry.known_dimensions.width
-                        || known_dimensions.width == Some(cached_size.width))
-                        && (known_dimensions.height == entry.known_dimensions.height
-                            || known_dimensions.height == Some(cached_size.height))
-                        && (known_dimensions.width.is_some()
-                            || entry.available_space.width.is_roughly_equal(available_space.width))
-                        && (known_dimensions.height.is_some()
-                            || entry.available_space.height.is_roughly_equal(available_space.height))
-                    {
-                        return Some(LayoutOutput::from_outer_size(cached_size));
+                let entries = self.measure_entries();
+                for (index, entry) in entries.iter().enumerate() {
+                    // Entries are filled from the front, so the first empty entry marks the end of the cached results
+                    let Some(entry) = entry.get() else { break };
+                    if entry.matches(inputs, entry.content) {
+                        Self::move_to_front(entries, index);
+                        return Some(LayoutOutput::from_outer_size(entry.content));
                     }
                 }
 
@@ -151,34 +356,303 @@ impl Cache {
     }
 
     /// Store a computed size in the cache
-    pub fn store(
-        &mut self,
-        known_dimensions: Size<Option<f32>>,
+    pub fn store(&mut self, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        self.store_and_report_eviction(inputs, layout_output);
+    }
+
+    /// Store a computed size in the cache, returning whether a previously cached result was evicted to make room for it
+    pub(crate) fn store_and_report_eviction(&mut self, inputs: &LayoutInput, layout_output: LayoutOutput) -> bool {
+        let evicted = match inputs.run_mode {
+            RunMode::PerformLayout => self.final_layout_entry.replace(CacheEntry::new(inputs, layout_output)).is_some(),
+            RunMode::ComputeSize => {
+                // Evict the least recently used entry by overwriting the last entry, and then make the new entry the
+                // most recently used
+                let entries = self.measure_entries.get_mut();
+                let Some(last) = entries.last_mut() else {
+                    self.has_discarded_results = true;
+                    return false;
+                };
+                let evicted = last.replace(CacheEntry::new(inputs, layout_output.size));
+                entries.rotate_right(1);
+                evicted.is_some()
+            }
+            RunMode::PerformHiddenLayout => false,
+        };
+        self.has_discarded_results |= evicted;
+        evicted
+    }
+
+    /// Try to retrieve the cached result of a leaf node's measure function
+    ///
+    /// Only measurements of the node's min-content or max-content size in an axis (that is, where the size in that
+    /// axis is unknown and the available space in that axis is [`AvailableSpace::MinContent`] or
+    /// [`AvailableSpace::MaxContent`]) are cached. These are keyed only by the known size in the other axis, so a
+    /// measurement is reused regardless of the available space in the other axis. Measurements made under
+    /// [`RunMode::ComputeSize`] are not reused under [`RunMode::PerformLayout`].
+    #[inline]
+    pub fn get_intrinsic_size(
+        &self,
+        run_mode: RunMode,
+        known_dimensions: Size<Option<Scalar>>,
         available_space: Size<AvailableSpace>,
+    ) -> Option<MeasureOutput> {
+        let (entry, axis, cross_size, cross_available_space) =
+            match IntrinsicSizeKey::new(known_dimensions, available_space)? {
+                IntrinsicSizeKey::MinContent(axis, size, space) => (self.min_content_entry?, axis, size, space),
+                IntrinsicSizeKey::MaxContent(axis, size, space) => (self.max_content_entry?, axis, size, space),
+            };
+        let run_mode_matches = match run_mode {
+            RunMode::ComputeSize => true,
+            RunMode::PerformLayout => entry.is_full_layout,
+            RunMode::PerformHiddenLayout => false,
+        };
+        (run_mode_matches
+            && entry.axis == axis
+            && entry.cross_size.unpack() == cross_size
+            && entry.cross_available_space.unpack().is_roughly_equal(cross_available_space))
+        .then(|| entry.output())
+    }
+
+    /// Store the result of a leaf node's measure function in the cache, if it's a measurement of the node's
+    /// min-content or max-content size. See [`get_intrinsic_size`](Self::get_intrinsic_size).
+    #[inline]
+    pub fn store_intrinsic_size(
+        &mut self,
         run_mode: RunMode,
-        layout_output: LayoutOutput,
+        known_dimensions: Size<Option<Scalar>>,
+        available_space: Size<AvailableSpace>,
+        output: MeasureOutput,
     ) {
-        match run_mode {
-            RunMode::PerformLayout => {
-                self.final_layout_entry = Some(CacheEntry { known_dimensions, available_space, content: layout_output })
-            }
-            RunMode::ComputeSize => {
-                let cache_slot = Self::compute_cache_slot(known_dimensions, available_space);
-                self.measure_entries[cache_slot] =
-                    Some(CacheEntry { known_dimensions, available_space, content: layout_output.size });
-            }
-            RunMode::PerformHiddenLayout => {}
+        let (slot, axis, cross_size, cross_available_space) =
+            match IntrinsicSizeKey::new(known_dimensions, available_space) {
+                Some(IntrinsicSizeKey::MinContent(axis, size, space)) => {
+                    (&mut self.min_content_entry, axis, size, space)
+                }
+                Some(IntrinsicSizeKey::MaxContent(axis, size, space)) => {
+                    (&mut self.max_content_entry, axis, size, space)
+                }
+                None => return,
+            };
+        *slot = Some(IntrinsicSizeEntry {
+            axis,
+            is_full_layout: run_mode == RunMode::PerformLayout,
+            cross_size: PackedOptionScalar::pack(cross_size),
+            cross_available_space: PackedAvailableSpace::pack(cross_available_space),
+            size: output.size,
+            content_size: match output.content_size {
+                Some(content_size) => content_size.map(|size| PackedOptionScalar::pack(Some(size))),
+                None => Size { width: PackedOptionScalar::pack(None), height: PackedOptionScalar::pack(None) },
+            },
+            first_baseline: PackedOptionScalar::pack(output.first_baseline),
+            last_baseline: PackedOptionScalar::pack(output.last_baseline),
+        });
+    }
+
+    /// The inputs and results of the node's cached final layout and size measurements, or `None` if not every
+    /// result computed for the node since the cache was last cleared has been retained
+    ///
+    /// Only the size of the node is cached for its size measurements, so the other fields of their results are zero.
+    pub(crate) fn cached_results(&self) -> Option<impl Iterator<Item = (LayoutInput, LayoutOutput)> + '_> {
+        if self.has_discarded_results {
+            return None;
         }
+        let final_layout = self.final_layout_entry.map(|entry| (entry.inputs(RunMode::PerformLayout), entry.content));
+        let measurements = self.measure_entries().iter().map_while(|entry| {
+            let entry = entry.get()?;
+            Some((entry.inputs(RunMode::ComputeSize), LayoutOutput::from_outer_size(entry.content)))
+        });
+        Some(final_layout.into_iter().chain(measurements))
     }
 
     /// Clear all cache entries
     pub fn clear(&mut self) {
         self.final_layout_entry = None;
-        self.measure_entries = [None; CACHE_SIZE];
+        self.measure_entries = Cell::new([None; CAPACITY]);
+        self.min_content_entry = None;
+        self.max_content_entry = None;
+        self.has_discarded_results = false;
+    }
+
+    /// The number of cached results, including measurements of a leaf node's min-content and max-content sizes
+    pub fn len(&self) -> usize {
+        let measurements = self.measure_entries().iter().take_while(|entry| entry.get().is_some()).count();
+        let other_entries =
+            [self.final_layout_entry.is_some(), self.min_content_entry.is_some(), self.max_content_entry.is_some()];
+        measurements + other_entries.into_iter().filter(|&is_some| is_some).count()
     }
 
     /// Returns true if all cache entries are None, else false
     pub fn is_empty(&self) -> bool {
-        self.final_layout_entry.is_none() && !self.measure_entries.iter().any(|entry| entry.is_some())
+        self.final_layout_entry.is_none()
+            && self.measure_entries().iter().all(|entry| entry.get().is_none())
+            && self.min_content_entry.is_none()
+            && self.max_content_entry.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::geometry::Line;
+    use crate::geometry::{Scalar, Size};
+    use crate::style::AvailableSpace;
+    use crate::tree::{LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
+
+    /// A definite available width with an indefinite available height
+    fn available_width(width: Scalar) -> Size<AvailableSpace> {
+        Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent }
+    }
+
+    /// The inputs for measuring a node's size with the given known dimensions and available space
+    fn measure_inputs(known_dimensions: Size<Option<Scalar>>, available_space: Size<AvailableSpace>) -> LayoutInput {
+        LayoutInput {
+            run_mode: RunMode::ComputeSize,
+            sizing_mode: SizingMode::InherentSize,
+            axis: RequestedAxis::Both,
+            known_dimensions,
+            parent_size: Size::NONE,
+            available_space,
+            vertical_margins_are_collapsible: Line::FALSE,
+        }
+    }
+
+    /// Store a measurement of the given size under the given available width
+    fn store<const N: usize>(cache: &mut Cache<N>, width: Scalar, size: Scalar) {
+        let output = LayoutOutput::from_outer_size(Size { width: size, height: size });
+        cache.store(&measure_inputs(Size::NONE, available_width(width)), output);
+    }
+
+    /// Look up a measurement made under the given available width
+    fn get<const N: usize>(cache: &Cache<N>, width: Scalar) -> Option<Scalar> {
+        cache.get(&measure_inputs(Size::NONE, available_width(width))).map(|output| output.size.width)
+    }
+
+    #[test]
+    fn capacity() {
+        assert_eq!(Cache::<3>::new().capacity(), 3);
+        assert_eq!(Cache::<0>::new().capacity(), 0);
+        assert_eq!(Cache::<{ super::DEFAULT_CACHE_CAPACITY }>::new().capacity(), 9);
+    }
+
+    #[test]
+    fn alternating_available_space_does_not_evict() {
+        let mut cache = Cache::<3>::new();
+        for frame in 0..10 {
+            for width in [100.0, 200.0, 300.0] {
+                match get(&cache, width) {
+                    Some(size) => assert!(frame > 0 && size == width / 2.0),
+                    None => {
+                        assert_eq!(frame, 0, "evicted {width} on frame {frame}");
+                        store(&mut cache, width, width / 2.0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let mut cache = Cache::<2>::new();
+        store(&mut cache, 100.0, 1.0);
+        store(&mut cache, 200.0, 2.0);
+        // Using the first entry makes the second entry the least recently used
+        assert_eq!(get(&cache, 100.0), Some(1.0));
+        store(&mut cache, 300.0, 3.0);
+
+        assert_eq!(get(&cache, 100.0), Some(1.0));
+        assert_eq!(get(&cache, 200.0), None);
+        assert_eq!(get(&cache, 300.0), Some(3.0));
+    }
+
+    #[test]
+    fn zero_capacity_caches_only_the_final_layout() {
+        let mut cache = Cache::<0>::new();
+        store(&mut cache, 100.0, 1.0);
+        assert_eq!(get(&cache, 100.0), None);
+        assert!(cache.is_empty());
+        // The measurement was discarded, so the cache doesn't retain every result computed for the node
+        assert!(cache.cached_results().is_none());
+
+        let output = LayoutOutput::from_outer_size(Size { width: 1.0, height: 1.0 });
+        let inputs =
+            LayoutInput { run_mode: RunMode::PerformLayout, ..measure_inputs(Size::NONE, available_width(100.0)) };
+        cache.store(&inputs, output);
+        assert!(!cache.is_empty());
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.cached_results().map(Iterator::count), Some(0));
+    }
+
+    #[test]
+    fn packed_inputs_are_distinguished() {
+        let mut cache = Cache::<4>::new();
+        let inputs = [
+            measure_inputs(Size::NONE, Size { width: AvailableSpace::MinContent, height: AvailableSpace::MaxContent }),
+            measure_inputs(Size::NONE, Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MinContent }),
+            measure_inputs(Size { width: Some(0.0), height: None }, available_width(0.0)),
+            measure_inputs(Size::NONE, available_width(0.0)),
+        ];
+        for (index, inputs) in inputs.iter().enumerate() {
+            let output = LayoutOutput::from_outer_size(Size { width: 1.0, height: index as Scalar });
+            cache.store(inputs, output);
+        }
+        for (index, inputs) in inputs.iter().enumerate() {
+            assert_eq!(cache.get(inputs).map(|output| output.size.height), Some(index as Scalar));
+        }
+    }
+
+    #[test]
+    fn sizing_mode_and_margin_collapsibility_are_part_of_the_key() {
+        let mut cache = Cache::<4>::new();
+        let inherent_size = measure_inputs(Size::NONE, available_width(100.0));
+        let content_size = LayoutInput { sizing_mode: SizingMode::ContentSize, ..inherent_size };
+        let collapsible = LayoutInput { vertical_margins_are_collapsible: Line::TRUE, ..inherent_size };
+
+        cache.store(&content_size, LayoutOutput::from_outer_size(Size { width: 1.0, height: 1.0 }));
+        assert_eq!(cache.get(&inherent_size), None);
+        assert_eq!(cache.get(&collapsible), None);
+        assert!(cache.get(&content_size).is_some());
+    }
+
+    #[test]
+    fn intrinsic_sizes_are_keyed_by_cross_axis_constraints() {
+        use crate::tree::MeasureOutput;
+        use AvailableSpace::{Definite, MaxContent, MinContent};
+
+        let mut cache = Cache::<0>::new();
+        let output = MeasureOutput {
+            size: Size { width: 10.0, height: 20.0 },
+            content_size: Some(Size { width: 30.0, height: 20.0 }),
+            first_baseline: Some(5.0),
+            last_baseline: None,
+        };
+        let space = |width, height| Size { width, height };
+
+        // A min-content width is reused regardless of the available height, but not for a different known height
+        cache.store_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(MinContent, MaxContent), output);
+        assert_eq!(
+            cache.get_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(MinContent, Definite(50.0))),
+            Some(output)
+        );
+        assert_eq!(cache.get_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(MaxContent, MaxContent)), None);
+        let known_height = Size { width: None, height: Some(20.0) };
+        assert_eq!(
+            cache.get_intrinsic_size(RunMode::ComputeSize, known_height, space(MinContent, Definite(20.0))),
+            None
+        );
+
+        // A max-content height depends on the available width
+        cache.store_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(Definite(100.0), MaxContent), output);
+        assert_eq!(
+            cache.get_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(Definite(100.0), MaxContent)),
+            Some(output)
+        );
+        assert_eq!(cache.get_intrinsic_size(RunMode::ComputeSize, Size::NONE, space(Definite(50.0), MaxContent)), None);
+
+        // Measurements made while only computing the node's size aren't reused when performing its layout
+        assert_eq!(cache.get_intrinsic_size(RunMode::PerformLayout, Size::NONE, space(MinContent, MaxContent)), None);
+        assert!(!cache.is_empty());
+        cache.clear();
+        assert!(cache.is_empty());
     }
 }
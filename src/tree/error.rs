@@ -0,0 +1,61 @@
+//! Contains [`TaffyError`](crate::tree::TaffyError): the error type for the operations of Taffy's built-in trees
+use crate::tree::NodeId;
+
+/// The error Taffy generates on invalid operations
+pub type TaffyResult<T> = Result<T, TaffyError>;
+
+/// An error that occurs while trying to access or modify a node's children by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaffyError {
+    /// The parent node does not have a child at `child_index`. It only has `child_count` children
+    ChildIndexOutOfBounds {
+        /// The parent node whose child was being looked up
+        parent: NodeId,
+        /// The index that was looked up
+        child_index: usize,
+        /// The total number of children the parent has
+        child_count: usize,
+    },
+    /// The parent node was not found in the tree.
+    InvalidParentNode(NodeId),
+    /// The child node was not found in the tree.
+    InvalidChildNode(NodeId),
+    /// The supplied node was not found in the tree.
+    InvalidInputNode(NodeId),
+    /// A node couldn't be added because the tree already has as many nodes as it has capacity for
+    NodeCapacityExceeded {
+        /// The maximum number of nodes in the tree
+        capacity: usize,
+    },
+    /// A child couldn't be added because the parent node already has as many children as it has capacity for
+    ChildCapacityExceeded {
+        /// The parent node that the child was being added to
+        parent: NodeId,
+        /// The maximum number of children of a node
+        capacity: usize,
+    },
+}
+
+impl core::fmt::Display for TaffyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count } => {
+                write!(f, "Index (is {child_index}) should be < child_count ({child_count}) for parent node {parent:?}")
+            }
+            TaffyError::InvalidParentNode(parent) => {
+                write!(f, "Parent Node {parent:?} is not in the tree")
+            }
+            TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the tree"),
+            TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the tree"),
+            TaffyError::NodeCapacityExceeded { capacity } => {
+                write!(f, "The tree already contains its maximum of {capacity} nodes")
+            }
+            TaffyError::ChildCapacityExceeded { parent, capacity } => {
+                write!(f, "Parent Node {parent:?} already has its maximum of {capacity} children")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TaffyError {}
@@ -0,0 +1,648 @@
+//! Contains [`FixedTaffy`](crate::tree::FixedTaffy): a tree of fixed capacity that lays out its nodes without
+//! allocating, for use on targets without an allocator
+use arrayvec::ArrayVec;
+
+use crate::compute::{
+    compute_cached_layout, compute_flexbox_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout,
+    round_layout, LayoutScratch,
+};
+use crate::geometry::{Scalar, Size};
+use crate::style::{AvailableSpace, Display, Style};
+use crate::tree::{
+    Cache, Layout, LayoutFlexboxContainer, LayoutInput, LayoutOutput, LayoutPartialTree, MeasureOutput, NodeId,
+    PrintTree, RoundTree, RunMode, TaffyError, TaffyResult, TraversePartialTree, TraverseTree,
+};
+use crate::CacheTree;
+
+/// The data stored for each node of a [`FixedTaffy`]
+#[derive(Debug, Clone)]
+struct NodeData<NodeContext, const MAX_CHILDREN: usize> {
+    /// The node's style
+    style: Style,
+    /// The context data associated with the node, which is passed to the measure function
+    context: Option<NodeContext>,
+    /// The node's children
+    children: ArrayVec<NodeId, MAX_CHILDREN>,
+    /// The node's parent, if it has one
+    parent: Option<NodeId>,
+    /// The cached results of laying out the node
+    cache: Cache,
+    /// The unrounded result of laying out the node
+    unrounded_layout: Layout,
+    /// The result of laying out the node, after rounding if it is enabled
+    final_layout: Layout,
+}
+
+impl<NodeContext, const MAX_CHILDREN: usize> NodeData<NodeContext, MAX_CHILDREN> {
+    /// Creates the data for a new node without children
+    fn new(style: Style, context: Option<NodeContext>) -> Self {
+        Self {
+            style,
+            context,
+            children: ArrayVec::new(),
+            parent: None,
+            cache: Cache::new(),
+            unrounded_layout: Layout::new(),
+            final_layout: Layout::new(),
+        }
+    }
+}
+
+/// A tree of at most `MAX_NODES` nodes, each of which has at most `MAX_CHILDREN` children, whose nodes are stored
+/// inline in arrays rather than allocated. Node ids are the nodes' indices in the tree.
+///
+/// Methods that would exceed either capacity return [`TaffyError::NodeCapacityExceeded`] or
+/// [`TaffyError::ChildCapacityExceeded`], and methods that are passed a node that isn't in the tree return an error
+/// rather than panicking. Nodes can't be removed individually, so ids are never reused; [`clear`](Self::clear) removes
+/// every node.
+///
+/// Only leaf and Flexbox layout are supported. Every node with children is laid out as a Flexbox container, whatever
+/// its `display` style (other than `Display::None`), even if the Block or Grid layout algorithms are enabled. `calc()`
+/// values resolve to zero, and layout is rounded with the default [`RoundingOptions`](crate::RoundingOptions) unless
+/// rounding is disabled.
+///
+/// When Taffy is built without the `std` and `alloc` features, the layout algorithms use fixed-capacity buffers in
+/// place of `Vec`s, which limit a tree to 256 nodes and a node to 16 children, so `MAX_NODES` and `MAX_CHILDREN` can't
+/// exceed these limits: creating a `FixedTaffy` whose capacities do fails to compile. As the whole tree is stored
+/// inline, a large `FixedTaffy` is best kept in a `static` rather than on the stack.
+///
+/// ```
+/// use taffy::prelude::*;
+/// use taffy::FixedTaffy;
+///
+/// let mut tree: FixedTaffy<8, 4> = FixedTaffy::new();
+/// let child = tree.new_leaf(Style { flex_grow: 1.0, ..Style::DEFAULT }).unwrap();
+/// let root = tree.new_with_children(Style { size: Size::from_lengths(100.0, 50.0), ..Style::DEFAULT }, &[child]).unwrap();
+/// tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+/// assert_eq!(tree.layout(child).unwrap().size, Size { width: 100.0, height: 50.0 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedTaffy<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext = ()> {
+    /// The nodes of the tree, indexed by their ids
+    nodes: ArrayVec<NodeData<NodeContext, MAX_CHILDREN>, MAX_NODES>,
+    /// Whether layout is rounded to whole pixels
+    use_rounding: bool,
+    /// Scratch buffers borrowed by the layout algorithms, which are reused from one layout to the next
+    scratch: LayoutScratch,
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext> Default
+    for FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext> FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext> {
+    /// Fails to compile if the tree's capacities exceed those of the buffers that the layout algorithms use when there
+    /// is no allocator
+    const CAPACITIES_FIT_LAYOUT_BUFFERS: () = {
+        #[cfg(not(any(feature = "std", feature = "alloc")))]
+        {
+            assert!(MAX_NODES <= crate::util::sys::MAX_NODE_COUNT, "MAX_NODES can't exceed 256 without an allocator");
+            assert!(
+                MAX_CHILDREN <= crate::util::sys::MAX_CHILD_COUNT,
+                "MAX_CHILDREN can't exceed 16 without an allocator"
+            );
+        }
+    };
+
+    /// Creates a new, empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITIES_FIT_LAYOUT_BUFFERS;
+        Self { nodes: ArrayVec::new(), use_rounding: true, scratch: LayoutScratch::new() }
+    }
+
+    /// Enables rounding of layout values. Rounding is enabled by default.
+    pub fn enable_rounding(&mut self) {
+        self.use_rounding = true;
+    }
+
+    /// Disables rounding of layout values
+    pub fn disable_rounding(&mut self) {
+        self.use_rounding = false;
+    }
+
+    /// Returns the data of a node, or `error` if it isn't in the tree
+    fn node(&self, node: NodeId, error: fn(NodeId) -> TaffyError) -> TaffyResult<&NodeData<NodeContext, MAX_CHILDREN>> {
+        self.nodes.get(usize::from(node)).ok_or(error(node))
+    }
+
+    /// Returns the mutable data of a node, or `error` if it isn't in the tree
+    fn node_mut(
+        &mut self,
+        node: NodeId,
+        error: fn(NodeId) -> TaffyError,
+    ) -> TaffyResult<&mut NodeData<NodeContext, MAX_CHILDREN>> {
+        self.nodes.get_mut(usize::from(node)).ok_or(error(node))
+    }
+
+    /// Adds a node to the tree and returns its id, or an error if the tree is full
+    fn push(&mut self, data: NodeData<NodeContext, MAX_CHILDREN>) -> TaffyResult<NodeId> {
+        self.nodes.try_push(data).map_err(|_| TaffyError::NodeCapacityExceeded { capacity: MAX_NODES })?;
+        Ok(NodeId::from(self.nodes.len() - 1))
+    }
+
+    /// Creates and adds a new node without children, and returns its id
+    pub fn new_leaf(&mut self, style: Style) -> TaffyResult<NodeId> {
+        self.push(NodeData::new(style, None))
+    }
+
+    /// Creates and adds a new node without children that has context data, which is passed to the measure function
+    /// when the node is measured, and returns its id
+    pub fn new_leaf_with_context(&mut self, style: Style, context: NodeContext) -> TaffyResult<NodeId> {
+        self.push(NodeData::new(style, Some(context)))
+    }
+
+    /// Creates and adds a new node with the given children, and returns its id. The children are removed from their
+    /// previous parents.
+    pub fn new_with_children(&mut self, style: Style, children: &[NodeId]) -> TaffyResult<NodeId> {
+        for &child in children {
+            self.node(child, TaffyError::InvalidChildNode)?;
+        }
+        // Check the number of children before adding the node, so that a failed call leaves the tree unchanged
+        if children.len() > MAX_CHILDREN {
+            let parent = NodeId::from(self.nodes.len());
+            return Err(TaffyError::ChildCapacityExceeded { parent, capacity: MAX_CHILDREN });
+        }
+        let node = self.push(NodeData::new(style, None))?;
+        self.set_children(node, children)?;
+        Ok(node)
+    }
+
+    /// Removes every node from the tree
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Returns the number of nodes in the tree
+    pub fn total_node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Appends `child` to the children of `parent`, removing it from its previous parent
+    pub fn add_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        let parent_data = self.node(parent, TaffyError::InvalidParentNode)?;
+        if parent_data.children.is_full() && self.parent(child) != Some(parent) {
+            return Err(TaffyError::ChildCapacityExceeded { parent, capacity: MAX_CHILDREN });
+        }
+        self.detach(child)?;
+        self.nodes[usize::from(child)].parent = Some(parent);
+        self.nodes[usize::from(parent)].children.push(child);
+        self.mark_dirty(parent)
+    }
+
+    /// Replaces the children of `parent`, removing each of them from its previous parent
+    pub fn set_children(&mut self, parent: NodeId, children: &[NodeId]) -> TaffyResult<()> {
+        self.node(parent, TaffyError::InvalidParentNode)?;
+        for &child in children {
+            self.node(child, TaffyError::InvalidChildNode)?;
+        }
+        if children.len() > MAX_CHILDREN {
+            return Err(TaffyError::ChildCapacityExceeded { parent, capacity: MAX_CHILDREN });
+        }
+        for child in core::mem::take(&mut self.nodes[usize::from(parent)].children) {
+            self.nodes[usize::from(child)].parent = None;
+        }
+        for &child in children {
+            self.detach(child)?;
+            self.nodes[usize::from(child)].parent = Some(parent);
+        }
+        self.nodes[usize::from(parent)].children = children.iter().copied().collect();
+        self.mark_dirty(parent)
+    }
+
+    /// Removes `child` from the children of its parent, if it has one
+    fn detach(&mut self, child: NodeId) -> TaffyResult<()> {
+        if let Some(parent) = self.node_mut(child, TaffyError::InvalidChildNode)?.parent.take() {
+            self.nodes[usize::from(parent)].children.retain(|&mut sibling| sibling != child);
+            self.mark_dirty(parent)?;
+        }
+        Ok(())
+    }
+
+    /// Removes `child` from the children of `parent`, without removing it from the tree
+    pub fn remove_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        self.node(parent, TaffyError::InvalidParentNode)?;
+        if self.node(child, TaffyError::InvalidChildNode)?.parent == Some(parent) {
+            self.detach(child)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the children of a node
+    pub fn children(&self, parent: NodeId) -> TaffyResult<&[NodeId]> {
+        Ok(&self.node(parent, TaffyError::InvalidParentNode)?.children)
+    }
+
+    /// Returns the parent of a node, if it has one
+    pub fn parent(&self, child: NodeId) -> Option<NodeId> {
+        self.nodes.get(usize::from(child)).and_then(|child| child.parent)
+    }
+
+    /// Returns the style of a node
+    pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
+        Ok(&self.node(node, TaffyError::InvalidInputNode)?.style)
+    }
+
+    /// Sets the style of a node, and marks it dirty
+    pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
+        self.node_mut(node, TaffyError::InvalidInputNode)?.style = style;
+        self.mark_dirty(node)
+    }
+
+    /// Returns the context data of a node, if it has any
+    pub fn get_node_context(&self, node: NodeId) -> Option<&NodeContext> {
+        self.nodes.get(usize::from(node)).and_then(|node| node.context.as_ref())
+    }
+
+    /// Returns the mutable context data of a node, if it has any
+    pub fn get_node_context_mut(&mut self, node: NodeId) -> Option<&mut NodeContext> {
+        self.nodes.get_mut(usize::from(node)).and_then(|node| node.context.as_mut())
+    }
+
+    /// Sets or removes the context data of a node, and marks it dirty
+    pub fn set_node_context(&mut self, node: NodeId, context: Option<NodeContext>) -> TaffyResult<()> {
+        self.node_mut(node, TaffyError::InvalidInputNode)?.context = context;
+        self.mark_dirty(node)
+    }
+
+    /// Marks the layout of a node and its ancestors as outdated, so that they are laid out again by the next call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    pub fn mark_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.node(node, TaffyError::InvalidInputNode)?;
+        let mut next = Some(node);
+        while let Some(node) = next {
+            let data = &mut self.nodes[usize::from(node)];
+            data.cache.clear();
+            next = data.parent;
+        }
+        Ok(())
+    }
+
+    /// Returns the layout of a node computed by the last call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure), which is rounded if rounding is enabled
+    pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        let node = self.node(node, TaffyError::InvalidInputNode)?;
+        Ok(if self.use_rounding { &node.final_layout } else { &node.unrounded_layout })
+    }
+
+    /// Returns the unrounded layout of a node computed by the last call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    pub fn unrounded_layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        Ok(&self.node(node, TaffyError::InvalidInputNode)?.unrounded_layout)
+    }
+
+    /// Computes the layout of the subtree rooted at `node` in the given available space, calling `measure_function`
+    /// to measure each leaf node
+    pub fn compute_layout_with_measure<MeasureFunction, MeasureResult>(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<()>
+    where
+        MeasureFunction: FnMut(
+            Size<Option<Scalar>>,
+            Size<AvailableSpace>,
+            NodeId,
+            Option<&mut NodeContext>,
+            &Style,
+        ) -> MeasureResult,
+        MeasureResult: Into<MeasureOutput>,
+    {
+        self.node(node, TaffyError::InvalidInputNode)?;
+        let use_rounding = self.use_rounding;
+        let mut view = FixedTaffyView { tree: self, measure_function };
+        compute_root_layout(&mut view, node, available_space);
+        if use_rounding {
+            round_layout(&mut view, node);
+        }
+        Ok(())
+    }
+
+    /// Computes the layout of the subtree rooted at `node` in the given available space, measuring every leaf node
+    /// as zero-sized
+    pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<()> {
+        self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Prints a debug representation of the tree's layout
+    #[cfg(feature = "std")]
+    pub fn print_tree(&mut self, root: NodeId) {
+        crate::util::print_tree(self, root)
+    }
+}
+
+/// Iterator over the ids of the children of a node of a [`FixedTaffy`]
+pub struct FixedTaffyChildIter<'a>(core::slice::Iter<'a, NodeId>);
+
+impl Iterator for FixedTaffyChildIter<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().copied()
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext> TraversePartialTree
+    for FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext>
+{
+    type ChildIter<'a>
+        = FixedTaffyChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        FixedTaffyChildIter(self.nodes[usize::from(parent_node_id)].children.iter())
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.nodes[usize::from(parent_node_id)].children.len()
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.nodes[usize::from(parent_node_id)].children[child_index]
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext> TraverseTree
+    for FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext>
+{
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext> PrintTree
+    for FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext>
+{
+    fn get_debug_label(&self, node_id: NodeId) -> &'static str {
+        let node = &self.nodes[usize::from(node_id)];
+        match (node.children.len(), node.style.display) {
+            (_, Display::None) => "NONE",
+            (0, _) => "LEAF",
+            _ => "FLEX",
+        }
+    }
+
+    #[inline(always)]
+    fn get_final_layout(&self, node_id: NodeId) -> &Layout {
+        let node = &self.nodes[usize::from(node_id)];
+        if self.use_rounding {
+            &node.final_layout
+        } else {
+            &node.unrounded_layout
+        }
+    }
+
+    #[inline(always)]
+    fn get_debug_style(&self, node_id: NodeId) -> Option<&Style> {
+        Some(&self.nodes[usize::from(node_id)].style)
+    }
+
+    #[inline(always)]
+    fn get_debug_cache(&self, node_id: NodeId) -> Option<&Cache> {
+        Some(&self.nodes[usize::from(node_id)].cache)
+    }
+}
+
+/// A [`FixedTaffy`] together with the measure function that its leaves are measured with during a layout, which
+/// implements the layout traits
+struct FixedTaffyView<'t, const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction> {
+    /// The tree being laid out
+    tree: &'t mut FixedTaffy<MAX_NODES, MAX_CHILDREN, NodeContext>,
+    /// The function that measures leaf nodes
+    measure_function: MeasureFunction,
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction>
+    FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+{
+    /// Returns the data of a node that the layout algorithms were passed
+    #[inline(always)]
+    fn node(&self, node_id: NodeId) -> &NodeData<NodeContext, MAX_CHILDREN> {
+        &self.tree.nodes[usize::from(node_id)]
+    }
+
+    /// Returns the mutable data of a node that the layout algorithms were passed
+    #[inline(always)]
+    fn node_mut(&mut self, node_id: NodeId) -> &mut NodeData<NodeContext, MAX_CHILDREN> {
+        &mut self.tree.nodes[usize::from(node_id)]
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction> TraversePartialTree
+    for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+{
+    type ChildIter<'a>
+        = FixedTaffyChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.tree.child_ids(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.tree.child_count(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.tree.get_child_id(parent_node_id, child_index)
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction> TraverseTree
+    for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+{
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction> CacheTree
+    for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+{
+    #[inline(always)]
+    fn cache_get(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        self.node(node_id).cache.get(inputs)
+    }
+
+    #[inline(always)]
+    fn cache_store(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        self.node_mut(node_id).cache.store(inputs, layout_output)
+    }
+
+    #[inline(always)]
+    fn cache_clear(&mut self, node_id: NodeId) {
+        self.node_mut(node_id).cache.clear()
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction, MeasureResult> LayoutPartialTree
+    for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type CoreContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn resolve_calc_value(&self, _val: u64, _basis: Scalar) -> Scalar {
+        0.0
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_mut(node_id).unrounded_layout = *layout;
+    }
+
+    fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        // A `Display::None` ancestor hides the node, whatever its own display style
+        if inputs.run_mode == RunMode::PerformHiddenLayout {
+            return compute_hidden_layout(self, node_id);
+        }
+
+        compute_cached_layout(self, node_id, inputs, |view, node_id, inputs| {
+            let node = view.node(node_id);
+            match (node.style.display, !node.children.is_empty()) {
+                (Display::None, _) => compute_hidden_layout(view, node_id),
+                (_, true) => compute_flexbox_layout(view, node_id, inputs),
+                (_, false) => {
+                    let NodeData { style, context, .. } = &mut view.tree.nodes[usize::from(node_id)];
+                    let style = &*style;
+                    let measure_function = &mut view.measure_function;
+                    compute_leaf_layout(
+                        inputs,
+                        style,
+                        |_val, _basis| 0.0,
+                        |known_dimensions, available_space| {
+                            measure_function(known_dimensions, available_space, node_id, context.as_mut(), style)
+                        },
+                    )
+                }
+            }
+        })
+    }
+
+    #[inline(always)]
+    fn scratch(&mut self) -> Option<&mut LayoutScratch> {
+        Some(&mut self.tree.scratch)
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction, MeasureResult>
+    LayoutFlexboxContainer for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type FlexboxContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type FlexboxItemStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_flexbox_container_style(&self, node_id: NodeId) -> Self::FlexboxContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_> {
+        &self.node(child_node_id).style
+    }
+}
+
+impl<const MAX_NODES: usize, const MAX_CHILDREN: usize, NodeContext, MeasureFunction> RoundTree
+    for FixedTaffyView<'_, MAX_NODES, MAX_CHILDREN, NodeContext, MeasureFunction>
+{
+    #[inline(always)]
+    fn get_unrounded_layout(&self, node_id: NodeId) -> &Layout {
+        &self.node(node_id).unrounded_layout
+    }
+
+    #[inline(always)]
+    fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_mut(node_id).final_layout = *layout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedTaffy;
+    use crate::prelude::*;
+    use crate::tree::TaffyError;
+    use crate::Point;
+
+    #[test]
+    fn fixed_taffy_lays_out_a_flex_row() {
+        let mut tree: FixedTaffy<4, 2, Size<Scalar>> = FixedTaffy::new();
+        let text = tree.new_leaf_with_context(Style { flex_grow: 1.0, ..Style::DEFAULT }, Size::length(30.0)).unwrap();
+        let fixed = tree.new_leaf(Style { size: Size::from_lengths(50.0, 20.0), ..Style::DEFAULT }).unwrap();
+        let root = tree
+            .new_with_children(Style { size: Size::from_lengths(200.0, 40.0), ..Style::DEFAULT }, &[fixed, text])
+            .unwrap();
+
+        let measure = |known: Size<Option<Scalar>>, _, _, context: Option<&mut Size<Scalar>>, _: &Style| {
+            known.unwrap_or(context.copied().unwrap_or(Size::ZERO))
+        };
+        tree.compute_layout_with_measure(root, Size::MAX_CONTENT, measure).unwrap();
+        assert_eq!(tree.layout(fixed).unwrap().size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(tree.layout(text).unwrap().location.x, 50.0);
+        assert_eq!(tree.layout(text).unwrap().size, Size { width: 150.0, height: 40.0 });
+    }
+
+    #[test]
+    fn fixed_taffy_rejects_nodes_beyond_its_capacity() {
+        let mut tree: FixedTaffy<3, 1> = FixedTaffy::new();
+        let first = tree.new_leaf(Style::DEFAULT).unwrap();
+        let second = tree.new_leaf(Style::DEFAULT).unwrap();
+
+        let parent = NodeId::from(2_usize);
+        assert_eq!(
+            tree.new_with_children(Style::DEFAULT, &[first, second]),
+            Err(TaffyError::ChildCapacityExceeded { parent, capacity: 1 })
+        );
+        assert_eq!(tree.total_node_count(), 2);
+
+        let parent = tree.new_with_children(Style::DEFAULT, &[first]).unwrap();
+        assert_eq!(tree.add_child(parent, second), Err(TaffyError::ChildCapacityExceeded { parent, capacity: 1 }));
+        assert_eq!(tree.new_leaf(Style::DEFAULT), Err(TaffyError::NodeCapacityExceeded { capacity: 3 }));
+
+        // Moving a child to a parent that has room for it is allowed
+        tree.set_children(parent, &[second]).unwrap();
+        assert_eq!(tree.children(parent).unwrap(), &[second]);
+        assert_eq!(tree.parent(first), None);
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn fixed_taffy_lays_out_other_containers_as_flexbox() {
+        let mut tree: FixedTaffy<4, 2> = FixedTaffy::new();
+        let first = tree.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Style::DEFAULT }).unwrap();
+        let second = tree.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Style::DEFAULT }).unwrap();
+        let root =
+            tree.new_with_children(Style { display: Display::Block, ..Style::DEFAULT }, &[first, second]).unwrap();
+
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+    }
+}
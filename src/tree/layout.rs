@@ -1,8 +1,8 @@
 //! Final data structures that represent the high-level UI layout
-use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Scalar, Size};
 use crate::style::AvailableSpace;
 use crate::style_helpers::TaffyMaxContent;
-use crate::util::sys::{f32_max, f32_min};
+use crate::util::sys::{scalar_max, scalar_min};
 
 /// Whether we are performing a full layout, or we merely need to size the node
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -28,13 +28,16 @@ pub enum SizingMode {
 }
 
 /// A set of margins that are available for collapsing with for block layout's margin collapsing
+///
+/// Only the largest positive margin and the smallest negative margin in the set are tracked, as these are all that is
+/// needed to resolve the collapsed margin: <https://www.w3.org/TR/CSS21/box.html#collapsing-margins>
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CollapsibleMarginSet {
     /// The largest positive margin
-    positive: f32,
+    positive: Scalar,
     /// The smallest negative margin (with largest absolute value)
-    negative: f32,
+    negative: Scalar,
 }
 
 impl CollapsibleMarginSet {
@@ -42,7 +45,7 @@ impl CollapsibleMarginSet {
     pub const ZERO: Self = Self { positive: 0.0, negative: 0.0 };
 
     /// Create a set from a single margin
-    pub fn from_margin(margin: f32) -> Self {
+    pub fn from_margin(margin: Scalar) -> Self {
         if margin >= 0.0 {
             Self { positive: margin, negative: 0.0 }
         } else {
@@ -51,25 +54,26 @@ impl CollapsibleMarginSet {
     }
 
     /// Collapse a single margin with this set
-    pub fn collapse_with_margin(mut self, margin: f32) -> Self {
+    pub fn collapse_with_margin(mut self, margin: Scalar) -> Self {
         if margin >= 0.0 {
-            self.positive = f32_max(self.positive, margin);
+            self.positive = scalar_max(self.positive, margin);
         } else {
-            self.negative = f32_min(self.negative, margin);
+            self.negative = scalar_min(self.negative, margin);
         }
         self
     }
 
     /// Collapse another margin set with this set
     pub fn collapse_with_set(mut self, other: CollapsibleMarginSet) -> Self {
-        self.positive = f32_max(self.positive, other.positive);
-        self.negative = f32_min(self.negative, other.negative);
+        self.positive = scalar_max(self.positive, other.positive);
+        self.negative = scalar_min(self.negative, other.negative);
         self
     }
 
     /// Resolve the resultant margin from this set once all collapsible margins
-    /// have been collapsed into it
-    pub fn resolve(&self) -> f32 {
+    /// have been collapsed into it. This is the sum of the largest positive margin and the smallest
+    /// negative margin (either of which is zero if the set contains no margins of that sign).
+    pub fn resolve(&self) -> Scalar {
         self.positive + self.negative
     }
 }
@@ -125,9 +129,9 @@ pub struct LayoutInput {
     ///
     ///   "The exact size of this node is WIDTHxHEIGHT. Please lay out your children"
     ///
-    pub known_dimensions: Size<Option<f32>>,
+    pub known_dimensions: Size<Option<Scalar>>,
     /// Parent size dimensions are intended to be used for percentage resolution.
-    pub parent_size: Size<Option<f32>>,
+    pub parent_size: Size<Option<Scalar>>,
     /// Available space represents an amount of space to layout into, and is used as a soft constraint
     /// for the purpose of wrapping.
     pub available_space: Size<AvailableSpace>,
@@ -155,17 +159,20 @@ impl LayoutInput {
 /// A baseline is the line on which text sits. Your node likely has a baseline if it is a text node, or contains
 /// children that may be text nodes. See <https://www.w3.org/TR/css-writing-modes-3/#intro-baselines> for details.
 /// If your node does not have a baseline (or you are unsure how to compute it), then simply return `Point::NONE`
-/// for the first_baselines field
+/// for the first_baselines and last_baselines fields
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LayoutOutput {
     /// The size of the node
-    pub size: Size<f32>,
+    pub size: Size<Scalar>,
     #[cfg(feature = "content_size")]
     /// The size of the content within the node
-    pub content_size: Size<f32>,
+    pub content_size: Size<Scalar>,
     /// The first baseline of the node in each dimension, if any
-    pub first_baselines: Point<Option<f32>>,
+    pub first_baselines: Point<Option<Scalar>>,
+    /// The last baseline of the node in each dimension, if any. For nodes with a single line of content this is
+    /// the same as the first baseline.
+    pub last_baselines: Point<Option<Scalar>>,
     /// Top margin that can be collapsed with. This is used for CSS block layout and can be set to
     /// `CollapsibleMarginSet::ZERO` for other layout modes that don't support margin collapsing
     pub top_margin: CollapsibleMarginSet,
@@ -184,6 +191,7 @@ impl LayoutOutput {
         #[cfg(feature = "content_size")]
         content_size: Size::ZERO,
         first_baselines: Point::NONE,
+        last_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: false,
@@ -192,17 +200,19 @@ impl LayoutOutput {
     /// A blank layout output
     pub const DEFAULT: Self = Self::HIDDEN;
 
-    /// Constructor to create a `LayoutOutput` from just the size and baselines
+    /// Constructor to create a `LayoutOutput` from just the size and baselines. The last baselines are set to the
+    /// same value as the first baselines.
     pub fn from_sizes_and_baselines(
-        size: Size<f32>,
-        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] content_size: Size<f32>,
-        first_baselines: Point<Option<f32>>,
+        size: Size<Scalar>,
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] content_size: Size<Scalar>,
+        first_baselines: Point<Option<Scalar>>,
     ) -> Self {
         Self {
             size,
             #[cfg(feature = "content_size")]
             content_size,
             first_baselines,
+            last_baselines: first_baselines,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: false,
@@ -210,19 +220,50 @@ impl LayoutOutput {
     }
 
     /// Construct a SizeBaselinesAndMargins from just the container and content sizes
-    pub fn from_sizes(size: Size<f32>, content_size: Size<f32>) -> Self {
+    pub fn from_sizes(size: Size<Scalar>, content_size: Size<Scalar>) -> Self {
         Self::from_sizes_and_baselines(size, content_size, Point::NONE)
     }
 
     /// Construct a SizeBaselinesAndMargins from just the container's size.
-    pub fn from_outer_size(size: Size<f32>) -> Self {
+    pub fn from_outer_size(size: Size<Scalar>) -> Self {
         Self::from_sizes(size, Size::zero())
     }
 }
 
-/// The final result of a layout algorithm for a single node.
+/// The result of measuring the content of a leaf node (such as a text or image node) with a measure function
+///
+/// Measure functions that only compute a size can return a plain `Size<Scalar>`, which converts into a `MeasureOutput`
+/// without baselines. Text nodes should also return the positions of their first and last baselines so that they
+/// can take part in baseline alignment. Baselines are measured from the top edge of the node's content box.
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MeasureOutput {
+    /// The size of the node's content
+    pub size: Size<Scalar>,
+    /// The size of the node's content if it overflows `size`, for example when a word that can't be broken is wider than
+    /// the node's known width. If `None`, the content is the same size as the node.
+    pub content_size: Option<Size<Scalar>>,
+    /// The position of the content's first baseline, if any
+    pub first_baseline: Option<Scalar>,
+    /// The position of the content's last baseline, if any. For content with a single line this is the same as the
+    /// first baseline.
+    pub last_baseline: Option<Scalar>,
+}
+
+impl MeasureOutput {
+    /// A zero-sized `MeasureOutput` without baselines
+    pub const ZERO: Self = Self { size: Size::ZERO, content_size: None, first_baseline: None, last_baseline: None };
+}
+
+impl From<Size<Scalar>> for MeasureOutput {
+    fn from(size: Size<Scalar>) -> Self {
+        Self { size, content_size: None, first_baseline: None, last_baseline: None }
+    }
+}
+
+/// The final result of a layout algorithm for a single node.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Layout {
     /// The relative ordering of the node
     ///
@@ -230,21 +271,35 @@ pub struct Layout {
     /// This is effectively a topological sort of each tree.
     pub order: u32,
     /// The top-left corner of the node
-    pub location: Point<f32>,
+    pub location: Point<Scalar>,
     /// The width and height of the node
-    pub size: Size<f32>,
+    pub size: Size<Scalar>,
     #[cfg(feature = "content_size")]
     /// The width and height of the content inside the node. This may be larger than the size of the node in the case of
     /// overflowing content and is useful for computing a "scroll width/height" for scrollable nodes
-    pub content_size: Size<f32>,
+    pub content_size: Size<Scalar>,
     /// The size of the scrollbars in each dimension. If there is no scrollbar then the size will be zero.
-    pub scrollbar_size: Size<f32>,
+    pub scrollbar_size: Size<Scalar>,
     /// The size of the borders of the node
-    pub border: Rect<f32>,
+    pub border: Rect<Scalar>,
     /// The size of the padding of the node
-    pub padding: Rect<f32>,
+    pub padding: Rect<Scalar>,
     /// The size of the margin of the node
-    pub margin: Rect<f32>,
+    ///
+    /// These are the margins used by the layout of the node's parent, with `auto` margins resolved to the space that
+    /// they absorbed. In block layout these are the node's own margins before collapsing with adjacent margins, so the
+    /// space between adjacent nodes may be less than the sum of their margins. The margins of the root node offset it
+    /// from the origin.
+    pub margin: Rect<Scalar>,
+    /// The position of the node's first baseline, measured down from the top of its border box, if it has one
+    ///
+    /// A leaf's baselines are those reported by its measure function. A flex or grid container's baseline is that of
+    /// its first line of items or first row, and a block container's is that of its first in-flow child or line of
+    /// inline content with a baseline.
+    pub first_baseline: Option<Scalar>,
+    /// The position of the node's last baseline, measured down from the top of its border box, if it has one. For
+    /// nodes with a single line of content, and for flex and grid containers, this is the same as the first baseline.
+    pub last_baseline: Option<Scalar>,
 }
 
 impl Default for Layout {
@@ -271,6 +326,8 @@ impl Layout {
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
+            first_baseline: None,
+            last_baseline: None,
         }
     }
 
@@ -290,56 +347,124 @@ impl Layout {
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
+            first_baseline: None,
+            last_baseline: None,
         }
     }
 
     /// Get the width of the node's content box
     #[inline]
-    pub fn content_box_width(&self) -> f32 {
+    pub fn content_box_width(&self) -> Scalar {
         self.size.width - self.padding.left - self.padding.right - self.border.left - self.border.right
     }
 
     /// Get the height of the node's content box
     #[inline]
-    pub fn content_box_height(&self) -> f32 {
+    pub fn content_box_height(&self) -> Scalar {
         self.size.height - self.padding.top - self.padding.bottom - self.border.top - self.border.bottom
     }
 
     /// Get the size of the node's content box
     #[inline]
-    pub fn content_box_size(&self) -> Size<f32> {
+    pub fn content_box_size(&self) -> Size<Scalar> {
         Size { width: self.content_box_width(), height: self.content_box_height() }
     }
 
     /// Get x offset of the node's content box relative to it's parent's border box
-    pub fn content_box_x(&self) -> f32 {
+    pub fn content_box_x(&self) -> Scalar {
         self.location.x + self.border.left + self.padding.left
     }
 
     /// Get x offset of the node's content box relative to it's parent's border box
-    pub fn content_box_y(&self) -> f32 {
+    pub fn content_box_y(&self) -> Scalar {
         self.location.y + self.border.top + self.padding.top
     }
+
+    /// Get the location of the node relative to its parent's content box rather than its border box, by subtracting the
+    /// border and padding recorded in the parent's layout
+    pub fn content_relative_location(&self, parent_layout: &Layout) -> Point<Scalar> {
+        Point {
+            x: self.location.x - parent_layout.border.left - parent_layout.padding.left,
+            y: self.location.y - parent_layout.border.top - parent_layout.padding.top,
+        }
+    }
+
+    /// Get the location and size of the node's border box, relative to it's parent's border box
+    #[inline]
+    pub fn bounds(&self) -> (Point<Scalar>, Size<Scalar>) {
+        (self.location, self.size)
+    }
+
+    /// Get the margin, border, padding and content boxes of the node, relative to it's parent's border box
+    pub fn box_model(&self) -> BoxModel {
+        let border_box = Rect {
+            left: self.location.x,
+            right: self.location.x + self.size.width,
+            top: self.location.y,
+            bottom: self.location.y + self.size.height,
+        };
+        let padding_box = Rect {
+            left: border_box.left + self.border.left,
+            right: border_box.right - self.border.right - self.scrollbar_size.width,
+            top: border_box.top + self.border.top,
+            bottom: border_box.bottom - self.border.bottom - self.scrollbar_size.height,
+        };
+        BoxModel {
+            margin_box: Rect {
+                left: border_box.left - self.margin.left,
+                right: border_box.right + self.margin.right,
+                top: border_box.top - self.margin.top,
+                bottom: border_box.bottom + self.margin.bottom,
+            },
+            border_box,
+            padding_box,
+            content_box: Rect {
+                left: padding_box.left + self.padding.left,
+                right: padding_box.right - self.padding.right,
+                top: padding_box.top + self.padding.top,
+                bottom: padding_box.bottom - self.padding.bottom,
+            },
+        }
+    }
+}
+
+/// The boxes of the [CSS box model](https://developer.mozilla.org/en-US/docs/Learn/CSS/Building_blocks/The_box_model)
+/// of a node, as computed by layout. See [`Layout::box_model`].
+///
+/// Each box is given by the coordinates of its edges relative to the parent's border box (the same coordinates as
+/// [`Layout::location`]). Each box is inset from the one before it by the margin, border, scrollbar gutter and padding
+/// that were resolved during layout, so percentage and `calc()` values are already accounted for.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BoxModel {
+    /// The margin box, which surrounds the border box with the node's margins
+    pub margin_box: Rect<Scalar>,
+    /// The border box, which is the box with the node's location and size
+    pub border_box: Rect<Scalar>,
+    /// The padding box, which lies within the node's borders and scrollbars
+    pub padding_box: Rect<Scalar>,
+    /// The content box, which lies within the node's padding
+    pub content_box: Rect<Scalar>,
 }
 
 #[cfg(feature = "content_size")]
 impl Layout {
     /// Return the scroll width of the node.
     /// The scroll width is the difference between the width and the content width, floored at zero
-    pub fn scroll_width(&self) -> f32 {
-        f32_max(
+    pub fn scroll_width(&self) -> Scalar {
+        scalar_max(
             0.0,
-            self.content_size.width + f32_min(self.scrollbar_size.width, self.size.width) - self.size.width
+            self.content_size.width + scalar_min(self.scrollbar_size.width, self.size.width) - self.size.width
                 + self.border.right,
         )
     }
 
     /// Return the scroll height of the node.
     /// The scroll height is the difference between the height and the content height, floored at zero
-    pub fn scroll_height(&self) -> f32 {
-        f32_max(
+    pub fn scroll_height(&self) -> Scalar {
+        scalar_max(
             0.0,
-            self.content_size.height + f32_min(self.scrollbar_size.height, self.size.height) - self.size.height
+            self.content_size.height + scalar_min(self.scrollbar_size.height, self.size.height) - self.size.height
                 + self.border.bottom,
         )
     }
@@ -1,16 +1,27 @@
 //! Contains both a high-level interface to Taffy using a ready-made node tree, and a set of traits for defining custom node trees.
 //!
 //! - For documentation on the high-level API, see the [`TaffyTree`] struct.
+//! - For a lighter-weight tree whose nodes are stored in a `Vec`, see the `VecTree` struct (with the `vec_tree` feature).
+//! - For a tree of fixed capacity that doesn't need an allocator, see the `FixedTaffy` struct (with the `fixed` feature).
 //! - For documentation on the low-level trait-based API, see the [`traits`] module.
 
 // Submodules
 mod cache;
+#[cfg(any(feature = "taffy_tree", feature = "vec_tree", feature = "fixed"))]
+mod error;
 mod layout;
 mod node;
 pub mod traits;
 
-pub use cache::Cache;
-pub use layout::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
+#[cfg(feature = "metrics")]
+pub use cache::CacheStats;
+pub use cache::{Cache, DEFAULT_CACHE_CAPACITY};
+#[cfg(any(feature = "taffy_tree", feature = "vec_tree", feature = "fixed"))]
+pub use error::{TaffyError, TaffyResult};
+pub use layout::{
+    BoxModel, CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, MeasureOutput, RequestedAxis, RunMode,
+    SizingMode,
+};
 pub use node::NodeId;
 pub(crate) use traits::LayoutPartialTreeExt;
 pub use traits::{LayoutPartialTree, PrintTree, RoundTree, TraversePartialTree, TraverseTree};
@@ -26,8 +37,22 @@ pub use traits::LayoutBlockContainer;
 
 #[cfg(feature = "taffy_tree")]
 mod taffy_tree;
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+pub use taffy_tree::NodeMeasureFunction;
 #[cfg(feature = "taffy_tree")]
-pub use taffy_tree::{TaffyError, TaffyResult, TaffyTree};
+pub use taffy_tree::{CalcResolver, MeasureArgs, NodeSizeReport, TaffyTree};
+#[cfg(all(feature = "taffy_tree", feature = "serde", any(feature = "std", feature = "alloc")))]
+pub use taffy_tree::{NodeSnapshot, TreeSnapshot};
+
+#[cfg(feature = "vec_tree")]
+mod vec_tree;
+#[cfg(feature = "vec_tree")]
+pub use vec_tree::{VecTree, VecTreeChildIter};
+
+#[cfg(feature = "fixed")]
+mod fixed_tree;
+#[cfg(feature = "fixed")]
+pub use fixed_tree::{FixedTaffy, FixedTaffyChildIter};
 
 #[cfg(feature = "detailed_layout_info")]
 pub use layout::DetailedLayoutInfo;
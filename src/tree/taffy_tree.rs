@@ -1,21 +1,28 @@
-//! Contains [TaffyTree](crate::tree::TaffyTree): the default implementation of [LayoutTree](crate::tree::LayoutTree), and the error type for Taffy.
+//! Contains [TaffyTree](crate::tree::TaffyTree): the default implementation of [LayoutTree](crate::tree::LayoutTree).
 #[cfg(not(feature = "std"))]
 use slotmap::SecondaryMap;
 #[cfg(feature = "std")]
 use slotmap::SparseSecondaryMap as SecondaryMap;
 use slotmap::{DefaultKey, SlotMap};
 
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+use crate::geometry::{Point, Scalar, Size};
+use crate::style::{AvailableSpace, Display, Position, Style};
+#[cfg(feature = "metrics")]
+use crate::tree::CacheStats;
 use crate::tree::{
-    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
-    TraversePartialTree, TraverseTree,
+    BoxModel, Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, MeasureOutput, NodeId, PrintTree, RoundTree,
+    RunMode, SizingMode, TaffyError, TaffyResult, TraversePartialTree, TraverseTree,
 };
 use crate::util::debug::{debug_log, debug_log_node};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::util::sys::Box;
 use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
+#[cfg(feature = "metrics")]
+use core::cell::Cell;
 
 use crate::compute::{
-    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout_with_options,
+    LayoutScratch, RoundingMode, RoundingOptions,
 };
 use crate::CacheTree;
 #[cfg(feature = "block_layout")]
@@ -30,60 +37,83 @@ use crate::compute::grid::DetailedGridInfo;
 #[cfg(feature = "detailed_layout_info")]
 use crate::tree::layout::DetailedLayoutInfo;
 
-/// The error Taffy generates on invalid operations
-pub type TaffyResult<T> = Result<T, TaffyError>;
-
-/// An error that occurs while trying to access or modify a node's children by index.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TaffyError {
-    /// The parent node does not have a child at `child_index`. It only has `child_count` children
-    ChildIndexOutOfBounds {
-        /// The parent node whose child was being looked up
-        parent: NodeId,
-        /// The index that was looked up
-        child_index: usize,
-        /// The total number of children the parent has
-        child_count: usize,
-    },
-    /// The parent node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
-    InvalidParentNode(NodeId),
-    /// The child node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
-    InvalidChildNode(NodeId),
-    /// The supplied node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
-    InvalidInputNode(NodeId),
-}
-
-impl core::fmt::Display for TaffyError {
-    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        match self {
-            TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count } => {
-                write!(f, "Index (is {child_index}) should be < child_count ({child_count}) for parent node {parent:?}")
-            }
-            TaffyError::InvalidParentNode(parent) => {
-                write!(f, "Parent Node {parent:?} is not in the TaffyTree instance")
-            }
-            TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the TaffyTree instance"),
-            TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the TaffyTree instance"),
-        }
-    }
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for TaffyError {}
-
 /// Global configuration values for a TaffyTree instance
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct TaffyConfig {
     /// Whether to round layout values
     pub(crate) use_rounding: bool,
+    /// How layout values are rounded, if they're rounded
+    pub(crate) rounding: RoundingOptions,
+    /// The function used to resolve `calc()` values
+    pub(crate) calc_resolver: CalcResolver,
 }
 
 impl Default for TaffyConfig {
     fn default() -> Self {
-        Self { use_rounding: true }
+        Self { use_rounding: true, rounding: RoundingOptions::DEFAULT, calc_resolver: |_, _| 0.0 }
+    }
+}
+
+/// A function that resolves a `calc()` value to a length, given the handle that the value was created from (see
+/// [`CompactLength::calc`](crate::CompactLength::calc)) and the basis that percentages within it should be resolved
+/// against.
+///
+/// Lengths that depend on a resolution context, such as font-relative (`em`, `rem`) or viewport-relative (`vw`, `vh`)
+/// units, should be resolved by the application when the calc value is created, or be recoverable from the data
+/// that the handle points to.
+pub type CalcResolver = fn(*const (), Scalar) -> Scalar;
+
+/// A measure function attached to an individual leaf node with [`TaffyTree::set_node_measure`]
+///
+/// It is passed the known dimensions and available space of the node's content box, and returns the size of its
+/// content.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type NodeMeasureFunction = Box<dyn FnMut(Size<Option<Scalar>>, Size<AvailableSpace>) -> Size<Scalar> + Send>;
+
+/// The measure functions attached to individual nodes
+///
+/// Closures can't be cloned, so cloning a tree doesn't clone the measure functions attached to its nodes.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Default)]
+struct NodeMeasureFunctions(SecondaryMap<DefaultKey, NodeMeasureFunction>);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Clone for NodeMeasureFunctions {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl core::fmt::Debug for NodeMeasureFunctions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.0.keys().map(NodeId::from)).finish()
     }
 }
 
+/// The inputs passed to the measure function of [`TaffyTree::compute_layout_with_measure_ex`] when measuring a leaf node
+#[derive(Debug)]
+pub struct MeasureArgs<'a, NodeContext> {
+    /// The dimensions of the node's content box that are already known. Only the unknown dimensions need to be measured.
+    pub known_dimensions: Size<Option<Scalar>>,
+    /// The space available for the node's content box in each axis
+    pub available_space: Size<AvailableSpace>,
+    /// The node being measured
+    pub node_id: NodeId,
+    /// The context data associated with the node, if any
+    pub node_context: Option<&'a mut NodeContext>,
+    /// The node's style
+    pub style: &'a Style,
+    /// Whether the layout algorithm only needs the node's size ([`RunMode::ComputeSize`]) or is performing the final
+    /// layout of the node ([`RunMode::PerformLayout`]). Measure functions can use this to estimate the size cheaply when
+    /// only the size is needed.
+    pub run_mode: RunMode,
+    /// Whether the node's size styles were taken into account by the layout algorithm when computing `known_dimensions`
+    pub sizing_mode: SizingMode,
+    /// The size of the node's containing block, against which percentages are resolved
+    pub parent_size: Size<Option<Scalar>>,
+}
+
 /// Layout information for a given [`Node`](crate::node::Node)
 ///
 /// Stored in a [`TaffyTree`].
@@ -103,6 +133,12 @@ struct NodeData {
     /// Whether the node has context data associated with it or not
     pub(crate) has_context: bool,
 
+    /// Whether the unrounded layout of the node or any of its descendants has changed since the node was last rounded
+    pub(crate) needs_rounding: bool,
+
+    /// The absolute position of the node's parent when the node was last rounded
+    pub(crate) rounded_parent_position: Point<Scalar>,
+
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
 
@@ -121,6 +157,8 @@ impl NodeData {
             unrounded_layout: Layout::new(),
             final_layout: Layout::new(),
             has_context: false,
+            needs_rounding: true,
+            rounded_parent_position: Point::ZERO,
             #[cfg(feature = "detailed_layout_info")]
             detailed_layout_info: DetailedLayoutInfo::None,
         }
@@ -128,13 +166,61 @@ impl NodeData {
 
     /// Marks a node and all of its ancestors as requiring relayout
     ///
-    /// This clears any cached data and signals that the data must be recomputed.
+    /// This clears any cached data and signals that the data must be recomputed. The node is also rounded again
+    /// after the next layout, as its children may have been replaced by nodes that were rounded elsewhere.
     #[inline]
     pub fn mark_dirty(&mut self) {
-        self.cache.clear()
+        self.cache.clear();
+        self.needs_rounding = true;
     }
 }
 
+/// The sizes in bytes of the data stored for each node in a [`TaffyTree`]. See [`TaffyTree::node_size_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeSizeReport {
+    /// The size of the data stored inline for each node, which includes its style, cache and layouts but not its
+    /// context, its list of children or its parent
+    pub node_data: usize,
+    /// The size of a [`Style`]. The grid track lists are stored on the heap and their contents aren't included.
+    pub style: usize,
+    /// The size of a node's [`Cache`]
+    pub cache: usize,
+    /// The size of a [`Layout`]. Each node stores two: its unrounded and final layouts.
+    pub layout: usize,
+}
+
+/// A serializable copy of a tree of nodes, their styles and their layouts. See [`TaffyTree::serialize_tree`].
+///
+/// Nodes are identified by their index in [`nodes`](Self::nodes), which lists them in depth-first order starting with
+/// the root, so the ids in a snapshot of a given tree are stable across runs and across trees.
+#[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TreeSnapshot {
+    /// The nodes of the tree. The root node is the first.
+    pub nodes: Vec<NodeSnapshot>,
+}
+
+/// A single node within a [`TreeSnapshot`]
+#[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    /// The style of the node
+    #[serde(default)]
+    pub style: Style,
+    /// The ids of the node's children, which are their indices in [`TreeSnapshot::nodes`]
+    #[serde(default)]
+    pub children: Vec<usize>,
+    /// Whether the node is a leaf that is measured, because it has context data or a measure function attached
+    #[serde(default)]
+    pub needs_measure: bool,
+    /// The size that a measured leaf was last measured as, which it is measured as when the snapshot is restored
+    #[serde(default)]
+    pub measured_size: Option<Size<Scalar>>,
+    /// The final layout of the node when the snapshot was taken
+    #[serde(default)]
+    pub layout: Option<Layout>,
+}
+
 /// An entire tree of UI nodes. The entry point to Taffy's high-level API.
 ///
 /// Allows you to build a tree of UI nodes, run Taffy's layout algorithms over that tree, and then access the resultant layout.]
@@ -146,6 +232,15 @@ pub struct TaffyTree<NodeContext = ()> {
     /// Functions/closures that compute the intrinsic size of leaf nodes
     node_context_data: SecondaryMap<DefaultKey, NodeContext>,
 
+    /// The measure functions attached to individual leaf nodes, which take precedence over the measure function passed
+    /// to [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    node_measure_functions: NodeMeasureFunctions,
+
+    /// The caches of the nodes marked with [`mark_measure_dirty`](Self::mark_measure_dirty) since the last layout, which
+    /// the results of remeasuring them are compared against
+    measure_dirty_caches: SecondaryMap<DefaultKey, Cache>,
+
     /// The children of each node
     ///
     /// The indexes in the outer vector correspond to the position of the parent [`NodeData`]
@@ -158,6 +253,15 @@ pub struct TaffyTree<NodeContext = ()> {
 
     /// Layout mode configuration
     config: TaffyConfig,
+
+    /// Scratch buffers borrowed by the layout algorithms. Kept for the lifetime of the tree so that the buffers warmed
+    /// up by one layout are reused by the next.
+    scratch: LayoutScratch,
+
+    /// Counts of the cache operations performed by the most recent layout pass. Stored in a `Cell` because cache
+    /// lookups only have shared access to the tree.
+    #[cfg(feature = "metrics")]
+    cache_stats: Cell<CacheStats>,
 }
 
 impl Default for TaffyTree {
@@ -204,25 +308,12 @@ impl<NodeContext> TraverseTree for TaffyTree<NodeContext> {}
 
 // CacheTree impl for TaffyTree
 impl<NodeContext> CacheTree for TaffyTree<NodeContext> {
-    fn cache_get(
-        &self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-    ) -> Option<LayoutOutput> {
-        self.nodes[node_id.into()].cache.get(known_dimensions, available_space, run_mode)
+    fn cache_get(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        self.get_cached(node_id, inputs)
     }
 
-    fn cache_store(
-        &mut self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-        layout_output: LayoutOutput,
-    ) {
-        self.nodes[node_id.into()].cache.store(known_dimensions, available_space, run_mode, layout_output)
+    fn cache_store(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        self.store_cached(node_id, inputs, layout_output)
     }
 
     fn cache_clear(&mut self, node_id: NodeId) {
@@ -243,6 +334,8 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
             (0, _) => "LEAF",
             #[cfg(feature = "block_layout")]
             (_, Display::Block) => "BLOCK",
+            #[cfg(feature = "block_layout")]
+            (_, Display::FlowRoot) => "FLOW ROOT",
             #[cfg(feature = "flexbox")]
             (_, Display::Flex) => {
                 use crate::FlexDirection;
@@ -264,6 +357,69 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
             &self.nodes[node_id.into()].unrounded_layout
         }
     }
+
+    #[inline(always)]
+    fn get_debug_style(&self, node_id: NodeId) -> Option<&Style> {
+        Some(&self.nodes[node_id.into()].style)
+    }
+
+    #[inline(always)]
+    fn get_debug_cache(&self, node_id: NodeId) -> Option<&Cache> {
+        Some(&self.nodes[node_id.into()].cache)
+    }
+}
+
+/// A panic raised while laying out a node, which is caught and re-raised as the panic unwinds through the layout of each
+/// of the node's ancestors to record the chain of nodes that were being laid out
+#[cfg(feature = "std")]
+struct LayoutPanic {
+    /// The nodes that were being laid out, from the node that panicked up to the root
+    nodes: Vec<NodeId>,
+    /// The payload of the original panic
+    payload: Box<dyn core::any::Any + Send>,
+}
+
+/// Calls `layout`, which lays out `node`, appending `node` to the chain of nodes recorded by any panic it raises
+#[inline(always)]
+fn with_layout_panic_context<R>(node: NodeId, layout: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "std")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(layout)).unwrap_or_else(|payload| {
+            let mut panic = payload
+                .downcast::<LayoutPanic>()
+                .unwrap_or_else(|payload| Box::new(LayoutPanic { nodes: Vec::new(), payload }));
+            panic.nodes.push(node);
+            std::panic::resume_unwind(panic)
+        })
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = node;
+        layout()
+    }
+}
+
+/// Calls `layout`, which lays out a tree. If it panics while laying out a node, the panic is re-raised with a message
+/// that identifies the node and the chain of its ancestors that were being laid out.
+#[inline(always)]
+fn with_layout_panic_message<R>(layout: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "std")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(layout)).unwrap_or_else(|payload| {
+            let panic = match payload.downcast::<LayoutPanic>() {
+                Ok(panic) => panic,
+                Err(payload) => std::panic::resume_unwind(payload),
+            };
+            let message = match panic.payload.downcast_ref::<&str>() {
+                Some(message) => message,
+                None => panic.payload.downcast_ref::<String>().map_or("Box<dyn Any>", String::as_str),
+            };
+            let chain: Vec<String> = panic.nodes.iter().rev().map(|node| format!("{node:?}")).collect();
+            panic!("{message}\nwhile laying out {:?} (from the root: {})", panic.nodes[0], chain.join(" > "))
+        })
+    }
+    #[cfg(not(feature = "std"))]
+    layout()
 }
 
 /// View over the Taffy tree that holds the tree itself along with a reference to the context
@@ -271,8 +427,7 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 /// which makes the lifetimes of the context much more flexible.
 pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     /// A reference to the TaffyTree
     pub(crate) taffy: &'t mut TaffyTree<NodeContext>,
@@ -283,8 +438,7 @@ where
 // TraversePartialTree impl for TaffyView
 impl<NodeContext, MeasureFunction> TraversePartialTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     type ChildIter<'a>
         = TaffyTreeChildIter<'a>
@@ -309,16 +463,14 @@ where
 
 // TraverseTree impl for TaffyView
 impl<NodeContext, MeasureFunction> TraverseTree for TaffyView<'_, NodeContext, MeasureFunction> where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput
 {
 }
 
 // LayoutPartialTree impl for TaffyView
 impl<NodeContext, MeasureFunction> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     type CoreContainerStyle<'a>
         = &'a Style
@@ -332,90 +484,122 @@ where
 
     #[inline(always)]
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
-        self.taffy.nodes[node_id.into()].unrounded_layout = *layout;
+        let node = &mut self.taffy.nodes[node_id.into()];
+        if node.unrounded_layout != *layout || node.needs_rounding {
+            node.unrounded_layout = *layout;
+            self.taffy.mark_needs_rounding(node_id);
+        }
     }
 
     #[inline(always)]
-    fn resolve_calc_value(&self, _val: u64, _basis: f32) -> f32 {
-        0.0
+    fn resolve_calc_value(&self, val: u64, basis: Scalar) -> Scalar {
+        (self.taffy.config.calc_resolver)(val as *const (), basis)
     }
 
     #[inline(always)]
     fn compute_child_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
-        // If RunMode is PerformHiddenLayout then this indicates that an ancestor node is `Display::None`
-        // and thus that we should lay out this node using hidden layout regardless of it's own display style.
-        if inputs.run_mode == RunMode::PerformHiddenLayout {
-            debug_log!("HIDDEN");
-            return compute_hidden_layout(self, node);
-        }
-
-        // We run the following wrapped in "compute_cached_layout", which will check the cache for an entry matching the node and inputs and:
-        //   - Return that entry if exists
-        //   - Else call the passed closure (below) to compute the result
-        //
-        // If there was no cache match and a new result needs to be computed then that result will be added to the cache
-        compute_cached_layout(self, node, inputs, |tree, node, inputs| {
-            let display_mode = tree.taffy.nodes[node.into()].style.display;
-            let has_children = tree.child_count(node) > 0;
-
-            debug_log!(display_mode);
-            debug_log_node!(
-                inputs.known_dimensions,
-                inputs.parent_size,
-                inputs.available_space,
-                inputs.run_mode,
-                inputs.sizing_mode
-            );
+        // A panic while laying out the node (for example in a measure function) is re-raised with the node appended to
+        // the chain of nodes being laid out, so that the panic message identifies the node
+        with_layout_panic_context(node, || {
+            // If RunMode is PerformHiddenLayout then this indicates that an ancestor node is `Display::None`
+            // and thus that we should lay out this node using hidden layout regardless of it's own display style.
+            if inputs.run_mode == RunMode::PerformHiddenLayout {
+                debug_log!("HIDDEN");
+                return compute_hidden_layout(self, node);
+            }
 
-            // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
-            match (display_mode, has_children) {
-                (Display::None, _) => compute_hidden_layout(tree, node),
-                #[cfg(feature = "block_layout")]
-                (Display::Block, true) => compute_block_layout(tree, node, inputs),
-                #[cfg(feature = "flexbox")]
-                (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
-                #[cfg(feature = "grid")]
-                (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
-                (_, false) => {
-                    let node_key = node.into();
-                    let style = &tree.taffy.nodes[node_key].style;
-                    let has_context = tree.taffy.nodes[node_key].has_context;
-                    let node_context = has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
-                    let measure_function = |known_dimensions, available_space| {
-                        (tree.measure_function)(known_dimensions, available_space, node, node_context, style)
-                    };
-                    // TODO: implement calc() in high-level API
-                    compute_leaf_layout(inputs, style, |_, _| 0.0, measure_function)
+            // We run the following wrapped in "compute_cached_layout", which will check the cache for an entry matching the node and inputs and:
+            //   - Return that entry if exists
+            //   - Else call the passed closure (below) to compute the result
+            //
+            // If there was no cache match and a new result needs to be computed then that result will be added to the cache
+            compute_cached_layout(self, node, inputs, |tree, node, inputs| {
+                let display_mode = tree.taffy.nodes[node.into()].style.display;
+                let has_children = tree.child_count(node) > 0;
+
+                debug_log!(display_mode);
+                debug_log_node!(
+                    inputs.known_dimensions,
+                    inputs.parent_size,
+                    inputs.available_space,
+                    inputs.run_mode,
+                    inputs.sizing_mode
+                );
+
+                // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
+                match (display_mode, has_children) {
+                    (Display::None, _) => compute_hidden_layout(tree, node),
+                    #[cfg(feature = "block_layout")]
+                    (Display::Block | Display::FlowRoot, true) => compute_block_layout(tree, node, inputs),
+                    #[cfg(feature = "flexbox")]
+                    (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
+                    #[cfg(feature = "grid")]
+                    (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
+                    (_, false) => {
+                        let node_key = node.into();
+                        let calc_resolver = tree.taffy.config.calc_resolver;
+                        let NodeData { style, cache, has_context, .. } = &mut tree.taffy.nodes[node_key];
+                        let node_context =
+                            has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
+                        #[cfg(any(feature = "std", feature = "alloc"))]
+                        let node_measure_function = tree.taffy.node_measure_functions.0.get_mut(node_key);
+                        let measure_function = |known_dimensions, available_space| {
+                            // Min-content and max-content measurements are cached separately from the node's layout
+                            // results, so that they're reused under any available space in the other axis
+                            if let Some(output) =
+                                cache.get_intrinsic_size(inputs.run_mode, known_dimensions, available_space)
+                            {
+                                return output;
+                            }
+                            // A measure function attached to the node takes precedence over the tree's measure function
+                            #[cfg(any(feature = "std", feature = "alloc"))]
+                            let node_output = node_measure_function
+                                .map(|measure| MeasureOutput::from(measure(known_dimensions, available_space)));
+                            #[cfg(not(any(feature = "std", feature = "alloc")))]
+                            let node_output = None;
+                            let output = node_output.unwrap_or_else(|| {
+                                (tree.measure_function)(MeasureArgs {
+                                    known_dimensions,
+                                    available_space,
+                                    node_id: node,
+                                    node_context,
+                                    style,
+                                    run_mode: inputs.run_mode,
+                                    sizing_mode: inputs.sizing_mode,
+                                    parent_size: inputs.parent_size,
+                                })
+                            });
+                            cache.store_intrinsic_size(inputs.run_mode, known_dimensions, available_space, output);
+                            output
+                        };
+                        compute_leaf_layout(
+                            inputs,
+                            style,
+                            |val, basis| calc_resolver(val as *const (), basis),
+                            measure_function,
+                        )
+                    }
                 }
-            }
+            })
         })
     }
+
+    #[inline(always)]
+    fn scratch(&mut self) -> Option<&mut LayoutScratch> {
+        Some(&mut self.taffy.scratch)
+    }
 }
 
 impl<NodeContext, MeasureFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
-    fn cache_get(
-        &self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-    ) -> Option<LayoutOutput> {
-        self.taffy.nodes[node_id.into()].cache.get(known_dimensions, available_space, run_mode)
+    fn cache_get(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        self.taffy.get_cached(node_id, inputs)
     }
 
-    fn cache_store(
-        &mut self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-        layout_output: LayoutOutput,
-    ) {
-        self.taffy.nodes[node_id.into()].cache.store(known_dimensions, available_space, run_mode, layout_output)
+    fn cache_store(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        self.taffy.store_cached(node_id, inputs, layout_output)
     }
 
     fn cache_clear(&mut self, node_id: NodeId) {
@@ -426,8 +610,7 @@ where
 #[cfg(feature = "block_layout")]
 impl<NodeContext, MeasureFunction> LayoutBlockContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     type BlockContainerStyle<'a>
         = &'a Style
@@ -452,8 +635,7 @@ where
 #[cfg(feature = "flexbox")]
 impl<NodeContext, MeasureFunction> LayoutFlexboxContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     type FlexboxContainerStyle<'a>
         = &'a Style
@@ -478,8 +660,7 @@ where
 #[cfg(feature = "grid")]
 impl<NodeContext, MeasureFunction> LayoutGridContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     type GridContainerStyle<'a>
         = &'a Style
@@ -510,8 +691,7 @@ where
 // RoundTree impl for TaffyView
 impl<NodeContext, MeasureFunction> RoundTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureOutput,
 {
     #[inline(always)]
     fn get_unrounded_layout(&self, node: NodeId) -> &Layout {
@@ -522,6 +702,15 @@ where
     fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout) {
         self.taffy.nodes[node_id.into()].final_layout = *layout;
     }
+
+    #[inline(always)]
+    fn needs_rounding(&mut self, node_id: NodeId, parent_position: Point<Scalar>) -> bool {
+        let node = &mut self.taffy.nodes[node_id.into()];
+        let needs_rounding = node.needs_rounding || node.rounded_parent_position != parent_position;
+        node.needs_rounding = false;
+        node.rounded_parent_position = parent_position;
+        needs_rounding
+    }
 }
 
 #[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
@@ -544,7 +733,24 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            node_measure_functions: NodeMeasureFunctions::default(),
+            measure_dirty_caches: SecondaryMap::new(),
             config: TaffyConfig::default(),
+            scratch: LayoutScratch::new(),
+            #[cfg(feature = "metrics")]
+            cache_stats: Cell::new(CacheStats::default()),
+        }
+    }
+
+    /// Returns the sizes of the data stored for each node, for estimating the memory used by very large trees
+    #[must_use]
+    pub const fn node_size_report() -> NodeSizeReport {
+        NodeSizeReport {
+            node_data: core::mem::size_of::<NodeData>(),
+            style: core::mem::size_of::<Style>(),
+            cache: core::mem::size_of::<Cache>(),
+            layout: core::mem::size_of::<Layout>(),
         }
     }
 
@@ -558,6 +764,134 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.config.use_rounding = false;
     }
 
+    /// Enables rounding and rounds the stored layout of the provided `node` and its descendants, without recomputing
+    /// it. Use this to turn rounding back on, or to apply a new rounding scale or modes, after the layout has been
+    /// computed.
+    ///
+    /// Rounding reads only the unrounded layouts, so rounding again (or after [`unround_layout`](Self::unround_layout))
+    /// gives the same result as rounding once.
+    pub fn round_layout(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.expect_node(node, "round_layout");
+        self.config.use_rounding = true;
+        let rounding = self.config.rounding;
+        let mut taffy_view = TaffyView { taffy: self, measure_function: |_| MeasureOutput::ZERO };
+        round_layout_with_options(&mut taffy_view, node, rounding);
+        Ok(())
+    }
+
+    /// Disables rounding and replaces the stored layout of the provided `node` and its descendants with their
+    /// unrounded layouts, without recomputing them. See [`round_layout`](Self::round_layout).
+    pub fn unround_layout(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.expect_node(node, "unround_layout");
+        self.config.use_rounding = false;
+        let mut stack: Vec<NodeId> = Vec::new();
+        stack.push(node);
+        while let Some(node) = stack.pop() {
+            let node_key: DefaultKey = node.into();
+            let data = &mut self.nodes[node_key];
+            data.final_layout = data.unrounded_layout;
+            // The final layout no longer holds the rounded layout, so it must be rounded again
+            data.needs_rounding = true;
+            stack.extend(self.children[node_key].iter().copied());
+        }
+        Ok(())
+    }
+
+    /// Sets the number of device pixels per unit of layout (the device pixel ratio), so that rounded layouts are
+    /// snapped to whole device pixels rather than to whole units. The scale is `1.0` by default.
+    ///
+    /// Takes effect the next time the tree's layout is computed. See [`RoundingOptions::scale`].
+    pub fn set_rounding_scale(&mut self, scale: Scalar) {
+        self.set_rounding_options(RoundingOptions { scale, ..self.config.rounding });
+    }
+
+    /// Sets how the left and top edges (`positions`) and the right and bottom edges (`sizes`) of each box are rounded.
+    /// Both are [`RoundingMode::Nearest`] by default.
+    ///
+    /// Takes effect the next time the tree's layout is computed. See [`round_layout_with_options`].
+    pub fn set_rounding_modes(&mut self, positions: RoundingMode, sizes: RoundingMode) {
+        self.set_rounding_options(RoundingOptions { positions, sizes, ..self.config.rounding });
+    }
+
+    /// Sets the options used to round the tree's layout, marking every node as needing rounding if they changed
+    fn set_rounding_options(&mut self, options: RoundingOptions) {
+        if self.config.rounding != options {
+            self.config.rounding = options;
+            // Every node's rounded layout depends on the rounding options
+            for (_, node) in self.nodes.iter_mut() {
+                node.needs_rounding = true;
+            }
+        }
+    }
+
+    /// Sets the largest number of elements for which the layout algorithms retain their scratch buffers between
+    /// layouts. See [`LayoutScratch::set_capacity_limit`].
+    pub fn set_scratch_capacity_limit(&mut self, limit: usize) {
+        self.scratch.set_capacity_limit(limit);
+    }
+
+    /// Set the function used to resolve `calc()` values during layout. By default all `calc()` values resolve to zero.
+    pub fn set_calc_resolver(&mut self, calc_resolver: CalcResolver) {
+        self.config.calc_resolver = calc_resolver;
+    }
+
+    /// Returns counts of the cache operations performed by the most recent call to
+    /// [`compute_layout`](Self::compute_layout) or [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    #[cfg(feature = "metrics")]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache_stats.get()
+    }
+
+    /// Look up a cached layout result for a node, recording the outcome in the cache statistics
+    #[inline(always)]
+    fn get_cached(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        let result = self.nodes[node_id.into()].cache.get(inputs);
+        // Hidden layout is never cached, so it is neither a hit nor a miss
+        #[cfg(feature = "metrics")]
+        if inputs.run_mode != RunMode::PerformHiddenLayout {
+            let mut stats = self.cache_stats.get();
+            match result {
+                Some(_) => stats.hits += 1,
+                None => stats.misses += 1,
+            }
+            self.cache_stats.set(stats);
+        }
+        result
+    }
+
+    /// Store a layout result in a node's cache, recording the outcome in the cache statistics
+    #[inline(always)]
+    fn store_cached(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        let cache = &mut self.nodes[node_id.into()].cache;
+        #[cfg(feature = "metrics")]
+        if inputs.run_mode != RunMode::PerformHiddenLayout {
+            let evicted = cache.store_and_report_eviction(inputs, layout_output);
+            let stats = self.cache_stats.get_mut();
+            stats.stores += 1;
+            stats.evictions += u32::from(evicted);
+            return;
+        }
+        cache.store(inputs, layout_output)
+    }
+
+    /// Panics with a message naming `method` and `node` if `node` isn't in the tree
+    ///
+    /// Methods call this before indexing into the tree's storage, which would otherwise panic with a message that
+    /// doesn't identify either.
+    #[track_caller]
+    #[inline(always)]
+    fn expect_node(&self, node: NodeId, method: &str) {
+        if !self.nodes.contains_key(node.into()) {
+            panic!("TaffyTree::{method} was called with {node:?}, which is not in the tree");
+        }
+    }
+
+    /// Whether `node` is in the tree
+    #[cfg(any(feature = "ffi", feature = "wasm-bindgen"))]
+    pub(crate) fn contains(&self, node: NodeId) -> bool {
+        self.nodes.contains_key(node.into())
+    }
+
     /// Creates and adds a new unattached leaf node to the tree, and returns the node of the new node
     pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<NodeId> {
         let id = self.nodes.insert(NodeData::new(layout));
@@ -585,6 +919,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Creates and adds a new node, which may have any number of `children`
     pub fn new_with_children(&mut self, layout: Style, children: &[NodeId]) -> TaffyResult<NodeId> {
+        for &child in children {
+            self.expect_node(child, "new_with_children");
+        }
         let id = NodeId::from(self.nodes.insert(NodeData::new(layout)));
 
         for child in children {
@@ -602,12 +939,16 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        self.node_measure_functions.0.clear();
+        self.measure_dirty_caches.clear();
     }
 
     /// Remove a specific node from the tree and drop it
     ///
     /// Returns the id of the node removed.
     pub fn remove(&mut self, node: NodeId) -> TaffyResult<NodeId> {
+        self.expect_node(node, "remove");
         let key = node.into();
         if let Some(parent) = self.parents[key] {
             if let Some(children) = self.children.get_mut(parent.into()) {
@@ -625,12 +966,16 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
         let _ = self.nodes.remove(key);
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        let _ = self.node_measure_functions.0.remove(key);
+        let _ = self.measure_dirty_caches.remove(key);
 
         Ok(node)
     }
 
     /// Sets the context data associated with the node
     pub fn set_node_context(&mut self, node: NodeId, measure: Option<NodeContext>) -> TaffyResult<()> {
+        self.expect_node(node, "set_node_context");
         let key = node.into();
         if let Some(measure) = measure {
             self.nodes[key].has_context = true;
@@ -645,6 +990,30 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
+    /// Attaches a measure function to a leaf node, or detaches it if `measure` is `None`
+    ///
+    /// When a leaf node has a measure function attached, it is used to measure the node instead of the measure function
+    /// passed to [`compute_layout_with_measure`](Self::compute_layout_with_measure), which isn't called for that node.
+    /// This allows different kinds of leaf node to be measured by closures defined independently of each other. The
+    /// node's context data (if any) isn't passed to the attached measure function, and it is ignored while the node
+    /// has children.
+    ///
+    /// Closures can't be cloned, so cloning the tree doesn't clone the measure functions attached to its nodes.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn set_node_measure(&mut self, node: NodeId, measure: Option<NodeMeasureFunction>) -> TaffyResult<()> {
+        self.expect_node(node, "set_node_measure");
+        let key = node.into();
+        if let Some(measure) = measure {
+            self.node_measure_functions.0.insert(key, measure);
+        } else {
+            self.node_measure_functions.0.remove(key);
+        }
+
+        self.mark_dirty(node)?;
+
+        Ok(())
+    }
+
     /// Gets a reference to the the context data associated with the node
     pub fn get_node_context(&self, node: NodeId) -> Option<&NodeContext> {
         self.node_context_data.get(node.into())
@@ -665,6 +1034,8 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Adds a `child` node under the supplied `parent`
     pub fn add_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        self.expect_node(parent, "add_child");
+        self.expect_node(child, "add_child");
         let parent_key = parent.into();
         let child_key = child.into();
         self.parents[child_key] = Some(parent);
@@ -676,6 +1047,8 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Inserts a `child` node at the given `child_index` under the supplied `parent`, shifting all children after it to the right.
     pub fn insert_child_at_index(&mut self, parent: NodeId, child_index: usize, child: NodeId) -> TaffyResult<()> {
+        self.expect_node(parent, "insert_child_at_index");
+        self.expect_node(child, "insert_child_at_index");
         let parent_key = parent.into();
 
         let child_count = self.children[parent_key].len();
@@ -692,6 +1065,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Directly sets the `children` of the supplied `parent`
     pub fn set_children(&mut self, parent: NodeId, children: &[NodeId]) -> TaffyResult<()> {
+        self.expect_node(parent, "set_children");
+        for &child in children {
+            self.expect_node(child, "set_children");
+        }
         let parent_key = parent.into();
 
         // Remove node as parent from all its current children.
@@ -721,7 +1098,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
     ///
     /// The child is not removed from the tree entirely, it is simply no longer attached to its previous parent.
     pub fn remove_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<NodeId> {
-        let index = self.children[parent.into()].iter().position(|n| *n == child).unwrap();
+        self.expect_node(parent, "remove_child");
+        let Some(index) = self.children[parent.into()].iter().position(|n| *n == child) else {
+            panic!("TaffyTree::remove_child was called with {child:?}, which is not a child of {parent:?}");
+        };
         self.remove_child_at_index(parent, index)
     }
 
@@ -729,6 +1109,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
     ///
     /// The child is not removed from the tree entirely, it is simply no longer attached to its previous parent.
     pub fn remove_child_at_index(&mut self, parent: NodeId, child_index: usize) -> TaffyResult<NodeId> {
+        self.expect_node(parent, "remove_child_at_index");
         let parent_key = parent.into();
         let child_count = self.children[parent_key].len();
         if child_index >= child_count {
@@ -752,6 +1133,8 @@ impl<NodeContext> TaffyTree<NodeContext> {
         child_index: usize,
         new_child: NodeId,
     ) -> TaffyResult<NodeId> {
+        self.expect_node(parent, "replace_child_at_index");
+        self.expect_node(new_child, "replace_child_at_index");
         let parent_key = parent.into();
 
         let child_count = self.children[parent_key].len();
@@ -770,6 +1153,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Returns the child node of the parent `node` at the provided `child_index`
     pub fn child_at_index(&self, parent: NodeId, child_index: usize) -> TaffyResult<NodeId> {
+        self.expect_node(parent, "child_at_index");
         let parent_key = parent.into();
         let child_count = self.children[parent_key].len();
         if child_index >= child_count {
@@ -789,16 +1173,59 @@ impl<NodeContext> TaffyTree<NodeContext> {
     /// - Return None if the specified node has no parent
     /// - Panics if the specified node does not exist
     pub fn parent(&self, child_id: NodeId) -> Option<NodeId> {
+        self.expect_node(child_id, "parent");
         self.parents[child_id.into()]
     }
 
     /// Returns a list of children that belong to the parent node
     pub fn children(&self, parent: NodeId) -> TaffyResult<Vec<NodeId>> {
+        self.expect_node(parent, "children");
         Ok(self.children[parent.into()].iter().copied().collect::<_>())
     }
 
+    /// Returns the children of the parent node in the order in which they should be painted, back to front
+    ///
+    /// This follows the order in which CSS paints the descendants of a stacking context, simplified to a single level:
+    /// in-flow children are painted first, then floats (in block containers), then absolutely positioned children.
+    /// Within each of these layers children are painted in their layout [`order`](Layout::order). Hidden children
+    /// aren't painted, so are skipped.
+    ///
+    /// This is a simplified model of painting: children don't form stacking contexts of their own, and there is no
+    /// `z-index` (which would order the absolutely positioned children, painting those with a negative `z-index`
+    /// before the in-flow children).
+    pub fn paint_order(&self, parent: NodeId) -> TaffyResult<impl Iterator<Item = NodeId>> {
+        self.expect_node(parent, "paint_order");
+        #[cfg(feature = "block_layout")]
+        let parent_is_block_container =
+            matches!(self.nodes[parent.into()].style.display, Display::Block | Display::FlowRoot);
+
+        let mut children: Vec<(u8, u32, NodeId)> = self.children[parent.into()]
+            .iter()
+            .filter_map(|&child| {
+                let node = &self.nodes[child.into()];
+                if node.style.display == Display::None {
+                    return None;
+                }
+                #[cfg(feature = "block_layout")]
+                let is_float = parent_is_block_container && node.style.float != crate::style::Float::None;
+                #[cfg(not(feature = "block_layout"))]
+                let is_float = false;
+                let layer = match node.style.position {
+                    Position::Absolute => 2,
+                    Position::Relative if is_float => 1,
+                    Position::Relative => 0,
+                };
+                Some((layer, node.unrounded_layout.order, child))
+            })
+            .collect();
+        // The sort is stable, so children with the same layer and order keep their document order
+        children.sort_by_key(|&(layer, order, _)| (layer, order));
+        Ok(children.into_iter().map(|(_, _, child)| child))
+    }
+
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
+        self.expect_node(node, "set_style");
         self.nodes[node.into()].style = style;
         self.mark_dirty(node)?;
         Ok(())
@@ -806,11 +1233,13 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Gets the [`Style`] of the provided `node`
     pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
+        self.expect_node(node, "style");
         Ok(&self.nodes[node.into()].style)
     }
 
     /// Return this node layout relative to its parent
     pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        self.expect_node(node, "layout");
         if self.config.use_rounding {
             Ok(&self.nodes[node.into()].final_layout)
         } else {
@@ -818,68 +1247,248 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
     }
 
+    /// Returns the margin, border, padding and content boxes of the node relative to its parent, computed from its
+    /// [`layout`](Self::layout) and the margins, borders and padding resolved during layout. See [`BoxModel`].
+    pub fn computed_box(&self, node: NodeId) -> TaffyResult<BoxModel> {
+        Ok(self.layout(node)?.box_model())
+    }
+
+    /// Returns this node's [`layout`](Self::layout) with its location relative to its parent's content box rather than
+    /// its border box. See [`Layout::content_relative_location`]. The location of a node without a parent is unchanged.
+    pub fn layout_relative_to_content_box(&self, node: NodeId) -> TaffyResult<Layout> {
+        let mut layout = *self.layout(node)?;
+        if let Some(parent) = self.parent(node) {
+            layout.location = layout.content_relative_location(self.layout(parent)?);
+        }
+        Ok(layout)
+    }
+
     /// Returns this node layout with unrounded values relative to its parent.
     pub fn unrounded_layout(&self, node: NodeId) -> &Layout {
+        self.expect_node(node, "unrounded_layout");
         &self.nodes[node.into()].unrounded_layout
     }
 
+    /// Takes a snapshot of the subtree rooted at `root`: the style and children of each node, which of them are
+    /// measured, and their current layouts. The snapshot can be serialized, and restored with
+    /// [`from_snapshot`](TaffyTree::from_snapshot), for example to reproduce a layout in a bug report or a test.
+    ///
+    /// Context data isn't included, as it isn't generally serializable. Instead, the size that each measured leaf was
+    /// last measured as is recorded, so that the restored tree can be laid out the same way. (Without the
+    /// `content_size` feature this isn't known, and the size of the leaf's content box is recorded instead.) The
+    /// baselines reported by measure functions aren't recorded.
+    #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+    pub fn serialize_tree(&self, root: NodeId) -> TreeSnapshot {
+        self.expect_node(root, "serialize_tree");
+        let mut nodes = Vec::new();
+        // Each entry holds a node to snapshot and the index of its parent's snapshot
+        let mut stack = Vec::new();
+        stack.push((root, None));
+        while let Some((node, parent)) = stack.pop() {
+            let id = nodes.len();
+            if let Some(parent) = parent {
+                let parent_snapshot: &mut NodeSnapshot = &mut nodes[parent];
+                parent_snapshot.children.push(id);
+            }
+
+            let key = node.into();
+            let data = &self.nodes[key];
+            let children = &self.children[key];
+            let has_measure_function = self.node_measure_functions.0.contains_key(key);
+            let needs_measure = children.is_empty() && (data.has_context || has_measure_function);
+            // A leaf's content size is the size returned by its measure function plus its padding
+            let measured_size = needs_measure.then(|| {
+                let layout = &data.unrounded_layout;
+                #[cfg(feature = "content_size")]
+                return layout.content_size - layout.padding.sum_axes();
+                #[cfg(not(feature = "content_size"))]
+                return Size {
+                    width: layout.content_box_width() - layout.scrollbar_size.width,
+                    height: layout.content_box_height() - layout.scrollbar_size.height,
+                };
+            });
+            nodes.push(NodeSnapshot {
+                style: data.style.clone(),
+                children: Vec::with_capacity(children.len()),
+                needs_measure,
+                measured_size,
+                layout: Some(*self.layout(node).unwrap()),
+            });
+
+            // Push the children in reverse, so that they're popped (and numbered) in order
+            stack.extend(children.iter().rev().map(|&child| (child, Some(id))));
+        }
+
+        TreeSnapshot { nodes }
+    }
+
     /// Get the "detailed layout info" for a node.
     ///
     /// Currently this is only implemented for CSS Grid containers where it contains
     /// the computed size of each grid track and the computed placement of each grid item
     #[cfg(feature = "detailed_layout_info")]
     pub fn detailed_layout_info(&self, node_id: NodeId) -> &DetailedLayoutInfo {
+        self.expect_node(node_id, "detailed_layout_info");
         &self.nodes[node_id.into()].detailed_layout_info
     }
 
     /// Marks the layout of this node and its ancestors as outdated
     ///
-    /// WARNING: this may stack-overflow if the tree contains a cycle
+    /// WARNING: this will loop forever if the tree contains a cycle
     pub fn mark_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
-        /// WARNING: this will stack-overflow if the tree contains a cycle
-        fn mark_dirty_recursive(
-            nodes: &mut SlotMap<DefaultKey, NodeData>,
-            parents: &SlotMap<DefaultKey, Option<NodeId>>,
-            node_key: DefaultKey,
-        ) {
-            nodes[node_key].mark_dirty();
-
-            if let Some(Some(node)) = parents.get(node_key) {
-                mark_dirty_recursive(nodes, parents, (*node).into());
+        self.expect_node(node, "mark_dirty");
+        let mut node_key: DefaultKey = node.into();
+        loop {
+            self.nodes[node_key].mark_dirty();
+            match self.parents.get(node_key) {
+                Some(Some(parent)) => node_key = (*parent).into(),
+                _ => break,
             }
         }
 
-        mark_dirty_recursive(&mut self.nodes, &self.parents, node.into());
+        Ok(())
+    }
+
+    /// Marks the layout of a node as outdated because the content that it's measured from (such as its text) has
+    /// changed, without marking its ancestors as outdated
+    ///
+    /// The next layout remeasures the node under each of the constraints that it was previously measured under. If
+    /// every result is unchanged (for example, when text is replaced by text of the same width), the cached layouts of
+    /// its ancestors are left intact. Otherwise the node is marked dirty as if by [`mark_dirty`](Self::mark_dirty) and
+    /// the layout continues from its root.
+    ///
+    /// This is intended to be called after updating a leaf node's context with
+    /// [`get_node_context_mut`](Self::get_node_context_mut), instead of replacing it with
+    /// [`set_node_context`](Self::set_node_context), which marks the node's ancestors dirty.
+    pub fn mark_measure_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.expect_node(node, "mark_measure_dirty");
+        let key = node.into();
+        let cache = &mut self.nodes[key].cache;
+        // The results that the node's ancestors were computed from are those cached before it was first marked
+        if self.measure_dirty_caches.contains_key(key) {
+            cache.clear();
+        } else {
+            self.measure_dirty_caches.insert(key, core::mem::take(cache));
+        }
 
         Ok(())
     }
 
+    /// Flags a node and its ancestors as needing to be rounded by the next call to [`round_layout`]
+    ///
+    /// Every ancestor of a flagged node is also flagged, so the walk stops at the first ancestor that is already flagged.
+    fn mark_needs_rounding(&mut self, node: NodeId) {
+        let mut node_key: DefaultKey = node.into();
+        self.nodes[node_key].needs_rounding = true;
+        while let Some(Some(parent)) = self.parents.get(node_key) {
+            node_key = (*parent).into();
+            if self.nodes[node_key].needs_rounding {
+                break;
+            }
+            self.nodes[node_key].needs_rounding = true;
+        }
+    }
+
     /// Indicates whether the layout of this node needs to be recomputed
     pub fn dirty(&self, node: NodeId) -> TaffyResult<bool> {
+        self.expect_node(node, "dirty");
         Ok(self.nodes[node.into()].cache.is_empty())
     }
 
+    /// Clears the cached layout results of every node in the tree, without discarding their computed layouts
+    ///
+    /// The layouts returned by [`layout`](Self::layout) remain valid until the next call to
+    /// [`compute_layout`](Self::compute_layout), which will recompute the layout of every node. Until then,
+    /// [`dirty`](Self::dirty) returns `true` for every node.
+    ///
+    /// Note that each node's cache is stored inline in the node, so this doesn't reduce the memory used by the tree.
+    pub fn clear_all_caches(&mut self) {
+        for (_, node) in self.nodes.iter_mut() {
+            node.cache.clear();
+        }
+    }
+
     /// Updates the stored layout of the provided `node` and its children
-    pub fn compute_layout_with_measure<MeasureFunction>(
+    ///
+    /// Relaying out a tree in which no node has been marked dirty since the previous layout is served entirely from
+    /// the layout caches and performs no heap allocations.
+    ///
+    /// `measure_function` is called to measure each leaf node, except for those with a measure function attached
+    /// with [`set_node_measure`](Self::set_node_measure), which are measured by that function instead.
+    pub fn compute_layout_with_measure<MeasureFunction, MeasureResult>(
         &mut self,
         node_id: NodeId,
         available_space: Size<AvailableSpace>,
-        measure_function: MeasureFunction,
+        mut measure_function: MeasureFunction,
     ) -> Result<(), TaffyError>
     where
-        MeasureFunction:
-            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        MeasureFunction: FnMut(
+            Size<Option<Scalar>>,
+            Size<AvailableSpace>,
+            NodeId,
+            Option<&mut NodeContext>,
+            &Style,
+        ) -> MeasureResult,
+        MeasureResult: Into<MeasureOutput>,
     {
+        self.compute_layout_with_measure_ex(node_id, available_space, |args: MeasureArgs<'_, NodeContext>| {
+            measure_function(args.known_dimensions, args.available_space, args.node_id, args.node_context, args.style)
+        })
+    }
+
+    /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// Like [`compute_layout_with_measure`](Self::compute_layout_with_measure), except that the measure function is
+    /// passed a [`MeasureArgs`], which additionally contains the [`RunMode`], [`SizingMode`] and parent size of the
+    /// node being measured.
+    pub fn compute_layout_with_measure_ex<MeasureFunction, MeasureResult>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        mut measure_function: MeasureFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction: FnMut(MeasureArgs<'_, NodeContext>) -> MeasureResult,
+        MeasureResult: Into<MeasureOutput>,
+    {
+        self.expect_node(node_id, "compute_layout_with_measure");
         let use_rounding = self.config.use_rounding;
+        #[cfg(feature = "metrics")]
+        self.cache_stats.set(CacheStats::default());
+        let measure_function = |args: MeasureArgs<'_, NodeContext>| -> MeasureOutput { measure_function(args).into() };
         let mut taffy_view = TaffyView { taffy: self, measure_function };
-        compute_root_layout(&mut taffy_view, node_id, available_space);
-        if use_rounding {
-            round_layout(&mut taffy_view, node_id);
-        }
-        Ok(())
+
+        with_layout_panic_message(|| {
+            // Remeasure the nodes whose content has changed, marking them dirty if any of their results have changed
+            let mut measure_dirty_caches = core::mem::take(&mut taffy_view.taffy.measure_dirty_caches);
+            for (key, previous_cache) in measure_dirty_caches.drain() {
+                let unchanged = previous_cache.cached_results().map_or(false, |mut results| {
+                    results.all(|(inputs, previous_output)| {
+                        let output = taffy_view.compute_child_layout(key.into(), inputs);
+                        match inputs.run_mode {
+                            RunMode::ComputeSize => output.size == previous_output.size,
+                            _ => output == previous_output,
+                        }
+                    })
+                });
+                if !unchanged {
+                    taffy_view.taffy.mark_dirty(key.into())?;
+                }
+            }
+            taffy_view.taffy.measure_dirty_caches = measure_dirty_caches;
+
+            compute_root_layout(&mut taffy_view, node_id, available_space);
+            if use_rounding {
+                let rounding = taffy_view.taffy.config.rounding;
+                round_layout_with_options(&mut taffy_view, node_id, rounding);
+            }
+            Ok(())
+        })
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// See [`compute_layout_with_measure`](Self::compute_layout_with_measure) for details.
     pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
     }
@@ -890,10 +1499,73 @@ impl<NodeContext> TaffyTree<NodeContext> {
         crate::util::print_tree(self, root)
     }
 
+    /// Returns a debug representation of the tree's layout, as printed by [`print_tree`](Self::print_tree)
+    #[cfg(feature = "std")]
+    pub fn layout_tree_string(&self, root: NodeId) -> String {
+        let mut output = String::new();
+        // Writing to a `String` can't fail
+        let _ = crate::util::format_tree(self, root, &mut output);
+        output
+    }
+
     /// Returns an instance of LayoutTree representing the TaffyTree
     #[cfg(test)]
     pub(crate) fn as_layout_tree(&mut self) -> impl LayoutPartialTree + CacheTree + '_ {
-        TaffyView { taffy: self, measure_function: |_, _, _, _, _| Size::ZERO }
+        TaffyView { taffy: self, measure_function: |_| MeasureOutput::ZERO }
+    }
+}
+
+impl TaffyTree {
+    /// Rebuilds a tree from a snapshot taken with [`serialize_tree`](TaffyTree::serialize_tree), and returns it along
+    /// with its root node
+    ///
+    /// Each measured leaf with a recorded [`measured_size`](NodeSnapshot::measured_size) has a measure function
+    /// attached that returns that size, so laying out the restored tree reproduces the recorded layout. The recorded
+    /// layouts themselves aren't restored: the tree must be laid out again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot has no nodes, or if the children of a node aren't later nodes in the snapshot that have
+    /// no other parent, as the nodes of a snapshot taken from a tree always are.
+    #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+    pub fn from_snapshot(snapshot: &TreeSnapshot) -> (TaffyTree, NodeId) {
+        assert!(!snapshot.nodes.is_empty(), "TaffyTree::from_snapshot was called with an empty snapshot");
+        let mut taffy = TaffyTree::with_capacity(snapshot.nodes.len());
+        let mut has_parent: Vec<bool> = core::iter::repeat(false).take(snapshot.nodes.len()).collect();
+        for (id, node) in snapshot.nodes.iter().enumerate() {
+            for &child in &node.children {
+                assert!(
+                    child > id && child < snapshot.nodes.len() && !has_parent[child],
+                    "TaffyTree::from_snapshot was called with a snapshot in which node {id} has invalid child {child}"
+                );
+                has_parent[child] = true;
+            }
+        }
+
+        // Children always follow their parents, so the nodes are created in reverse
+        let mut node_ids: Vec<Option<NodeId>> = core::iter::repeat(None).take(snapshot.nodes.len()).collect();
+        for (id, node) in snapshot.nodes.iter().enumerate().rev() {
+            let style = node.style.clone();
+            let node_id = if !node.children.is_empty() {
+                let children: Vec<NodeId> =
+                    node.children.iter().map(|&child| node_ids[child].take().unwrap()).collect();
+                taffy.new_with_children(style, &children).unwrap()
+            } else if node.needs_measure {
+                let leaf = taffy.new_leaf_with_context(style, ()).unwrap();
+                if let Some(measured_size) = node.measured_size {
+                    let measure =
+                        move |known_dimensions: Size<Option<Scalar>>, _| known_dimensions.unwrap_or(measured_size);
+                    taffy.set_node_measure(leaf, Some(Box::new(measure))).unwrap();
+                }
+                leaf
+            } else {
+                taffy.new_leaf(style).unwrap()
+            };
+            node_ids[id] = Some(node_id);
+        }
+
+        let root = node_ids[0].unwrap();
+        (taffy, root)
     }
 }
 
@@ -901,17 +1573,19 @@ impl<NodeContext> TaffyTree<NodeContext> {
 mod tests {
 
     use super::*;
+    use crate::compute::round_layout;
+    use crate::geometry::Rect;
     use crate::style::{Dimension, Display, FlexDirection};
     use crate::style_helpers::*;
     use crate::util::sys;
 
     fn size_measure_function(
-        known_dimensions: Size<Option<f32>>,
+        known_dimensions: Size<Option<Scalar>>,
         _available_space: Size<AvailableSpace>,
         _node_id: NodeId,
-        node_context: Option<&mut Size<f32>>,
+        node_context: Option<&mut Size<Scalar>>,
         _style: &Style,
-    ) -> Size<f32> {
+    ) -> Size<Scalar> {
         known_dimensions.unwrap_or(node_context.cloned().unwrap_or(Size::ZERO))
     }
 
@@ -949,7 +1623,7 @@ mod tests {
 
     #[test]
     fn new_leaf_with_context() {
-        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let mut taffy: TaffyTree<Size<Scalar>> = TaffyTree::new();
 
         let res = taffy.new_leaf_with_context(Style::default(), Size::ZERO);
         assert!(res.is_ok());
@@ -1017,7 +1691,7 @@ mod tests {
 
     #[test]
     fn set_measure() {
-        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let mut taffy: TaffyTree<Size<Scalar>> = TaffyTree::new();
         let node = taffy.new_leaf_with_context(Style::default(), Size { width: 200.0, height: 200.0 }).unwrap();
         taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
         assert_eq!(taffy.layout(node).unwrap().size.width, 200.0);
@@ -1029,7 +1703,7 @@ mod tests {
 
     #[test]
     fn set_measure_of_previously_unmeasured_node() {
-        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let mut taffy: TaffyTree<Size<Scalar>> = TaffyTree::new();
         let node = taffy.new_leaf(Style::default()).unwrap();
         taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
         assert_eq!(taffy.layout(node).unwrap().size.width, 0.0);
@@ -1039,6 +1713,58 @@ mod tests {
         assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
     }
 
+    #[test]
+    fn node_measure_takes_precedence_over_measure_function() {
+        let mut taffy: TaffyTree<Size<Scalar>> = TaffyTree::new();
+        let attached = taffy.new_leaf(Style::default()).unwrap();
+        let context = taffy.new_leaf_with_context(Style::default(), Size { width: 50.0, height: 20.0 }).unwrap();
+        let both = taffy.new_leaf_with_context(Style::default(), Size { width: 70.0, height: 70.0 }).unwrap();
+        let style = Style { align_items: Some(crate::AlignItems::Start), ..Default::default() };
+        let node = taffy.new_with_children(style, &[attached, context, both]).unwrap();
+
+        taffy.set_node_measure(attached, Some(Box::new(|_, _| Size { width: 30.0, height: 10.0 }))).unwrap();
+        taffy.set_node_measure(both, Some(Box::new(|_, _| Size { width: 40.0, height: 40.0 }))).unwrap();
+
+        let mut measured = Vec::new();
+        taffy
+            .compute_layout_with_measure(node, Size::MAX_CONTENT, |known, available, node_id, node_context, style| {
+                measured.push(node_id);
+                size_measure_function(known, available, node_id, node_context, style)
+            })
+            .unwrap();
+
+        assert!(measured.iter().all(|node_id| *node_id == context));
+        assert_eq!(taffy.layout(attached).unwrap().size, Size { width: 30.0, height: 10.0 });
+        assert_eq!(taffy.layout(context).unwrap().size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(taffy.layout(both).unwrap().size, Size { width: 40.0, height: 40.0 });
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 120.0, height: 40.0 });
+    }
+
+    #[test]
+    fn detaching_node_measure_falls_back_to_measure_function() {
+        let mut taffy: TaffyTree<Size<Scalar>> = TaffyTree::new();
+        let node = taffy.new_leaf_with_context(Style::default(), Size { width: 100.0, height: 100.0 }).unwrap();
+        taffy.set_node_measure(node, Some(Box::new(|_, _| Size { width: 10.0, height: 10.0 }))).unwrap();
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 10.0);
+
+        // Cloning the tree doesn't clone the attached measure function
+        let mut clone = taffy.clone();
+        clone.mark_dirty(node).unwrap();
+        clone.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(clone.layout(node).unwrap().size.width, 100.0);
+
+        taffy.set_node_measure(node, None).unwrap();
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
+    }
+
+    #[test]
+    fn taffy_tree_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<TaffyTree<()>>();
+    }
+
     /// Test that adding `add_child()` works
     #[test]
     fn add_child() {
@@ -1267,6 +1993,460 @@ mod tests {
         assert_eq!(taffy.dirty(node), Ok(true));
     }
 
+    #[test]
+    fn clear_all_caches_keeps_layouts() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let child1 = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let node = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.0, 50.0), ..Default::default() }, &[child0, child1])
+            .unwrap();
+        let nodes = [node, child0, child1];
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        let layouts = nodes.map(|node| *taffy.layout(node).unwrap());
+
+        taffy.clear_all_caches();
+        for (node, layout) in nodes.iter().zip(layouts.iter()) {
+            assert_eq!(taffy.dirty(*node), Ok(true));
+            assert_eq!(taffy.layout(*node), Ok(layout));
+        }
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        for (node, layout) in nodes.iter().zip(layouts.iter()) {
+            assert_eq!(taffy.dirty(*node), Ok(false));
+            assert_eq!(taffy.layout(*node), Ok(layout));
+        }
+    }
+
+    /// Pins the per-node memory footprint with the default features, so that increases are visible in review
+    #[test]
+    #[cfg(all(
+        target_pointer_width = "64",
+        feature = "flexbox",
+        feature = "grid",
+        feature = "block_layout",
+        feature = "content_size",
+        feature = "detailed_layout_info",
+        not(feature = "f64")
+    ))]
+    fn node_size_report() {
+        let report = TaffyTree::<()>::node_size_report();
+        assert_eq!(report, NodeSizeReport { node_data: 1072, style: 352, cache: 496, layout: 100 });
+    }
+
+    #[test]
+    #[cfg(all(feature = "flexbox", feature = "content_size"))]
+    fn layout_tree_string_matches_snapshot() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(20.0, 10.0),
+                padding: Rect { left: length(2.0), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let hidden = taffy.new_leaf(Style { display: Display::None, ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_lengths(100.0, 50.0), ..Default::default() }, &[child, hidden])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(
+            taffy.layout_tree_string(root),
+            "TREE
+└──  FLEX ROW [x: 0    y: 0    w: 100  h: 50   content_w: 20   content_h: 10   border: l:0 r:0 t:0 b:0 padding: l:0 r:0 t:0 b:0] (NodeId(4294967299))
+    ├──  LEAF [x: 0    y: 0    w: 20   h: 10   content_w: 2    content_h: 0    border: l:0 r:0 t:0 b:0 padding: l:2 r:0 t:0 b:0] (NodeId(4294967297))
+    └──  NONE [x: 0    y: 0    w: 0    h: 0    content_w: 0    content_h: 0    border: l:0 r:0 t:0 b:0 padding: l:0 r:0 t:0 b:0] (NodeId(4294967298))
+"
+        );
+
+        let columns =
+            crate::PrintColumns { location: false, size: true, content_size: false, ..crate::PrintColumns::DEFAULT };
+        let columns = crate::PrintColumns { border: false, padding: false, margin: true, ..columns };
+        let mut output = String::new();
+        crate::util::format_tree_with_columns(&taffy, child, columns, &mut output).unwrap();
+        assert_eq!(output, "TREE\n└──  LEAF [w: 20   h: 10   margin: l:0 r:0 t:0 b:0] (NodeId(4294967297))\n");
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn verbose_tree_output_includes_style_summary_and_cache_state() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style { size: Size::from_lengths(20.0, 10.0), margin: Rect::length(5.0), ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { padding: Rect::length(2.0), flex_grow: 1.0, ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let sibling = taffy.new_leaf(Style { position: crate::Position::Absolute, ..Default::default() }).unwrap();
+        taffy.add_child(root, sibling).unwrap();
+
+        let columns = crate::PrintColumns {
+            location: false,
+            content_size: false,
+            border: false,
+            padding: false,
+            ..crate::PrintColumns::ALL
+        };
+        let mut output = String::new();
+        crate::util::format_tree_with_columns(&taffy, root, columns, &mut output).unwrap();
+        assert_eq!(
+            output,
+            "TREE
+└──  FLEX ROW [w: 34   h: 24   margin: l:0 r:0 t:0 b:0] (NodeId(4294967298)) {display: FLEX; padding: 2px} dirty: true cached: 0
+    ├──  LEAF [w: 20   h: 10   margin: l:5 r:5 t:5 b:5] (NodeId(4294967297)) {display: FLEX; size: 20px 10px; margin: 5px} dirty: false cached: 3
+    └──  LEAF [w: 0    h: 0    margin: l:0 r:0 t:0 b:0] (NodeId(4294967299)) {display: FLEX; position: absolute} dirty: true cached: 0
+"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "flexbox", feature = "grid"))]
+    fn dot_export_matches_snapshot() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grid_item = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let grid =
+            taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[grid_item]).unwrap();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let hidden = taffy.new_leaf(Style { display: Display::None, ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size::from_lengths(100.0, 50.0), ..Default::default() },
+                &[grid, leaf, hidden],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        taffy.mark_dirty(grid_item).unwrap();
+
+        let mut output = String::new();
+        crate::util::export_dot(&taffy, grid, &mut output).unwrap();
+        assert_eq!(
+            output,
+            r#"digraph tree {
+    node [shape=box];
+    n4294967298 [label="GRID\nid: 4294967298\nsize: 30 x 50\nlocation: 0, 0"];
+    n4294967298 -> n4294967297;
+    n4294967297 [label="LEAF\nid: 4294967297\nsize: 30 x 20\nlocation: 0, 0"];
+}
+"#
+        );
+
+        let options = crate::DotOptions { color_by_algorithm: true, mark_dirty: true };
+        let mut output = String::new();
+        crate::util::export_dot_with_options(&taffy, root, options, &mut output).unwrap();
+        assert_eq!(
+            output,
+            r#"digraph tree {
+    node [shape=box];
+    n4294967301 [label="FLEX ROW\nid: 4294967301\nsize: 100 x 50\nlocation: 0, 0", style=filled, fillcolor=lightblue, color=red, penwidth=2];
+    n4294967301 -> n4294967298;
+    n4294967301 -> n4294967299;
+    n4294967301 -> n4294967300;
+    n4294967298 [label="GRID\nid: 4294967298\nsize: 30 x 50\nlocation: 0, 0", style=filled, fillcolor=lightgreen, color=red, penwidth=2];
+    n4294967298 -> n4294967297;
+    n4294967297 [label="LEAF\nid: 4294967297\nsize: 30 x 20\nlocation: 0, 0", style=filled, fillcolor=lightgrey, color=red, penwidth=2];
+    n4294967299 [label="LEAF\nid: 4294967299\nsize: 10 x 10\nlocation: 30, 0", style=filled, fillcolor=lightgrey];
+    n4294967300 [label="NONE\nid: 4294967300\nsize: 0 x 0\nlocation: 0, 0"];
+}
+"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn computed_box_uses_the_padding_resolved_during_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(100.0, 50.0),
+                margin: Rect::length(5.0),
+                border: Rect::length(2.0),
+                // Percentage padding is resolved against the width of the parent's content box (200px)
+                padding: Rect::percent(0.1),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_lengths(200.0, 100.0),
+                    align_items: Some(crate::AlignItems::Start),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = *taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, Rect::length(20.0));
+        assert_eq!(
+            taffy.computed_box(child).unwrap(),
+            BoxModel {
+                margin_box: Rect { left: 0.0, right: 110.0, top: 0.0, bottom: 60.0 },
+                border_box: Rect { left: 5.0, right: 105.0, top: 5.0, bottom: 55.0 },
+                padding_box: Rect { left: 7.0, right: 103.0, top: 7.0, bottom: 53.0 },
+                content_box: Rect { left: 27.0, right: 83.0, top: 27.0, bottom: 33.0 },
+            }
+        );
+        assert_eq!(taffy.computed_box(child).unwrap().content_box.left, layout.content_box_x());
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn layout_relative_to_content_box_subtracts_the_parents_resolved_border_and_padding() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let first = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 20.0), ..Default::default() }).unwrap();
+        let second = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(30.0, 20.0),
+                margin: Rect { left: length(4.0), right: zero(), top: length(6.0), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_lengths(170.0, 100.0),
+                    border: Rect::length(3.0),
+                    // Percentage padding is resolved against the width of the root's own containing block, so that
+                    // it isn't a whole number of pixels: 0.07 * 170 = 11.9
+                    padding: Rect::percent(0.07),
+                    align_items: Some(crate::AlignItems::Start),
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+
+        for use_rounding in [false, true] {
+            if use_rounding {
+                taffy.enable_rounding();
+            } else {
+                taffy.disable_rounding();
+            }
+            taffy.compute_layout(root, Size { width: length(170.0), height: AvailableSpace::MaxContent }).unwrap();
+
+            let root_layout = *taffy.layout(root).unwrap();
+            for (child, expected) in [(first, Point { x: 0.0, y: 0.0 }), (second, Point { x: 34.0, y: 6.0 })] {
+                let layout = *taffy.layout(child).unwrap();
+                let relative = taffy.layout_relative_to_content_box(child).unwrap();
+                let manual = Point {
+                    x: layout.location.x - root_layout.border.left - root_layout.padding.left,
+                    y: layout.location.y - root_layout.border.top - root_layout.padding.top,
+                };
+                assert_eq!(relative.location, manual);
+                assert_eq!(relative.location, expected, "rounding: {use_rounding}");
+                assert_eq!(relative.size, layout.size);
+            }
+        }
+        assert_eq!(taffy.layout_relative_to_content_box(root).unwrap(), *taffy.layout(root).unwrap());
+    }
+
+    #[test]
+    #[cfg(all(feature = "block_layout", feature = "flexbox"))]
+    fn paint_order_paints_in_flow_children_then_floats_then_positioned_children() {
+        use crate::style::Float;
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = |taffy: &mut TaffyTree<()>, position: Position, float: Float, display: Display| {
+            taffy
+                .new_leaf(Style {
+                    display,
+                    position,
+                    float,
+                    size: Size::from_lengths(10.0, 10.0),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let absolute = leaf(&mut taffy, Position::Absolute, Float::None, Display::Block);
+        let float = leaf(&mut taffy, Position::Relative, Float::Left, Display::Block);
+        let in_flow = leaf(&mut taffy, Position::Relative, Float::None, Display::Block);
+        let hidden = leaf(&mut taffy, Position::Relative, Float::None, Display::None);
+        let flex = leaf(&mut taffy, Position::Relative, Float::None, Display::Flex);
+        let floated_absolute = leaf(&mut taffy, Position::Absolute, Float::Right, Display::Block);
+        let children = [absolute, float, in_flow, hidden, flex, floated_absolute];
+        let block =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &children).unwrap();
+        taffy.compute_layout(block, Size::MAX_CONTENT).unwrap();
+
+        // Absolutely positioned floats aren't floated
+        let paint_order: Vec<NodeId> = taffy.paint_order(block).unwrap().collect();
+        assert_eq!(paint_order, [in_flow, flex, float, absolute, floated_absolute]);
+
+        // Floats in flex containers are ignored, so they are painted in order with the other in-flow children
+        taffy.set_style(block, Style { display: Display::Flex, ..Default::default() }).unwrap();
+        taffy.compute_layout(block, Size::MAX_CONTENT).unwrap();
+        let paint_order: Vec<NodeId> = taffy.paint_order(block).unwrap().collect();
+        assert_eq!(paint_order, [float, in_flow, flex, absolute, floated_absolute]);
+
+        // Reordering the children reorders their painting once they are laid out again
+        taffy.remove_child(block, flex).unwrap();
+        taffy.insert_child_at_index(block, 0, flex).unwrap();
+        taffy.remove_child(block, floated_absolute).unwrap();
+        taffy.insert_child_at_index(block, 0, floated_absolute).unwrap();
+        taffy.compute_layout(block, Size::MAX_CONTENT).unwrap();
+        let paint_order: Vec<NodeId> = taffy.paint_order(block).unwrap().collect();
+        assert_eq!(paint_order, [flex, float, in_flow, floated_absolute, absolute]);
+    }
+
+    #[test]
+    fn panic_while_laying_out_identifies_the_node_and_its_ancestors() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf_with_context(Style::default(), ()).unwrap();
+        let container = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[container]).unwrap();
+
+        let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, |_, _, _, context, _| match context {
+                Some(()) => panic!("text shaping failed"),
+                None => Size::ZERO,
+            })
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            payload.downcast_ref::<String>().unwrap(),
+            &format!(
+                "text shaping failed\nwhile laying out {leaf:?} (from the root: {root:?} > {container:?} > {leaf:?})"
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "TaffyTree::style was called with NodeId(4294967297), which is not in the tree")]
+    fn accessing_a_removed_node_panics_naming_the_method_and_node() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(node).unwrap();
+        let _ = taffy.style(node);
+    }
+
+    /// Assert that the final layouts of every node match those produced by rounding the entire tree from scratch
+    fn assert_matches_full_rounding(taffy: &mut TaffyTree<()>, root: NodeId) {
+        let layouts: Vec<(DefaultKey, Layout)> =
+            taffy.nodes.iter().map(|(key, node)| (key, node.final_layout)).collect();
+        for (_, node) in taffy.nodes.iter_mut() {
+            node.needs_rounding = true;
+        }
+        round_layout(&mut TaffyView { taffy: &mut *taffy, measure_function: |_| MeasureOutput::ZERO }, root);
+        for (key, layout) in layouts {
+            assert_eq!(taffy.nodes[key].final_layout, layout);
+        }
+    }
+
+    #[test]
+    fn rounding_skips_unchanged_subtrees_without_changing_the_result() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf_style = |width: f32| Style { size: Size::from_lengths(width, 10.3), ..Default::default() };
+        let mut rows = Vec::new();
+        let mut leaves = Vec::new();
+        for _ in 0..4 {
+            let row_leaves: Vec<NodeId> = (0..5).map(|_| taffy.new_leaf(leaf_style(10.3)).unwrap()).collect();
+            rows.push(
+                taffy
+                    .new_with_children(Style { padding: Rect::length(0.4), ..Default::default() }, &row_leaves)
+                    .unwrap(),
+            );
+            leaves.extend(row_leaves);
+        }
+        let root = taffy
+            .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &rows)
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Resizing a leaf moves its later siblings without changing the other rows
+        taffy.set_style(leaves[6], leaf_style(17.7)).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_matches_full_rounding(&mut taffy, root);
+
+        // Moving a leaf to a row with a different fractional offset must round it again even though its own unrounded
+        // layout is unchanged
+        taffy.set_style(rows[0], Style { padding: Rect::length(0.7), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        taffy.remove_child(rows[1], leaves[9]).unwrap();
+        taffy.add_child(rows[0], leaves[9]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_matches_full_rounding(&mut taffy, root);
+    }
+
+    #[test]
+    fn round_and_unround_layout_cycles_are_lossless() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaves: Vec<NodeId> = (0..5)
+            .map(|_| taffy.new_leaf(Style { size: Size::from_lengths(10.3, 10.3), ..Default::default() }).unwrap())
+            .collect();
+        let root =
+            taffy.new_with_children(Style { padding: Rect::length(0.4), ..Default::default() }, &leaves).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let nodes: Vec<NodeId> = core::iter::once(root).chain(leaves.iter().copied()).collect();
+        let rounded: Vec<Layout> = nodes.iter().map(|&node| *taffy.layout(node).unwrap()).collect();
+        let unrounded: Vec<Layout> = nodes.iter().map(|&node| *taffy.unrounded_layout(node)).collect();
+        assert_ne!(rounded, unrounded);
+
+        for _ in 0..3 {
+            taffy.unround_layout(root).unwrap();
+            assert!(nodes.iter().zip(&unrounded).all(|(&node, layout)| taffy.layout(node).unwrap() == layout));
+            assert!(nodes.iter().zip(&unrounded).all(|(&node, layout)| taffy.get_final_layout(node) == layout));
+            taffy.round_layout(root).unwrap();
+            assert!(nodes.iter().zip(&rounded).all(|(&node, layout)| taffy.layout(node).unwrap() == layout));
+        }
+        // Rounding an already rounded layout doesn't change it
+        taffy.round_layout(root).unwrap();
+        assert!(nodes.iter().zip(&rounded).all(|(&node, layout)| taffy.layout(node).unwrap() == layout));
+        assert!(nodes.iter().zip(&unrounded).all(|(&node, layout)| taffy.unrounded_layout(node) == layout));
+    }
+
+    #[test]
+    fn round_layout_applies_a_new_rounding_scale_without_recomputing_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.3, 10.3), ..Default::default() }).unwrap();
+        let root =
+            taffy.new_with_children(Style { padding: Rect::length(0.4), ..Default::default() }, &[leaf]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        taffy.set_rounding_scale(2.0);
+        taffy.round_layout(root).unwrap();
+        let rerounded = *taffy.layout(leaf).unwrap();
+        assert_eq!(rerounded.location, Point { x: 0.5, y: 0.5 });
+        assert_eq!(rerounded.size, Size { width: 10.0, height: 10.0 });
+
+        // The same as recomputing the layout with the new scale
+        taffy.mark_dirty(root).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(*taffy.layout(leaf).unwrap(), rerounded);
+    }
+
+    #[test]
+    fn very_deep_tree_can_be_marked_dirty_and_rounded() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let mut root = leaf;
+        for _ in 0..50_000 {
+            root = taffy.new_with_children(Style::default(), &[root]).unwrap();
+        }
+
+        // Every node is offset by a fraction of a pixel from its parent, so that rounding depends on the cumulative
+        // position of each node
+        for (_, node) in taffy.nodes.iter_mut() {
+            node.unrounded_layout.location = crate::geometry::Point { x: 0.25, y: 0.0 };
+            node.unrounded_layout.size = Size { width: 1.0, height: 1.0 };
+        }
+
+        taffy.mark_dirty(leaf).unwrap();
+        assert_eq!(taffy.dirty(root), Ok(true));
+
+        // Layout itself still recurses once per level, so this only rounds the preset unrounded layouts
+        round_layout(&mut TaffyView { taffy: &mut taffy, measure_function: |_| MeasureOutput::ZERO }, root);
+        let leaf_layout = taffy.layout(leaf).unwrap();
+        assert_eq!(leaf_layout.location.x, 0.0);
+        // The leaf starts at x = 50,001 * 0.25 = 12500.25 and ends at 12501.25
+        assert_eq!(leaf_layout.size.width, 1.0);
+    }
+
     #[test]
     fn compute_layout_should_produce_valid_result() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
@@ -1323,8 +2503,8 @@ mod tests {
         // - top-right:    {x: 20, y: 30}
         // - bottom-right: {x: 20, y: 40}
         let layout = taffy.layout(node).unwrap();
-        assert_eq!(layout.location.x, 10f32);
-        assert_eq!(layout.location.y, 30f32);
+        assert_eq!(layout.location.x, 10.0);
+        assert_eq!(layout.location.y, 30.0);
     }
 
     #[test]
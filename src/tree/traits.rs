@@ -28,7 +28,7 @@
 //! | ---                   | ---                     | ---                                                                                                                                                                                                                                                                                                                                                                                                                       |
 //! | [`LayoutPartialTree`] | [`TraversePartialTree`] | [`compute_flexbox_layout`](crate::compute_flexbox_layout)<br />[`compute_grid_layout`](crate::compute_grid_layout)<br />[`compute_block_layout`](crate::compute_block_layout)<br />[`compute_root_layout`](crate::compute_root_layout)<br />[`compute_leaf_layout`](crate::compute_leaf_layout)<br />[`compute_hidden_layout`](crate::compute_hidden_layout)<br />[`compute_cached_layout`](crate::compute_cached_layout) |
 //! | [`RoundTree`]         | [`TraverseTree`]        | [`round_layout`](crate::round_layout)                                                                                                                                                                                                                                                                                                                                                                                     |
-//! | [`PrintTree`]         | [`TraverseTree`]        | [`print_tree`](crate::print_tree)                                                                                                                                                                                                                                                                                                                                                                                         |
+//! | [`PrintTree`]         | [`TraverseTree`]        | [`print_tree`](crate::print_tree)<br />[`export_dot`](crate::util::export_dot)                                                                                                                                                                                                                                                                                                                                            |
 //!
 //! ## All of the traits on one page
 //!
@@ -123,14 +123,18 @@
 //!     fn get_debug_label(&self, node_id: NodeId) -> &'static str;
 //!     /// Get a reference to the node's final layout
 //!     fn get_final_layout(&self, node_id: NodeId) -> &Layout;
+//!     /// Get a reference to the node's style, which is summarised in verbose debug output
+//!     fn get_debug_style(&self, node_id: NodeId) -> Option<&Style> { None }
+//!     /// Get a reference to the node's layout cache, whose state is included in verbose debug output
+//!     fn get_debug_cache(&self, node_id: NodeId) -> Option<&Cache> { None }
 //! }
 //! ```
 //!
-use super::{Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
+use super::{Cache, Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
 #[cfg(feature = "detailed_layout_info")]
 use crate::debug::debug_log;
-use crate::geometry::{AbsoluteAxis, Line, Size};
-use crate::style::{AvailableSpace, CoreStyle};
+use crate::geometry::{AbsoluteAxis, Line, Point, Scalar, Size};
+use crate::style::{AvailableSpace, CoreStyle, Style};
 #[cfg(feature = "flexbox")]
 use crate::style::{FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
@@ -140,6 +144,7 @@ use crate::{BlockContainerStyle, BlockItemStyle};
 
 #[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
 use crate::compute::grid::DetailedGridInfo;
+use crate::compute::LayoutScratch;
 
 /// Taffy's abstraction for downward tree traversal.
 ///
@@ -181,37 +186,33 @@ pub trait LayoutPartialTree: TraversePartialTree {
     fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_>;
 
     /// Resolve calc value
-    fn resolve_calc_value(&self, val: u64, basis: f32) -> f32;
+    fn resolve_calc_value(&self, val: u64, basis: Scalar) -> Scalar;
 
     /// Set the node's unrounded layout
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout);
 
     /// Compute the specified node's size or full layout given the specified constraints
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput;
+
+    /// Get the pool of scratch buffers that the layout algorithms borrow their per-container temporaries from. Keeping
+    /// a single [`LayoutScratch`] for the duration of a layout (or across layouts) lets nested and sibling containers
+    /// reuse each other's buffers rather than allocating their own.
+    ///
+    /// The default implementation returns `None`, in which case the algorithms allocate their temporaries as needed.
+    fn scratch(&mut self) -> Option<&mut LayoutScratch> {
+        None
+    }
 }
 
 /// Trait used by the `compute_cached_layout` method which allows cached layout results to be stored and retrieved.
 ///
 /// The `Cache` struct implements a per-node cache that is compatible with this trait.
 pub trait CacheTree {
-    /// Try to retrieve a cached result from the cache
-    fn cache_get(
-        &self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-    ) -> Option<LayoutOutput>;
+    /// Try to retrieve a cached result for the given inputs from the cache
+    fn cache_get(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput>;
 
-    /// Store a computed size in the cache
-    fn cache_store(
-        &mut self,
-        node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-        layout_output: LayoutOutput,
-    );
+    /// Store the result of laying out the node with the given inputs in the cache
+    fn cache_store(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput);
 
     /// Clear all cache entries for the node
     fn cache_clear(&mut self, node_id: NodeId);
@@ -226,6 +227,15 @@ pub trait RoundTree: TraverseTree {
     fn get_unrounded_layout(&self, node_id: NodeId) -> &Layout;
     /// Get a reference to the node's final layout
     fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout);
+
+    /// Called before the node is rounded with its parent at the (unrounded) absolute position `parent_position`.
+    /// Returning `false` skips rounding the node and all of its descendants, which is only correct if neither the node
+    /// nor any descendant has had its unrounded layout changed since it was last rounded at the same `parent_position`.
+    ///
+    /// The default implementation always returns `true`.
+    fn needs_rounding(&mut self, _node_id: NodeId, _parent_position: Point<Scalar>) -> bool {
+        true
+    }
 }
 
 /// Trait used by the `print_tree` method which prints a debug representation
@@ -236,6 +246,16 @@ pub trait PrintTree: TraverseTree {
     fn get_debug_label(&self, node_id: NodeId) -> &'static str;
     /// Get a reference to the node's final layout
     fn get_final_layout(&self, node_id: NodeId) -> &Layout;
+    /// Get a reference to the node's style, which is summarised in verbose debug output
+    #[inline(always)]
+    fn get_debug_style(&self, _node_id: NodeId) -> Option<&Style> {
+        None
+    }
+    /// Get a reference to the node's layout cache, whose state is included in verbose debug output
+    #[inline(always)]
+    fn get_debug_cache(&self, _node_id: NodeId) -> Option<&Cache> {
+        None
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -316,13 +336,13 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
     fn measure_child_size(
         &mut self,
         node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        parent_size: Size<Option<f32>>,
+        known_dimensions: Size<Option<Scalar>>,
+        parent_size: Size<Option<Scalar>>,
         available_space: Size<AvailableSpace>,
         sizing_mode: SizingMode,
         axis: AbsoluteAxis,
         vertical_margins_are_collapsible: Line<bool>,
-    ) -> f32 {
+    ) -> Scalar {
         self.compute_child_layout(
             node_id,
             LayoutInput {
@@ -344,8 +364,8 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
     fn perform_child_layout(
         &mut self,
         node_id: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        parent_size: Size<Option<f32>>,
+        known_dimensions: Size<Option<Scalar>>,
+        parent_size: Size<Option<Scalar>>,
         available_space: Size<AvailableSpace>,
         sizing_mode: SizingMode,
         vertical_margins_are_collapsible: Line<bool>,
@@ -366,7 +386,7 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
 
     /// Alias to `resolve_calc_value` with a shorter function name
     #[inline(always)]
-    fn calc(&self, val: u64, basis: f32) -> f32 {
+    fn calc(&self, val: u64, basis: Scalar) -> Scalar {
         self.resolve_calc_value(val, basis)
     }
 }
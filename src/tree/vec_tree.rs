@@ -0,0 +1,660 @@
+//! Contains [`VecTree`](crate::tree::VecTree): a lightweight tree whose nodes are stored in a `Vec` and identified by
+//! their index in it
+//!
+//! As well as being usable in its own right, this module is written using only Taffy's public low-level API, so that it
+//! can serve as an example of implementing the layout traits over an index-based tree in safe Rust.
+use crate::compute::{
+    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout, LayoutScratch,
+};
+use crate::geometry::{Scalar, Size};
+use crate::style::{AvailableSpace, Display, Style};
+use crate::tree::{
+    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, MeasureOutput, NodeId, PrintTree, RoundTree, RunMode,
+    TaffyError, TaffyResult, TraversePartialTree, TraverseTree,
+};
+use crate::util::sys::{ChildrenVec, Vec};
+use crate::CacheTree;
+#[cfg(feature = "block_layout")]
+use crate::{compute::compute_block_layout, LayoutBlockContainer};
+#[cfg(feature = "flexbox")]
+use crate::{compute::compute_flexbox_layout, LayoutFlexboxContainer};
+#[cfg(feature = "grid")]
+use crate::{compute::compute_grid_layout, LayoutGridContainer};
+
+/// The data stored for each node of a [`VecTree`]
+#[derive(Debug, Clone)]
+struct NodeData<NodeContext> {
+    /// The node's style
+    style: Style,
+    /// The context data associated with the node, which is passed to the measure function
+    context: Option<NodeContext>,
+    /// The node's children
+    children: ChildrenVec<NodeId>,
+    /// The node's parent, if it has one
+    parent: Option<NodeId>,
+    /// The cached results of laying out the node
+    cache: Cache,
+    /// The unrounded result of laying out the node
+    unrounded_layout: Layout,
+    /// The result of laying out the node, after rounding if it is enabled
+    final_layout: Layout,
+}
+
+impl<NodeContext> NodeData<NodeContext> {
+    /// Creates the data for a new node without children
+    fn new(style: Style, context: Option<NodeContext>) -> Self {
+        Self {
+            style,
+            context,
+            children: ChildrenVec::new(),
+            parent: None,
+            cache: Cache::new(),
+            unrounded_layout: Layout::new(),
+            final_layout: Layout::new(),
+        }
+    }
+}
+
+/// A tree of nodes stored in a `Vec`, whose ids are their indices in it. A lighter-weight alternative to
+/// [`TaffyTree`](crate::TaffyTree) for trees that are built once and then laid out, possibly many times.
+///
+/// Nodes can't be removed individually, so ids are never reused; [`clear`](Self::clear) removes every node. Unlike
+/// [`TaffyTree`](crate::TaffyTree), methods that are passed a node that isn't in the tree return an error rather than
+/// panicking.
+///
+/// `calc()` values resolve to zero, and layout is rounded with the default [`RoundingOptions`](crate::RoundingOptions)
+/// unless rounding is disabled.
+#[derive(Debug, Clone)]
+pub struct VecTree<NodeContext = ()> {
+    /// The nodes of the tree, indexed by their ids
+    nodes: Vec<NodeData<NodeContext>>,
+    /// Whether layout is rounded to whole pixels
+    use_rounding: bool,
+    /// Scratch buffers borrowed by the layout algorithms, which are reused from one layout to the next
+    scratch: LayoutScratch,
+}
+
+impl<NodeContext> Default for VecTree<NodeContext> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NodeContext> VecTree<NodeContext> {
+    /// Creates a new, empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(16)
+    }
+
+    /// Creates a new, empty tree that can store `capacity` nodes before reallocating
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { nodes: Vec::with_capacity(capacity), use_rounding: true, scratch: LayoutScratch::new() }
+    }
+
+    /// Enables rounding of layout values. Rounding is enabled by default.
+    pub fn enable_rounding(&mut self) {
+        self.use_rounding = true;
+    }
+
+    /// Disables rounding of layout values
+    pub fn disable_rounding(&mut self) {
+        self.use_rounding = false;
+    }
+
+    /// Returns the data of a node, or `error` if it isn't in the tree
+    fn node(&self, node: NodeId, error: fn(NodeId) -> TaffyError) -> TaffyResult<&NodeData<NodeContext>> {
+        self.nodes.get(usize::from(node)).ok_or(error(node))
+    }
+
+    /// Returns the mutable data of a node, or `error` if it isn't in the tree
+    fn node_mut(&mut self, node: NodeId, error: fn(NodeId) -> TaffyError) -> TaffyResult<&mut NodeData<NodeContext>> {
+        self.nodes.get_mut(usize::from(node)).ok_or(error(node))
+    }
+
+    /// Adds a node to the tree and returns its id
+    fn push(&mut self, data: NodeData<NodeContext>) -> NodeId {
+        self.nodes.push(data);
+        NodeId::from(self.nodes.len() - 1)
+    }
+
+    /// Creates and adds a new node without children, and returns its id
+    pub fn new_leaf(&mut self, style: Style) -> TaffyResult<NodeId> {
+        Ok(self.push(NodeData::new(style, None)))
+    }
+
+    /// Creates and adds a new node without children that has context data, which is passed to the measure function
+    /// when the node is measured, and returns its id
+    pub fn new_leaf_with_context(&mut self, style: Style, context: NodeContext) -> TaffyResult<NodeId> {
+        Ok(self.push(NodeData::new(style, Some(context))))
+    }
+
+    /// Creates and adds a new node with the given children, and returns its id. The children are removed from their
+    /// previous parents.
+    pub fn new_with_children(&mut self, style: Style, children: &[NodeId]) -> TaffyResult<NodeId> {
+        for &child in children {
+            self.node(child, TaffyError::InvalidChildNode)?;
+        }
+        let node = self.push(NodeData::new(style, None));
+        self.set_children(node, children)?;
+        Ok(node)
+    }
+
+    /// Removes every node from the tree
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Returns the number of nodes in the tree
+    pub fn total_node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Appends `child` to the children of `parent`, removing it from its previous parent
+    pub fn add_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        self.node(parent, TaffyError::InvalidParentNode)?;
+        self.detach(child)?;
+        self.nodes[usize::from(child)].parent = Some(parent);
+        self.nodes[usize::from(parent)].children.push(child);
+        self.mark_dirty(parent)
+    }
+
+    /// Replaces the children of `parent`, removing each of them from its previous parent
+    pub fn set_children(&mut self, parent: NodeId, children: &[NodeId]) -> TaffyResult<()> {
+        self.node(parent, TaffyError::InvalidParentNode)?;
+        for &child in children {
+            self.node(child, TaffyError::InvalidChildNode)?;
+        }
+        for child in core::mem::take(&mut self.nodes[usize::from(parent)].children) {
+            self.nodes[usize::from(child)].parent = None;
+        }
+        for &child in children {
+            self.detach(child)?;
+            self.nodes[usize::from(child)].parent = Some(parent);
+        }
+        self.nodes[usize::from(parent)].children = children.to_vec();
+        self.mark_dirty(parent)
+    }
+
+    /// Removes `child` from the children of its parent, if it has one
+    fn detach(&mut self, child: NodeId) -> TaffyResult<()> {
+        if let Some(parent) = self.node_mut(child, TaffyError::InvalidChildNode)?.parent.take() {
+            self.nodes[usize::from(parent)].children.retain(|&sibling| sibling != child);
+            self.mark_dirty(parent)?;
+        }
+        Ok(())
+    }
+
+    /// Removes `child` from the children of `parent`, without removing it from the tree
+    pub fn remove_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
+        self.node(parent, TaffyError::InvalidParentNode)?;
+        if self.node(child, TaffyError::InvalidChildNode)?.parent == Some(parent) {
+            self.detach(child)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the children of a node
+    pub fn children(&self, parent: NodeId) -> TaffyResult<&[NodeId]> {
+        Ok(&self.node(parent, TaffyError::InvalidParentNode)?.children)
+    }
+
+    /// Returns the parent of a node, if it has one
+    pub fn parent(&self, child: NodeId) -> Option<NodeId> {
+        self.nodes.get(usize::from(child)).and_then(|child| child.parent)
+    }
+
+    /// Returns the style of a node
+    pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
+        Ok(&self.node(node, TaffyError::InvalidInputNode)?.style)
+    }
+
+    /// Sets the style of a node, and marks it dirty
+    pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
+        self.node_mut(node, TaffyError::InvalidInputNode)?.style = style;
+        self.mark_dirty(node)
+    }
+
+    /// Returns the context data of a node, if it has any
+    pub fn get_node_context(&self, node: NodeId) -> Option<&NodeContext> {
+        self.nodes.get(usize::from(node)).and_then(|node| node.context.as_ref())
+    }
+
+    /// Returns the mutable context data of a node, if it has any
+    pub fn get_node_context_mut(&mut self, node: NodeId) -> Option<&mut NodeContext> {
+        self.nodes.get_mut(usize::from(node)).and_then(|node| node.context.as_mut())
+    }
+
+    /// Sets or removes the context data of a node, and marks it dirty
+    pub fn set_node_context(&mut self, node: NodeId, context: Option<NodeContext>) -> TaffyResult<()> {
+        self.node_mut(node, TaffyError::InvalidInputNode)?.context = context;
+        self.mark_dirty(node)
+    }
+
+    /// Marks the layout of a node and its ancestors as outdated, so that they are laid out again by the next call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    pub fn mark_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
+        self.node(node, TaffyError::InvalidInputNode)?;
+        let mut next = Some(node);
+        while let Some(node) = next {
+            let data = &mut self.nodes[usize::from(node)];
+            data.cache.clear();
+            next = data.parent;
+        }
+        Ok(())
+    }
+
+    /// Returns the layout of a node computed by the last call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure), which is rounded if rounding is enabled
+    pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        let node = self.node(node, TaffyError::InvalidInputNode)?;
+        Ok(if self.use_rounding { &node.final_layout } else { &node.unrounded_layout })
+    }
+
+    /// Returns the unrounded layout of a node computed by the last call to
+    /// [`compute_layout_with_measure`](Self::compute_layout_with_measure)
+    pub fn unrounded_layout(&self, node: NodeId) -> TaffyResult<&Layout> {
+        Ok(&self.node(node, TaffyError::InvalidInputNode)?.unrounded_layout)
+    }
+
+    /// Computes the layout of the subtree rooted at `node` in the given available space, calling `measure_function`
+    /// to measure each leaf node
+    pub fn compute_layout_with_measure<MeasureFunction, MeasureResult>(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<()>
+    where
+        MeasureFunction: FnMut(
+            Size<Option<Scalar>>,
+            Size<AvailableSpace>,
+            NodeId,
+            Option<&mut NodeContext>,
+            &Style,
+        ) -> MeasureResult,
+        MeasureResult: Into<MeasureOutput>,
+    {
+        self.node(node, TaffyError::InvalidInputNode)?;
+        let use_rounding = self.use_rounding;
+        let mut view = VecTreeView { tree: self, measure_function };
+        compute_root_layout(&mut view, node, available_space);
+        if use_rounding {
+            round_layout(&mut view, node);
+        }
+        Ok(())
+    }
+
+    /// Computes the layout of the subtree rooted at `node` in the given available space, measuring every leaf node
+    /// as zero-sized
+    pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<()> {
+        self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Prints a debug representation of the tree's layout
+    #[cfg(feature = "std")]
+    pub fn print_tree(&mut self, root: NodeId) {
+        crate::util::print_tree(self, root)
+    }
+}
+
+/// Iterator over the ids of the children of a node of a [`VecTree`]
+pub struct VecTreeChildIter<'a>(core::slice::Iter<'a, NodeId>);
+
+impl Iterator for VecTreeChildIter<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().copied()
+    }
+}
+
+impl<NodeContext> TraversePartialTree for VecTree<NodeContext> {
+    type ChildIter<'a>
+        = VecTreeChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        VecTreeChildIter(self.nodes[usize::from(parent_node_id)].children.iter())
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.nodes[usize::from(parent_node_id)].children.len()
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.nodes[usize::from(parent_node_id)].children[child_index]
+    }
+}
+
+impl<NodeContext> TraverseTree for VecTree<NodeContext> {}
+
+impl<NodeContext> PrintTree for VecTree<NodeContext> {
+    fn get_debug_label(&self, node_id: NodeId) -> &'static str {
+        let node = &self.nodes[usize::from(node_id)];
+        match (node.children.len(), node.style.display) {
+            (_, Display::None) => "NONE",
+            (0, _) => "LEAF",
+            #[cfg(feature = "block_layout")]
+            (_, Display::Block) => "BLOCK",
+            #[cfg(feature = "block_layout")]
+            (_, Display::FlowRoot) => "FLOW ROOT",
+            #[cfg(feature = "flexbox")]
+            (_, Display::Flex) => "FLEX",
+            #[cfg(feature = "grid")]
+            (_, Display::Grid) => "GRID",
+        }
+    }
+
+    #[inline(always)]
+    fn get_final_layout(&self, node_id: NodeId) -> &Layout {
+        let node = &self.nodes[usize::from(node_id)];
+        if self.use_rounding {
+            &node.final_layout
+        } else {
+            &node.unrounded_layout
+        }
+    }
+
+    #[inline(always)]
+    fn get_debug_style(&self, node_id: NodeId) -> Option<&Style> {
+        Some(&self.nodes[usize::from(node_id)].style)
+    }
+
+    #[inline(always)]
+    fn get_debug_cache(&self, node_id: NodeId) -> Option<&Cache> {
+        Some(&self.nodes[usize::from(node_id)].cache)
+    }
+}
+
+/// A [`VecTree`] together with the measure function that its leaves are measured with during a layout, which
+/// implements the layout traits
+struct VecTreeView<'t, NodeContext, MeasureFunction> {
+    /// The tree being laid out
+    tree: &'t mut VecTree<NodeContext>,
+    /// The function that measures leaf nodes
+    measure_function: MeasureFunction,
+}
+
+impl<NodeContext, MeasureFunction> VecTreeView<'_, NodeContext, MeasureFunction> {
+    /// Returns the data of a node that the layout algorithms were passed
+    #[inline(always)]
+    fn node(&self, node_id: NodeId) -> &NodeData<NodeContext> {
+        &self.tree.nodes[usize::from(node_id)]
+    }
+
+    /// Returns the mutable data of a node that the layout algorithms were passed
+    #[inline(always)]
+    fn node_mut(&mut self, node_id: NodeId) -> &mut NodeData<NodeContext> {
+        &mut self.tree.nodes[usize::from(node_id)]
+    }
+}
+
+impl<NodeContext, MeasureFunction> TraversePartialTree for VecTreeView<'_, NodeContext, MeasureFunction> {
+    type ChildIter<'a>
+        = VecTreeChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.tree.child_ids(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.tree.child_count(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.tree.get_child_id(parent_node_id, child_index)
+    }
+}
+
+impl<NodeContext, MeasureFunction> TraverseTree for VecTreeView<'_, NodeContext, MeasureFunction> {}
+
+impl<NodeContext, MeasureFunction> CacheTree for VecTreeView<'_, NodeContext, MeasureFunction> {
+    #[inline(always)]
+    fn cache_get(&self, node_id: NodeId, inputs: &LayoutInput) -> Option<LayoutOutput> {
+        self.node(node_id).cache.get(inputs)
+    }
+
+    #[inline(always)]
+    fn cache_store(&mut self, node_id: NodeId, inputs: &LayoutInput, layout_output: LayoutOutput) {
+        self.node_mut(node_id).cache.store(inputs, layout_output)
+    }
+
+    #[inline(always)]
+    fn cache_clear(&mut self, node_id: NodeId) {
+        self.node_mut(node_id).cache.clear()
+    }
+}
+
+impl<NodeContext, MeasureFunction, MeasureResult> LayoutPartialTree for VecTreeView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type CoreContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn resolve_calc_value(&self, _val: u64, _basis: Scalar) -> Scalar {
+        0.0
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_mut(node_id).unrounded_layout = *layout;
+    }
+
+    fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        // A `Display::None` ancestor hides the node, whatever its own display style
+        if inputs.run_mode == RunMode::PerformHiddenLayout {
+            return compute_hidden_layout(self, node_id);
+        }
+
+        compute_cached_layout(self, node_id, inputs, |view, node_id, inputs| {
+            let node = view.node(node_id);
+            match (node.style.display, !node.children.is_empty()) {
+                (Display::None, _) => compute_hidden_layout(view, node_id),
+                #[cfg(feature = "block_layout")]
+                (Display::Block | Display::FlowRoot, true) => compute_block_layout(view, node_id, inputs),
+                #[cfg(feature = "flexbox")]
+                (Display::Flex, true) => compute_flexbox_layout(view, node_id, inputs),
+                #[cfg(feature = "grid")]
+                (Display::Grid, true) => compute_grid_layout(view, node_id, inputs),
+                (_, false) => {
+                    let NodeData { style, context, .. } = &mut view.tree.nodes[usize::from(node_id)];
+                    let style = &*style;
+                    let measure_function = &mut view.measure_function;
+                    compute_leaf_layout(
+                        inputs,
+                        style,
+                        |_val, _basis| 0.0,
+                        |known_dimensions, available_space| {
+                            measure_function(known_dimensions, available_space, node_id, context.as_mut(), style)
+                        },
+                    )
+                }
+            }
+        })
+    }
+
+    #[inline(always)]
+    fn scratch(&mut self) -> Option<&mut LayoutScratch> {
+        Some(&mut self.tree.scratch)
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<NodeContext, MeasureFunction, MeasureResult> LayoutBlockContainer for VecTreeView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type BlockContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type BlockItemStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_block_container_style(&self, node_id: NodeId) -> Self::BlockContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_> {
+        &self.node(child_node_id).style
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<NodeContext, MeasureFunction, MeasureResult> LayoutFlexboxContainer
+    for VecTreeView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type FlexboxContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type FlexboxItemStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_flexbox_container_style(&self, node_id: NodeId) -> Self::FlexboxContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_> {
+        &self.node(child_node_id).style
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<NodeContext, MeasureFunction, MeasureResult> LayoutGridContainer for VecTreeView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<Scalar>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureResult,
+    MeasureResult: Into<MeasureOutput>,
+{
+    type GridContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type GridItemStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_grid_container_style(&self, node_id: NodeId) -> Self::GridContainerStyle<'_> {
+        &self.node(node_id).style
+    }
+
+    #[inline(always)]
+    fn get_grid_child_style(&self, child_node_id: NodeId) -> Self::GridItemStyle<'_> {
+        &self.node(child_node_id).style
+    }
+}
+
+impl<NodeContext, MeasureFunction> RoundTree for VecTreeView<'_, NodeContext, MeasureFunction> {
+    #[inline(always)]
+    fn get_unrounded_layout(&self, node_id: NodeId) -> &Layout {
+        &self.node(node_id).unrounded_layout
+    }
+
+    #[inline(always)]
+    fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_mut(node_id).final_layout = *layout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VecTree;
+    use crate::prelude::*;
+    use crate::tree::TaffyError;
+
+    #[test]
+    fn vec_tree_lays_out_measured_leaves() {
+        let mut tree: VecTree<Size<Scalar>> = VecTree::new();
+        let text = tree.new_leaf_with_context(Style { flex_grow: 1.0, ..Style::DEFAULT }, Size::length(30.0)).unwrap();
+        let fixed = tree.new_leaf(Style { size: Size::from_lengths(50.0, 20.0), ..Style::DEFAULT }).unwrap();
+        let root = tree
+            .new_with_children(Style { size: Size::from_lengths(200.0, 40.0), ..Style::DEFAULT }, &[fixed, text])
+            .unwrap();
+
+        let measure = |known: Size<Option<Scalar>>, _, _, context: Option<&mut Size<Scalar>>, _: &Style| {
+            known.unwrap_or(context.copied().unwrap_or(Size::ZERO))
+        };
+        tree.compute_layout_with_measure(root, Size::MAX_CONTENT, measure).unwrap();
+        assert_eq!(tree.layout(fixed).unwrap().size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(tree.layout(text).unwrap().location.x, 50.0);
+        assert_eq!(tree.layout(text).unwrap().size, Size { width: 150.0, height: 40.0 });
+
+        // Changing a node's context invalidates the cached layouts of it and its ancestors
+        tree.set_style(text, Style::DEFAULT).unwrap();
+        tree.set_node_context(text, Some(Size::length(10.0))).unwrap();
+        tree.compute_layout_with_measure(root, Size::MAX_CONTENT, measure).unwrap();
+        assert_eq!(tree.layout(text).unwrap().size, Size { width: 10.0, height: 40.0 });
+    }
+
+    #[test]
+    fn vec_tree_moves_children_between_parents() {
+        let mut tree: VecTree = VecTree::new();
+        let child = tree.new_leaf(Style::DEFAULT).unwrap();
+        let first = tree.new_with_children(Style::DEFAULT, &[child]).unwrap();
+        let second = tree.new_leaf(Style::DEFAULT).unwrap();
+
+        tree.add_child(second, child).unwrap();
+        assert_eq!(tree.children(first).unwrap(), &[]);
+        assert_eq!(tree.children(second).unwrap(), &[child]);
+        assert_eq!(tree.parent(child), Some(second));
+
+        tree.remove_child(second, child).unwrap();
+        assert_eq!(tree.children(second).unwrap(), &[]);
+        assert_eq!(tree.parent(child), None);
+    }
+
+    #[test]
+    fn vec_tree_rejects_nodes_that_are_not_in_the_tree() {
+        let mut tree: VecTree = VecTree::new();
+        let node = tree.new_leaf(Style::DEFAULT).unwrap();
+        let missing = NodeId::from(1_usize);
+
+        assert_eq!(tree.new_with_children(Style::DEFAULT, &[missing]), Err(TaffyError::InvalidChildNode(missing)));
+        assert_eq!(tree.add_child(missing, node), Err(TaffyError::InvalidParentNode(missing)));
+        assert_eq!(tree.layout(missing), Err(TaffyError::InvalidInputNode(missing)));
+        assert_eq!(tree.compute_layout(missing, Size::MAX_CONTENT), Err(TaffyError::InvalidInputNode(missing)));
+
+        tree.clear();
+        assert_eq!(tree.total_node_count(), 0);
+        assert_eq!(tree.style(node), Err(TaffyError::InvalidInputNode(node)));
+    }
+}
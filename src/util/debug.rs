@@ -67,26 +67,36 @@ impl DebugLogger {
 #[cfg(any(feature = "debug", feature = "profile"))]
 pub(crate) static NODE_LOGGER: DebugLogger = DebugLogger::new();
 
+/// Logs a message for the node being laid out. With the `tracing` feature, each message is emitted as a trace-level
+/// event within the node's `layout` span.
 macro_rules! debug_log {
     // String literal label with debug printing
     ($label:literal, dbg:$item:expr) => {{
         #[cfg(feature = "debug")]
         $crate::util::debug::NODE_LOGGER.labelled_debug_log($label, $item);
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!(value = ?$item, "{}", $label);
     }};
     // String literal label with display printing
     ($label:literal, $item:expr) => {{
         #[cfg(feature = "debug")]
         $crate::util::debug::NODE_LOGGER.labelled_log($label, $item);
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!(value = %$item, "{}", $label);
     }};
     // Debug printing
     (dbg:$item:expr) => {{
         #[cfg(feature = "debug")]
         $crate::util::debug::NODE_LOGGER.debug_log($item);
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!("{:?}", $item);
     }};
     // Display printing
     ($item:expr) => {{
         #[cfg(feature = "debug")]
         $crate::util::debug::NODE_LOGGER.log($item);
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!("{}", $item);
     }};
     // Blank newline
     () => {{
@@ -105,14 +115,33 @@ macro_rules! debug_log_node {
     };
 }
 
+/// Marks the start of the layout of a node. With the `tracing` feature, this enters a `layout` span for the node which
+/// is exited at the end of the enclosing block.
 macro_rules! debug_push_node {
-    ($node_id:expr) => {
+    ($node_id:expr, $run_mode:expr) => {
         #[cfg(any(feature = "debug", feature = "profile"))]
         $crate::util::debug::NODE_LOGGER.push_node($node_id);
+        #[cfg(feature = "debug")]
         debug_log!("");
+        #[cfg(feature = "tracing")]
+        let _span_guard = ::tracing::trace_span!(
+            "layout",
+            node = ?$node_id,
+            run_mode = ?$run_mode,
+            algorithm = ::tracing::field::Empty
+        )
+        .entered();
     };
 }
 
+/// Records the algorithm used to lay out the node on its `layout` span, with the `tracing` feature
+macro_rules! debug_record_algorithm {
+    ($algorithm:literal) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::Span::current().record("algorithm", $algorithm);
+    }};
+}
+
 macro_rules! debug_pop_node {
     () => {
         #[cfg(any(feature = "debug", feature = "profile"))]
@@ -140,4 +169,4 @@ macro_rules! time {
 }
 
 #[allow(unused_imports)]
-pub(crate) use {debug_log, debug_log_node, debug_pop_node, debug_push_node, time};
+pub(crate) use {debug_log, debug_log_node, debug_pop_node, debug_push_node, debug_record_algorithm, time};
@@ -0,0 +1,104 @@
+//! Contains the export_dot function for writing a tree as a Graphviz DOT graph
+use crate::style::Display;
+use crate::tree::{NodeId, PrintTree};
+use core::fmt::{Result, Write};
+
+/// Options controlling the annotations added to each node of the graph written by [`export_dot_with_options`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DotOptions {
+    /// Whether to fill each node with a color identifying the layout algorithm used to lay it out: blue for flexbox,
+    /// green for grid, yellow for block and grey for leaves. Requires the tree to provide each node's style (see
+    /// [`PrintTree::get_debug_style`]).
+    pub color_by_algorithm: bool,
+    /// Whether to outline dirty nodes in red. Requires the tree to provide each node's cache (see
+    /// [`PrintTree::get_debug_cache`]).
+    pub mark_dirty: bool,
+}
+
+/// Writes a tree of nodes, starting with the passed root node, to `out` as a Graphviz DOT graph.
+///
+/// Each node is labelled with its debug label, its id, and the size and location of its computed layout. Each parent
+/// has an edge to each of its children. The output can be rendered with Graphviz (e.g. `dot -Tsvg tree.dot`).
+pub fn export_dot(tree: &impl PrintTree, root: NodeId, out: &mut impl Write) -> Result {
+    export_dot_with_options(tree, root, DotOptions::default(), out)
+}
+
+/// Writes a tree of nodes, starting with the passed root node, to `out` as a Graphviz DOT graph, annotating each node as
+/// selected by `options`. See [`export_dot`].
+pub fn export_dot_with_options(
+    tree: &impl PrintTree,
+    root: NodeId,
+    options: DotOptions,
+    out: &mut impl Write,
+) -> Result {
+    writeln!(out, "digraph tree {{")?;
+    writeln!(out, "    node [shape=box];")?;
+
+    // As in `format_tree`, the tree is traversed with an explicit stack so that very deep trees can't overflow the
+    // call stack. Nodes are written before their children, and children in order.
+    let mut stack = vec![root];
+    while let Some(node_id) = stack.pop() {
+        let layout = tree.get_final_layout(node_id);
+        let id = u64::from(node_id);
+
+        write!(out, "    n{id} [label=\"")?;
+        write_escaped(tree.get_debug_label(node_id), out)?;
+        write!(
+            out,
+            "\\nid: {id}\\nsize: {w} x {h}\\nlocation: {x}, {y}\"",
+            w = layout.size.width,
+            h = layout.size.height,
+            x = layout.location.x,
+            y = layout.location.y,
+        )?;
+        if options.color_by_algorithm {
+            if let Some(color) = algorithm_color(tree, node_id) {
+                write!(out, ", style=filled, fillcolor={color}")?;
+            }
+        }
+        if options.mark_dirty && tree.get_debug_cache(node_id).map_or(false, |cache| cache.is_empty()) {
+            write!(out, ", color=red, penwidth=2")?;
+        }
+        writeln!(out, "];")?;
+
+        for child in tree.child_ids(node_id) {
+            writeln!(out, "    n{id} -> n{};", u64::from(child))?;
+        }
+
+        // Push the children in reverse so that they are written in order
+        let children: Vec<NodeId> = tree.child_ids(node_id).collect();
+        stack.extend(children.into_iter().rev());
+    }
+
+    writeln!(out, "}}")
+}
+
+/// The fill color of a node identifying the algorithm used to lay it out, if the tree provides the node's style.
+/// Hidden nodes aren't laid out by any algorithm, so aren't colored.
+fn algorithm_color(tree: &impl PrintTree, node_id: NodeId) -> Option<&'static str> {
+    let style = tree.get_debug_style(node_id)?;
+    if style.display != Display::None && tree.child_count(node_id) == 0 {
+        return Some("lightgrey");
+    }
+    match style.display {
+        Display::None => None,
+        #[cfg(feature = "block_layout")]
+        Display::Block | Display::FlowRoot => Some("lightyellow"),
+        #[cfg(feature = "flexbox")]
+        Display::Flex => Some("lightblue"),
+        #[cfg(feature = "grid")]
+        Display::Grid => Some("lightgreen"),
+    }
+}
+
+/// Writes a string escaped for use within a quoted DOT string
+fn write_escaped(value: &str, out: &mut impl Write) -> Result {
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => write!(out, "\\{c}")?,
+            '\n' => out.write_str("\\n")?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 //! Contains numerical helper traits and functions
 #![allow(clippy::manual_clamp)]
 
-use crate::geometry::Size;
+use crate::geometry::{Scalar, Size};
 use crate::style::AvailableSpace;
 
 /// A trait to conveniently calculate minimums and maximums when some data may not be defined
@@ -25,8 +25,8 @@ pub trait MaybeMath<In, Out> {
     fn maybe_sub(self, rhs: In) -> Out;
 }
 
-impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
-    fn maybe_min(self, rhs: Option<f32>) -> Option<f32> {
+impl MaybeMath<Option<Scalar>, Option<Scalar>> for Option<Scalar> {
+    fn maybe_min(self, rhs: Option<Scalar>) -> Option<Scalar> {
         match (self, rhs) {
             (Some(l), Some(r)) => Some(l.min(r)),
             (Some(_l), None) => self,
@@ -35,7 +35,7 @@ impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
         }
     }
 
-    fn maybe_max(self, rhs: Option<f32>) -> Option<f32> {
+    fn maybe_max(self, rhs: Option<Scalar>) -> Option<Scalar> {
         match (self, rhs) {
             (Some(l), Some(r)) => Some(l.max(r)),
             (Some(_l), None) => self,
@@ -44,7 +44,7 @@ impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
         }
     }
 
-    fn maybe_clamp(self, min: Option<f32>, max: Option<f32>) -> Option<f32> {
+    fn maybe_clamp(self, min: Option<Scalar>, max: Option<Scalar>) -> Option<Scalar> {
         match (self, min, max) {
             (Some(base), Some(min), Some(max)) => Some(base.min(max).max(min)),
             (Some(base), None, Some(max)) => Some(base.min(max)),
@@ -54,7 +54,7 @@ impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
         }
     }
 
-    fn maybe_add(self, rhs: Option<f32>) -> Option<f32> {
+    fn maybe_add(self, rhs: Option<Scalar>) -> Option<Scalar> {
         match (self, rhs) {
             (Some(l), Some(r)) => Some(l + r),
             (Some(_l), None) => self,
@@ -63,7 +63,7 @@ impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
         }
     }
 
-    fn maybe_sub(self, rhs: Option<f32>) -> Option<f32> {
+    fn maybe_sub(self, rhs: Option<Scalar>) -> Option<Scalar> {
         match (self, rhs) {
             (Some(l), Some(r)) => Some(l - r),
             (Some(_l), None) => self,
@@ -73,44 +73,44 @@ impl MaybeMath<Option<f32>, Option<f32>> for Option<f32> {
     }
 }
 
-impl MaybeMath<f32, Option<f32>> for Option<f32> {
-    fn maybe_min(self, rhs: f32) -> Option<f32> {
+impl MaybeMath<Scalar, Option<Scalar>> for Option<Scalar> {
+    fn maybe_min(self, rhs: Scalar) -> Option<Scalar> {
         self.map(|val| val.min(rhs))
     }
 
-    fn maybe_max(self, rhs: f32) -> Option<f32> {
+    fn maybe_max(self, rhs: Scalar) -> Option<Scalar> {
         self.map(|val| val.max(rhs))
     }
 
-    fn maybe_clamp(self, min: f32, max: f32) -> Option<f32> {
+    fn maybe_clamp(self, min: Scalar, max: Scalar) -> Option<Scalar> {
         self.map(|val| val.min(max).max(min))
     }
 
-    fn maybe_add(self, rhs: f32) -> Option<f32> {
+    fn maybe_add(self, rhs: Scalar) -> Option<Scalar> {
         self.map(|val| val + rhs)
     }
 
-    fn maybe_sub(self, rhs: f32) -> Option<f32> {
+    fn maybe_sub(self, rhs: Scalar) -> Option<Scalar> {
         self.map(|val| val - rhs)
     }
 }
 
-impl MaybeMath<Option<f32>, f32> for f32 {
-    fn maybe_min(self, rhs: Option<f32>) -> f32 {
+impl MaybeMath<Option<Scalar>, Scalar> for Scalar {
+    fn maybe_min(self, rhs: Option<Scalar>) -> Scalar {
         match rhs {
             Some(val) => self.min(val),
             None => self,
         }
     }
 
-    fn maybe_max(self, rhs: Option<f32>) -> f32 {
+    fn maybe_max(self, rhs: Option<Scalar>) -> Scalar {
         match rhs {
             Some(val) => self.max(val),
             None => self,
         }
     }
 
-    fn maybe_clamp(self, min: Option<f32>, max: Option<f32>) -> f32 {
+    fn maybe_clamp(self, min: Option<Scalar>, max: Option<Scalar>) -> Scalar {
         match (min, max) {
             (Some(min), Some(max)) => self.min(max).max(min),
             (None, Some(max)) => self.min(max),
@@ -119,14 +119,14 @@ impl MaybeMath<Option<f32>, f32> for f32 {
         }
     }
 
-    fn maybe_add(self, rhs: Option<f32>) -> f32 {
+    fn maybe_add(self, rhs: Option<Scalar>) -> Scalar {
         match rhs {
             Some(val) => self + val,
             None => self,
         }
     }
 
-    fn maybe_sub(self, rhs: Option<f32>) -> f32 {
+    fn maybe_sub(self, rhs: Option<Scalar>) -> Scalar {
         match rhs {
             Some(val) => self - val,
             None => self,
@@ -134,15 +134,15 @@ impl MaybeMath<Option<f32>, f32> for f32 {
     }
 }
 
-impl MaybeMath<f32, AvailableSpace> for AvailableSpace {
-    fn maybe_min(self, rhs: f32) -> AvailableSpace {
+impl MaybeMath<Scalar, AvailableSpace> for AvailableSpace {
+    fn maybe_min(self, rhs: Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(val) => AvailableSpace::Definite(val.min(rhs)),
             AvailableSpace::MinContent => AvailableSpace::Definite(rhs),
             AvailableSpace::MaxContent => AvailableSpace::Definite(rhs),
         }
     }
-    fn maybe_max(self, rhs: f32) -> AvailableSpace {
+    fn maybe_max(self, rhs: Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(val) => AvailableSpace::Definite(val.max(rhs)),
             AvailableSpace::MinContent => AvailableSpace::MinContent,
@@ -150,7 +150,7 @@ impl MaybeMath<f32, AvailableSpace> for AvailableSpace {
         }
     }
 
-    fn maybe_clamp(self, min: f32, max: f32) -> AvailableSpace {
+    fn maybe_clamp(self, min: Scalar, max: Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(val) => AvailableSpace::Definite(val.min(max).max(min)),
             AvailableSpace::MinContent => AvailableSpace::MinContent,
@@ -158,14 +158,14 @@ impl MaybeMath<f32, AvailableSpace> for AvailableSpace {
         }
     }
 
-    fn maybe_add(self, rhs: f32) -> AvailableSpace {
+    fn maybe_add(self, rhs: Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(val) => AvailableSpace::Definite(val + rhs),
             AvailableSpace::MinContent => AvailableSpace::MinContent,
             AvailableSpace::MaxContent => AvailableSpace::MaxContent,
         }
     }
-    fn maybe_sub(self, rhs: f32) -> AvailableSpace {
+    fn maybe_sub(self, rhs: Scalar) -> AvailableSpace {
         match self {
             AvailableSpace::Definite(val) => AvailableSpace::Definite(val - rhs),
             AvailableSpace::MinContent => AvailableSpace::MinContent,
@@ -174,8 +174,8 @@ impl MaybeMath<f32, AvailableSpace> for AvailableSpace {
     }
 }
 
-impl MaybeMath<Option<f32>, AvailableSpace> for AvailableSpace {
-    fn maybe_min(self, rhs: Option<f32>) -> AvailableSpace {
+impl MaybeMath<Option<Scalar>, AvailableSpace> for AvailableSpace {
+    fn maybe_min(self, rhs: Option<Scalar>) -> AvailableSpace {
         match (self, rhs) {
             (AvailableSpace::Definite(val), Some(rhs)) => AvailableSpace::Definite(val.min(rhs)),
             (AvailableSpace::Definite(val), None) => AvailableSpace::Definite(val),
@@ -185,7 +185,7 @@ impl MaybeMath<Option<f32>, AvailableSpace> for AvailableSpace {
             (AvailableSpace::MaxContent, None) => AvailableSpace::MaxContent,
         }
     }
-    fn maybe_max(self, rhs: Option<f32>) -> AvailableSpace {
+    fn maybe_max(self, rhs: Option<Scalar>) -> AvailableSpace {
         match (self, rhs) {
             (AvailableSpace::Definite(val), Some(rhs)) => AvailableSpace::Definite(val.max(rhs)),
             (AvailableSpace::Definite(val), None) => AvailableSpace::Definite(val),
@@ -194,7 +194,7 @@ impl MaybeMath<Option<f32>, AvailableSpace> for AvailableSpace {
         }
     }
 
-    fn maybe_clamp(self, min: Option<f32>, max: Option<f32>) -> AvailableSpace {
+    fn maybe_clamp(self, min: Option<Scalar>, max: Option<Scalar>) -> AvailableSpace {
         match (self, min, max) {
             (AvailableSpace::Definite(val), Some(min), Some(max)) => AvailableSpace::Definite(val.min(max).max(min)),
             (AvailableSpace::Definite(val), None, Some(max)) => AvailableSpace::Definite(val.min(max)),
@@ -205,7 +205,7 @@ impl MaybeMath<Option<f32>, AvailableSpace> for AvailableSpace {
         }
     }
 
-    fn maybe_add(self, rhs: Option<f32>) -> AvailableSpace {
+    fn maybe_add(self, rhs: Option<Scalar>) -> AvailableSpace {
         match (self, rhs) {
             (AvailableSpace::Definite(val), Some(rhs)) => AvailableSpace::Definite(val + rhs),
             (AvailableSpace::Definite(val), None) => AvailableSpace::Definite(val),
@@ -213,7 +213,7 @@ impl MaybeMath<Option<f32>, AvailableSpace> for AvailableSpace {
             (AvailableSpace::MaxContent, _) => AvailableSpace::MaxContent,
         }
     }
-    fn maybe_sub(self, rhs: Option<f32>) -> AvailableSpace {
+    fn maybe_sub(self, rhs: Option<Scalar>) -> AvailableSpace {
         match (self, rhs) {
             (AvailableSpace::Definite(val), Some(rhs)) => AvailableSpace::Definite(val - rhs),
             (AvailableSpace::Definite(val), None) => AvailableSpace::Definite(val),
@@ -10,7 +10,15 @@ pub use resolve::{MaybeResolve, ResolveOrZero};
 #[macro_use]
 pub(crate) mod debug;
 
+#[cfg(feature = "std")]
+mod dot;
 #[cfg(feature = "std")]
 mod print;
+#[cfg(feature = "test_utils")]
+mod test_utils;
+#[cfg(feature = "std")]
+pub use dot::{export_dot, export_dot_with_options, DotOptions};
 #[cfg(feature = "std")]
-pub use print::print_tree;
+pub use print::{format_tree, format_tree_with_columns, print_tree, print_tree_verbose, PrintColumns};
+#[cfg(feature = "test_utils")]
+pub use test_utils::assert_layout_matches;
@@ -1,59 +1,148 @@
 //! Contains the print_tree function for printing a debug representation of the tree
-use crate::tree::{NodeId, PrintTree};
+use crate::tree::{Layout, NodeId, PrintTree};
+use core::fmt::{Result, Write};
+
+/// The values of each node's layout that are included in a debug representation of a tree. See [`format_tree_with_columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintColumns {
+    /// Whether to include the node's location
+    pub location: bool,
+    /// Whether to include the node's size
+    pub size: bool,
+    /// Whether to include the size of the node's content. This is ignored unless the `content_size` feature is enabled.
+    pub content_size: bool,
+    /// Whether to include the node's border widths
+    pub border: bool,
+    /// Whether to include the node's padding
+    pub padding: bool,
+    /// Whether to include the node's margins
+    pub margin: bool,
+    /// Whether to include a summary of the node's style (see [`Style::summary`](crate::Style::summary)), if the tree provides its style
+    pub style: bool,
+    /// Whether to include whether the node is dirty and the number of results in its cache, if the tree provides its
+    /// cache
+    pub cache: bool,
+}
+
+impl PrintColumns {
+    /// The columns included by [`format_tree`] and [`print_tree`]: every value of the layout except the margins
+    pub const DEFAULT: Self = Self {
+        location: true,
+        size: true,
+        content_size: true,
+        border: true,
+        padding: true,
+        margin: false,
+        style: false,
+        cache: false,
+    };
+
+    /// Every value of the layout, along with the node's style and cache state. Used by [`print_tree_verbose`].
+    pub const ALL: Self = Self { margin: true, style: true, cache: true, ..Self::DEFAULT };
+}
+
+impl Default for PrintColumns {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
 
 /// Prints a debug representation of the computed layout for a tree of nodes, starting with the passed root node.
+///
+/// This writes to stdout. Use [`format_tree`] to write the representation elsewhere.
 pub fn print_tree(tree: &impl PrintTree, root: NodeId) {
-    println!("TREE");
-    print_node(tree, root, false, String::new());
+    let mut output = String::new();
+    // Writing to a `String` can't fail
+    let _ = format_tree(tree, root, &mut output);
+    print!("{output}");
+}
+
+/// Prints a debug representation of the computed layout for a tree of nodes, starting with the passed root node, along
+/// with a summary of each node's style and the state of its cache. See [`PrintColumns::ALL`].
+pub fn print_tree_verbose(tree: &impl PrintTree, root: NodeId) {
+    let mut output = String::new();
+    // Writing to a `String` can't fail
+    let _ = format_tree_with_columns(tree, root, PrintColumns::ALL, &mut output);
+    print!("{output}");
+}
+
+/// Writes a debug representation of the computed layout for a tree of nodes, starting with the passed root node, to `out`
+pub fn format_tree(tree: &impl PrintTree, root: NodeId, out: &mut impl Write) -> Result {
+    format_tree_with_columns(tree, root, PrintColumns::DEFAULT, out)
+}
 
-    /// Recursive function that prints each node in the tree
-    fn print_node(tree: &impl PrintTree, node_id: NodeId, has_sibling: bool, lines_string: String) {
-        let layout = &tree.get_final_layout(node_id);
+/// Writes a debug representation of the computed layout for a tree of nodes, starting with the passed root node, to `out`
+///
+/// Only the values of each node's layout selected by `columns` are included.
+pub fn format_tree_with_columns(
+    tree: &impl PrintTree,
+    root: NodeId,
+    columns: PrintColumns,
+    out: &mut impl Write,
+) -> Result {
+    writeln!(out, "TREE")?;
+
+    // The tree is traversed with an explicit stack rather than by recursion so that very deep trees can't overflow the
+    // call stack. Each entry is a node that remains to be printed, whether it has a following sibling, and the prefix
+    // of lines drawn for its ancestors.
+    let mut stack = vec![(root, false, String::new())];
+    while let Some((node_id, has_sibling, lines_string)) = stack.pop() {
+        let layout = tree.get_final_layout(node_id);
         let display = tree.get_debug_label(node_id);
         let num_children = tree.child_count(node_id);
 
         let fork_string = if has_sibling { "├── " } else { "└── " };
-        #[cfg(feature = "content_size")]
-        println!(
-                "{lines}{fork} {display} [x: {x:<4} y: {y:<4} w: {width:<4} h: {height:<4} content_w: {content_width:<4} content_h: {content_height:<4} border: l:{bl} r:{br} t:{bt} b:{bb}, padding: l:{pl} r:{pr} t:{pt} b:{pb}] ({key:?})",
-                lines = lines_string,
-                fork = fork_string,
-                display = display,
-                x = layout.location.x,
-                y = layout.location.y,
-                width = layout.size.width,
-                height = layout.size.height,
-                content_width = layout.content_size.width,
-                content_height = layout.content_size.height,
-                bl = layout.border.left,
-                br = layout.border.right,
-                bt = layout.border.top,
-                bb = layout.border.bottom,
-                pl = layout.padding.left,
-                pr = layout.padding.right,
-                pt = layout.padding.top,
-                pb = layout.padding.bottom,
-                key = node_id,
-            );
-        #[cfg(not(feature = "content_size"))]
-        println!(
-            "{lines}{fork} {display} [x: {x:<4} y: {y:<4} width: {width:<4} height: {height:<4}] ({key:?})",
-            lines = lines_string,
-            fork = fork_string,
-            display = display,
-            x = layout.location.x,
-            y = layout.location.y,
-            width = layout.size.width,
-            height = layout.size.height,
-            key = node_id,
-        );
+        write!(out, "{lines_string}{fork_string} {display} [")?;
+        write_columns(layout, columns, out)?;
+        write!(out, "] ({node_id:?})")?;
+        if let Some(style) = tree.get_debug_style(node_id).filter(|_| columns.style) {
+            write!(out, " {{{}}}", style.summary())?;
+        }
+        if let Some(cache) = tree.get_debug_cache(node_id).filter(|_| columns.cache) {
+            write!(out, " dirty: {} cached: {}", cache.is_empty(), cache.len())?;
+        }
+        writeln!(out)?;
+
         let bar = if has_sibling { "│   " } else { "    " };
         let new_string = lines_string + bar;
 
-        // Recurse into children
-        for (index, child) in tree.child_ids(node_id).enumerate() {
+        // Push the children in reverse so that they are printed in order
+        let children: Vec<NodeId> = tree.child_ids(node_id).collect();
+        for (index, child) in children.into_iter().enumerate().rev() {
             let has_sibling = index < num_children - 1;
-            print_node(tree, child, has_sibling, new_string.clone());
+            stack.push((child, has_sibling, new_string.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the values of a node's layout selected by `columns`, separated by spaces
+fn write_columns(layout: &Layout, columns: PrintColumns, out: &mut impl Write) -> Result {
+    let mut separator = "";
+    if columns.location {
+        write!(out, "{separator}x: {x:<4} y: {y:<4}", x = layout.location.x, y = layout.location.y)?;
+        separator = " ";
+    }
+    if columns.size {
+        write!(out, "{separator}w: {w:<4} h: {h:<4}", w = layout.size.width, h = layout.size.height)?;
+        separator = " ";
+    }
+    #[cfg(feature = "content_size")]
+    if columns.content_size {
+        let size = layout.content_size;
+        write!(out, "{separator}content_w: {w:<4} content_h: {h:<4}", w = size.width, h = size.height)?;
+        separator = " ";
+    }
+    for (enabled, label, rect) in [
+        (columns.border, "border", layout.border),
+        (columns.padding, "padding", layout.padding),
+        (columns.margin, "margin", layout.margin),
+    ] {
+        if enabled {
+            write!(out, "{separator}{label}: l:{} r:{} t:{} b:{}", rect.left, rect.right, rect.top, rect.bottom)?;
+            separator = " ";
         }
     }
+    Ok(())
 }
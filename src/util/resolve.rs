@@ -1,8 +1,9 @@
 //! Helper trait to calculate dimensions during layout resolution
 
-use crate::geometry::{Rect, Size};
+use crate::geometry::{Rect, Scalar, Size};
 use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto};
 use crate::style_helpers::TaffyZero;
+use crate::util::sys::scalar_max;
 use crate::CompactLength;
 
 /// Trait to encapsulate behaviour where we need to resolve from a
@@ -12,7 +13,7 @@ use crate::CompactLength;
 /// Will return a `None` if it unable to resolve.
 pub trait MaybeResolve<In, Out> {
     /// Resolve a dimension that might be dependent on a context, with `None` as fallback value
-    fn maybe_resolve(self, context: In, calc: impl Fn(u64, f32) -> f32) -> Out;
+    fn maybe_resolve(self, context: In, calc: impl Fn(u64, Scalar) -> Scalar) -> Out;
 }
 
 /// Trait to encapsulate behaviour where we need to resolve from a
@@ -22,57 +23,60 @@ pub trait MaybeResolve<In, Out> {
 /// Will return a default value if it unable to resolve.
 pub trait ResolveOrZero<TContext, TOutput: TaffyZero> {
     /// Resolve a dimension that might be dependent on a context, with a default fallback value
-    fn resolve_or_zero(self, context: TContext, calc: impl Fn(u64, f32) -> f32) -> TOutput;
+    fn resolve_or_zero(self, context: TContext, calc: impl Fn(u64, Scalar) -> Scalar) -> TOutput;
 }
 
-impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
+impl MaybeResolve<Option<Scalar>, Option<Scalar>> for LengthPercentage {
     /// Converts the given [`LengthPercentage`] into an absolute length
     /// Can return `None`
-    fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    ///
+    /// `LengthPercentage` is only used by properties that may not be negative (padding, border and gap),
+    /// so a `calc()` value that resolves to a negative number is clamped at zero.
+    fn maybe_resolve(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Option<Scalar> {
         match self.0.tag() {
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
-            _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value() as Scalar),
+            _ if self.0.is_calc() => context.map(|dim| scalar_max(calc(self.0.calc_value(), dim), 0.0)),
             _ => unreachable!(),
         }
     }
 }
 
-impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
+impl MaybeResolve<Option<Scalar>, Option<Scalar>> for LengthPercentageAuto {
     /// Converts the given [`LengthPercentageAuto`] into an absolute length
     /// Can return `None`
-    fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    fn maybe_resolve(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Option<Scalar> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value() as Scalar),
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
         }
     }
 }
 
-impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
+impl MaybeResolve<Option<Scalar>, Option<Scalar>> for Dimension {
     /// Converts the given [`Dimension`] into an absolute length
     ///
     /// Can return `None`
-    fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    fn maybe_resolve(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Option<Scalar> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
-            CompactLength::LENGTH_TAG => Some(self.0.value()),
-            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            CompactLength::LENGTH_TAG => Some(self.0.value() as Scalar),
+            CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value() as Scalar),
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
         }
     }
 }
 
-// Generic implementation of MaybeResolve for f32 context where MaybeResolve is implemented
-// for Option<f32> context
-impl<T: MaybeResolve<Option<f32>, Option<f32>>> MaybeResolve<f32, Option<f32>> for T {
+// Generic implementation of MaybeResolve for Scalar context where MaybeResolve is implemented
+// for Option<Scalar> context
+impl<T: MaybeResolve<Option<Scalar>, Option<Scalar>>> MaybeResolve<Scalar, Option<Scalar>> for T {
     /// Converts the given MaybeResolve value into an absolute length
     /// Can return `None`
-    fn maybe_resolve(self, context: f32, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
+    fn maybe_resolve(self, context: Scalar, calc: impl Fn(u64, Scalar) -> Scalar) -> Option<Scalar> {
         self.maybe_resolve(Some(context), calc)
     }
 }
@@ -80,7 +84,7 @@ impl<T: MaybeResolve<Option<f32>, Option<f32>>> MaybeResolve<f32, Option<f32>> f
 // Generic MaybeResolve for Size
 impl<In, Out, T: MaybeResolve<In, Out>> MaybeResolve<Size<In>, Size<Out>> for Size<T> {
     /// Converts any `parent`-relative values for size into an absolute size
-    fn maybe_resolve(self, context: Size<In>, calc: impl Fn(u64, f32) -> f32) -> Size<Out> {
+    fn maybe_resolve(self, context: Size<In>, calc: impl Fn(u64, Scalar) -> Scalar) -> Size<Out> {
         Size {
             width: self.width.maybe_resolve(context.width, &calc),
             height: self.height.maybe_resolve(context.height, &calc),
@@ -88,23 +92,23 @@ impl<In, Out, T: MaybeResolve<In, Out>> MaybeResolve<Size<In>, Size<Out>> for Si
     }
 }
 
-impl ResolveOrZero<Option<f32>, f32> for LengthPercentage {
+impl ResolveOrZero<Option<Scalar>, Scalar> for LengthPercentage {
     /// Will return a default value of result is evaluated to `None`
-    fn resolve_or_zero(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> f32 {
+    fn resolve_or_zero(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Scalar {
         self.maybe_resolve(context, calc).unwrap_or(0.0)
     }
 }
 
-impl ResolveOrZero<Option<f32>, f32> for LengthPercentageAuto {
+impl ResolveOrZero<Option<Scalar>, Scalar> for LengthPercentageAuto {
     /// Will return a default value of result is evaluated to `None`
-    fn resolve_or_zero(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> f32 {
+    fn resolve_or_zero(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Scalar {
         self.maybe_resolve(context, calc).unwrap_or(0.0)
     }
 }
 
-impl ResolveOrZero<Option<f32>, f32> for Dimension {
+impl ResolveOrZero<Option<Scalar>, Scalar> for Dimension {
     /// Will return a default value of result is evaluated to `None`
-    fn resolve_or_zero(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> f32 {
+    fn resolve_or_zero(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Scalar {
         self.maybe_resolve(context, calc).unwrap_or(0.0)
     }
 }
@@ -112,7 +116,7 @@ impl ResolveOrZero<Option<f32>, f32> for Dimension {
 // Generic ResolveOrZero for Size
 impl<In, Out: TaffyZero, T: ResolveOrZero<In, Out>> ResolveOrZero<Size<In>, Size<Out>> for Size<T> {
     /// Converts any `parent`-relative values for size into an absolute size
-    fn resolve_or_zero(self, context: Size<In>, calc: impl Fn(u64, f32) -> f32) -> Size<Out> {
+    fn resolve_or_zero(self, context: Size<In>, calc: impl Fn(u64, Scalar) -> Scalar) -> Size<Out> {
         Size {
             width: self.width.resolve_or_zero(context.width, &calc),
             height: self.height.resolve_or_zero(context.height, &calc),
@@ -123,7 +127,7 @@ impl<In, Out: TaffyZero, T: ResolveOrZero<In, Out>> ResolveOrZero<Size<In>, Size
 // Generic ResolveOrZero for resolving Rect against Size
 impl<In: Copy, Out: TaffyZero, T: ResolveOrZero<In, Out>> ResolveOrZero<Size<In>, Rect<Out>> for Rect<T> {
     /// Converts any `parent`-relative values for Rect into an absolute Rect
-    fn resolve_or_zero(self, context: Size<In>, calc: impl Fn(u64, f32) -> f32) -> Rect<Out> {
+    fn resolve_or_zero(self, context: Size<In>, calc: impl Fn(u64, Scalar) -> Scalar) -> Rect<Out> {
         Rect {
             left: self.left.resolve_or_zero(context.width, &calc),
             right: self.right.resolve_or_zero(context.width, &calc),
@@ -134,9 +138,9 @@ impl<In: Copy, Out: TaffyZero, T: ResolveOrZero<In, Out>> ResolveOrZero<Size<In>
 }
 
 // Generic ResolveOrZero for resolving Rect against Option
-impl<Out: TaffyZero, T: ResolveOrZero<Option<f32>, Out>> ResolveOrZero<Option<f32>, Rect<Out>> for Rect<T> {
+impl<Out: TaffyZero, T: ResolveOrZero<Option<Scalar>, Out>> ResolveOrZero<Option<Scalar>, Rect<Out>> for Rect<T> {
     /// Converts any `parent`-relative values for Rect into an absolute Rect
-    fn resolve_or_zero(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Rect<Out> {
+    fn resolve_or_zero(self, context: Option<Scalar>, calc: impl Fn(u64, Scalar) -> Scalar) -> Rect<Out> {
         Rect {
             left: self.left.resolve_or_zero(context, &calc),
             right: self.right.resolve_or_zero(context, &calc),
@@ -186,8 +190,8 @@ mod tests {
             mr_case(Dimension::AUTO, Some(0.), None);
         }
 
-        /// `Dimension::Length` should always return `Some(f32)`
-        /// where the f32 value is the inner absolute length.
+        /// `Dimension::Length` should always return `Some(Scalar)`
+        /// where the Scalar value is the inner absolute length.
         ///
         /// The parent / context should not affect the outcome.
         #[test]
@@ -199,8 +203,8 @@ mod tests {
         }
 
         /// `Dimension::Percent` should return `None` if context is  `None`.
-        /// Otherwise it should return `Some(f32)`
-        /// where the f32 value is the inner value of the percent * context value.
+        /// Otherwise it should return `Some(Scalar)`
+        /// where the Scalar value is the inner value of the percent * context value.
         ///
         /// The parent / context __should__ affect the outcome.
         #[test]
@@ -228,8 +232,8 @@ mod tests {
             mr_case(Size::<Dimension>::auto(), Size::new(0.0, 0.0), Size::NONE);
         }
 
-        /// Size<Dimension::Length> should always return a Size<Some(f32)>
-        /// where the f32 values are the absolute length.
+        /// Size<Dimension::Length> should always return a Size<Some(Scalar)>
+        /// where the Scalar values are the absolute length.
         ///
         /// The parent / context should not affect the outcome.
         #[test]
@@ -241,8 +245,8 @@ mod tests {
         }
 
         /// `Size<Dimension::Percent>` should return `Size<None>` if context is `Size<None>`.
-        /// Otherwise it should return `Size<Some(f32)>`
-        /// where the f32 value is the inner value of the percent * context value.
+        /// Otherwise it should return `Size<Some(Scalar)>`
+        /// where the Scalar value is the inner value of the percent * context value.
         ///
         /// The context __should__ affect the outcome.
         #[test]
@@ -15,9 +15,13 @@ pub(crate) use self::core::*;
 /// For when `std` is enabled
 #[cfg(feature = "std")]
 mod std {
+    use crate::geometry::Scalar;
+
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = std::vec::Vec<A>;
-    /// A vector of child nodes
+    /// An allocation-backend agnostic box type
+    pub(crate) use std::boxed::Box;
+    /// A vector of child nodes. An empty `ChildrenVec` does not allocate, so leaf nodes have no heap footprint.
     pub(crate) type ChildrenVec<A> = std::vec::Vec<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
@@ -32,40 +36,40 @@ mod std {
     /// Rounds to the nearest whole number
     #[must_use]
     #[inline(always)]
-    pub(crate) fn round(value: f32) -> f32 {
+    pub(crate) fn round(value: Scalar) -> Scalar {
         value.round()
     }
 
     /// Rounds up to the nearest whole number
     #[must_use]
     #[inline(always)]
-    pub(crate) fn ceil(value: f32) -> f32 {
+    pub(crate) fn ceil(value: Scalar) -> Scalar {
         value.ceil()
     }
 
     /// Rounds down to the nearest whole number
     #[must_use]
     #[inline(always)]
-    pub(crate) fn floor(value: f32) -> f32 {
+    pub(crate) fn floor(value: Scalar) -> Scalar {
         value.floor()
     }
 
     /// Computes the absolute value
     #[must_use]
     #[inline(always)]
-    pub(crate) fn abs(value: f32) -> f32 {
+    pub(crate) fn abs(value: Scalar) -> Scalar {
         value.abs()
     }
 
-    /// Returns the largest of two f32 values
+    /// Returns the largest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_max(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_max(a: Scalar, b: Scalar) -> Scalar {
         a.max(b)
     }
 
-    /// Returns the smallest of two f32 values
+    /// Returns the smallest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_min(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_min(a: Scalar, b: Scalar) -> Scalar {
         a.min(b)
     }
 }
@@ -74,11 +78,14 @@ mod std {
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 mod alloc {
     extern crate alloc;
+    use crate::geometry::Scalar;
     use core::cmp::Ordering;
 
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = alloc::vec::Vec<A>;
-    /// A vector of child nodes
+    /// An allocation-backend agnostic box type
+    pub(crate) use alloc::boxed::Box;
+    /// A vector of child nodes. An empty `ChildrenVec` does not allocate, so leaf nodes have no heap footprint.
     pub(crate) type ChildrenVec<A> = alloc::vec::Vec<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
@@ -102,15 +109,15 @@ mod alloc {
     /// Computes the absolute value
     pub(crate) use super::polyfill::abs;
 
-    /// Returns the largest of two f32 values
+    /// Returns the largest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_max(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_max(a: Scalar, b: Scalar) -> Scalar {
         a.max(b)
     }
 
-    /// Returns the smallest of two f32 values
+    /// Returns the smallest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_min(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_min(a: Scalar, b: Scalar) -> Scalar {
         a.min(b)
     }
 }
@@ -118,6 +125,7 @@ mod alloc {
 /// For when neither `alloc` nor `std` is enabled
 #[cfg(all(not(feature = "alloc"), not(feature = "std")))]
 mod core {
+    use crate::geometry::Scalar;
     use core::cmp::Ordering;
 
     /// The maximum number of nodes in the tree
@@ -147,18 +155,24 @@ mod core {
     /// Rounds to the nearest whole number
     pub(crate) use super::polyfill::round;
 
+    /// Rounds up to the nearest whole number
+    pub(crate) use super::polyfill::ceil;
+
+    /// Rounds down to the nearest whole number
+    pub(crate) use super::polyfill::floor;
+
     /// Computes the absolute value
     pub(crate) use super::polyfill::abs;
 
-    /// Returns the largest of two f32 values
+    /// Returns the largest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_max(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_max(a: Scalar, b: Scalar) -> Scalar {
         a.max(b)
     }
 
-    /// Returns the smallest of two f32 values
+    /// Returns the smallest of two scalar values
     #[inline(always)]
-    pub(crate) fn f32_min(a: f32, b: f32) -> f32 {
+    pub(crate) fn scalar_min(a: Scalar, b: Scalar) -> Scalar {
         a.min(b)
     }
 }
@@ -167,9 +181,11 @@ mod core {
 /// Copied from `num-traits` crate
 #[cfg(not(feature = "std"))]
 mod polyfill {
+    use crate::geometry::Scalar;
+
     #[must_use]
     #[inline(always)]
-    fn fract(value: f32) -> f32 {
+    fn fract(value: Scalar) -> Scalar {
         if value == 0.0 {
             0.0
         } else {
@@ -179,7 +195,7 @@ mod polyfill {
 
     #[must_use]
     #[inline(always)]
-    pub(crate) fn round(value: f32) -> f32 {
+    pub(crate) fn round(value: Scalar) -> Scalar {
         let f = fract(value);
         if f.is_nan() || f == 0.0 {
             value
@@ -198,7 +214,7 @@ mod polyfill {
 
     #[must_use]
     #[inline(always)]
-    pub(crate) fn floor(value: f32) -> f32 {
+    pub(crate) fn floor(value: Scalar) -> Scalar {
         let f = fract(value);
         if f.is_nan() || f == 0.0 {
             value
@@ -211,7 +227,7 @@ mod polyfill {
 
     #[must_use]
     #[inline(always)]
-    pub(crate) fn ceil(value: f32) -> f32 {
+    pub(crate) fn ceil(value: Scalar) -> Scalar {
         let f = fract(value);
         if f.is_nan() || f == 0.0 {
             value
@@ -225,13 +241,13 @@ mod polyfill {
     /// Computes the absolute value
     #[must_use]
     #[inline(always)]
-    pub(crate) fn abs(value: f32) -> f32 {
+    pub(crate) fn abs(value: Scalar) -> Scalar {
         if value.is_sign_positive() {
             return value;
         } else if value.is_sign_negative() {
             return -value;
         } else {
-            f32::NAN
+            Scalar::NAN
         }
     }
 }
@@ -0,0 +1,152 @@
+//! Contains the assert_layout macro for asserting on the computed layout of a node in tests
+use crate::geometry::Scalar;
+use crate::tree::{Layout, NodeId, PrintTree};
+use crate::util::format_tree;
+use std::fmt::Write;
+
+/// Asserts that the computed layout of a node matches the expected values, printing the differences and the node's
+/// subtree (see [`format_tree`](crate::util::format_tree)) if it doesn't.
+///
+/// Takes a tree implementing [`PrintTree`](crate::PrintTree) (such as a [`TaffyTree`](crate::TaffyTree)), a node, an
+/// optional `tolerance` and a list of the values to check. The values that can be checked are `x`, `y`, `width`,
+/// `height`, the `margin_*`, `border_*` and `padding_*` widths of each side (`left`, `right`, `top` and `bottom`),
+/// `first_baseline` and `last_baseline` (which are NaN for a node without baselines), and (with the `content_size`
+/// feature) `content_width`, `content_height`, `scroll_width` and `scroll_height`. Without a tolerance the values
+/// must match exactly.
+///
+/// ```
+/// # use taffy::prelude::*;
+/// # use taffy::assert_layout;
+/// let mut taffy: TaffyTree<()> = TaffyTree::new();
+/// let node = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 41.0), ..Default::default() }).unwrap();
+/// taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+///
+/// assert_layout!(taffy, node, x: 0.0, y: 0.0, width: 50.0, height: 41.0);
+/// assert_layout!(taffy, node, tolerance: 0.5, width: 50.2);
+/// ```
+#[macro_export]
+macro_rules! assert_layout {
+    ($tree:expr, $node:expr, tolerance: $tolerance:expr, $($field:ident: $value:expr),+ $(,)?) => {
+        $crate::util::assert_layout_matches(
+            &$tree,
+            $node,
+            $tolerance as $crate::geometry::Scalar,
+            &[$((stringify!($field), $value as $crate::geometry::Scalar)),+],
+        )
+    };
+    ($tree:expr, $node:expr, $($field:ident: $value:expr),+ $(,)?) => {
+        $crate::util::assert_layout_matches(&$tree, $node, 0.0, &[$((stringify!($field), $value as $crate::geometry::Scalar)),+])
+    };
+}
+
+/// Asserts that each of the named values of a node's computed layout is within `tolerance` of its expected value. This
+/// is the function behind [`assert_layout!`](crate::assert_layout), whose documentation lists the names of the values.
+///
+/// # Panics
+///
+/// Panics with a diff of the mismatched values and the node's subtree if any value doesn't match, or if a name isn't
+/// the name of a value.
+#[track_caller]
+pub fn assert_layout_matches(tree: &impl PrintTree, node: NodeId, tolerance: Scalar, expected: &[(&str, Scalar)]) {
+    let layout = tree.get_final_layout(node);
+
+    let mut diff = String::new();
+    let mut matches = true;
+    for &(name, expected_value) in expected {
+        let Some(actual_value) = layout_value(layout, name) else {
+            panic!("`{name}` is not a value of a node's layout that can be asserted on");
+        };
+        // Expected values are computed in `f32`s. Values that lie halfway between two pixels in `f32`s can be rounded
+        // the other way in `f64`s, and unrounded values can be off by an `f32` rounding error.
+        #[cfg(feature = "f64")]
+        let tolerance = if expected_value.fract() == 0.0 {
+            tolerance.max(1.0)
+        } else {
+            tolerance.max(expected_value.abs().max(1.0) * f32::EPSILON as Scalar * 4.0)
+        };
+        if actual_value == expected_value || (actual_value - expected_value).abs() <= tolerance {
+            // Writing to a `String` can't fail
+            let _ = writeln!(diff, "    {name}: {actual_value}");
+        } else {
+            matches = false;
+            let _ = writeln!(diff, "  - {name}: {expected_value}");
+            let _ = writeln!(diff, "  + {name}: {actual_value}");
+        }
+    }
+
+    if !matches {
+        let mut subtree = String::new();
+        let _ = format_tree(tree, node, &mut subtree);
+        panic!(
+            "layout of node {node:?} does not match (- expected, + actual, tolerance {tolerance}):\n{diff}\n{subtree}"
+        );
+    }
+}
+
+/// Gets the value of a node's layout with the given name, if there is one
+fn layout_value(layout: &Layout, name: &str) -> Option<Scalar> {
+    Some(match name {
+        "x" => layout.location.x,
+        "y" => layout.location.y,
+        "width" => layout.size.width,
+        "height" => layout.size.height,
+        "margin_left" => layout.margin.left,
+        "margin_right" => layout.margin.right,
+        "margin_top" => layout.margin.top,
+        "margin_bottom" => layout.margin.bottom,
+        "border_left" => layout.border.left,
+        "border_right" => layout.border.right,
+        "border_top" => layout.border.top,
+        "border_bottom" => layout.border.bottom,
+        "padding_left" => layout.padding.left,
+        "padding_right" => layout.padding.right,
+        "padding_top" => layout.padding.top,
+        "padding_bottom" => layout.padding.bottom,
+        "first_baseline" => layout.first_baseline.unwrap_or(Scalar::NAN),
+        "last_baseline" => layout.last_baseline.unwrap_or(Scalar::NAN),
+        #[cfg(feature = "content_size")]
+        "content_width" => layout.content_size.width,
+        #[cfg(feature = "content_size")]
+        "content_height" => layout.content_size.height,
+        #[cfg(feature = "content_size")]
+        "scroll_width" => layout.scroll_width(),
+        #[cfg(feature = "content_size")]
+        "scroll_height" => layout.scroll_height(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn laid_out_tree() -> (TaffyTree<()>, NodeId) {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 41.0), ..Default::default() }).unwrap();
+        let root =
+            taffy.new_with_children(Style { padding: Rect::length(10.0), ..Default::default() }, &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        (taffy, child)
+    }
+
+    #[test]
+    fn assert_layout_accepts_values_within_tolerance() {
+        let (taffy, node) = laid_out_tree();
+        assert_layout!(taffy, node, x: 10.0, y: 10.0, width: 50.0, height: 41.0);
+        assert_layout!(taffy, node, tolerance: 0.1, x: 10.05, height: 40.95);
+    }
+
+    #[test]
+    #[should_panic(expected = "  - width: 48\n  + width: 50\n    height: 41\n\nTREE\n└──  LEAF [x: 10")]
+    fn assert_layout_reports_mismatched_values_and_the_subtree() {
+        let (taffy, node) = laid_out_tree();
+        assert_layout!(taffy, node, width: 48.0, height: 41.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`depth` is not a value of a node's layout")]
+    fn assert_layout_rejects_unknown_values() {
+        let (taffy, node) = laid_out_tree();
+        assert_layout!(taffy, node, depth: 1.0);
+    }
+}
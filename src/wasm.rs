@@ -0,0 +1,230 @@
+//! Bindings for using a [`TaffyTree`](crate::TaffyTree) from JavaScript with [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen)
+//!
+//! The bindings export a `TaffyTree` class, whose nodes are identified by `bigint` ids. Styles are passed as JSON
+//! strings in Taffy's [serde representation](crate::style) of [`Style`], in which omitted properties take their default
+//! values:
+//!
+//! ```js
+//! const tree = new TaffyTree();
+//! const child = tree.newLeaf(JSON.stringify({ size: { width: { length: 100 }, height: "auto" } }));
+//! const root = tree.newWithChildren(JSON.stringify({ display: "flex" }), [child]);
+//! tree.computeLayout(root, 800, undefined);
+//! const { x, y, width, height } = tree.layout(child);
+//! ```
+//!
+//! Leaf nodes can be given a measure callback with `setMeasure`, which is called with the known dimensions of the
+//! node's content box (`{ width, height }`, each of which is `undefined` if it isn't known) and the space available
+//! to it (`{ width, height }`, each of which is a number or `"min-content"` or `"max-content"`), and must return the
+//! `{ width, height }` of the node's content.
+//!
+//! To use the bindings, build Taffy for the `wasm32-unknown-unknown` target with the `wasm-bindgen` feature enabled
+//! and run `wasm-bindgen` on the output.
+
+use js_sys::{Function, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::geometry::{Scalar, Size};
+use crate::style::{AvailableSpace, Style};
+use crate::tree::{NodeId, TaffyError};
+use crate::TaffyTree as Tree;
+
+/// A tree of nodes, whose layout can be computed
+#[wasm_bindgen]
+pub struct TaffyTree {
+    /// The tree, whose nodes' contexts are their measure callbacks
+    tree: Tree<Function>,
+}
+
+/// Parses a style from its JSON representation
+fn parse_style(style: &str) -> Result<Style, JsError> {
+    serde_json::from_str(style).map_err(|error| JsError::new(&format!("invalid style: {error}")))
+}
+
+/// Creates a JavaScript `{ width, height }` object
+fn size_object(width: JsValue, height: JsValue) -> Object {
+    let object = Object::new();
+    // Setting a property of a new plain object can't fail
+    let _ = Reflect::set(&object, &"width".into(), &width);
+    let _ = Reflect::set(&object, &"height".into(), &height);
+    object
+}
+
+/// Converts available space to a number or a `"min-content"` or `"max-content"` string
+fn available_space_value(space: AvailableSpace) -> JsValue {
+    match space {
+        AvailableSpace::Definite(value) => value.into(),
+        AvailableSpace::MinContent => "min-content".into(),
+        AvailableSpace::MaxContent => "max-content".into(),
+    }
+}
+
+/// Reads a number property of the result of a measure callback
+fn measured_dimension(size: &JsValue, property: &str) -> Result<Scalar, JsValue> {
+    let value = Reflect::get(size, &property.into())?;
+    value
+        .as_f64()
+        .map(|value| value as Scalar)
+        .ok_or_else(|| JsError::new(&format!("measure callback returned a non-numeric {property}")).into())
+}
+
+impl TaffyTree {
+    /// Converts a node id passed from JavaScript to a [`NodeId`], checking that the node is in the tree
+    fn node(&self, node: u64) -> Result<NodeId, TaffyError> {
+        let node = NodeId::from(node);
+        if self.tree.contains(node) {
+            Ok(node)
+        } else {
+            Err(TaffyError::InvalidInputNode(node))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl TaffyTree {
+    /// Creates a new, empty tree
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TaffyTree {
+        TaffyTree { tree: Tree::new() }
+    }
+
+    /// Sets whether the layout of the tree is rounded to whole pixels. Rounding is enabled by default.
+    #[wasm_bindgen(js_name = setRounding)]
+    pub fn set_rounding(&mut self, enabled: bool) {
+        if enabled {
+            self.tree.enable_rounding();
+        } else {
+            self.tree.disable_rounding();
+        }
+    }
+
+    /// Creates a new node without children from the JSON representation of its style, and returns its id
+    #[wasm_bindgen(js_name = newLeaf)]
+    pub fn new_leaf(&mut self, style: &str) -> Result<u64, JsError> {
+        Ok(self.tree.new_leaf(parse_style(style)?)?.into())
+    }
+
+    /// Creates a new node with the given children from the JSON representation of its style, and returns its id
+    #[wasm_bindgen(js_name = newWithChildren)]
+    pub fn new_with_children(&mut self, style: &str, children: &[u64]) -> Result<u64, JsError> {
+        let style = parse_style(style)?;
+        let children = children.iter().map(|&child| self.node(child)).collect::<Result<Vec<_>, _>>()?;
+        Ok(self.tree.new_with_children(style, &children)?.into())
+    }
+
+    /// Removes a node from the tree. Its children are detached from it, but aren't removed.
+    pub fn remove(&mut self, node: u64) -> Result<(), JsError> {
+        let node = self.node(node)?;
+        self.tree.remove(node)?;
+        Ok(())
+    }
+
+    /// Sets the style of a node from its JSON representation
+    #[wasm_bindgen(js_name = setStyle)]
+    pub fn set_style(&mut self, node: u64, style: &str) -> Result<(), JsError> {
+        let node = self.node(node)?;
+        self.tree.set_style(node, parse_style(style)?)?;
+        Ok(())
+    }
+
+    /// Sets the callback that measures a leaf node, or removes it if `measure` is `undefined`
+    #[wasm_bindgen(js_name = setMeasure)]
+    pub fn set_measure(&mut self, node: u64, measure: Option<Function>) -> Result<(), JsError> {
+        let node = self.node(node)?;
+        self.tree.set_node_context(node, measure)?;
+        Ok(())
+    }
+
+    /// Marks the layout of a node and its ancestors as outdated, for example because the content of a measured leaf
+    /// has changed
+    #[wasm_bindgen(js_name = markDirty)]
+    pub fn mark_dirty(&mut self, node: u64) -> Result<(), JsError> {
+        let node = self.node(node)?;
+        self.tree.mark_dirty(node)?;
+        Ok(())
+    }
+
+    /// Appends `child` to the children of `parent`
+    #[wasm_bindgen(js_name = addChild)]
+    pub fn add_child(&mut self, parent: u64, child: u64) -> Result<(), JsError> {
+        let (parent, child) = (self.node(parent)?, self.node(child)?);
+        self.tree.add_child(parent, child)?;
+        Ok(())
+    }
+
+    /// Detaches `child` from `parent`, without removing it from the tree
+    #[wasm_bindgen(js_name = removeChild)]
+    pub fn remove_child(&mut self, parent: u64, child: u64) -> Result<(), JsError> {
+        let (parent, child) = (self.node(parent)?, self.node(child)?);
+        self.tree.remove_child(parent, child)?;
+        Ok(())
+    }
+
+    /// Returns the ids of the children of a node
+    pub fn children(&self, node: u64) -> Result<Vec<u64>, JsError> {
+        let node = self.node(node)?;
+        Ok(self.tree.children(node)?.into_iter().map(u64::from).collect())
+    }
+
+    /// Computes the layout of the subtree rooted at `node` with the given available width and height, each of which
+    /// is a max-content constraint if it is `undefined`
+    #[wasm_bindgen(js_name = computeLayout)]
+    pub fn compute_layout(&mut self, node: u64, width: Option<Scalar>, height: Option<Scalar>) -> Result<(), JsValue> {
+        let node = self.node(node).map_err(JsError::from)?;
+        let available_space = Size { width: width.into(), height: height.into() };
+        // The first error thrown by a measure callback, which is rethrown once layout is finished
+        let mut measure_error = None;
+        self.tree
+            .compute_layout_with_measure(
+                node,
+                available_space,
+                |known_dimensions, available_space, _node_id, measure, _style| {
+                    let (Some(measure), None) = (measure, &measure_error) else { return Size::ZERO };
+                    let known_dimensions = size_object(known_dimensions.width.into(), known_dimensions.height.into());
+                    let available_space = size_object(
+                        available_space_value(available_space.width),
+                        available_space_value(available_space.height),
+                    );
+                    let size =
+                        measure.call2(&JsValue::UNDEFINED, &known_dimensions, &available_space).and_then(|size| {
+                            Ok(Size {
+                                width: measured_dimension(&size, "width")?,
+                                height: measured_dimension(&size, "height")?,
+                            })
+                        });
+                    size.unwrap_or_else(|error| {
+                        measure_error = Some(error);
+                        Size::ZERO
+                    })
+                },
+            )
+            .map_err(JsError::from)?;
+        match measure_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the computed layout of a node as an `{ x, y, width, height }` object, whose position is relative to
+    /// its parent's border box
+    pub fn layout(&self, node: u64) -> Result<Object, JsError> {
+        let node = self.node(node)?;
+        let layout = self.tree.layout(node)?;
+        let object = Object::new();
+        for (property, value) in [
+            ("x", layout.location.x),
+            ("y", layout.location.y),
+            ("width", layout.size.width),
+            ("height", layout.size.height),
+        ] {
+            // Setting a property of a new plain object can't fail
+            let _ = Reflect::set(&object, &property.into(), &value.into());
+        }
+        Ok(object)
+    }
+}
+
+impl Default for TaffyTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
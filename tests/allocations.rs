@@ -0,0 +1,183 @@
+//! Tests that check the number of heap allocations performed during layout. These live in their own test
+//! binary because they install a counting global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use taffy::prelude::*;
+
+/// A global allocator that counts the allocations made by the current thread
+struct CountingAllocator;
+
+thread_local! {
+    /// The number of allocations made by the current thread
+    static ALLOCATION_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATION_COUNT.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATION_COUNT.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by the current thread while running `f`
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATION_COUNT.with(Cell::get);
+    f();
+    ALLOCATION_COUNT.with(Cell::get) - before
+}
+
+#[test]
+fn relayout_of_flexbox_tree_does_not_allocate() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+    let mut containers = Vec::new();
+    for i in 0..100 {
+        let children: Vec<NodeId> = (0..5)
+            .map(|_| {
+                let style = Style { flex_grow: 1.0, size: Size::from_lengths(10.0, 10.0), ..Default::default() };
+                taffy.new_leaf(style).unwrap()
+            })
+            .collect();
+        let style =
+            Style { flex_wrap: if i % 2 == 0 { FlexWrap::Wrap } else { FlexWrap::NoWrap }, ..Default::default() };
+        containers.push(taffy.new_with_children(style, &children).unwrap());
+    }
+    let root_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+    let root = taffy.new_with_children(root_style, &containers).unwrap();
+
+    let available_space = Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::Definite(2000.0) };
+
+    // Perform an initial layout to warm up any scratch buffers
+    taffy.compute_layout(root, available_space).unwrap();
+
+    // Invalidate the cached layouts so that the flexbox algorithm is run again for every container
+    for &container in &containers {
+        taffy.mark_dirty(container).unwrap();
+    }
+
+    let allocations = count_allocations(|| taffy.compute_layout(root, available_space).unwrap());
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn creating_childless_leaves_does_not_allocate() {
+    const LEAF_COUNT: usize = 100_000;
+    let mut taffy: TaffyTree<()> = TaffyTree::with_capacity(LEAF_COUNT);
+
+    // With storage for every node reserved up front, the only allocations would be those of the leaves' (empty) lists
+    // of children
+    let allocations = count_allocations(|| {
+        for _ in 0..LEAF_COUNT {
+            taffy.new_leaf(Style::default()).unwrap();
+        }
+    });
+    assert_eq!(allocations, 0);
+}
+
+/// Build a tree mixing flexbox, grid and block containers, with measured leaves
+fn build_mixed_tree(taffy: &mut TaffyTree<Scalar>) -> NodeId {
+    let mut sections = Vec::new();
+    for i in 0..20 {
+        let leaves: Vec<NodeId> = (0..6)
+            .map(|j| {
+                let style = Style { flex_grow: 1.0, min_size: Size::from_lengths(5.0, 5.0), ..Default::default() };
+                taffy.new_leaf_with_context(style, 10.0 + (i * j) as Scalar).unwrap()
+            })
+            .collect();
+        let row = taffy.new_with_children(Style::default(), &leaves[3..]).unwrap();
+        let mut children = leaves[..3].to_vec();
+        children.push(row);
+        let style = match i % 3 {
+            0 => Style { display: Display::Flex, flex_wrap: FlexWrap::Wrap, ..Default::default() },
+            1 => Style {
+                display: Display::Grid,
+                grid_template_columns: vec![fr(1.0), auto(), minmax(length(10.0), fr(2.0))],
+                ..Default::default()
+            },
+            _ => Style { display: Display::Block, ..Default::default() },
+        };
+        sections.push(taffy.new_with_children(style, &children).unwrap());
+    }
+    let root_style = Style { display: Display::Grid, grid_template_columns: vec![fr(1.0); 4], ..Default::default() };
+    taffy.new_with_children(root_style, &sections).unwrap()
+}
+
+/// Measure a leaf whose content is a square with the side length stored in its context
+fn measure_square(
+    known_dimensions: Size<Option<Scalar>>,
+    _available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    side: Option<&mut Scalar>,
+    _style: &Style,
+) -> Size<Scalar> {
+    let side = side.copied().unwrap_or(0.0);
+    Size { width: known_dimensions.width.unwrap_or(side), height: known_dimensions.height.unwrap_or(side) }
+}
+
+#[test]
+fn cached_relayout_does_not_allocate() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let root = build_mixed_tree(&mut taffy);
+    let available_space = Size { width: AvailableSpace::Definite(800.0), height: AvailableSpace::MaxContent };
+
+    taffy.compute_layout_with_measure(root, available_space, measure_square).unwrap();
+
+    let allocations =
+        count_allocations(|| taffy.compute_layout_with_measure(root, available_space, measure_square).unwrap());
+    assert_eq!(allocations, 0);
+}
+
+/// Build a tree of nested containers `depth` levels deep, in which every container has `fanout` children. Levels
+/// alternate between grid and flexbox containers, starting with a grid at the root. Returns the root and the number of
+/// grid containers.
+fn build_nested_flex_grid_tree(taffy: &mut TaffyTree<()>, depth: usize, fanout: usize, grid: bool) -> (NodeId, usize) {
+    if depth == 0 {
+        let leaf = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        return (leaf, 0);
+    }
+    let mut grid_count = usize::from(grid);
+    let children: Vec<NodeId> = (0..fanout)
+        .map(|_| {
+            let (child, child_grid_count) = build_nested_flex_grid_tree(taffy, depth - 1, fanout, !grid);
+            grid_count += child_grid_count;
+            child
+        })
+        .collect();
+    let style = if grid {
+        Style { display: Display::Grid, grid_template_columns: vec![auto(), fr(1.0)], ..Default::default() }
+    } else {
+        Style { display: Display::Flex, flex_wrap: FlexWrap::Wrap, ..Default::default() }
+    };
+    (taffy.new_with_children(style, &children).unwrap(), grid_count)
+}
+
+#[test]
+fn initial_layout_of_nested_containers_allocates_per_level_not_per_node() {
+    const DEPTH: usize = 5;
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let (root, grid_count) = build_nested_flex_grid_tree(&mut taffy, DEPTH, 4, true);
+
+    let allocations = count_allocations(|| taffy.compute_layout(root, Size::MAX_CONTENT).unwrap());
+
+    // Storing the detailed layout info of each grid container allocates a box, the items, and the gutters and sizes of
+    // the rows and columns. These are part of the output rather than scratch space.
+    let detailed_info_allocations = if cfg!(feature = "detailed_layout_info") { 6 * grid_count } else { 0 };
+
+    // The scratch buffers of each level of nesting are reused by every container at that level, so the remaining
+    // allocations are bounded by the depth of the tree (1365 nodes) rather than its size
+    assert!(allocations - detailed_info_allocations <= 8 * DEPTH, "{allocations} allocations");
+}
@@ -0,0 +1,142 @@
+use taffy::{assert_layout, prelude::*};
+
+/// Measures a single line of text whose line height is the node's context, with its baseline 80% of the way down
+fn text_measure_function(
+    known_dimensions: Size<Option<Scalar>>,
+    _available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    line_height: Option<&mut Scalar>,
+    _style: &Style,
+) -> taffy::MeasureOutput {
+    let Some(&mut line_height) = line_height else { return Size::ZERO.into() };
+    let size =
+        Size { width: known_dimensions.width.unwrap_or(50.0), height: known_dimensions.height.unwrap_or(line_height) };
+    let baseline = Some(line_height * 0.8);
+    taffy::MeasureOutput { size, content_size: None, first_baseline: baseline, last_baseline: baseline }
+}
+
+#[test]
+fn leaf_baselines_are_measured_from_the_border_box() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let text = taffy
+        .new_leaf_with_context(
+            Style {
+                border: Rect { top: length(2.0), bottom: zero(), left: zero(), right: zero() },
+                padding: Rect { top: length(3.0), bottom: zero(), left: zero(), right: zero() },
+                ..Default::default()
+            },
+            20.0,
+        )
+        .unwrap();
+    let empty = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[text, empty]).unwrap();
+
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+    assert_layout!(taffy, text, first_baseline: 21.0, last_baseline: 21.0);
+    assert_eq!(taffy.layout(empty).unwrap().first_baseline, None);
+    assert_eq!(taffy.layout(empty).unwrap().last_baseline, None);
+}
+
+#[test]
+fn flex_container_baseline_is_that_of_its_first_line() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let small_text = taffy
+        .new_leaf_with_context(
+            Style {
+                padding: Rect { top: length(5.0), bottom: zero(), left: zero(), right: zero() },
+                ..Default::default()
+            },
+            20.0,
+        )
+        .unwrap();
+    let large_text = taffy.new_leaf_with_context(Style::default(), 40.0).unwrap();
+    let wrapped_text = taffy
+        .new_leaf_with_context(Style { size: Size { width: percent(1.0), height: auto() }, ..Default::default() }, 10.0)
+        .unwrap();
+    let flex = taffy
+        .new_with_children(
+            Style {
+                flex_wrap: FlexWrap::Wrap,
+                align_items: Some(AlignItems::Baseline),
+                padding: Rect::length(4.0),
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[small_text, large_text, wrapped_text],
+        )
+        .unwrap();
+
+    taffy.compute_layout_with_measure(flex, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+    // The items of the first line are aligned to the large text's baseline, which is 32 below the top of the line
+    assert_layout!(taffy, small_text, y: 15.0, first_baseline: 21.0);
+    assert_layout!(taffy, large_text, y: 4.0, first_baseline: 32.0);
+    assert_layout!(taffy, flex, first_baseline: 36.0, last_baseline: 36.0);
+}
+
+#[test]
+fn grid_container_baseline_is_that_of_its_first_row() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let first_row = taffy.new_leaf_with_context(Style::default(), 20.0).unwrap();
+    let second_row = taffy.new_leaf_with_context(Style::default(), 40.0).unwrap();
+    let grid = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_rows: vec![length(30.0), length(50.0)],
+                align_items: Some(AlignItems::Baseline),
+                padding: Rect::length(10.0),
+                ..Default::default()
+            },
+            &[first_row, second_row],
+        )
+        .unwrap();
+
+    taffy.compute_layout_with_measure(grid, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+    assert_layout!(taffy, first_row, first_baseline: 16.0);
+    assert_layout!(taffy, second_row, first_baseline: 32.0);
+    assert_layout!(taffy, grid, first_baseline: 26.0);
+}
+
+#[test]
+fn block_container_baselines_are_those_of_its_first_and_last_children_with_baselines() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let spacer = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+    let first_text = taffy.new_leaf_with_context(Style::default(), 20.0).unwrap();
+    let last_text = taffy.new_leaf_with_context(Style::default(), 40.0).unwrap();
+    let block = taffy
+        .new_with_children(
+            Style { display: Display::Block, padding: Rect::length(10.0), ..Default::default() },
+            &[spacer, first_text, last_text],
+        )
+        .unwrap();
+
+    taffy.compute_layout_with_measure(block, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+    // The spacer has no baseline, so the first baseline is that of the first text: 10 + 10 + 16
+    assert_layout!(taffy, block, first_baseline: 36.0, last_baseline: 72.0);
+}
+
+#[test]
+fn baselines_are_rounded_from_their_absolute_position() {
+    let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+    let text = taffy.new_leaf_with_context(Style::default(), 20.5).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { top: length(0.4), bottom: zero(), left: zero(), right: zero() },
+                ..Default::default()
+            },
+            &[text],
+        )
+        .unwrap();
+
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+    // The baseline is 0.4 + 16.4 = 16.8 from the top of the root, which rounds to 17 while the text's top edge rounds
+    // to 0
+    assert_eq!(taffy.unrounded_layout(text).first_baseline, Some(20.5 * 0.8));
+    assert_layout!(taffy, text, y: 0.0, first_baseline: 17.0, last_baseline: 17.0);
+}
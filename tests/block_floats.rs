@@ -0,0 +1,233 @@
+//! Tests for floated children of block containers. Expected values are those produced by browsers for the equivalent
+//! HTML, with `display: block` on every node.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{Clear, Float, Overflow};
+
+/// A block-level style with the given fixed size
+fn block(width: f32, height: f32) -> Style {
+    Style { display: Display::Block, size: Size::from_lengths(width, height), ..Default::default() }
+}
+
+/// A block-level style with the given fixed size, floated to the given side
+fn floated(float: Float, width: f32, height: f32) -> Style {
+    Style { float, ..block(width, height) }
+}
+
+/// The border box of a node as (x, y, width, height)
+fn border_box(taffy: &TaffyTree<()>, node: NodeId) -> (Scalar, Scalar, Scalar, Scalar) {
+    let layout = taffy.layout(node).unwrap();
+    (layout.location.x, layout.location.y, layout.size.width, layout.size.height)
+}
+
+#[test]
+fn left_and_right_floats_with_cleared_block() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let left = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let right = taffy.new_leaf(floated(Float::Right, 60.0, 30.0)).unwrap();
+    let cleared = taffy
+        .new_leaf(Style {
+            display: Display::Block,
+            clear: Clear::Both,
+            size: Size { width: auto(), height: length(20.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[left, right, cleared],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, left), (0.0, 0.0, 50.0, 40.0));
+    assert_eq!(border_box(&taffy, right), (140.0, 0.0, 60.0, 30.0));
+    assert_eq!(border_box(&taffy, cleared), (0.0, 40.0, 200.0, 20.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 200.0, 60.0));
+}
+
+#[test]
+fn uncleared_block_is_not_moved_by_floats() {
+    // Only the inline content of in-flow blocks flows around floats, so the block box itself is placed beside them
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let float = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let in_flow = taffy
+        .new_leaf(Style {
+            display: Display::Block,
+            size: Size { width: auto(), height: length(20.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[float, in_flow],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, float), (0.0, 0.0, 50.0, 40.0));
+    assert_eq!(border_box(&taffy, in_flow), (0.0, 0.0, 200.0, 20.0));
+    // The root establishes a block formatting context, so it is tall enough to contain the float
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 200.0, 40.0));
+}
+
+#[test]
+fn float_wraps_below_earlier_floats_when_it_does_not_fit() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let first = taffy.new_leaf(floated(Float::Left, 40.0, 20.0)).unwrap();
+    let second = taffy.new_leaf(floated(Float::Left, 40.0, 30.0)).unwrap();
+    let third = taffy.new_leaf(floated(Float::Left, 40.0, 10.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(100.0), height: auto() },
+                ..Default::default()
+            },
+            &[first, second, third],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 40.0, 20.0));
+    assert_eq!(border_box(&taffy, second), (40.0, 0.0, 40.0, 30.0));
+    // The third float may not be placed to the left of the second float while they overlap vertically
+    assert_eq!(border_box(&taffy, third), (0.0, 30.0, 40.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 100.0, 40.0));
+}
+
+#[test]
+fn cleared_float_is_placed_below_earlier_floats() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let first = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let second = taffy.new_leaf(Style { clear: Clear::Left, ..floated(Float::Left, 50.0, 20.0) }).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 50.0, 40.0));
+    assert_eq!(border_box(&taffy, second), (0.0, 40.0, 50.0, 20.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 200.0, 60.0));
+}
+
+#[test]
+fn float_margins_do_not_collapse() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let float = taffy
+        .new_leaf(Style {
+            margin: Rect { left: length(5.0), right: length(5.0), top: length(10.0), bottom: length(10.0) },
+            ..floated(Float::Right, 50.0, 40.0)
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                padding: Rect { left: zero(), right: zero(), top: length(10.0), bottom: zero() },
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[float],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, float), (145.0, 20.0, 50.0, 40.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 200.0, 70.0));
+}
+
+#[test]
+fn floats_are_only_contained_by_block_formatting_context_roots() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+    // A block with `overflow: hidden` establishes a block formatting context and contains its floats
+    let contained_float = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let container = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                overflow: Point { x: Overflow::Hidden, y: Overflow::Hidden },
+                ..Default::default()
+            },
+            &[contained_float],
+        )
+        .unwrap();
+
+    // A plain block does not, so its floats overflow it
+    let uncontained_float = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let non_container =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[uncontained_float]).unwrap();
+
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[container, non_container],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, container), (0.0, 0.0, 200.0, 40.0));
+    assert_eq!(border_box(&taffy, contained_float), (0.0, 0.0, 50.0, 40.0));
+    assert_eq!(border_box(&taffy, non_container), (0.0, 40.0, 200.0, 0.0));
+    assert_eq!(border_box(&taffy, uncontained_float), (0.0, 0.0, 50.0, 40.0));
+}
+
+#[test]
+fn max_content_width_of_block_with_floats() {
+    // Consecutive floats are placed side by side when the container is sized under a max-content constraint
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let first = taffy.new_leaf(floated(Float::Left, 30.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(floated(Float::Left, 40.0, 20.0)).unwrap();
+    let root =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[first, second]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (30.0, 0.0, 40.0, 20.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 70.0, 20.0));
+}
+
+#[test]
+fn flow_root_contains_floats() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let float = taffy.new_leaf(floated(Float::Left, 50.0, 40.0)).unwrap();
+    let flow_root =
+        taffy.new_with_children(Style { display: Display::FlowRoot, ..Default::default() }, &[float]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[flow_root],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(border_box(&taffy, flow_root), (0.0, 0.0, 200.0, 40.0));
+    assert_eq!(border_box(&taffy, float), (0.0, 0.0, 50.0, 40.0));
+}
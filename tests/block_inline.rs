@@ -0,0 +1,145 @@
+//! Tests for inline-level children of block containers, which are placed side by side on shared lines. Text is
+//! measured by `test_measure_function` using the metrics of the Ahem font, where each character is a 10x10 square.
+
+use taffy::prelude::*;
+use taffy::style::Float;
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+/// A block container style with the given width
+fn container(width: Dimension) -> Style {
+    Style { display: Display::Block, size: Size { width, height: auto() }, ..Default::default() }
+}
+
+/// An inline-level style
+fn inline() -> Style {
+    Style { item_is_inline: true, ..Default::default() }
+}
+
+/// The node context for a run of text
+fn text(text_content: &'static str) -> TestNodeContext {
+    TestNodeContext::ahem_text(text_content, WritingMode::Horizontal)
+}
+
+/// The border box of a node as (x, y, width, height)
+fn border_box(taffy: &TaffyTree<TestNodeContext>, node: NodeId) -> (Scalar, Scalar, Scalar, Scalar) {
+    let layout = taffy.layout(node).unwrap();
+    (layout.location.x, layout.location.y, layout.size.width, layout.size.height)
+}
+
+#[test]
+fn adjacent_text_leaves_share_a_line() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let second = taffy.new_leaf_with_context(inline(), text("HHH")).unwrap();
+    let root = taffy.new_with_children(container(length(100.0)), &[first, second]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (20.0, 0.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 100.0, 10.0));
+}
+
+#[test]
+fn text_leaf_that_does_not_fit_wraps_onto_the_next_line() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let second = taffy.new_leaf_with_context(inline(), text("HHH")).unwrap();
+    let third = taffy.new_leaf_with_context(inline(), text("H")).unwrap();
+    let root = taffy.new_with_children(container(length(40.0)), &[first, second, third]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (0.0, 10.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, third), (30.0, 10.0, 10.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 40.0, 20.0));
+}
+
+#[test]
+fn text_leaf_wider_than_the_container_wraps_its_own_text() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let second = taffy.new_leaf_with_context(inline(), text("HHHH\u{200B}HHHH")).unwrap();
+    let root = taffy.new_with_children(container(length(50.0)), &[first, second]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (0.0, 10.0, 50.0, 20.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 50.0, 30.0));
+}
+
+#[test]
+fn items_on_a_line_are_aligned_by_their_bottom_edges() {
+    let mut taffy = new_test_tree();
+    let tall = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 30.0), ..inline() }).unwrap();
+    let short = taffy
+        .new_leaf(Style {
+            size: Size::from_lengths(20.0, 10.0),
+            margin: Rect { left: length(5.0), right: zero(), top: zero(), bottom: length(5.0) },
+            ..inline()
+        })
+        .unwrap();
+    let root = taffy.new_with_children(container(length(100.0)), &[tall, short]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(border_box(&taffy, tall), (0.0, 0.0, 20.0, 30.0));
+    assert_eq!(border_box(&taffy, short), (25.0, 15.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 100.0, 30.0));
+}
+
+#[test]
+fn inline_runs_are_stacked_between_block_level_siblings() {
+    let mut taffy = new_test_tree();
+    let block = |taffy: &mut TaffyTree<TestNodeContext>| {
+        let style = Style {
+            display: Display::Block,
+            size: Size { width: auto(), height: length(10.0) },
+            margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(5.0) },
+            ..Default::default()
+        };
+        taffy.new_leaf(style).unwrap()
+    };
+    let before = block(&mut taffy);
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let second = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let after = block(&mut taffy);
+    let root = taffy.new_with_children(container(length(30.0)), &[before, first, second, after]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    // The margins of the block-level siblings don't collapse through the lines between them
+    assert_eq!(border_box(&taffy, before), (0.0, 5.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, first), (0.0, 20.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (0.0, 30.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, after), (0.0, 45.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 30.0, 60.0));
+}
+
+#[test]
+fn content_based_width_of_inline_items() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let second = taffy.new_leaf_with_context(inline(), text("HHH")).unwrap();
+    let root = taffy.new_with_children(container(auto()), &[first, second]).unwrap();
+
+    // Under a max-content constraint the items are placed on a single line
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 50.0, 10.0));
+
+    // Under a min-content constraint each item is placed on its own line
+    taffy.compute_layout_with_measure(root, Size::MIN_CONTENT, test_measure_function).unwrap();
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 20.0, 10.0));
+    assert_eq!(border_box(&taffy, second), (0.0, 10.0, 30.0, 10.0));
+    assert_eq!(border_box(&taffy, root), (0.0, 0.0, 30.0, 20.0));
+}
+
+#[test]
+fn floated_inline_items_are_block_level() {
+    let mut taffy = new_test_tree();
+    let float =
+        taffy.new_leaf(Style { float: Float::Right, size: Size::from_lengths(10.0, 10.0), ..inline() }).unwrap();
+    let first = taffy.new_leaf_with_context(inline(), text("HH")).unwrap();
+    let root = taffy.new_with_children(container(length(100.0)), &[float, first]).unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(border_box(&taffy, float), (90.0, 0.0, 10.0, 10.0));
+    assert_eq!(border_box(&taffy, first), (0.0, 0.0, 20.0, 10.0));
+}
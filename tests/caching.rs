@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod caching {
     use taffy::prelude::*;
-    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext};
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
 
     const NODE_CONTEXT: TestNodeContext = TestNodeContext::fixed(50.0, 50.0);
 
@@ -21,6 +21,27 @@ mod caching {
         assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 4);
     }
 
+    #[test]
+    fn measure_count_flexbox_wrapped_row() {
+        let mut taffy = new_test_tree();
+
+        let leaves: Vec<_> = (0..100)
+            .map(|_| {
+                let style = Style { align_self: Some(AlignSelf::Baseline), ..Default::default() };
+                taffy.new_leaf_with_context(style, NODE_CONTEXT).unwrap()
+            })
+            .collect();
+
+        let style = Style { flex_wrap: FlexWrap::Wrap, size: Size::from_lengths(500.0, 500.0), ..Default::default() };
+        let node = taffy.new_with_children(style, &leaves).unwrap();
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        for leaf in leaves {
+            assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 3);
+        }
+    }
+
     #[test]
     #[cfg(feature = "grid")]
     fn measure_count_grid() {
@@ -37,4 +58,285 @@ mod caching {
         taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
         assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 4);
     }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn measure_count_block_definite_width() {
+        let mut taffy = new_test_tree();
+
+        let style = || Style { display: Display::Block, ..Default::default() };
+        let leaves: Vec<_> = (0..100)
+            .map(|_| {
+                let context = TestNodeContext::ahem_text("HHHH\u{200b}HHHH", WritingMode::Horizontal);
+                taffy.new_leaf_with_context(style(), context).unwrap()
+            })
+            .collect();
+
+        let root_style = Style { size: Size { width: length(500.0), height: auto() }, ..style() };
+        let node = taffy.new_with_children(root_style, &leaves).unwrap();
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        for leaf in leaves {
+            assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn measure_count_block_content_based_width() {
+        let mut taffy = new_test_tree();
+
+        let style = || Style { display: Display::Block, ..Default::default() };
+        let leaves: Vec<_> = (0..100)
+            .map(|_| {
+                let context = TestNodeContext::ahem_text("HHHH\u{200b}HHHH", WritingMode::Horizontal);
+                taffy.new_leaf_with_context(style(), context).unwrap()
+            })
+            .collect();
+
+        let node = taffy.new_with_children(style(), &leaves).unwrap();
+
+        // The children are laid out when determining the container's width. As each child's final width is the same as
+        // the width determined in that pass, their cached layouts should be reused by the final layout pass.
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        for leaf in leaves {
+            assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn cache_stats() {
+        let mut taffy = new_test_tree();
+
+        let leaves: Vec<_> =
+            (0..3).map(|_| taffy.new_leaf_with_context(Style::default(), NODE_CONTEXT).unwrap()).collect();
+        let inner = taffy.new_with_children(Style::DEFAULT, &leaves).unwrap();
+        let sibling = taffy.new_leaf_with_context(Style::default(), NODE_CONTEXT).unwrap();
+        let root = taffy.new_with_children(Style::DEFAULT, &[inner, sibling]).unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        let stats = taffy.cache_stats();
+        assert!(stats.misses > 0);
+        assert_eq!(stats.stores, stats.misses);
+
+        // Laying out an unchanged tree again is served entirely from the cache
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        let stats = taffy.cache_stats();
+        assert!(stats.hits > 0);
+        assert_eq!(stats.misses, 0);
+        assert_eq!(stats.stores, 0);
+
+        // Only the dirtied leaf and its ancestors are recomputed
+        taffy.mark_dirty(leaves[0]).unwrap();
+        let count_before = taffy.get_node_context_mut(sibling).unwrap().count;
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        let stats = taffy.cache_stats();
+        assert!(stats.hits > 0);
+        assert!(stats.misses > 0);
+        assert_eq!(taffy.get_node_context_mut(sibling).unwrap().count, count_before);
+    }
+
+    /// Builds a grid whose items are block containers with explicit sizes and vertically-collapsing child margins
+    fn build_grid_of_blocks(taffy: &mut TaffyTree<TestNodeContext>) -> (NodeId, Vec<NodeId>) {
+        let mut nodes = Vec::new();
+        let mut items = Vec::new();
+        for width in [length(40.0), auto(), percent(0.5)] {
+            let first = taffy
+                .new_leaf(Style {
+                    size: Size::from_lengths(20.0, 10.0),
+                    margin: Rect::length(5.0),
+                    ..Default::default()
+                })
+                .unwrap();
+            let second = taffy
+                .new_leaf(Style {
+                    size: Size { width: auto(), height: length(10.0) },
+                    margin: Rect::length(8.0),
+                    ..Default::default()
+                })
+                .unwrap();
+            let item = taffy
+                .new_with_children(
+                    Style {
+                        display: Display::Block,
+                        size: Size { width, height: auto() },
+                        max_size: Size { width: length(60.0), height: auto() },
+                        ..Default::default()
+                    },
+                    &[first, second],
+                )
+                .unwrap();
+            nodes.extend([first, second, item]);
+            items.push(item);
+        }
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), fr(1.0), auto()],
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+        nodes.push(root);
+        (root, nodes)
+    }
+
+    #[test]
+    fn grid_of_blocks_matches_fresh_layout_after_warming_cache() {
+        let available = Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::MaxContent };
+
+        // Warm the block items' caches with measurements made under other constraints before the final layout
+        let mut warm = new_test_tree();
+        let (warm_root, warm_nodes) = build_grid_of_blocks(&mut warm);
+        warm.compute_layout(warm_root, Size::MIN_CONTENT).unwrap();
+        warm.compute_layout(warm_root, Size::MAX_CONTENT).unwrap();
+        warm.compute_layout(warm_root, available).unwrap();
+
+        let mut fresh = new_test_tree();
+        let (fresh_root, fresh_nodes) = build_grid_of_blocks(&mut fresh);
+        fresh.compute_layout(fresh_root, available).unwrap();
+
+        for (warm_node, fresh_node) in warm_nodes.into_iter().zip(fresh_nodes) {
+            assert_eq!(warm.layout(warm_node).unwrap(), fresh.layout(fresh_node).unwrap());
+        }
+    }
+
+    /// Builds a flex row containing a nested flex column of text, alongside a sibling text leaf
+    fn build_row_of_text(taffy: &mut TaffyTree<TestNodeContext>) -> (NodeId, NodeId) {
+        let text = TestNodeContext::ahem_text("HHH\u{200b}HH", WritingMode::Horizontal);
+        let leaf = taffy.new_leaf_with_context(Style::default(), text).unwrap();
+        let sibling = taffy.new_leaf_with_context(Style::default(), text).unwrap();
+        let column_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+        let column = taffy.new_with_children(column_style, &[leaf]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[column, sibling]).unwrap();
+        (root, leaf)
+    }
+
+    /// Lays out the tree, returning the nodes that were measured
+    fn measured_nodes(taffy: &mut TaffyTree<TestNodeContext>, root: NodeId) -> Vec<NodeId> {
+        let mut measured = Vec::new();
+        taffy
+            .compute_layout_with_measure(
+                root,
+                Size::MAX_CONTENT,
+                |known_dimensions, available_space, node, context, style| {
+                    measured.push(node);
+                    test_measure_function(known_dimensions, available_space, node, context, style)
+                },
+            )
+            .unwrap();
+        measured
+    }
+
+    #[test]
+    fn measure_dirty_leaf_with_unchanged_size_relayouts_only_the_leaf() {
+        let mut taffy = new_test_tree();
+        let (root, leaf) = build_row_of_text(&mut taffy);
+        measured_nodes(&mut taffy, root);
+        let root_layout = *taffy.layout(root).unwrap();
+
+        // Replace the text with text of the same width
+        taffy.get_node_context_mut(leaf).unwrap().measure_data =
+            TestNodeContext::ahem_text("HH\u{200b}HHH", WritingMode::Horizontal).measure_data;
+        taffy.mark_measure_dirty(leaf).unwrap();
+        assert!(taffy.dirty(leaf).unwrap());
+        assert!(!taffy.dirty(root).unwrap());
+
+        let measured = measured_nodes(&mut taffy, root);
+        assert!(!measured.is_empty());
+        assert!(measured.iter().all(|&node| node == leaf));
+        // Each of the leaf's results is recomputed once, and the root's layout is served from its cache
+        #[cfg(feature = "metrics")]
+        {
+            let stats = taffy.cache_stats();
+            assert_eq!(stats.misses, 6);
+            assert_eq!(stats.hits, 1);
+        }
+        assert!(!taffy.dirty(leaf).unwrap());
+        assert_eq!(*taffy.layout(root).unwrap(), root_layout);
+
+        // Nothing is remeasured once the leaf has been verified
+        assert!(measured_nodes(&mut taffy, root).is_empty());
+    }
+
+    #[test]
+    fn measure_dirty_leaf_with_changed_size_relayouts_its_ancestors() {
+        let mut taffy = new_test_tree();
+        let (root, leaf) = build_row_of_text(&mut taffy);
+        measured_nodes(&mut taffy, root);
+        assert_eq!(taffy.layout(root).unwrap().size.width, 100.0);
+
+        // Replace the text with wider text
+        taffy.get_node_context_mut(leaf).unwrap().measure_data =
+            TestNodeContext::ahem_text("HHH\u{200b}HHH", WritingMode::Horizontal).measure_data;
+        taffy.mark_measure_dirty(leaf).unwrap();
+
+        measured_nodes(&mut taffy, root);
+        assert_eq!(taffy.layout(leaf).unwrap().size.width, 60.0);
+        assert_eq!(taffy.layout(root).unwrap().size.width, 110.0);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_of_text_measures_each_leaf_once_per_constraint() {
+        let mut taffy = new_test_tree();
+
+        let texts = ["HH\u{200b}HHHH", "HHH\u{200b}H\u{200b}HH", "H\u{200b}HHHHH\u{200b}HH"];
+        let leaves: Vec<_> = (0..9)
+            .map(|index| {
+                let context = TestNodeContext::ahem_text(texts[index % texts.len()], WritingMode::Horizontal);
+                taffy.new_leaf_with_context(Style::default(), context).unwrap()
+            })
+            .collect();
+        // Each cell of the grid is a flex column, which sizes its text under several different available heights
+        let cells: Vec<_> = leaves
+            .iter()
+            .map(|&leaf| {
+                let style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+                taffy.new_with_children(style, &[leaf]).unwrap()
+            })
+            .collect();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![min_content(), auto(), fr(1.0)],
+                    grid_template_rows: vec![fr(1.0), fr(1.0), fr(1.0)],
+                    size: Size { width: auto(), height: length(300.0) },
+                    ..Default::default()
+                },
+                &cells,
+            )
+            .unwrap();
+
+        // Count the measurements made under each distinct constraint. The available height isn't part of the
+        // constraint, as the height of horizontal text only depends on its width.
+        let mut measurements = std::collections::HashMap::new();
+        taffy
+            .compute_layout_with_measure_ex(root, Size::MAX_CONTENT, |args: taffy::MeasureArgs<TestNodeContext>| {
+                let constraint = format!(
+                    "{:?} {:?} {:?} {:?}",
+                    args.node_id, args.run_mode, args.known_dimensions, args.available_space.width
+                );
+                *measurements.entry(constraint).or_insert(0) += 1;
+                test_measure_function(
+                    args.known_dimensions,
+                    args.available_space,
+                    args.node_id,
+                    args.node_context,
+                    args.style,
+                )
+            })
+            .unwrap();
+
+        assert!(!measurements.is_empty());
+        for (constraint, count) in measurements {
+            assert_eq!(count, 1, "measured {count} times under {constraint}");
+        }
+    }
 }
@@ -0,0 +1,298 @@
+//! Tests for resolving `calc()` values in the high-level API.
+
+use taffy::prelude::*;
+
+/// The size of a `rem` unit
+const REM_SIZE: Scalar = 16.0;
+
+/// A minimal calc representation: a sum of a length and a percentage. The handle stored in the style points to one of
+/// these, so it must be aligned to at least 8 bytes.
+#[repr(align(8))]
+struct LengthPlusPercent {
+    /// The length component, with any font-relative units already resolved
+    length: Scalar,
+    /// The percentage component
+    percent: Scalar,
+}
+
+/// `calc(1rem + 50%)`
+static ONE_REM_PLUS_HALF: LengthPlusPercent = LengthPlusPercent { length: REM_SIZE, percent: 0.5 };
+
+/// `calc(10px + 10%)`
+static TEN_PX_PLUS_TEN_PERCENT: LengthPlusPercent = LengthPlusPercent { length: 10.0, percent: 0.1 };
+
+/// `calc(10px - 50%)`, which is negative for bases larger than 20px
+static TEN_PX_MINUS_HALF: LengthPlusPercent = LengthPlusPercent { length: 10.0, percent: -0.5 };
+
+/// Resolves handles that point to a `LengthPlusPercent`
+fn resolve_length_plus_percent(calc: *const (), basis: Scalar) -> Scalar {
+    let calc = unsafe { &*(calc as *const LengthPlusPercent) };
+    calc.length + calc.percent * basis
+}
+
+/// The handle for `calc(1rem + 50%)`
+fn one_rem_plus_half() -> *const () {
+    &ONE_REM_PLUS_HALF as *const LengthPlusPercent as *const ()
+}
+
+/// The handle for `calc(10px + 10%)`
+fn ten_px_plus_ten_percent() -> *const () {
+    &TEN_PX_PLUS_TEN_PERCENT as *const LengthPlusPercent as *const ()
+}
+
+/// The handle for `calc(10px - 50%)`
+fn ten_px_minus_half() -> *const () {
+    &TEN_PX_MINUS_HALF as *const LengthPlusPercent as *const ()
+}
+
+/// A tree that resolves calc handles that point to a `LengthPlusPercent`
+fn new_calc_tree() -> TaffyTree<()> {
+    let mut taffy = TaffyTree::new();
+    taffy.set_calc_resolver(resolve_length_plus_percent);
+    taffy
+}
+
+/// The x position of a node
+fn x(taffy: &TaffyTree<()>, node: NodeId) -> Scalar {
+    taffy.layout(node).unwrap().location.x
+}
+
+#[test]
+fn calc_resolves_to_zero_without_a_resolver() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let node = taffy
+        .new_leaf(Style {
+            size: Size { width: length(100.0), height: auto() },
+            padding: Rect { left: LengthPercentage::calc(one_rem_plus_half()), ..Rect::zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(node).unwrap().padding.left, 0.0);
+}
+
+#[test]
+fn calc_padding() {
+    let mut taffy = new_calc_tree();
+    let child = taffy
+        .new_leaf(Style { padding: Rect { left: calc(one_rem_plus_half()), ..Rect::zero() }, ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(Style { size: Size::from_lengths(200.0, 100.0), ..Default::default() }, &[child])
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Percentage padding resolves against the width of the containing block: 16 + 0.5 * 200
+    assert_eq!(taffy.layout(child).unwrap().padding.left, 116.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 116.0);
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn calc_grid_track_size() {
+    let mut taffy = new_calc_tree();
+    let first = taffy.new_leaf(Style::default()).unwrap();
+    let second = taffy.new_leaf(Style::default()).unwrap();
+    let track = calc(one_rem_plus_half());
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                size: Size::from_lengths(200.0, 100.0),
+                grid_template_columns: vec![track, fr(1.0)],
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Percentage track sizes resolve against the width of the grid container: 16 + 0.5 * 200
+    assert_eq!(taffy.layout(first).unwrap().size.width, 116.0);
+    assert_eq!(taffy.layout(second).unwrap().location.x, 116.0);
+    assert_eq!(taffy.layout(second).unwrap().size.width, 84.0);
+}
+
+/// A container of two 50x50 children, returning the container and the children
+fn container_with_two_children(taffy: &mut TaffyTree<()>, style: Style) -> (NodeId, NodeId, NodeId) {
+    let first = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }).unwrap();
+    let second = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }).unwrap();
+    let container =
+        taffy.new_with_children(Style { size: Size::from_lengths(200.0, 100.0), ..style }, &[first, second]).unwrap();
+    (container, first, second)
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn calc_flexbox_gap() {
+    let mut taffy = new_calc_tree();
+    let style = Style { gap: Size { width: calc(ten_px_plus_ten_percent()), height: zero() }, ..Default::default() };
+    let (root, _, second) = container_with_two_children(&mut taffy, style);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 50 + 10 + 0.1 * 200
+    assert_eq!(x(&taffy, second), 80.0);
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn calc_grid_gap() {
+    let mut taffy = new_calc_tree();
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![length(50.0), length(50.0)],
+        gap: Size { width: calc(ten_px_plus_ten_percent()), height: zero() },
+        ..Default::default()
+    };
+    let (root, _, second) = container_with_two_children(&mut taffy, style);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 50 + 10 + 0.1 * 200
+    assert_eq!(x(&taffy, second), 80.0);
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn calc_fixed_grid_track() {
+    let mut taffy = new_calc_tree();
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![calc(ten_px_plus_ten_percent()), length(50.0)],
+        ..Default::default()
+    };
+    let (root, _, second) = container_with_two_children(&mut taffy, style);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 10 + 0.1 * 200
+    assert_eq!(x(&taffy, second), 30.0);
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn calc_flex_basis() {
+    let mut taffy = new_calc_tree();
+    let child = taffy.new_leaf(Style { flex_basis: calc(ten_px_plus_ten_percent()), ..Default::default() }).unwrap();
+    let root = taffy
+        .new_with_children(Style { size: Size::from_lengths(200.0, 100.0), ..Default::default() }, &[child])
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 10 + 0.1 * 200
+    assert_eq!(taffy.layout(child).unwrap().size.width, 30.0);
+}
+
+#[test]
+#[cfg(all(feature = "flexbox", feature = "grid"))]
+fn calc_margin() {
+    for display in [Display::Flex, Display::Grid, Display::Block] {
+        let mut taffy = new_calc_tree();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(50.0, 50.0),
+                margin: Rect { left: calc(ten_px_plus_ten_percent()), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display, size: Size::from_lengths(200.0, 100.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // 10 + 0.1 * 200
+        assert_eq!(x(&taffy, child), 30.0, "{display:?}");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "flexbox", feature = "grid"))]
+fn calc_inset() {
+    for display in [Display::Flex, Display::Grid, Display::Block] {
+        for position in [Position::Relative, Position::Absolute] {
+            let mut taffy = new_calc_tree();
+            let child = taffy
+                .new_leaf(Style {
+                    position,
+                    size: Size::from_lengths(50.0, 50.0),
+                    inset: Rect { left: calc(ten_px_plus_ten_percent()), right: auto(), top: auto(), bottom: auto() },
+                    ..Default::default()
+                })
+                .unwrap();
+            let root = taffy
+                .new_with_children(
+                    Style { display, size: Size::from_lengths(200.0, 100.0), ..Default::default() },
+                    &[child],
+                )
+                .unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            // 10 + 0.1 * 200
+            assert_eq!(x(&taffy, child), 30.0, "{display:?} {position:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "grid")]
+#[should_panic(expected = "calc() values are not supported as the argument of fit-content()")]
+fn calc_fit_content_argument_panics() {
+    let _: MaxTrackSizingFunction = fit_content(calc(ten_px_plus_ten_percent()));
+}
+
+#[test]
+#[cfg(feature = "block_layout")]
+fn negative_calc_padding_and_border_are_clamped() {
+    let mut taffy = new_calc_tree();
+    let child = taffy
+        .new_leaf(Style {
+            display: Display::Block,
+            padding: Rect { left: calc(ten_px_minus_half()), ..Rect::zero() },
+            border: Rect { top: calc(ten_px_minus_half()), ..Rect::zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { display: Display::Block, size: Size::from_lengths(200.0, 100.0), ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 10 - 0.5 * 200 is negative, so the used values are zero
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!(layout.padding.left, 0.0);
+    assert_eq!(layout.border.top, 0.0);
+    assert_eq!(layout.size, Size { width: 200.0, height: 0.0 });
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn negative_calc_grid_gap_is_clamped() {
+    let mut taffy = new_calc_tree();
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![length(50.0), length(50.0)],
+        gap: Size { width: calc(ten_px_minus_half()), height: zero() },
+        ..Default::default()
+    };
+    let (root, _, second) = container_with_two_children(&mut taffy, style);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // 10 - 0.5 * 200 is negative, so the columns are not pulled over each other
+    assert_eq!(x(&taffy, second), 50.0);
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn negative_calc_flexbox_gap_is_clamped() {
+    let mut taffy = new_calc_tree();
+    let style = Style { gap: Size { width: calc(ten_px_minus_half()), height: zero() }, ..Default::default() };
+    let (root, _, second) = container_with_two_children(&mut taffy, style);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(x(&taffy, second), 50.0);
+}
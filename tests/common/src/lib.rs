@@ -1,4 +1,4 @@
-use taffy::{AvailableSpace, NodeId, Size, Style, TaffyTree};
+use taffy::{AvailableSpace, NodeId, Scalar, Size, Style, TaffyTree, VecTree};
 
 /// Creates a `TaffyTree` that uses `TestNodeContext`. The purpose of this function is
 /// to allow `TaffyTree` to be monomophised once in this crate rather than separately for
@@ -7,6 +7,13 @@ pub fn new_test_tree() -> TaffyTree<TestNodeContext> {
     TaffyTree::new()
 }
 
+/// Creates a `VecTree` that uses `TestNodeContext`. `VecTree` has the same methods for building and laying out a
+/// tree as `TaffyTree`, so the generated tests can be run against it by mounting them in a crate whose
+/// `new_test_tree` is this function.
+pub fn new_test_vec_tree() -> VecTree<TestNodeContext> {
+    VecTree::new()
+}
+
 /// A sharednode context for tests which means that tests compiled with separate crates
 /// and using different styles of measure function. This saves on compile time when running tests.
 #[derive(Debug, Copy, Clone)]
@@ -29,12 +36,12 @@ impl TestNodeContext {
     }
 
     /// Create a `TestNodeContext` for a fixed-sized node
-    pub const fn fixed(width: f32, height: f32) -> Self {
+    pub const fn fixed(width: Scalar, height: Scalar) -> Self {
         Self::new(TestMeasureData::Fixed(Size { width, height }))
     }
 
     /// Create a `TestNodeContext` for a node with a width and aspect-ratio
-    pub const fn aspect_ratio(width: f32, height_ratio: f32) -> Self {
+    pub const fn aspect_ratio(width: Scalar, height_ratio: Scalar) -> Self {
         let data = AspectRatioMeasureData { width, height_ratio };
         Self::new(TestMeasureData::AspectRatio(data))
     }
@@ -52,7 +59,7 @@ pub enum TestMeasureData {
     /// A zero-sized node
     Zero,
     /// A node with a fixed size
-    Fixed(Size<f32>),
+    Fixed(Size<Scalar>),
     /// A node with a fixed size
     AspectRatio(AspectRatioMeasureData),
     /// A node with text using the Ahem font
@@ -61,12 +68,12 @@ pub enum TestMeasureData {
 
 /// A measure function for tests that works with `TestNodeContext`
 pub fn test_measure_function(
-    known_dimensions: Size<Option<f32>>,
+    known_dimensions: Size<Option<Scalar>>,
     available_space: Size<AvailableSpace>,
     _node_id: NodeId,
     context: Option<&mut TestNodeContext>,
     _style: &Style,
-) -> Size<f32> {
+) -> Size<Scalar> {
     if let Size { width: Some(width), height: Some(height) } = known_dimensions {
         return Size { width, height };
     }
@@ -92,11 +99,11 @@ pub fn test_measure_function(
 /// Measure data for nodes that returns results based on an intrinsic aspect ratio
 #[derive(Debug, Copy, Clone)]
 pub struct AspectRatioMeasureData {
-    width: f32,
-    height_ratio: f32,
+    width: Scalar,
+    height_ratio: Scalar,
 }
 impl AspectRatioMeasureData {
-    fn measure(&self, known_dimensions: Size<Option<f32>>) -> Size<f32> {
+    fn measure(&self, known_dimensions: Size<Option<Scalar>>) -> Size<Scalar> {
         let width = known_dimensions.width.unwrap_or(self.width);
         let height = known_dimensions.height.unwrap_or(width * self.height_ratio);
         Size { width, height }
@@ -122,15 +129,15 @@ pub struct AhemTextMeasureData {
 impl AhemTextMeasureData {
     fn measure(
         &self,
-        known_dimensions: taffy::Size<Option<f32>>,
+        known_dimensions: taffy::Size<Option<Scalar>>,
         available_space: taffy::Size<taffy::AvailableSpace>,
-    ) -> taffy::Size<f32> {
+    ) -> taffy::Size<Scalar> {
         use taffy::prelude::*;
         use taffy::AbsoluteAxis;
 
         const ZWS: char = '\u{200B}';
-        const H_WIDTH: f32 = 10.0;
-        const H_HEIGHT: f32 = 10.0;
+        const H_WIDTH: Scalar = 10.0;
+        const H_HEIGHT: Scalar = 10.0;
 
         let inline_axis = match self.writing_mode {
             WritingMode::Horizontal => AbsoluteAxis::Horizontal,
@@ -148,11 +155,11 @@ impl AhemTextMeasureData {
         let inline_size = known_dimensions
             .get_abs(inline_axis)
             .unwrap_or_else(|| match available_space.get_abs(inline_axis) {
-                AvailableSpace::MinContent => min_line_length as f32 * H_WIDTH,
-                AvailableSpace::MaxContent => max_line_length as f32 * H_WIDTH,
-                AvailableSpace::Definite(inline_size) => inline_size.min(max_line_length as f32 * H_WIDTH),
+                AvailableSpace::MinContent => min_line_length as Scalar * H_WIDTH,
+                AvailableSpace::MaxContent => max_line_length as Scalar * H_WIDTH,
+                AvailableSpace::Definite(inline_size) => inline_size.min(max_line_length as Scalar * H_WIDTH),
             })
-            .max(min_line_length as f32 * H_WIDTH);
+            .max(min_line_length as Scalar * H_WIDTH);
         let block_size = known_dimensions.get_abs(block_axis).unwrap_or_else(|| {
             let inline_line_length = (inline_size / H_WIDTH).floor() as usize;
             let mut line_count = 1;
@@ -167,7 +174,7 @@ impl AhemTextMeasureData {
                     current_line_length += line.len();
                 };
             }
-            (line_count as f32) * H_HEIGHT
+            (line_count as Scalar) * H_HEIGHT
         });
 
         match self.writing_mode {
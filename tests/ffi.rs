@@ -0,0 +1,157 @@
+//! Drives the C API through the signatures declared in `include/taffy.h`
+#![cfg(feature = "ffi")]
+#![allow(unsafe_code)]
+
+use core::ffi::c_void;
+use taffy::ffi::{
+    TaffyAvailableSpace, TaffyAvailableSpaceKind, TaffyDimension, TaffyLayout, TaffyMeasureFunction, TaffyNodeId,
+    TaffyReturnCode, TaffySize, TaffyStyle, TaffyUnit,
+};
+
+/// The opaque tree handle, as C sees it
+#[repr(C)]
+struct TaffyTree {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    fn taffy_tree_new() -> *mut TaffyTree;
+    fn taffy_tree_free(tree: *mut TaffyTree);
+    fn taffy_style_default() -> TaffyStyle;
+    fn taffy_node_new(tree: *mut TaffyTree, style: *const TaffyStyle, node: *mut TaffyNodeId) -> TaffyReturnCode;
+    fn taffy_node_remove(tree: *mut TaffyTree, node: TaffyNodeId) -> TaffyReturnCode;
+    fn taffy_node_set_style(tree: *mut TaffyTree, node: TaffyNodeId, style: *const TaffyStyle) -> TaffyReturnCode;
+    fn taffy_node_set_measure(
+        tree: *mut TaffyTree,
+        node: TaffyNodeId,
+        measure: TaffyMeasureFunction,
+        user_data: *mut c_void,
+    ) -> TaffyReturnCode;
+    fn taffy_node_add_child(tree: *mut TaffyTree, parent: TaffyNodeId, child: TaffyNodeId) -> TaffyReturnCode;
+    fn taffy_node_remove_child(tree: *mut TaffyTree, parent: TaffyNodeId, child: TaffyNodeId) -> TaffyReturnCode;
+    fn taffy_node_child_count(tree: *mut TaffyTree, node: TaffyNodeId, count: *mut usize) -> TaffyReturnCode;
+    fn taffy_node_child_at_index(
+        tree: *mut TaffyTree,
+        node: TaffyNodeId,
+        index: usize,
+        child: *mut TaffyNodeId,
+    ) -> TaffyReturnCode;
+    fn taffy_compute_layout(
+        tree: *mut TaffyTree,
+        node: TaffyNodeId,
+        available_width: TaffyAvailableSpace,
+        available_height: TaffyAvailableSpace,
+    ) -> TaffyReturnCode;
+    fn taffy_node_get_layout(tree: *mut TaffyTree, node: TaffyNodeId, layout: *mut TaffyLayout) -> TaffyReturnCode;
+}
+
+/// Measures a leaf as the size that its user data points to
+unsafe extern "C" fn measure_fixed_size(
+    user_data: *mut c_void,
+    _node: TaffyNodeId,
+    known_width: f32,
+    known_height: f32,
+    _available_width: TaffyAvailableSpace,
+    _available_height: TaffyAvailableSpace,
+) -> TaffySize {
+    let size = *(user_data as *const TaffySize);
+    TaffySize {
+        width: if known_width.is_nan() { size.width } else { known_width },
+        height: if known_height.is_nan() { size.height } else { known_height },
+    }
+}
+
+fn length(value: f32) -> TaffyDimension {
+    TaffyDimension { unit: TaffyUnit::Length, value }
+}
+
+unsafe fn new_node(tree: *mut TaffyTree, style: &TaffyStyle) -> TaffyNodeId {
+    let mut node = 0;
+    assert_eq!(taffy_node_new(tree, style, &mut node), TaffyReturnCode::Ok);
+    node
+}
+
+unsafe fn get_layout(tree: *mut TaffyTree, node: TaffyNodeId) -> TaffyLayout {
+    let mut layout = core::mem::MaybeUninit::uninit();
+    assert_eq!(taffy_node_get_layout(tree, node, layout.as_mut_ptr()), TaffyReturnCode::Ok);
+    layout.assume_init()
+}
+
+#[test]
+fn ffi_lays_out_a_tree_with_a_measured_leaf() {
+    unsafe {
+        let tree = taffy_tree_new();
+
+        let mut root_style = taffy_style_default();
+        root_style.size.width = length(200.0);
+        root_style.padding.left = length(10.0);
+        let root = new_node(tree, &root_style);
+
+        let mut fixed_style = taffy_style_default();
+        fixed_style.size.width = length(50.0);
+        fixed_style.size.height = length(20.0);
+        let fixed = new_node(tree, &fixed_style);
+
+        let mut text_size = TaffySize { width: 30.0, height: 15.0 };
+        let mut text_style = taffy_style_default();
+        text_style.flex_grow = 1.0;
+        let text = new_node(tree, &text_style);
+        let measure: TaffyMeasureFunction = Some(measure_fixed_size);
+        let user_data = &mut text_size as *mut TaffySize as *mut c_void;
+        assert_eq!(taffy_node_set_measure(tree, text, measure, user_data), TaffyReturnCode::Ok);
+
+        assert_eq!(taffy_node_add_child(tree, root, fixed), TaffyReturnCode::Ok);
+        assert_eq!(taffy_node_add_child(tree, root, text), TaffyReturnCode::Ok);
+        let mut count = 0;
+        assert_eq!(taffy_node_child_count(tree, root, &mut count), TaffyReturnCode::Ok);
+        assert_eq!(count, 2);
+        let mut child = 0;
+        assert_eq!(taffy_node_child_at_index(tree, root, 1, &mut child), TaffyReturnCode::Ok);
+        assert_eq!(child, text);
+
+        let max_content = TaffyAvailableSpace { kind: TaffyAvailableSpaceKind::MaxContent, value: 0.0 };
+        assert_eq!(taffy_compute_layout(tree, root, max_content, max_content), TaffyReturnCode::Ok);
+
+        let root_layout = get_layout(tree, root);
+        assert_eq!((root_layout.width, root_layout.height), (200.0, 20.0));
+        assert_eq!(root_layout.padding.left, 10.0);
+        let fixed_layout = get_layout(tree, fixed);
+        assert_eq!((fixed_layout.x, fixed_layout.width, fixed_layout.height), (10.0, 50.0, 20.0));
+        let text_layout = get_layout(tree, text);
+        assert_eq!((text_layout.x, text_layout.width, text_layout.height), (60.0, 140.0, 20.0));
+
+        // Restyling a node changes its layout
+        fixed_style.size.width = length(100.0);
+        assert_eq!(taffy_node_set_style(tree, fixed, &fixed_style), TaffyReturnCode::Ok);
+        assert_eq!(taffy_compute_layout(tree, root, max_content, max_content), TaffyReturnCode::Ok);
+        assert_eq!(get_layout(tree, text).x, 110.0);
+
+        assert_eq!(taffy_node_remove_child(tree, root, fixed), TaffyReturnCode::Ok);
+        assert_eq!(taffy_node_remove(tree, fixed), TaffyReturnCode::Ok);
+        taffy_tree_free(tree);
+    }
+}
+
+#[test]
+fn ffi_reports_invalid_arguments() {
+    unsafe {
+        let tree = taffy_tree_new();
+        let style = taffy_style_default();
+        let parent = new_node(tree, &style);
+        let child = new_node(tree, &style);
+
+        assert_eq!(taffy_node_new(core::ptr::null_mut(), &style, &mut 0), TaffyReturnCode::NullPointer);
+        assert_eq!(taffy_node_new(tree, core::ptr::null(), &mut 0), TaffyReturnCode::NullPointer);
+        assert_eq!(taffy_node_remove_child(tree, parent, child), TaffyReturnCode::NotAChild);
+        assert_eq!(taffy_node_child_at_index(tree, parent, 0, &mut 0), TaffyReturnCode::ChildIndexOutOfBounds);
+
+        let mut auto_padding = style;
+        auto_padding.padding.top = TaffyDimension { unit: TaffyUnit::Auto, value: 0.0 };
+        assert_eq!(taffy_node_set_style(tree, parent, &auto_padding), TaffyReturnCode::InvalidStyle);
+
+        assert_eq!(taffy_node_remove(tree, child), TaffyReturnCode::Ok);
+        assert_eq!(taffy_node_add_child(tree, parent, child), TaffyReturnCode::InvalidNode);
+        assert_eq!(taffy_node_get_layout(tree, child, core::ptr::null_mut()), TaffyReturnCode::InvalidNode);
+        taffy_tree_free(tree);
+    }
+}
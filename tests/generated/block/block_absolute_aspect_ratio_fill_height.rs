@@ -2,7 +2,7 @@
 #[allow(non_snake_case)]
 fn block_absolute_aspect_ratio_fill_height__border_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     let node0 = taffy
         .new_leaf(taffy::style::Style {
@@ -35,25 +35,15 @@ fn block_absolute_aspect_ratio_fill_height__border_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 400f32, "width of node {:?}. Expected {}. Actual {}", node, 400f32, size.width);
-    assert_eq!(size.height, 300f32, "height of node {:?}. Expected {}. Actual {}", node, 300f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 200f32, "width of node {:?}. Expected {}. Actual {}", node0, 200f32, size.width);
-    assert_eq!(size.height, 67f32, "height of node {:?}. Expected {}. Actual {}", node0, 67f32, size.height);
-    assert_eq!(location.x, 20f32, "x of node {:?}. Expected {}. Actual {}", node0, 20f32, location.x);
-    assert_eq!(location.y, 15f32, "y of node {:?}. Expected {}. Actual {}", node0, 15f32, location.y);
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 400f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 20f32, y: 15f32, width: 200f32, height: 67f32);
 }
 
 #[test]
 #[allow(non_snake_case)]
 fn block_absolute_aspect_ratio_fill_height__content_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     let node0 = taffy
         .new_leaf(taffy::style::Style {
@@ -88,16 +78,6 @@ fn block_absolute_aspect_ratio_fill_height__content_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 400f32, "width of node {:?}. Expected {}. Actual {}", node, 400f32, size.width);
-    assert_eq!(size.height, 300f32, "height of node {:?}. Expected {}. Actual {}", node, 300f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 200f32, "width of node {:?}. Expected {}. Actual {}", node0, 200f32, size.width);
-    assert_eq!(size.height, 67f32, "height of node {:?}. Expected {}. Actual {}", node0, 67f32, size.height);
-    assert_eq!(location.x, 20f32, "x of node {:?}. Expected {}. Actual {}", node0, 20f32, location.x);
-    assert_eq!(location.y, 15f32, "y of node {:?}. Expected {}. Actual {}", node0, 15f32, location.y);
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 400f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 20f32, y: 15f32, width: 200f32, height: 67f32);
 }
@@ -2,7 +2,7 @@
 #[allow(non_snake_case)]
 fn block_absolute_aspect_ratio_fill_height_from_inset__border_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     let node0 = taffy
         .new_leaf(taffy::style::Style {
@@ -34,25 +34,15 @@ fn block_absolute_aspect_ratio_fill_height_from_inset__border_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 400f32, "width of node {:?}. Expected {}. Actual {}", node, 400f32, size.width);
-    assert_eq!(size.height, 300f32, "height of node {:?}. Expected {}. Actual {}", node, 300f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 320f32, "width of node {:?}. Expected {}. Actual {}", node0, 320f32, size.width);
-    assert_eq!(size.height, 107f32, "height of node {:?}. Expected {}. Actual {}", node0, 107f32, size.height);
-    assert_eq!(location.x, 40f32, "x of node {:?}. Expected {}. Actual {}", node0, 40f32, location.x);
-    assert_eq!(location.y, 15f32, "y of node {:?}. Expected {}. Actual {}", node0, 15f32, location.y);
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 400f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 40f32, y: 15f32, width: 320f32, height: 107f32);
 }
 
 #[test]
 #[allow(non_snake_case)]
 fn block_absolute_aspect_ratio_fill_height_from_inset__content_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     let node0 = taffy
         .new_leaf(taffy::style::Style {
@@ -86,16 +76,6 @@ fn block_absolute_aspect_ratio_fill_height_from_inset__content_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 400f32, "width of node {:?}. Expected {}. Actual {}", node, 400f32, size.width);
-    assert_eq!(size.height, 300f32, "height of node {:?}. Expected {}. Actual {}", node, 300f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 320f32, "width of node {:?}. Expected {}. Actual {}", node0, 320f32, size.width);
-    assert_eq!(size.height, 107f32, "height of node {:?}. Expected {}. Actual {}", node0, 107f32, size.height);
-    assert_eq!(location.x, 40f32, "x of node {:?}. Expected {}. Actual {}", node0, 40f32, location.x);
-    assert_eq!(location.y, 15f32, "y of node {:?}. Expected {}. Actual {}", node0, 15f32, location.y);
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 400f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 40f32, y: 15f32, width: 320f32, height: 107f32);
 }
@@ -0,0 +1,111 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_inset_zero_overflow_scroll__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            inset: taffy::geometry::Rect { left: auto(), right: length(0f32), top: auto(), bottom: length(0f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Scroll,
+                    y: taffy::style::Overflow::Scroll,
+                },
+                scrollbar_width: 15f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                padding: taffy::geometry::Rect {
+                    left: length(10f32),
+                    right: length(10f32),
+                    top: length(10f32),
+                    bottom: length(10f32),
+                },
+                border: taffy::geometry::Rect {
+                    left: length(5f32),
+                    right: length(5f32),
+                    top: length(5f32),
+                    bottom: length(5f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 60f32, y: 60f32, width: 20f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_inset_zero_overflow_scroll__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            inset: taffy::geometry::Rect { left: auto(), right: length(0f32), top: auto(), bottom: length(0f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Scroll,
+                    y: taffy::style::Overflow::Scroll,
+                },
+                scrollbar_width: 15f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                padding: taffy::geometry::Rect {
+                    left: length(10f32),
+                    right: length(10f32),
+                    top: length(10f32),
+                    bottom: length(10f32),
+                },
+                border: taffy::geometry::Rect {
+                    left: length(5f32),
+                    right: length(5f32),
+                    top: length(5f32),
+                    bottom: length(5f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 130f32, height: 130f32);
+    assert_layout!(taffy, node0, x: 90f32, y: 90f32, width: 20f32, height: 20f32);
+}
@@ -0,0 +1,89 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_margin_auto_top_fixed_bottom_with_inset__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            margin: taffy::geometry::Rect {
+                left: length(4f32),
+                right: length(2f32),
+                top: auto(),
+                bottom: length(3f32),
+            },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: length(10f32), bottom: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 4f32, y: 67f32, width: 20f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_margin_auto_top_fixed_bottom_with_inset__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            margin: taffy::geometry::Rect {
+                left: length(4f32),
+                right: length(2f32),
+                top: auto(),
+                bottom: length(3f32),
+            },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: length(10f32), bottom: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 4f32, y: 67f32, width: 20f32, height: 20f32);
+}
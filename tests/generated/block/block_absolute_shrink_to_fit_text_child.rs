@@ -0,0 +1,91 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_shrink_to_fit_text_child__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf_with_context(
+            taffy::style::Style { ..Default::default() },
+            crate::TestNodeContext::ahem_text("HH\u{200b}HHHH", crate::WritingMode::Horizontal),
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                position: taffy::style::Position::Absolute,
+                inset: taffy::geometry::Rect { left: length(0f32), right: auto(), top: length(0f32), bottom: auto() },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(50f32),
+                    height: taffy::style::Dimension::from_length(50f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 20f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 50f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_shrink_to_fit_text_child__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf_with_context(
+            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
+            crate::TestNodeContext::ahem_text("HH\u{200b}HHHH", crate::WritingMode::Horizontal),
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                position: taffy::style::Position::Absolute,
+                inset: taffy::geometry::Rect { left: length(0f32), right: auto(), top: length(0f32), bottom: auto() },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(50f32),
+                    height: taffy::style::Dimension::from_length(50f32),
+                },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 20f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 50f32, height: 20f32);
+}
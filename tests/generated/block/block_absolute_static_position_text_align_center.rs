@@ -0,0 +1,129 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_static_position_text_align_center__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(40f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            margin: taffy::geometry::Rect { left: length(10f32), right: zero(), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                text_align: taffy::style::TextAlign::LegacyCenter,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(60f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 60f32);
+    assert_layout!(taffy, node0, x: 30f32, y: 0f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 40f32, y: 20f32, width: 20f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 45f32, y: 20f32, width: 20f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_absolute_static_position_text_align_center__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(40f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            margin: taffy::geometry::Rect { left: length(10f32), right: zero(), top: zero(), bottom: zero() },
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                text_align: taffy::style::TextAlign::LegacyCenter,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(60f32),
+                },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 60f32);
+    assert_layout!(taffy, node0, x: 30f32, y: 0f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 40f32, y: 20f32, width: 20f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 45f32, y: 20f32, width: 20f32, height: 20f32);
+}
@@ -0,0 +1,65 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_aspect_ratio_stretch_width_with_margin__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            aspect_ratio: Some(2f32),
+            margin: taffy::geometry::Rect { left: length(20f32), right: length(80f32), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(300f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 20f32, y: 0f32, width: 200f32, height: 100f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_aspect_ratio_stretch_width_with_margin__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            aspect_ratio: Some(2f32),
+            margin: taffy::geometry::Rect { left: length(20f32), right: length(80f32), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(300f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 20f32, y: 0f32, width: 200f32, height: 100f32);
+}
@@ -0,0 +1,90 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_inset_percentage_definite_height__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: percent(0.1f32), bottom: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: auto(), bottom: percent(0.1f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(200f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 200f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 20f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 0f32, width: 100f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_inset_percentage_definite_height__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: percent(0.1f32), bottom: auto() },
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: auto(), bottom: percent(0.1f32) },
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(200f32),
+                },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 200f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 20f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 0f32, width: 100f32, height: 20f32);
+}
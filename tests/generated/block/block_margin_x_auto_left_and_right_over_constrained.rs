@@ -0,0 +1,88 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_margin_x_auto_left_and_right_over_constrained__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(150f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            margin: taffy::geometry::Rect { left: auto(), right: auto(), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(120f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 120f32, height: 20f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 10f32, width: 120f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_margin_x_auto_left_and_right_over_constrained__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(150f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            margin: taffy::geometry::Rect { left: auto(), right: auto(), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Block,
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(120f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 120f32, height: 20f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 10f32, width: 120f32, height: 10f32);
+}
@@ -0,0 +1,89 @@
+#[test]
+#[allow(non_snake_case)]
+fn block_measure_child_with_padding_wraps_in_fixed_width__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf_with_context(
+            taffy::style::Style {
+                padding: taffy::geometry::Rect {
+                    left: length(20f32),
+                    right: length(20f32),
+                    top: length(5f32),
+                    bottom: length(5f32),
+                },
+                ..Default::default()
+            },
+            crate::TestNodeContext::ahem_text(
+                "HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH",
+                crate::WritingMode::Horizontal,
+            ),
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::AUTO,
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn block_measure_child_with_padding_wraps_in_fixed_width__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf_with_context(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                padding: taffy::geometry::Rect {
+                    left: length(20f32),
+                    right: length(20f32),
+                    top: length(5f32),
+                    bottom: length(5f32),
+                },
+                ..Default::default()
+            },
+            crate::TestNodeContext::ahem_text(
+                "HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH\u{200b}HHH",
+                crate::WritingMode::Horizontal,
+            ),
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                display: taffy::style::Display::Block,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::AUTO,
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+}
@@ -12,6 +12,7 @@ mod block_absolute_aspect_ratio_width_overrides_inset;
 mod block_absolute_child_with_margin_x;
 mod block_absolute_child_with_margin_y;
 mod block_absolute_child_with_max_height;
+mod block_absolute_inset_zero_overflow_scroll;
 mod block_absolute_layout_child_order;
 mod block_absolute_layout_no_size;
 mod block_absolute_layout_percentage_bottom_based_on_parent_height;
@@ -40,10 +41,12 @@ mod block_absolute_margin_auto_multiple_children_with_inset;
 mod block_absolute_margin_auto_multiple_children_without_inset;
 mod block_absolute_margin_auto_right_with_inset;
 mod block_absolute_margin_auto_right_without_inset;
+mod block_absolute_margin_auto_top_fixed_bottom_with_inset;
 mod block_absolute_margin_auto_top_with_inset;
 mod block_absolute_margin_auto_top_without_inset;
 mod block_absolute_margin_bottom_left_with_inset;
 mod block_absolute_margin_bottom_left_without_inset;
+mod block_absolute_margin_bottom_with_inset_bottom;
 mod block_absolute_minmax_bottom_right_max;
 mod block_absolute_minmax_bottom_right_min_max;
 mod block_absolute_minmax_bottom_right_min_max_preferred;
@@ -53,6 +56,9 @@ mod block_absolute_no_styles;
 mod block_absolute_padding_border_overrides_max_size;
 mod block_absolute_padding_border_overrides_size;
 mod block_absolute_resolved_insets;
+mod block_absolute_shrink_to_fit_text_child;
+mod block_absolute_static_position_text_align_center;
+mod block_absolute_static_position_text_align_right;
 mod block_align_baseline_child;
 mod block_align_baseline_child_margin;
 mod block_align_baseline_child_margin_percent;
@@ -66,6 +72,10 @@ mod block_aspect_ratio_fill_max_width;
 mod block_aspect_ratio_fill_min_height;
 mod block_aspect_ratio_fill_min_width;
 mod block_aspect_ratio_fill_width;
+mod block_aspect_ratio_stretch_width;
+mod block_aspect_ratio_stretch_width_flex_child;
+mod block_aspect_ratio_stretch_width_with_margin;
+mod block_aspect_ratio_stretch_width_with_max_width;
 mod block_basic;
 mod block_border_fixed_size;
 mod block_border_intrinsic_size;
@@ -78,6 +88,9 @@ mod block_display_none_with_margin;
 mod block_display_none_with_position_absolute;
 mod block_inset_fixed;
 mod block_inset_percentage;
+mod block_inset_percentage_definite_height;
+mod block_inset_percentage_definite_height_with_padding;
+mod block_inset_percentage_indefinite_height;
 mod block_intrinsic_width;
 mod block_item_max_width;
 mod block_item_min_width_overrides_max_width;
@@ -95,6 +108,8 @@ mod block_margin_auto_left_right_child_bigger_than_parent;
 mod block_margin_auto_multiple_children;
 mod block_margin_auto_right;
 mod block_margin_auto_top;
+mod block_margin_x_auto_left_and_right_over_constrained;
+mod block_margin_x_auto_left_over_constrained;
 mod block_margin_x_fixed_auto_bottom;
 mod block_margin_x_fixed_auto_left;
 mod block_margin_x_fixed_auto_left_and_right;
@@ -104,6 +119,8 @@ mod block_margin_x_fixed_size_negative;
 mod block_margin_x_fixed_size_positive;
 mod block_margin_x_intrinsic_size_negative;
 mod block_margin_x_intrinsic_size_positive;
+mod block_margin_x_over_constrained;
+mod block_margin_x_over_constrained_with_padding;
 mod block_margin_x_percentage_fixed_size_negative;
 mod block_margin_x_percentage_fixed_size_positive;
 mod block_margin_x_percentage_intrinsic_size_other_negative;
@@ -114,6 +131,7 @@ mod block_margin_y_collapse_complex;
 mod block_margin_y_collapse_through_blocked_by_aspect_ratio;
 mod block_margin_y_collapse_through_blocked_by_border_bottom;
 mod block_margin_y_collapse_through_blocked_by_border_top;
+mod block_margin_y_collapse_through_blocked_by_flow_root;
 mod block_margin_y_collapse_through_blocked_by_height;
 mod block_margin_y_collapse_through_blocked_by_line_box;
 mod block_margin_y_collapse_through_blocked_by_line_box_with_height_zero;
@@ -126,8 +144,11 @@ mod block_margin_y_collapse_through_blocked_by_overflow_y_scroll;
 mod block_margin_y_collapse_through_blocked_by_padding_bottom;
 mod block_margin_y_collapse_through_blocked_by_padding_top;
 mod block_margin_y_collapse_through_negative;
+mod block_margin_y_collapse_through_negative_bottom_margin;
+mod block_margin_y_collapse_through_negative_flow_root_wrapper;
 mod block_margin_y_collapse_through_positive;
 mod block_margin_y_collapse_through_positive_and_negative;
+mod block_margin_y_collapse_through_positive_flow_root_wrapper;
 mod block_margin_y_collapse_through_with_absolute_child;
 mod block_margin_y_first_child_collapse_blocked_by_border_top;
 mod block_margin_y_first_child_collapse_blocked_by_overflow_x_hidden;
@@ -144,6 +165,7 @@ mod block_margin_y_first_child_collapse_positive_and_negative;
 mod block_margin_y_first_child_collapse_positive_equal;
 mod block_margin_y_first_child_collapse_positive_parent_larger;
 mod block_margin_y_first_child_collapse_positive_parent_smaller;
+mod block_margin_y_first_child_collapse_through_mixed_signs;
 mod block_margin_y_first_granchild_collapse_positive_and_negative;
 mod block_margin_y_first_granchild_collapse_positive_equal;
 mod block_margin_y_last_child_collapse_blocked_by_border_bottom;
@@ -164,10 +186,12 @@ mod block_margin_y_last_child_collapse_positive_parent_smaller;
 mod block_margin_y_last_granchild_collapse_positive_equal;
 mod block_margin_y_sibling_collapse_negative;
 mod block_margin_y_sibling_collapse_negative_percentage;
+mod block_margin_y_sibling_collapse_negative_then_positive;
 mod block_margin_y_sibling_collapse_positive;
 mod block_margin_y_sibling_collapse_positive_and_negative;
 mod block_margin_y_sibling_collapse_positive_and_negative_percentage;
 mod block_margin_y_sibling_collapse_positive_percentage;
+mod block_margin_y_sibling_collapse_positive_then_negative;
 mod block_margin_y_simple_negative;
 mod block_margin_y_simple_negative_percentage_other;
 mod block_margin_y_simple_negative_percentage_self;
@@ -176,6 +200,10 @@ mod block_margin_y_simple_positive_percentage_other;
 mod block_margin_y_simple_positive_percentage_self;
 mod block_margin_y_total_collapse;
 mod block_margin_y_total_collapse_complex;
+mod block_measure_child_with_padding_wraps_in_fixed_width;
+mod block_overflow_hidden_absolute_child_overflowing;
+mod block_overflow_hidden_fixed_height_taller_children;
+mod block_overflow_hidden_wider_child_with_margin;
 mod block_overflow_scrollbars_overridden_by_available_space;
 mod block_overflow_scrollbars_overridden_by_max_size;
 mod block_overflow_scrollbars_overridden_by_size;
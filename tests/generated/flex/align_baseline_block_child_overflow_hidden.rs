@@ -0,0 +1,168 @@
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_block_child_overflow_hidden__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(30f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(15f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy.new_with_children(taffy::style::Style { ..Default::default() }, &[node100]).unwrap();
+    let node110 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(25f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node11 = taffy.new_with_children(taffy::style::Style { ..Default::default() }, &[node110]).unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Hidden,
+                    y: taffy::style::Overflow::Hidden,
+                },
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                ..Default::default()
+            },
+            &[node10, node11],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 10f32, width: 50f32, height: 30f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 50f32, height: 40f32);
+    #[cfg(feature = "content_size")]
+    assert_layout!(taffy, node1, scroll_width: 0f32, scroll_height: 0f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 50f32, height: 15f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 0f32, width: 20f32, height: 15f32);
+    assert_layout!(taffy, node11, x: 0f32, y: 15f32, width: 50f32, height: 25f32);
+    assert_layout!(taffy, node110, x: 0f32, y: 0f32, width: 20f32, height: 25f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_block_child_overflow_hidden__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(30f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(15f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy
+        .new_with_children(
+            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
+            &[node100],
+        )
+        .unwrap();
+    let node110 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(25f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node11 = taffy
+        .new_with_children(
+            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
+            &[node110],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Hidden,
+                    y: taffy::style::Overflow::Hidden,
+                },
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node10, node11],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 10f32, width: 50f32, height: 30f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 50f32, height: 40f32);
+    #[cfg(feature = "content_size")]
+    assert_layout!(taffy, node1, scroll_width: 0f32, scroll_height: 0f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 50f32, height: 15f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 0f32, width: 20f32, height: 15f32);
+    assert_layout!(taffy, node11, x: 0f32, y: 15f32, width: 50f32, height: 25f32);
+    assert_layout!(taffy, node110, x: 0f32, y: 0f32, width: 20f32, height: 25f32);
+}
@@ -0,0 +1,193 @@
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_nested_column_block_child__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(40f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1000 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy.new_with_children(taffy::style::Style { ..Default::default() }, &[node1000]).unwrap();
+    let node101 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(30f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                padding: taffy::geometry::Rect { left: zero(), right: zero(), top: length(6f32), bottom: zero() },
+                ..Default::default()
+            },
+            &[node100, node101],
+        )
+        .unwrap();
+    let node11 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(30f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                flex_direction: taffy::style::FlexDirection::Column,
+                padding: taffy::geometry::Rect { left: zero(), right: zero(), top: length(4f32), bottom: zero() },
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                ..Default::default()
+            },
+            &[node10, node11],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 40f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 20f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 4f32, width: 50f32, height: 36f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 6f32, width: 50f32, height: 10f32);
+    assert_layout!(taffy, node1000, x: 0f32, y: 0f32, width: 20f32, height: 10f32);
+    assert_layout!(taffy, node101, x: 0f32, y: 16f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node11, x: 0f32, y: 40f32, width: 30f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_nested_column_block_child__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(40f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1000 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(20f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy
+        .new_with_children(
+            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
+            &[node1000],
+        )
+        .unwrap();
+    let node101 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(30f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                padding: taffy::geometry::Rect { left: zero(), right: zero(), top: length(6f32), bottom: zero() },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node100, node101],
+        )
+        .unwrap();
+    let node11 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(30f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                flex_direction: taffy::style::FlexDirection::Column,
+                padding: taffy::geometry::Rect { left: zero(), right: zero(), top: length(4f32), bottom: zero() },
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                ..Default::default()
+            },
+            &[node10, node11],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 40f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 20f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 4f32, width: 50f32, height: 36f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 6f32, width: 50f32, height: 10f32);
+    assert_layout!(taffy, node1000, x: 0f32, y: 0f32, width: 20f32, height: 10f32);
+    assert_layout!(taffy, node101, x: 0f32, y: 16f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node11, x: 0f32, y: 40f32, width: 30f32, height: 10f32);
+}
@@ -0,0 +1,139 @@
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_nested_row_in_column__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(50f32), height: length(60f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(50f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node101 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(50f32), height: length(40f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Center),
+                ..Default::default()
+            },
+            &[node100, node101],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_direction: taffy::style::FlexDirection::Column,
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size { width: length(200f32), height: length(200f32) },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 200f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 60f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 30f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 10f32, width: 50f32, height: 20f32);
+    assert_layout!(taffy, node101, x: 50f32, y: 0f32, width: 50f32, height: 40f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_nested_row_in_column__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(50f32), height: length(60f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node100 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(50f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node101 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(50f32), height: length(40f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node10 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Center),
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node100, node101],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_direction: taffy::style::FlexDirection::Column,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size { width: length(200f32), height: length(200f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 200f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 50f32, height: 60f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 30f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 100f32, height: 40f32);
+    assert_layout!(taffy, node100, x: 0f32, y: 10f32, width: 50f32, height: 20f32);
+    assert_layout!(taffy, node101, x: 50f32, y: 0f32, width: 50f32, height: 40f32);
+}
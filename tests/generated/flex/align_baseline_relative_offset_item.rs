@@ -0,0 +1,80 @@
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_relative_offset_item__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: length(30f32), bottom: auto() },
+            size: taffy::geometry::Size { width: length(50f32), height: length(40f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(50f32), height: length(60f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 50f32, width: 50f32, height: 40f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 50f32, height: 60f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn align_baseline_relative_offset_item__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            inset: taffy::geometry::Rect { left: auto(), right: auto(), top: length(30f32), bottom: auto() },
+            size: taffy::geometry::Size { width: length(50f32), height: length(40f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(50f32), height: length(60f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                align_items: Some(taffy::style::AlignItems::Baseline),
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 50f32, width: 50f32, height: 40f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 50f32, height: 60f32);
+}
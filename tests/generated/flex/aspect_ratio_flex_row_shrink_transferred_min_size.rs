@@ -0,0 +1,130 @@
+#[test]
+#[allow(non_snake_case)]
+fn aspect_ratio_flex_row_shrink_transferred_min_size__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size { width: length(100f32), height: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size { width: auto(), height: length(120f32) },
+                max_size: taffy::geometry::Size { width: length(200f32), height: length(60f32) },
+                aspect_ratio: Some(1f32),
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size { width: length(100f32), height: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size { width: auto(), height: length(120f32) },
+                max_size: taffy::geometry::Size { width: length(200f32), height: length(60f32) },
+                aspect_ratio: Some(1f32),
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(100f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 60f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 60f32, height: 60f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 100f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 60f32, y: 0f32, width: 60f32, height: 60f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 100f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn aspect_ratio_flex_row_shrink_transferred_min_size__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size { width: length(100f32), height: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size { width: auto(), height: length(120f32) },
+                max_size: taffy::geometry::Size { width: length(200f32), height: length(60f32) },
+                aspect_ratio: Some(1f32),
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size { width: length(100f32), height: length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size { width: auto(), height: length(120f32) },
+                max_size: taffy::geometry::Size { width: length(200f32), height: length(60f32) },
+                aspect_ratio: Some(1f32),
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(100f32), height: auto() },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 60f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 60f32, height: 60f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 100f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 60f32, y: 0f32, width: 60f32, height: 60f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 100f32, height: 10f32);
+}
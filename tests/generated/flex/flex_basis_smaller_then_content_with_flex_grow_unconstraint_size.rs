@@ -1,174 +0,0 @@
-#[test]
-#[allow(non_snake_case)]
-fn flex_basis_smaller_then_content_with_flex_grow_unconstraint_size__border_box() {
-    #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
-    let mut taffy = crate::new_test_tree();
-    let node00 = taffy
-        .new_leaf(taffy::style::Style {
-            size: taffy::geometry::Size {
-                width: taffy::style::Dimension::from_length(70f32),
-                height: taffy::style::Dimension::from_length(100f32),
-            },
-            ..Default::default()
-        })
-        .unwrap();
-    let node0 = taffy
-        .new_with_children(
-            taffy::style::Style {
-                flex_direction: taffy::style::FlexDirection::Column,
-                flex_grow: 1f32,
-                flex_basis: taffy::style::Dimension::from_length(0f32),
-                ..Default::default()
-            },
-            &[node00],
-        )
-        .unwrap();
-    let node10 = taffy
-        .new_leaf(taffy::style::Style {
-            size: taffy::geometry::Size {
-                width: taffy::style::Dimension::from_length(20f32),
-                height: taffy::style::Dimension::from_length(100f32),
-            },
-            ..Default::default()
-        })
-        .unwrap();
-    let node1 = taffy
-        .new_with_children(
-            taffy::style::Style {
-                flex_direction: taffy::style::FlexDirection::Column,
-                flex_grow: 1f32,
-                flex_basis: taffy::style::Dimension::from_length(0f32),
-                ..Default::default()
-            },
-            &[node10],
-        )
-        .unwrap();
-    let node = taffy.new_with_children(taffy::style::Style { ..Default::default() }, &[node0, node1]).unwrap();
-    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
-    println!("\nComputed tree:");
-    taffy.print_tree(node);
-    println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 90f32, "width of node {:?}. Expected {}. Actual {}", node, 90f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 70f32, "width of node {:?}. Expected {}. Actual {}", node0, 70f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node0, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node0, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node0, 0f32, location.y);
-    let layout = taffy.layout(node00).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 70f32, "width of node {:?}. Expected {}. Actual {}", node00, 70f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node00, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node00, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node00, 0f32, location.y);
-    let layout = taffy.layout(node1).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 20f32, "width of node {:?}. Expected {}. Actual {}", node1, 20f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node1, 100f32, size.height);
-    assert_eq!(location.x, 70f32, "x of node {:?}. Expected {}. Actual {}", node1, 70f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node1, 0f32, location.y);
-    let layout = taffy.layout(node10).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 20f32, "width of node {:?}. Expected {}. Actual {}", node10, 20f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node10, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node10, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node10, 0f32, location.y);
-}
-
-#[test]
-#[allow(non_snake_case)]
-fn flex_basis_smaller_then_content_with_flex_grow_unconstraint_size__content_box() {
-    #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
-    let mut taffy = crate::new_test_tree();
-    let node00 = taffy
-        .new_leaf(taffy::style::Style {
-            box_sizing: taffy::style::BoxSizing::ContentBox,
-            size: taffy::geometry::Size {
-                width: taffy::style::Dimension::from_length(70f32),
-                height: taffy::style::Dimension::from_length(100f32),
-            },
-            ..Default::default()
-        })
-        .unwrap();
-    let node0 = taffy
-        .new_with_children(
-            taffy::style::Style {
-                box_sizing: taffy::style::BoxSizing::ContentBox,
-                flex_direction: taffy::style::FlexDirection::Column,
-                flex_grow: 1f32,
-                flex_basis: taffy::style::Dimension::from_length(0f32),
-                ..Default::default()
-            },
-            &[node00],
-        )
-        .unwrap();
-    let node10 = taffy
-        .new_leaf(taffy::style::Style {
-            box_sizing: taffy::style::BoxSizing::ContentBox,
-            size: taffy::geometry::Size {
-                width: taffy::style::Dimension::from_length(20f32),
-                height: taffy::style::Dimension::from_length(100f32),
-            },
-            ..Default::default()
-        })
-        .unwrap();
-    let node1 = taffy
-        .new_with_children(
-            taffy::style::Style {
-                box_sizing: taffy::style::BoxSizing::ContentBox,
-                flex_direction: taffy::style::FlexDirection::Column,
-                flex_grow: 1f32,
-                flex_basis: taffy::style::Dimension::from_length(0f32),
-                ..Default::default()
-            },
-            &[node10],
-        )
-        .unwrap();
-    let node = taffy
-        .new_with_children(
-            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
-            &[node0, node1],
-        )
-        .unwrap();
-    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
-    println!("\nComputed tree:");
-    taffy.print_tree(node);
-    println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 90f32, "width of node {:?}. Expected {}. Actual {}", node, 90f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 70f32, "width of node {:?}. Expected {}. Actual {}", node0, 70f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node0, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node0, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node0, 0f32, location.y);
-    let layout = taffy.layout(node00).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 70f32, "width of node {:?}. Expected {}. Actual {}", node00, 70f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node00, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node00, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node00, 0f32, location.y);
-    let layout = taffy.layout(node1).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 20f32, "width of node {:?}. Expected {}. Actual {}", node1, 20f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node1, 100f32, size.height);
-    assert_eq!(location.x, 70f32, "x of node {:?}. Expected {}. Actual {}", node1, 70f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node1, 0f32, location.y);
-    let layout = taffy.layout(node10).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert_eq!(size.width, 20f32, "width of node {:?}. Expected {}. Actual {}", node10, 20f32, size.width);
-    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node10, 100f32, size.height);
-    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node10, 0f32, location.x);
-    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node10, 0f32, location.y);
-}
@@ -0,0 +1,111 @@
+#[test]
+#[allow(non_snake_case)]
+fn flex_grow_min_and_max_violation_same_pass__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(200f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            max_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(40f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(300f32),
+                    height: taffy::style::Dimension::from_length(10f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 200f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 200f32, y: 0f32, width: 40f32, height: 10f32);
+    assert_layout!(taffy, node2, x: 240f32, y: 0f32, width: 60f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn flex_grow_min_and_max_violation_same_pass__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(200f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            max_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(40f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_grow: 1f32,
+            flex_basis: taffy::style::Dimension::from_length(0f32),
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(300f32),
+                    height: taffy::style::Dimension::from_length(10f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 200f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 200f32, y: 0f32, width: 40f32, height: 10f32);
+    assert_layout!(taffy, node2, x: 240f32, y: 0f32, width: 60f32, height: 10f32);
+}
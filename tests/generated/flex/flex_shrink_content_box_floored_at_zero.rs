@@ -0,0 +1,92 @@
+#[test]
+#[allow(non_snake_case)]
+fn flex_shrink_content_box_floored_at_zero__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_shrink: 10f32,
+            flex_basis: taffy::style::Dimension::from_length(40f32),
+            padding: taffy::geometry::Rect { left: length(30f32), right: zero(), top: zero(), bottom: zero() },
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(0f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_basis: taffy::style::Dimension::from_length(100f32),
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(0f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(60f32),
+                    height: taffy::style::Dimension::from_length(10f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 60f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 30f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 30f32, y: 0f32, width: 30f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn flex_shrink_content_box_floored_at_zero__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_shrink: 10f32,
+            flex_basis: taffy::style::Dimension::from_length(40f32),
+            padding: taffy::geometry::Rect { left: length(30f32), right: zero(), top: zero(), bottom: zero() },
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(0f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_basis: taffy::style::Dimension::from_length(100f32),
+            min_size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(0f32), height: auto() },
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(60f32),
+                    height: taffy::style::Dimension::from_length(10f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 60f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 30f32, height: 10f32);
+    assert_layout!(taffy, node1, x: 30f32, y: 0f32, width: 30f32, height: 10f32);
+}
@@ -0,0 +1,112 @@
+#[test]
+#[allow(non_snake_case)]
+fn gap_row_gap_wrap_align_content_space_around__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node3 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_wrap: taffy::style::FlexWrap::Wrap,
+                align_content: Some(taffy::style::AlignContent::SpaceAround),
+                gap: taffy::geometry::Size { width: length(20f32), height: length(10f32) },
+                size: taffy::geometry::Size { width: length(100f32), height: length(110f32) },
+                ..Default::default()
+            },
+            &[node0, node1, node2, node3],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 110f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 15f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 60f32, y: 15f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 0f32, y: 75f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node3, x: 60f32, y: 75f32, width: 40f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn gap_row_gap_wrap_align_content_space_around__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node3 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(40f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_wrap: taffy::style::FlexWrap::Wrap,
+                align_content: Some(taffy::style::AlignContent::SpaceAround),
+                gap: taffy::geometry::Size { width: length(20f32), height: length(10f32) },
+                size: taffy::geometry::Size { width: length(100f32), height: length(110f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1, node2, node3],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 110f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 15f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 60f32, y: 15f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 0f32, y: 75f32, width: 40f32, height: 20f32);
+    assert_layout!(taffy, node3, x: 60f32, y: 75f32, width: 40f32, height: 20f32);
+}
@@ -0,0 +1,163 @@
+#[test]
+#[allow(non_snake_case)]
+fn intrinsic_main_size_chosen_flex_fraction_min_content__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0000 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(150f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node000 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                flex_grow: 1.0,
+                flex_shrink: 0.0,
+                flex_basis: taffy::style::Dimension::from_length(100f32),
+                ..Default::default()
+            },
+            &[node0000],
+        )
+        .unwrap();
+    let node0010 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node001 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                flex_grow: 1.0,
+                flex_shrink: 0.0,
+                flex_basis: taffy::style::Dimension::from_length(100f32),
+                ..Default::default()
+            },
+            &[node0010],
+        )
+        .unwrap();
+    let node00 = taffy.new_with_children(taffy::style::Style { ..Default::default() }, &[node000, node001]).unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style { flex_direction: taffy::style::FlexDirection::Column, ..Default::default() },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 50f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node000, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node0000, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node001, x: 150f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node0010, x: 0f32, y: 0f32, width: 50f32, height: 10f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn intrinsic_main_size_chosen_flex_fraction_min_content__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0000 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(150f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node000 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                flex_grow: 1.0,
+                flex_shrink: 0.0,
+                flex_basis: taffy::style::Dimension::from_length(100f32),
+                ..Default::default()
+            },
+            &[node0000],
+        )
+        .unwrap();
+    let node0010 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(50f32),
+                height: taffy::style::Dimension::from_length(10f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node001 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                flex_grow: 1.0,
+                flex_shrink: 0.0,
+                flex_basis: taffy::style::Dimension::from_length(100f32),
+                ..Default::default()
+            },
+            &[node0010],
+        )
+        .unwrap();
+    let node00 = taffy
+        .new_with_children(
+            taffy::style::Style { box_sizing: taffy::style::BoxSizing::ContentBox, ..Default::default() },
+            &[node000, node001],
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                flex_direction: taffy::style::FlexDirection::Column,
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(50f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 50f32, height: 10f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 300f32, height: 10f32);
+    assert_layout!(taffy, node000, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node0000, x: 0f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node001, x: 150f32, y: 0f32, width: 150f32, height: 10f32);
+    assert_layout!(taffy, node0010, x: 0f32, y: 0f32, width: 50f32, height: 10f32);
+}
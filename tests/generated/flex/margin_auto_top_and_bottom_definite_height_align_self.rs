@@ -0,0 +1,84 @@
+#[test]
+#[allow(non_snake_case)]
+fn margin_auto_top_and_bottom_definite_height_align_self__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: auto() },
+            align_self: Some(taffy::style::AlignSelf::FlexEnd),
+            size: taffy::geometry::Size { width: length(50f32), height: length(50f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: zero() },
+            align_self: Some(taffy::style::AlignSelf::Stretch),
+            size: taffy::geometry::Size { width: length(50f32), height: length(50f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 25f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 50f32, width: 50f32, height: 50f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn margin_auto_top_and_bottom_definite_height_align_self__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: auto() },
+            align_self: Some(taffy::style::AlignSelf::FlexEnd),
+            size: taffy::geometry::Size { width: length(50f32), height: length(50f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: zero() },
+            align_self: Some(taffy::style::AlignSelf::Stretch),
+            size: taffy::geometry::Size { width: length(50f32), height: length(50f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 25f32, width: 50f32, height: 50f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 50f32, width: 50f32, height: 50f32);
+}
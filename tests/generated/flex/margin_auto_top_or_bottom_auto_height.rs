@@ -0,0 +1,120 @@
+#[test]
+#[allow(non_snake_case)]
+fn margin_auto_top_or_bottom_auto_height__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(30f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: zero() },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(30f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                margin: taffy::geometry::Rect { left: zero(), right: zero(), top: zero(), bottom: auto() },
+                align_self: Some(taffy::style::AlignSelf::FlexEnd),
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 80f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 30f32, y: 0f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 30f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn margin_auto_top_or_bottom_auto_height__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(30f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                margin: taffy::geometry::Rect { left: zero(), right: zero(), top: auto(), bottom: zero() },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(30f32), height: length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                margin: taffy::geometry::Rect { left: zero(), right: zero(), top: zero(), bottom: auto() },
+                align_self: Some(taffy::style::AlignSelf::FlexEnd),
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: length(100f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 100f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 80f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 30f32, y: 0f32, width: 30f32, height: 20f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 30f32, height: 20f32);
+}
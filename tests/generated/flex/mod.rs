@@ -13,6 +13,7 @@ mod absolute_child_with_cross_margin;
 mod absolute_child_with_main_margin;
 mod absolute_child_with_max_height;
 mod absolute_child_with_max_height_larger_shrinkable_grandchild;
+mod absolute_inset_zero_overflow_scroll;
 mod absolute_layout_align_items_and_justify_content_center;
 mod absolute_layout_align_items_and_justify_content_center_and_bottom_position;
 mod absolute_layout_align_items_and_justify_content_center_and_left_position;
@@ -45,7 +46,14 @@ mod absolute_minmax_top_left_bottom_right_min_max;
 mod absolute_padding_border_overrides_max_size;
 mod absolute_padding_border_overrides_size;
 mod absolute_resolved_insets;
+mod absolute_static_position_column;
+mod absolute_static_position_column_reverse;
+mod absolute_static_position_row;
+mod absolute_static_position_row_reverse;
+mod absolute_static_position_row_wrap_reverse;
 mod align_baseline;
+mod align_baseline_block_child_overflow_hidden;
+mod align_baseline_block_child_two_paragraphs;
 mod align_baseline_child;
 mod align_baseline_child_margin;
 mod align_baseline_child_margin_percent;
@@ -61,8 +69,14 @@ mod align_baseline_multiline;
 mod align_baseline_multiline_column;
 mod align_baseline_multiline_column2;
 mod align_baseline_multiline_row_and_column;
+mod align_baseline_nested_block_child;
 mod align_baseline_nested_child;
 mod align_baseline_nested_column;
+mod align_baseline_nested_column_block_child;
+mod align_baseline_nested_column_justify_content_end;
+mod align_baseline_nested_relative_offset_child;
+mod align_baseline_nested_row_in_column;
+mod align_baseline_relative_offset_item;
 mod align_center_should_size_based_on_content;
 mod align_content_center_single_line;
 mod align_content_center_single_line_negative_space;
@@ -172,6 +186,8 @@ mod aspect_ratio_flex_row_fill_min_height;
 mod aspect_ratio_flex_row_fill_min_width;
 mod aspect_ratio_flex_row_fill_width;
 mod aspect_ratio_flex_row_fill_width_flex;
+mod aspect_ratio_flex_row_shrink_transferred_min_size;
+mod aspect_ratio_flex_row_shrink_transferred_min_size_overflow_hidden;
 mod aspect_ratio_flex_row_stretch_fill_height;
 mod aspect_ratio_flex_row_stretch_fill_max_height;
 mod aspect_ratio_flex_row_stretch_fill_max_width;
@@ -227,7 +243,6 @@ mod flex_basis_smaller_than_main_dimen_column;
 mod flex_basis_smaller_than_main_dimen_row;
 mod flex_basis_smaller_then_content_with_flex_grow_large_size;
 mod flex_basis_smaller_then_content_with_flex_grow_small_size;
-mod flex_basis_smaller_then_content_with_flex_grow_unconstraint_size;
 mod flex_basis_smaller_then_content_with_flex_grow_very_large_size;
 mod flex_basis_unconstraint_column;
 mod flex_basis_unconstraint_row;
@@ -246,6 +261,7 @@ mod flex_grow_flex_basis_percent_min_max;
 mod flex_grow_height_maximized;
 mod flex_grow_in_at_most_container;
 mod flex_grow_less_than_factor_one;
+mod flex_grow_min_and_max_violation_same_pass;
 mod flex_grow_root_minimized;
 mod flex_grow_shrink_at_most;
 mod flex_grow_to_min;
@@ -259,9 +275,11 @@ mod flex_grow_within_max_width;
 mod flex_root_ignored;
 mod flex_row_relative_all_sides;
 mod flex_shrink_by_outer_margin_with_max_size;
+mod flex_shrink_content_box_floored_at_zero;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
 mod flex_shrink_to_zero;
+mod flex_shrink_zero_flex_basis_min_width_overflow;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
 mod flex_wrap_wrap_to_child_height;
@@ -297,8 +315,16 @@ mod gap_row_gap_align_items_end;
 mod gap_row_gap_align_items_stretch;
 mod gap_row_gap_column_child_margins;
 mod gap_row_gap_determines_parent_height;
+mod gap_row_gap_percentage_column_auto_height;
+mod gap_row_gap_percentage_column_definite_height;
 mod gap_row_gap_percentage_wrapping;
 mod gap_row_gap_row_wrap_child_margins;
+mod gap_row_gap_wrap_align_content_space_around;
+mod gap_row_gap_wrap_align_content_space_between;
+mod gap_row_gap_wrap_align_content_space_evenly;
+mod gap_row_gap_wrap_intrinsic_height;
+mod intrinsic_main_size_chosen_flex_fraction_max_content;
+mod intrinsic_main_size_chosen_flex_fraction_min_content;
 mod intrinsic_sizing_cross_size_column;
 mod intrinsic_sizing_main_size_column;
 mod intrinsic_sizing_main_size_column_nested;
@@ -345,8 +371,14 @@ mod justify_content_row_flex_start;
 mod justify_content_row_max_width_and_margin;
 mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
+mod justify_content_row_space_around_negative_space;
+mod justify_content_row_space_around_single_item;
 mod justify_content_row_space_between;
+mod justify_content_row_space_between_negative_space;
+mod justify_content_row_space_between_single_item;
 mod justify_content_row_space_evenly;
+mod justify_content_row_space_evenly_negative_space;
+mod justify_content_row_space_evenly_single_item;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -358,6 +390,7 @@ mod margin_auto_left;
 mod margin_auto_left_and_right;
 mod margin_auto_left_and_right_column;
 mod margin_auto_left_and_right_column_and_center;
+mod margin_auto_left_and_right_column_auto_width;
 mod margin_auto_left_and_right_stretch;
 mod margin_auto_left_child_bigger_than_parent;
 mod margin_auto_left_fix_right_child_bigger_than_parent;
@@ -367,7 +400,11 @@ mod margin_auto_multiple_children_column;
 mod margin_auto_multiple_children_row;
 mod margin_auto_right;
 mod margin_auto_top;
+mod margin_auto_top_and_bottom_auto_height;
+mod margin_auto_top_and_bottom_definite_height_align_self;
+mod margin_auto_top_and_bottom_percentage_height_child;
 mod margin_auto_top_and_bottom_stretch;
+mod margin_auto_top_or_bottom_auto_height;
 mod margin_auto_top_stretching_child;
 mod margin_bottom;
 mod margin_fix_left_auto_right_child_bigger_than_parent;
@@ -392,6 +429,8 @@ mod measure_child_with_flex_grow;
 mod measure_child_with_flex_shrink;
 mod measure_child_with_flex_shrink_hidden;
 mod measure_child_with_min_size_greater_than_available_space;
+mod measure_child_with_padding_wraps_in_fixed_width_column;
+mod measure_child_with_padding_wraps_in_fixed_width_row;
 mod measure_flex_basis_overrides_measure;
 mod measure_height_overrides_measure;
 mod measure_remeasure_child_after_growing;
@@ -423,6 +462,11 @@ mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
 mod only_shrinkable_item_with_flex_basis_zero;
 mod overflow_cross_axis;
+mod overflow_hidden_item_end_margin_contributes_to_scroll_size;
+mod overflow_hidden_item_negative_end_margin_scroll_size;
+mod overflow_hidden_item_negative_start_margin_scroll_size;
+mod overflow_hidden_justify_content_end_start_overflow_scroll_size;
+mod overflow_hidden_relative_offset_scroll_size;
 mod overflow_main_axis;
 mod overflow_main_axis_shrink_hidden;
 mod overflow_main_axis_shrink_scroll;
@@ -438,8 +482,6 @@ mod padding_align_end_child;
 mod padding_border_overrides_max_size;
 mod padding_border_overrides_min_size;
 mod padding_border_overrides_size;
-mod padding_border_overrides_size_flex_basis_0;
-mod padding_border_overrides_size_flex_basis_0_growable;
 mod padding_border_overrides_size_root;
 mod padding_center_child;
 mod padding_container_match_child;
@@ -463,6 +505,8 @@ mod percentage_flex_basis_cross_min_width;
 mod percentage_flex_basis_main_max_height;
 mod percentage_flex_basis_main_max_width;
 mod percentage_flex_basis_main_min_width;
+mod percentage_height_child_of_stretched_item_container_min_height;
+mod percentage_height_child_of_stretched_item_wrap_align_content_stretch;
 mod percentage_main_max_height;
 mod percentage_margin_should_calculate_based_only_on_width;
 mod percentage_moderate_complexity;
@@ -497,6 +541,7 @@ mod rounding_total_fractial_nested;
 mod scroll_size;
 mod simple_child;
 mod single_flex_child_after_absolute_child;
+mod single_line_min_height;
 mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
@@ -516,15 +561,21 @@ mod width_smaller_then_content_with_flex_grow_very_large_size;
 mod wrap_child;
 mod wrap_column;
 mod wrap_grandchild;
+mod wrap_max_height_smaller_than_lines;
+mod wrap_max_height_smaller_than_lines_align_content_center;
 mod wrap_nodes_with_content_sizing_margin_cross;
 mod wrap_nodes_with_content_sizing_overflowing_margin;
 mod wrap_reverse_column;
+mod wrap_reverse_column_align_content_space_between;
 mod wrap_reverse_column_fixed_size;
 mod wrap_reverse_row;
 mod wrap_reverse_row_align_content_center;
 mod wrap_reverse_row_align_content_flex_start;
 mod wrap_reverse_row_align_content_space_around;
+mod wrap_reverse_row_align_content_space_between;
 mod wrap_reverse_row_align_content_stretch;
+mod wrap_reverse_row_align_items_baseline;
+mod wrap_reverse_row_align_self;
 mod wrap_reverse_row_single_line_different_size;
 mod wrap_row;
 mod wrap_row_align_items_center;
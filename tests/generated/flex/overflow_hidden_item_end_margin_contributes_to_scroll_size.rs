@@ -0,0 +1,89 @@
+#[test]
+#[allow(non_snake_case)]
+fn overflow_hidden_item_end_margin_contributes_to_scroll_size__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(200f32),
+                height: taffy::style::Dimension::from_length(50f32),
+            },
+            margin: taffy::geometry::Rect { left: zero(), right: length(20f32), top: zero(), bottom: length(70f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Hidden,
+                    y: taffy::style::Overflow::Hidden,
+                },
+                scrollbar_width: 15f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    #[cfg(feature = "content_size")]
+    assert_layout!(taffy, node, scroll_width: 120f32, scroll_height: 20f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 200f32, height: 50f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn overflow_hidden_item_end_margin_contributes_to_scroll_size__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            flex_shrink: 0f32,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(200f32),
+                height: taffy::style::Dimension::from_length(50f32),
+            },
+            margin: taffy::geometry::Rect { left: zero(), right: length(20f32), top: zero(), bottom: length(70f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                overflow: taffy::geometry::Point {
+                    x: taffy::style::Overflow::Hidden,
+                    y: taffy::style::Overflow::Hidden,
+                },
+                scrollbar_width: 15f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 100f32);
+    #[cfg(feature = "content_size")]
+    assert_layout!(taffy, node, scroll_width: 120f32, scroll_height: 20f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 200f32, height: 50f32);
+}
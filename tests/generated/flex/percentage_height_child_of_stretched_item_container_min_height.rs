@@ -0,0 +1,152 @@
+#[test]
+#[allow(non_snake_case)]
+fn percentage_height_child_of_stretched_item_container_min_height__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style { display: taffy::style::Display::Flex, ..Default::default() },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_direction: taffy::style::FlexDirection::Column,
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node20 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::Style { display: taffy::style::Display::Block, ..Default::default() },
+            &[node20],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: auto() },
+                min_size: taffy::geometry::Size { width: auto(), height: length(300f32) },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+    assert_layout!(taffy, node1, x: 20f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+    assert_layout!(taffy, node2, x: 40f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node20, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn percentage_height_child_of_stretched_item_container_min_height__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node10 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                flex_direction: taffy::style::FlexDirection::Column,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node10],
+        )
+        .unwrap();
+    let node20 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: length(20f32), height: percent(0.5f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Block,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node20],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Flex,
+                size: taffy::geometry::Size { width: length(200f32), height: auto() },
+                min_size: taffy::geometry::Size { width: auto(), height: length(300f32) },
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 200f32, height: 300f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node00, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+    assert_layout!(taffy, node1, x: 20f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node10, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+    assert_layout!(taffy, node2, x: 40f32, y: 0f32, width: 20f32, height: 300f32);
+    assert_layout!(taffy, node20, x: 0f32, y: 0f32, width: 20f32, height: 150f32);
+}
@@ -2,7 +2,7 @@
 #[allow(non_snake_case)]
 fn percentage_moderate_complexity__border_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     taffy.disable_rounding();
     let node00 = taffy
@@ -67,61 +67,16 @@ fn percentage_moderate_complexity__border_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!((size.width - 200f32).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", node, 200f32, size.width);
-    assert!(
-        (size.height - 42.15625f32).abs() < 0.1,
-        "height of node {:?}. Expected {}. Actual {}",
-        node,
-        42.15625f32,
-        size.height
-    );
-    assert!((location.x - 0f32).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert!((location.y - 0f32).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!((size.width - 97f32).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", node0, 97f32, size.width);
-    assert!(
-        (size.height - 26.15625f32).abs() < 0.1,
-        "height of node {:?}. Expected {}. Actual {}",
-        node0,
-        26.15625f32,
-        size.height
-    );
-    assert!((location.x - 8f32).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", node0, 8f32, location.x);
-    assert!((location.y - 8f32).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", node0, 8f32, location.y);
-    let layout = taffy.layout(node00).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!(
-        (size.width - 38.414063f32).abs() < 0.1,
-        "width of node {:?}. Expected {}. Actual {}",
-        node00,
-        38.414063f32,
-        size.width
-    );
-    assert!((size.height - 6f32).abs() < 0.1, "height of node {:?}. Expected {}. Actual {}", node00, 6f32, size.height);
-    assert!(
-        (location.x - 10.078125f32).abs() < 0.1,
-        "x of node {:?}. Expected {}. Actual {}",
-        node00,
-        10.078125f32,
-        location.x
-    );
-    assert!(
-        (location.y - 10.078125f32).abs() < 0.1,
-        "y of node {:?}. Expected {}. Actual {}",
-        node00,
-        10.078125f32,
-        location.y
-    );
+    assert_layout!(taffy, node, tolerance: 0.1, x: 0f32, y: 0f32, width: 200f32, height: 42.15625f32);
+    assert_layout!(taffy, node0, tolerance: 0.1, x: 8f32, y: 8f32, width: 97f32, height: 26.15625f32);
+    assert_layout!(taffy, node00, tolerance: 0.1, x: 10.078125f32, y: 10.078125f32, width: 38.414063f32, height: 6f32);
 }
 
 #[test]
 #[allow(non_snake_case)]
 fn percentage_moderate_complexity__content_box() {
     #[allow(unused_imports)]
-    use taffy::{prelude::*, Layout};
+    use taffy::{assert_layout, prelude::*};
     let mut taffy = crate::new_test_tree();
     taffy.disable_rounding();
     let node00 = taffy
@@ -189,28 +144,7 @@ fn percentage_moderate_complexity__content_box() {
     println!("\nComputed tree:");
     taffy.print_tree(node);
     println!();
-    let layout = taffy.layout(node).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!((size.width - 206f32).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", node, 206f32, size.width);
-    assert!((size.height - 44f32).abs() < 0.1, "height of node {:?}. Expected {}. Actual {}", node, 44f32, size.height);
-    assert!((location.x - 0f32).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", node, 0f32, location.x);
-    assert!((location.y - 0f32).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", node, 0f32, location.y);
-    let layout = taffy.layout(node0).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!((size.width - 112f32).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", node0, 112f32, size.width);
-    assert!(
-        (size.height - 28f32).abs() < 0.1,
-        "height of node {:?}. Expected {}. Actual {}",
-        node0,
-        28f32,
-        size.height
-    );
-    assert!((location.x - 8f32).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", node0, 8f32, location.x);
-    assert!((location.y - 8f32).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", node0, 8f32, location.y);
-    let layout = taffy.layout(node00).unwrap();
-    let Layout { size, location, .. } = layout;
-    assert!((size.width - 51f32).abs() < 0.1, "width of node {:?}. Expected {}. Actual {}", node00, 51f32, size.width);
-    assert!((size.height - 6f32).abs() < 0.1, "height of node {:?}. Expected {}. Actual {}", node00, 6f32, size.height);
-    assert!((location.x - 11f32).abs() < 0.1, "x of node {:?}. Expected {}. Actual {}", node00, 11f32, location.x);
-    assert!((location.y - 11f32).abs() < 0.1, "y of node {:?}. Expected {}. Actual {}", node00, 11f32, location.y);
+    assert_layout!(taffy, node, tolerance: 0.1, x: 0f32, y: 0f32, width: 206f32, height: 44f32);
+    assert_layout!(taffy, node0, tolerance: 0.1, x: 8f32, y: 8f32, width: 112f32, height: 28f32);
+    assert_layout!(taffy, node00, tolerance: 0.1, x: 11f32, y: 11f32, width: 51f32, height: 6f32);
 }
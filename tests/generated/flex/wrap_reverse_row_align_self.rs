@@ -0,0 +1,121 @@
+#[test]
+#[allow(non_snake_case)]
+fn wrap_reverse_row_align_self__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            align_self: Some(taffy::style::AlignSelf::FlexStart),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            align_self: Some(taffy::style::AlignSelf::Start),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            align_self: Some(taffy::style::AlignSelf::End),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                flex_wrap: taffy::style::FlexWrap::WrapReverse,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(240f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 240f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 220f32, width: 60f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 80f32, width: 60f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 0f32, y: 60f32, width: 60f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn wrap_reverse_row_align_self__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            align_self: Some(taffy::style::AlignSelf::FlexStart),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            align_self: Some(taffy::style::AlignSelf::Start),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            align_self: Some(taffy::style::AlignSelf::End),
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from_length(60f32),
+                height: taffy::style::Dimension::from_length(20f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                flex_wrap: taffy::style::FlexWrap::WrapReverse,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::from_length(100f32),
+                    height: taffy::style::Dimension::from_length(240f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 100f32, height: 240f32);
+    assert_layout!(taffy, node0, x: 0f32, y: 220f32, width: 60f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 0f32, y: 80f32, width: 60f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 0f32, y: 60f32, width: 60f32, height: 20f32);
+}
@@ -0,0 +1,110 @@
+#[test]
+#[allow(non_snake_case)]
+fn grid_justify_content_center_overflowing_tracks__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node3 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Grid,
+                justify_content: Some(taffy::style::JustifyContent::Center),
+                grid_template_columns: vec![length(100f32), length(100f32), length(100f32), length(100f32)],
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(300f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0, node1, node2, node3],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 20f32);
+    assert_layout!(taffy, node0, x: -50f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 150f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node3, x: 250f32, y: 0f32, width: 100f32, height: 20f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn grid_justify_content_center_overflowing_tracks__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node2 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node3 = taffy
+        .new_leaf(taffy::style::Style {
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(20f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Grid,
+                box_sizing: taffy::style::BoxSizing::ContentBox,
+                justify_content: Some(taffy::style::JustifyContent::Center),
+                grid_template_columns: vec![length(100f32), length(100f32), length(100f32), length(100f32)],
+                size: taffy::geometry::Size { width: taffy::style::Dimension::from_length(300f32), height: auto() },
+                ..Default::default()
+            },
+            &[node0, node1, node2, node3],
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 300f32, height: 20f32);
+    assert_layout!(taffy, node0, x: -50f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node1, x: 50f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node2, x: 150f32, y: 0f32, width: 100f32, height: 20f32);
+    assert_layout!(taffy, node3, x: 250f32, y: 0f32, width: 100f32, height: 20f32);
+}
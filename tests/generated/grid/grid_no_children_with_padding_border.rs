@@ -0,0 +1,62 @@
+#[test]
+#[allow(non_snake_case)]
+fn grid_no_children_with_padding_border__border_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Grid,
+            padding: taffy::geometry::Rect {
+                left: length(10f32),
+                right: length(10f32),
+                top: length(10f32),
+                bottom: length(10f32),
+            },
+            border: taffy::geometry::Rect {
+                left: length(5f32),
+                right: length(5f32),
+                top: length(5f32),
+                bottom: length(5f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 30f32, height: 30f32);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn grid_no_children_with_padding_border__content_box() {
+    #[allow(unused_imports)]
+    use taffy::{assert_layout, prelude::*};
+    let mut taffy = crate::new_test_tree();
+    let node = taffy
+        .new_leaf(taffy::style::Style {
+            display: taffy::style::Display::Grid,
+            box_sizing: taffy::style::BoxSizing::ContentBox,
+            padding: taffy::geometry::Rect {
+                left: length(10f32),
+                right: length(10f32),
+                top: length(10f32),
+                bottom: length(10f32),
+            },
+            border: taffy::geometry::Rect {
+                left: length(5f32),
+                right: length(5f32),
+                top: length(5f32),
+                bottom: length(5f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    taffy.compute_layout_with_measure(node, taffy::geometry::Size::MAX_CONTENT, crate::test_measure_function).unwrap();
+    println!("\nComputed tree:");
+    taffy.print_tree(node);
+    println!();
+    assert_layout!(taffy, node, x: 0f32, y: 0f32, width: 30f32, height: 30f32);
+}
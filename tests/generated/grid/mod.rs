@@ -28,6 +28,8 @@ mod grid_absolute_container_top_right;
 #[cfg(feature = "grid")]
 mod grid_absolute_container_top_right_margin;
 #[cfg(feature = "grid")]
+mod grid_absolute_inset_zero_overflow_scroll;
+#[cfg(feature = "grid")]
 mod grid_absolute_justify_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_absolute_layout_within_border;
@@ -150,14 +152,24 @@ mod grid_aspect_ratio_overridden_by_explicit_sizes;
 #[cfg(feature = "grid")]
 mod grid_aspect_ratio_overridden_by_explicit_sizes_flex;
 #[cfg(feature = "grid")]
+mod grid_auto_column_flex_wrap_max_width;
+#[cfg(feature = "grid")]
+mod grid_auto_column_flex_wrap_max_width_multiple_items_per_line;
+#[cfg(feature = "grid")]
+mod grid_auto_column_flex_wrap_no_max_width;
+#[cfg(feature = "grid")]
 mod grid_auto_columns;
 #[cfg(feature = "grid")]
 mod grid_auto_columns_fixed_width;
 #[cfg(feature = "grid")]
+mod grid_auto_fill_after_fixed_count_repetition;
+#[cfg(feature = "grid")]
 mod grid_auto_fill_fixed_size;
 #[cfg(feature = "grid")]
 mod grid_auto_fill_with_empty_auto_track;
 #[cfg(feature = "grid")]
+mod grid_auto_fill_zero_size_tracks;
+#[cfg(feature = "grid")]
 mod grid_auto_fit_definite_percentage;
 #[cfg(feature = "grid")]
 mod grid_auto_fit_with_empty_auto_track;
@@ -188,6 +200,8 @@ mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
 mod grid_display_none_fixed_size;
 #[cfg(feature = "grid")]
+mod grid_empty_template_with_gap_and_content_alignment;
+#[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_argument;
 #[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_max_content;
@@ -240,38 +254,52 @@ mod grid_justify_content_center;
 #[cfg(feature = "grid")]
 mod grid_justify_content_center_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_center_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_center_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_end;
 #[cfg(feature = "grid")]
 mod grid_justify_content_end_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_end_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_end_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_around;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_around_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_space_around_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_around_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_between;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_between_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_space_between_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_between_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_evenly;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_evenly_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_space_evenly_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_evenly_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_start;
 #[cfg(feature = "grid")]
 mod grid_justify_content_start_negative_space_gap;
 #[cfg(feature = "grid")]
+mod grid_justify_content_start_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_content_stretch_overflowing_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_justify_items_sized_end;
@@ -302,6 +330,10 @@ mod grid_margins_percent_start;
 #[cfg(feature = "grid")]
 mod grid_margins_percent_stretch;
 #[cfg(feature = "grid")]
+mod grid_max_content_gap_with_auto_columns_and_auto_placed_items;
+#[cfg(feature = "grid")]
+mod grid_max_content_gap_with_implicit_columns;
+#[cfg(feature = "grid")]
 mod grid_max_content_maximum_single_item;
 #[cfg(feature = "grid")]
 mod grid_max_content_single_item;
@@ -328,6 +360,8 @@ mod grid_max_width_smaller_than_max_content;
 #[cfg(feature = "grid")]
 mod grid_max_width_smaller_than_min_content;
 #[cfg(feature = "grid")]
+mod grid_min_content_and_max_content_columns_block_child_text;
+#[cfg(feature = "grid")]
 mod grid_min_content_flex_column;
 #[cfg(feature = "grid")]
 mod grid_min_content_flex_row;
@@ -390,6 +424,10 @@ mod grid_minmax_min_content_percent_definite;
 #[cfg(feature = "grid")]
 mod grid_minmax_min_content_percent_indefinite;
 #[cfg(feature = "grid")]
+mod grid_no_children_with_padding_border;
+#[cfg(feature = "grid")]
+mod grid_only_absolute_and_hidden_children;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
 mod grid_overflow_inline_axis_hidden;
@@ -450,6 +488,10 @@ mod grid_placement_auto_negative;
 #[cfg(feature = "grid")]
 mod grid_placement_definite_in_secondary_axis_with_fully_definite_negative;
 #[cfg(feature = "grid")]
+mod grid_placement_definite_secondary_axis_after_auto_items;
+#[cfg(feature = "grid")]
+mod grid_placement_definite_secondary_axis_after_auto_items_column_flow;
+#[cfg(feature = "grid")]
 mod grid_relative_all_sides;
 #[cfg(feature = "grid")]
 mod grid_relayout_vertical_text;
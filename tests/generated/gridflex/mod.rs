@@ -9,4 +9,6 @@ mod gridflex_kitchen_sink_minimise2;
 #[cfg(feature = "grid")]
 mod gridflex_kitchen_sink_minimise3;
 #[cfg(feature = "grid")]
+mod gridflex_row_grid_with_gap_and_implicit_columns;
+#[cfg(feature = "grid")]
 mod gridflex_row_integration;
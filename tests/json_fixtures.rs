@@ -0,0 +1,22 @@
+//! Runs the layout test fixtures written as JSON in `tests/json_fixtures`
+#![cfg(feature = "json_fixtures")]
+
+use taffy::test_utils::{build_tree_from_json, test_measure_function};
+
+#[test]
+fn json_fixtures() {
+    let mut paths: Vec<_> = std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/json_fixtures"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() == Some("json".as_ref()))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty());
+
+    for path in paths {
+        println!("{}", path.display());
+        let (mut taffy, root, expected) = build_tree_from_json(&std::fs::read_to_string(&path).unwrap());
+        taffy.compute_layout_with_measure(root, expected.available_space, test_measure_function).unwrap();
+        expected.assert_matches(&taffy);
+    }
+}
@@ -0,0 +1,65 @@
+//! Tests for laying out nodes far from the origin, where `f32`s can't represent sub-pixel positions (above 16,777,216
+//! they can't even represent every whole pixel), so only run with the `f64` feature.
+#![cfg(feature = "f64")]
+
+use taffy::prelude::*;
+
+/// Builds a column of rows 10.25px tall that starts 50,000,000px down the page
+fn distant_column(taffy: &mut TaffyTree<()>) -> (NodeId, Vec<NodeId>) {
+    let rows: Vec<NodeId> = (0..8)
+        .map(|_| taffy.new_leaf(Style { size: Size::from_lengths(100.0, 10.25), ..Default::default() }))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let column = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                padding: Rect { top: length(50_000_000.0), ..Rect::zero() },
+                ..Default::default()
+            },
+            &rows,
+        )
+        .unwrap();
+    (column, rows)
+}
+
+#[test]
+fn sub_pixel_positions_are_exact_around_50_000_000() {
+    let mut taffy = TaffyTree::new();
+    taffy.disable_rounding();
+    let (column, rows) = distant_column(&mut taffy);
+    taffy.compute_layout(column, Size::MAX_CONTENT).unwrap();
+
+    for (index, &row) in rows.iter().enumerate() {
+        let layout = taffy.layout(row).unwrap();
+        assert_eq!(layout.location.y, 50_000_000.0 + 10.25 * index as Scalar, "row {index}");
+        assert_eq!(layout.size.height, 10.25, "row {index}");
+    }
+    assert_eq!(taffy.layout(column).unwrap().size.height, 50_000_082.0);
+}
+
+#[test]
+fn rounded_rows_tile_without_gaps_around_50_000_000() {
+    let mut taffy = TaffyTree::new();
+    let (column, rows) = distant_column(&mut taffy);
+    taffy.compute_layout(column, Size::MAX_CONTENT).unwrap();
+
+    let mut top = 50_000_000.0;
+    for (index, &row) in rows.iter().enumerate() {
+        let layout = taffy.layout(row).unwrap();
+        let bottom = 50_000_000.0 + 10.25 * (index + 1) as Scalar;
+        assert_eq!(layout.location.y, top, "row {index}");
+        assert_eq!(layout.size.height, bottom.round() - top, "row {index}");
+        top = bottom.round();
+    }
+
+    // Laying out again after a change to another part of the tree moves none of the rows
+    let layouts: Vec<Layout> = rows.iter().map(|&row| *taffy.layout(row).unwrap()).collect();
+    let last_row = *rows.last().unwrap();
+    taffy.set_style(last_row, Style { size: Size::from_lengths(100.0, 20.5), ..Default::default() }).unwrap();
+    taffy.compute_layout(column, Size::MAX_CONTENT).unwrap();
+    for (&row, layout) in rows.iter().zip(&layouts).take(rows.len() - 1) {
+        assert_eq!(taffy.layout(row).unwrap(), layout);
+    }
+    assert_eq!(taffy.layout(column).unwrap().size.height, 50_000_092.0);
+}
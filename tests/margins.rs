@@ -0,0 +1,83 @@
+use taffy::{assert_layout, prelude::*};
+use taffy_test_helpers::new_test_tree;
+
+#[test]
+fn auto_margins_of_a_centered_flex_item_are_resolved() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: length(40.0), height: length(20.0) },
+            margin: Rect { left: auto(), right: auto(), top: auto(), bottom: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(100.0), height: length(50.0) }, ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_layout!(taffy, child, x: 30.0, y: 15.0, width: 40.0, height: 20.0);
+    assert_layout!(taffy, child, margin_left: 30.0, margin_right: 30.0, margin_top: 15.0, margin_bottom: 15.0);
+}
+
+#[test]
+fn percentage_margins_of_a_grid_item_are_resolved() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: length(40.0), height: length(20.0) },
+            margin: Rect { left: percent(0.1), right: zero(), top: percent(0.05), bottom: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                size: Size { width: length(200.0), height: length(50.0) },
+                align_items: Some(AlignItems::Start),
+                justify_items: Some(JustifyItems::Start),
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Percentage margins are resolved against the width of the grid area
+    assert_layout!(taffy, child, x: 20.0, y: 10.0, margin_left: 20.0, margin_top: 10.0, margin_bottom: 20.0);
+}
+
+#[test]
+fn block_items_report_their_margins_before_collapsing() {
+    let mut taffy = new_test_tree();
+    let item_style = Style {
+        display: Display::Block,
+        size: Size { width: auto(), height: length(10.0) },
+        margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(5.0) },
+        ..Default::default()
+    };
+    let first = taffy.new_leaf(item_style.clone()).unwrap();
+    let second = taffy.new_leaf(item_style).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(100.0), height: auto() },
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The adjacent bottom and top margins collapse into a single 5px gap, but each item reports its own margins
+    assert_layout!(taffy, first, y: 5.0, margin_top: 5.0, margin_bottom: 5.0);
+    assert_layout!(taffy, second, y: 20.0, margin_top: 5.0, margin_bottom: 5.0);
+}
@@ -209,12 +209,12 @@ mod measure {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
 
         fn custom_measure_function(
-            known_dimensions: Size<Option<f32>>,
+            known_dimensions: Size<Option<Scalar>>,
             _available_space: Size<AvailableSpace>,
             _node_id: NodeId,
             _node_context: Option<&mut ()>,
             _style: &Style,
-        ) -> taffy::geometry::Size<f32> {
+        ) -> taffy::geometry::Size<Scalar> {
             let height = known_dimensions.height.unwrap_or(50.0);
             let width = known_dimensions.width.unwrap_or(height);
             Size { width, height }
@@ -238,6 +238,115 @@ mod measure {
         assert_eq!(taffy.layout(child).unwrap().size.height, 100.0);
     }
 
+    #[test]
+    fn measured_baselines_align_text_of_different_line_heights() {
+        // The node context is the line height of a single line of text
+        let mut taffy: TaffyTree<Scalar> = TaffyTree::new();
+
+        fn text_measure_function(
+            known_dimensions: Size<Option<Scalar>>,
+            _available_space: Size<AvailableSpace>,
+            _node_id: NodeId,
+            line_height: Option<&mut Scalar>,
+            _style: &Style,
+        ) -> taffy::MeasureOutput {
+            let line_height = line_height.copied().unwrap_or(0.0);
+            let size = Size {
+                width: known_dimensions.width.unwrap_or(50.0),
+                height: known_dimensions.height.unwrap_or(line_height),
+            };
+            let baseline = Some(line_height * 0.8);
+            taffy::MeasureOutput { size, content_size: None, first_baseline: baseline, last_baseline: baseline }
+        }
+
+        let small_text = taffy
+            .new_leaf_with_context(
+                Style {
+                    padding: Rect { top: length(5.0), bottom: zero(), left: zero(), right: zero() },
+                    ..Default::default()
+                },
+                20.0,
+            )
+            .unwrap();
+        let large_text = taffy.new_leaf_with_context(Style::default(), 40.0).unwrap();
+
+        let node = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::Baseline), ..Default::default() },
+                &[small_text, large_text],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+        // The small text's baseline is 5 + 16 = 21 from the top of its border box and the large text's is 32
+        assert_eq!(taffy.layout(small_text).unwrap().size.height, 25.0);
+        assert_eq!(taffy.layout(small_text).unwrap().location.y, 11.0);
+        assert_eq!(taffy.layout(large_text).unwrap().size.height, 40.0);
+        assert_eq!(taffy.layout(large_text).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(node).unwrap().size.height, 40.0);
+    }
+
+    #[test]
+    fn measured_content_size_overflows_constrained_leaf() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        // An unbreakable word that is 200px wide, but which shrinks its box to fit the width that it's constrained to
+        fn unbreakable_measure_function(
+            known_dimensions: Size<Option<Scalar>>,
+            available_space: Size<AvailableSpace>,
+            _node_id: NodeId,
+            _node_context: Option<&mut ()>,
+            _style: &Style,
+        ) -> taffy::MeasureOutput {
+            let content_size = Size { width: 200.0, height: 10.0 };
+            let width = known_dimensions.width.unwrap_or(match available_space.width {
+                AvailableSpace::Definite(width) => width.min(content_size.width),
+                _ => content_size.width,
+            });
+            let size = Size { width, height: known_dimensions.height.unwrap_or(content_size.height) };
+            taffy::MeasureOutput { size, content_size: Some(content_size), first_baseline: None, last_baseline: None }
+        }
+
+        let style = Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() };
+        let child = taffy.new_leaf_with_context(style.clone(), ()).unwrap();
+        let node = taffy.new_with_children(style, &[child]).unwrap();
+
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, unbreakable_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
+        assert_eq!(taffy.layout(child).unwrap().content_size.width, 200.0);
+        assert_eq!(taffy.layout(node).unwrap().scroll_width(), 100.0);
+    }
+
+    #[test]
+    fn measure_args_run_mode_distinguishes_sizing_from_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf_with_context(Style::default(), ()).unwrap();
+        let node = taffy
+            .new_with_children(
+                Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+
+        let mut run_modes = Vec::new();
+        taffy
+            .compute_layout_with_measure_ex(node, Size::MAX_CONTENT, |args: taffy::MeasureArgs<()>| {
+                assert_eq!(args.node_id, child);
+                assert!(args.node_context.is_some());
+                run_modes.push(args.run_mode);
+                Size { width: 50.0, height: 50.0 }
+            })
+            .unwrap();
+
+        // The child is measured to determine its flex basis and the container's height, and is then laid out
+        assert!(run_modes.len() > 1);
+        assert_eq!(run_modes.last(), Some(&taffy::RunMode::PerformLayout));
+        assert!(run_modes[..run_modes.len() - 1].iter().all(|mode| *mode == taffy::RunMode::ComputeSize));
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 50.0, height: 50.0 });
+    }
+
     #[test]
     fn width_overrides_measure() {
         let mut taffy = new_test_tree();
@@ -0,0 +1,68 @@
+//! Lays out a [`FixedTaffy`] in a `#![no_std]` crate, with Taffy built without the `std` and `alloc` features.
+//!
+//! As well as being tested on the host, this crate is built for a bare-metal target in CI, which has no allocator.
+#![no_std]
+
+use taffy::prelude::*;
+use taffy::{FixedTaffy, TaffyResult};
+
+/// A tree of the size that a small embedded UI might use
+pub type Tree = FixedTaffy<32, 8>;
+
+/// Builds a row of `count` equally sized buttons along the top of a 320x240 screen, and returns the root node and the
+/// buttons
+pub fn build_toolbar(tree: &mut Tree, count: usize) -> TaffyResult<(NodeId, [Option<NodeId>; 8])> {
+    let mut buttons = [None; 8];
+    let screen = tree.new_leaf(Style {
+        flex_direction: FlexDirection::Column,
+        size: Size::from_lengths(320.0, 240.0),
+        ..Style::DEFAULT
+    })?;
+    let toolbar = tree.new_leaf(Style {
+        gap: Size::length(4.0),
+        padding: Rect::length(4.0),
+        size: Size { width: Dimension::percent(1.0), height: Dimension::length(40.0) },
+        ..Style::DEFAULT
+    })?;
+    tree.add_child(screen, toolbar)?;
+    for index in 0..count {
+        let button = tree.new_leaf(Style { flex_grow: 1.0, ..Style::DEFAULT })?;
+        tree.add_child(toolbar, button)?;
+        if let Some(slot) = buttons.get_mut(index) {
+            *slot = Some(button);
+        }
+    }
+    Ok((screen, buttons))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use taffy::{Point, TaffyError};
+
+    #[test]
+    fn lays_out_a_toolbar_without_allocating() {
+        let mut tree = Tree::new();
+        let (screen, buttons) = build_toolbar(&mut tree, 4).unwrap();
+        tree.compute_layout(screen, Size::MAX_CONTENT).unwrap();
+
+        let [first, second, third] = [0, 1, 2].map(|index| buttons[index].unwrap());
+        assert_eq!(tree.layout(first).unwrap().location, Point { x: 4.0, y: 4.0 });
+        assert_eq!(tree.layout(second).unwrap().location, Point { x: 83.0, y: 4.0 });
+        assert_eq!(tree.layout(third).unwrap().size, Size { width: 75.0, height: 32.0 });
+    }
+
+    #[test]
+    fn reports_exceeding_the_capacity_of_the_tree() {
+        let mut tree = Tree::new();
+        let result = build_toolbar(&mut tree, 9);
+        let toolbar = tree.children(NodeId::from(0_usize)).unwrap()[0];
+        assert_eq!(result, Err(TaffyError::ChildCapacityExceeded { parent: toolbar, capacity: 8 }));
+
+        tree.clear();
+        while tree.total_node_count() < 32 {
+            tree.new_leaf(Style::DEFAULT).unwrap();
+        }
+        assert_eq!(tree.new_leaf(Style::DEFAULT), Err(TaffyError::NodeCapacityExceeded { capacity: 32 }));
+    }
+}
@@ -36,7 +36,7 @@ fn relayout() {
     taffy
         .compute_layout(
             node,
-            taffy::geometry::Size { width: AvailableSpace::Definite(100f32), height: AvailableSpace::Definite(100f32) },
+            taffy::geometry::Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::Definite(100.0) },
         )
         .unwrap();
     let initial = taffy.layout(node).unwrap().location;
@@ -47,8 +47,8 @@ fn relayout() {
             .compute_layout(
                 node,
                 taffy::geometry::Size {
-                    width: AvailableSpace::Definite(100f32),
-                    height: AvailableSpace::Definite(100f32),
+                    width: AvailableSpace::Definite(100.0),
+                    height: AvailableSpace::Definite(100.0),
                 },
             )
             .unwrap();
@@ -184,6 +184,46 @@ fn toggle_flex_child_display_none() {
     assert_eq!(layout.size.height, 0.0);
 }
 
+/// Lays out a container with the specified display mode whose children are a mix of in-flow, hidden and absolutely
+/// positioned nodes, and checks that every child's layout order is its index in the container's list of children
+fn assert_child_order_matches_child_index(display: Display) {
+    let item_style = Style { size: Size { width: length(10.0), height: length(10.0) }, ..Default::default() };
+    let hidden_style = Style { display: Display::None, ..item_style.clone() };
+    let absolute_style = Style { position: Position::Absolute, ..item_style.clone() };
+
+    let mut taffy = new_test_tree();
+    let children = [
+        taffy.new_leaf(item_style.clone()).unwrap(),
+        taffy.new_leaf(hidden_style.clone()).unwrap(),
+        taffy.new_leaf(absolute_style).unwrap(),
+        taffy.new_leaf(item_style).unwrap(),
+        taffy.new_leaf(hidden_style).unwrap(),
+    ];
+    let root = taffy.new_with_children(Style { display, ..Default::default() }, &children).unwrap();
+
+    // Hidden and absolutely positioned children are skipped by item generation, but every child's layout order
+    // should still be its index in the container's list of children (and therefore distinct)
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    for (index, &child) in children.iter().enumerate() {
+        assert_eq!(taffy.layout(child).unwrap().order, index as u32);
+    }
+}
+
+#[test]
+fn flex_child_order_matches_child_index() {
+    assert_child_order_matches_child_index(Display::Flex);
+}
+
+#[test]
+fn block_child_order_matches_child_index() {
+    assert_child_order_matches_child_index(Display::Block);
+}
+
+#[test]
+fn grid_child_order_matches_child_index() {
+    assert_child_order_matches_child_index(Display::Grid);
+}
+
 #[test]
 fn toggle_flex_container_display_none() {
     let hidden_style = Style {
@@ -386,8 +426,10 @@ fn relayout_is_stable_with_rounding() {
         assert_eq!(wrapper_layout.size.width, 150.0);
         assert_eq!(wrapper_layout.size.height, 1080.0);
 
+        // The inner node's absolute left edge (-148.5) rounds to -149, which is 151 to the left of the outer node's
+        // rounded left edge (1.5 rounds to 2)
         let inner_layout = taffy.layout(inner).unwrap();
-        assert_eq!(inner_layout.location.x, -150.0);
+        assert_eq!(inner_layout.location.x, -151.0);
         assert_eq!(inner_layout.location.y, 0.0);
         assert_eq!(inner_layout.size.width, 301.0);
         assert_eq!(inner_layout.size.height, 1080.0);
@@ -107,4 +107,68 @@ mod root_constraints {
         assert_eq!(layout.size.width, 40.0);
         assert_eq!(layout.size.height, 40.0);
     }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn block_root_with_margins() {
+        let mut tree: TaffyTree<()> = TaffyTree::new();
+
+        let child = tree
+            .new_leaf(Style {
+                display: taffy::style::Display::Block,
+                size: Size { width: taffy::style_helpers::auto(), height: length(10.0) },
+                margin: Rect { left: length(0.0), right: length(0.0), top: length(5.0), bottom: length(0.0) },
+                ..Default::default()
+            })
+            .unwrap();
+
+        let root = tree
+            .new_with_children(
+                Style {
+                    display: taffy::style::Display::Block,
+                    margin: Rect { left: length(20.0), right: length(20.0), top: length(20.0), bottom: length(20.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        tree.compute_layout(
+            root,
+            Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::Definite(200.0) },
+        )
+        .unwrap();
+
+        // The root's margins offset it from the origin and are excluded from its stretch-fit width
+        let layout = tree.layout(root).unwrap();
+        assert_eq!(layout.location.x, 20.0);
+        assert_eq!(layout.location.y, 20.0);
+        assert_eq!(layout.size.width, 160.0);
+        assert_eq!(layout.size.height, 15.0);
+
+        // The root's margins don't collapse with those of its children
+        let layout = tree.layout(child).unwrap();
+        assert_eq!(layout.location.y, 5.0);
+    }
+
+    #[test]
+    fn flex_root_with_margins() {
+        let mut tree: TaffyTree<()> = TaffyTree::new();
+
+        let root = tree
+            .new_leaf(Style {
+                size: Size { width: length(50.0), height: length(50.0) },
+                margin: Rect { left: length(10.0), right: length(20.0), top: length(30.0), bottom: length(40.0) },
+                ..Default::default()
+            })
+            .unwrap();
+
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = tree.layout(root).unwrap();
+        assert_eq!(layout.location.x, 10.0);
+        assert_eq!(layout.location.y, 30.0);
+        assert_eq!(layout.size.width, 50.0);
+        assert_eq!(layout.size.height, 50.0);
+    }
 }
@@ -1,4 +1,4 @@
-use taffy::prelude::*;
+use taffy::{assert_layout, prelude::*, RoundingMode};
 use taffy_test_helpers::new_test_tree;
 
 #[test]
@@ -28,3 +28,241 @@ fn rounding_doesnt_leave_gaps() {
     let layout_b = taffy.layout(child_b).unwrap();
     assert_eq!(layout_a.location.x + layout_a.size.width, layout_b.location.x);
 }
+
+#[test]
+fn rounding_doesnt_leave_gaps_at_a_fractional_scale() {
+    let mut taffy = new_test_tree();
+    taffy.set_rounding_scale(1.5);
+
+    let w_square = Size { width: length(100.3), height: length(100.3) };
+    let child_a = taffy.new_leaf(Style { size: w_square, ..Default::default() }).unwrap();
+    let child_b = taffy.new_leaf(Style { size: w_square, ..Default::default() }).unwrap();
+
+    let root_node = taffy
+        .new_with_children(
+            Style {
+                size: Size { width: length(963.3333), height: length(1000.) },
+                justify_content: Some(JustifyContent::Center),
+                ..Default::default()
+            },
+            &[child_a, child_b],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root_node, Size::MAX_CONTENT).unwrap();
+    taffy.print_tree(root_node);
+
+    let layout_a = taffy.layout(child_a).unwrap();
+    let layout_b = taffy.layout(child_b).unwrap();
+    assert_eq!(layout_a.location.x + layout_a.size.width, layout_b.location.x);
+
+    // Every value lies on the device pixel grid, which is 1/1.5 layout units wide
+    for value in [layout_a.location.x, layout_a.size.width, layout_b.location.x, layout_b.size.width] {
+        let device_pixels = value * 1.5;
+        assert!((device_pixels - device_pixels.round()).abs() < 0.001, "{value} is not on the device pixel grid");
+    }
+}
+
+#[test]
+fn rounded_border_and_padding_meet_the_rounded_content_box() {
+    let mut taffy = new_test_tree();
+
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: length(10.0), height: length(10.0) },
+            border: Rect { left: length(0.3), right: zero(), top: length(0.6), bottom: zero() },
+            padding: Rect { left: length(0.3), right: length(9.4), top: length(0.6), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(0.4), right: zero(), top: length(0.6), bottom: zero() },
+                align_items: Some(AlignItems::Start),
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The content box's left edge is at 0.4 + 0.3 + 0.3 = 1.0 and its right edge at 0.4 + 10 - 9.4 = 1.0, so it has
+    // zero width. Its top edge is at 0.6 + 0.6 + 0.6 = 1.8, which rounds to 2.
+    assert_layout!(taffy, child, x: 0.0, y: 1.0, width: 10.0, height: 10.0);
+    assert_layout!(taffy, child, border_left: 1.0, padding_left: 0.0, padding_right: 9.0);
+    assert_layout!(taffy, child, border_top: 0.0, padding_top: 1.0);
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!(layout.content_box_width(), 0.0);
+    assert_eq!(layout.content_box_x(), 1.0);
+    assert_eq!(layout.content_box_y(), 2.0);
+}
+
+#[test]
+fn rounding_doesnt_drift_along_a_long_row_of_fractional_children() {
+    // The row is placed both at the origin and within an ancestor, each offset by a fraction of a pixel, so that the
+    // rounded locations of the row and its ancestor (0 + 0) don't add up to the row's rounded absolute position (1)
+    for offset in [0.0, 0.4] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let children: Vec<NodeId> = (0..100)
+            .map(|_| {
+                taffy.new_leaf(Style { size: Size::from_lengths(7.3, 10.0), flex_shrink: 0.0, ..Default::default() })
+            })
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let row = taffy
+            .new_with_children(
+                Style { margin: Rect { left: length(offset), ..Rect::zero() }, ..Default::default() },
+                &children,
+            )
+            .unwrap();
+        let ancestor = taffy
+            .new_with_children(
+                Style { padding: Rect { left: length(offset), ..Rect::zero() }, ..Default::default() },
+                &[row],
+            )
+            .unwrap();
+        taffy.compute_layout(ancestor, Size::MAX_CONTENT).unwrap();
+
+        // Absolute positions are the sums of the rounded locations of each node and its ancestors
+        let row_x = [ancestor, row].iter().map(|&node| taffy.layout(node).unwrap().location.x).sum::<Scalar>();
+        for (index, &child) in children.iter().enumerate() {
+            let layout = taffy.layout(child).unwrap();
+            let right = row_x + layout.location.x + layout.size.width;
+            // Summed one width at a time as the flexbox algorithm does, as several of the exact sums lie halfway
+            // between pixels
+            let widths = (0..=index).map(|_| 7.3f32 as Scalar).sum::<Scalar>();
+            let expected = (2.0 * offset as Scalar + widths).round();
+            assert_eq!(right, expected, "child {index} (row offset by {})", 2.0 * offset);
+        }
+    }
+}
+
+/// A minimal xorshift pseudo-random number generator, so that the generated trees are the same on every run
+struct Rng(u32);
+
+impl Rng {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    /// A random multiple of 1/8 below `max`. These are exactly representable, so the unrounded edges of boxes that
+    /// touch are exactly equal, and many of them lie exactly halfway between two pixels.
+    fn eighths(&mut self, max: u32) -> f32 {
+        (self.next() % (max * 8)) as f32 / 8.0
+    }
+}
+
+/// Builds a random tree of flex containers, each of which is sized to fit its fixed-size leaves and nested containers
+fn random_tree(taffy: &mut TaffyTree<()>, rng: &mut Rng, depth: u32) -> NodeId {
+    let child_count = if depth == 0 { 0 } else { rng.next() % 5 };
+    if child_count == 0 {
+        let size = Size::from_lengths(rng.eighths(40), rng.eighths(40));
+        return taffy.new_leaf(Style { size, flex_shrink: 0.0, ..Default::default() }).unwrap();
+    }
+
+    let children: Vec<NodeId> = (0..child_count).map(|_| random_tree(taffy, rng, depth - 1)).collect();
+    let style = Style {
+        flex_direction: if rng.next() % 2 == 0 { FlexDirection::Row } else { FlexDirection::Column },
+        align_items: Some(AlignItems::Start),
+        border: Rect { left: length(rng.eighths(3)), right: length(rng.eighths(3)), top: zero(), bottom: zero() },
+        padding: Rect { left: zero(), right: zero(), top: length(rng.eighths(3)), bottom: length(rng.eighths(3)) },
+        flex_shrink: 0.0,
+        ..Default::default()
+    };
+    taffy.new_with_children(style, &children).unwrap()
+}
+
+const ROUNDING_MODES: [RoundingMode; 4] =
+    [RoundingMode::Nearest, RoundingMode::Ceil, RoundingMode::Floor, RoundingMode::HalfEven];
+
+/// Lays out many random trees with the given rounding modes, calling `check` with each parent and child
+fn for_each_random_parent_and_child(
+    positions: RoundingMode,
+    sizes: RoundingMode,
+    mut check: impl FnMut(&TaffyTree<()>, NodeId, NodeId),
+) {
+    let mut rng = Rng(0x9e37_79b9);
+    for _ in 0..100 {
+        let mut taffy = TaffyTree::new();
+        taffy.set_rounding_modes(positions, sizes);
+        let offset = rng.eighths(4);
+        let tree = random_tree(&mut taffy, &mut rng, 3);
+        // Place the random tree at a fractional position so that its own edges need rounding
+        let root = taffy
+            .new_with_children(
+                Style { padding: Rect { left: length(offset), ..Rect::zero() }, ..Default::default() },
+                &[tree],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let mut stack = vec![root];
+        while let Some(parent) = stack.pop() {
+            for child in taffy.children(parent).unwrap() {
+                check(&taffy, parent, child);
+                stack.push(child);
+            }
+        }
+    }
+}
+
+#[test]
+fn rounded_children_stay_within_their_parents_in_every_mode() {
+    for positions in ROUNDING_MODES {
+        for sizes in ROUNDING_MODES {
+            for_each_random_parent_and_child(positions, sizes, |taffy, parent, child| {
+                let parent = taffy.layout(parent).unwrap();
+                let child = taffy.layout(child).unwrap();
+                assert!(child.location.x >= 0.0 && child.location.y >= 0.0, "{positions:?}/{sizes:?}: {child:?}");
+                assert!(
+                    child.location.x + child.size.width <= parent.size.width
+                        && child.location.y + child.size.height <= parent.size.height,
+                    "{positions:?}/{sizes:?}: {child:?} overflows {parent:?}"
+                );
+            });
+        }
+    }
+}
+
+#[test]
+fn rounding_doesnt_leave_gaps_in_any_mode() {
+    let modes = ROUNDING_MODES.map(|mode| (mode, mode));
+    // Sizes that round up further than positions can make boxes overlap, but not leave gaps
+    for (positions, sizes) in modes.into_iter().chain([(RoundingMode::Floor, RoundingMode::Ceil)]) {
+        for_each_random_parent_and_child(positions, sizes, |taffy, parent, child| {
+            let children = taffy.children(parent).unwrap();
+            let index = children.iter().position(|&sibling| sibling == child).unwrap();
+            let Some(&next) = children.get(index + 1) else { return };
+            let (child, next) = (taffy.layout(child).unwrap(), taffy.layout(next).unwrap());
+            let (child_end, next_start) = match taffy.style(parent).unwrap().flex_direction {
+                FlexDirection::Row => (child.location.x + child.size.width, next.location.x),
+                _ => (child.location.y + child.size.height, next.location.y),
+            };
+            if positions == sizes {
+                assert_eq!(child_end, next_start, "{positions:?}/{sizes:?}: {child:?} and {next:?}");
+            } else {
+                assert!(child_end >= next_start, "{positions:?}/{sizes:?}: {child:?} and {next:?}");
+            }
+        });
+    }
+}
+
+#[test]
+fn ceil_sizes_with_floor_positions_never_shrink_boxes() {
+    for_each_random_parent_and_child(RoundingMode::Floor, RoundingMode::Ceil, |taffy, _, child| {
+        let rounded = taffy.layout(child).unwrap();
+        let unrounded = taffy.unrounded_layout(child);
+        assert!(rounded.size.width >= unrounded.size.width && rounded.size.height >= unrounded.size.height);
+    });
+}
+
+#[test]
+fn half_even_rounds_halfway_values_to_the_even_pixel() {
+    let rounded = [0.5, 1.5, 2.5, -0.5, -1.5, 2.25, 2.75].map(|value| RoundingMode::HalfEven.round(value));
+    assert_eq!(rounded, [0.0, 2.0, 2.0, 0.0, -2.0, 2.0, 3.0]);
+}
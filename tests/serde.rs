@@ -2,8 +2,18 @@
 #[cfg(feature = "serde")]
 mod serde {
 
-    use serde_json::{self, Value};
-    use taffy::style::Style;
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::style::{Clear, Float, Overflow, Style, TextAlign};
+    use taffy::TreeSnapshot;
+
+    /// An aligned value for a calc handle to point to
+    static CALC: u64 = 0;
+
+    /// The handle of a `calc()` value
+    fn calc_handle() -> *const () {
+        &CALC as *const u64 as *const ()
+    }
 
     #[test]
     fn serde_can_serialize() {
@@ -12,44 +22,219 @@ mod serde {
     }
 
     #[test]
-    fn serde_can_deserialize_partial_values() {
-        use serde_json;
+    fn serde_can_deserialize_omitted_fields_and_hand_written_values() {
         let json = r###"{
-            "inset": {
-                "left": { "Length": 22 },
-                "right": "Auto"
-            },
-            "size":
-            {
-                "width": { "Percent": 50 }
-            },
-            "min_size":
-            {
-                "height": { "Length": 10 }
-            },
-            "max_size":
-            {
-                "width": "Auto"
-            },
-            "margin":
-            {
-                "right": { "Length": 99.0 },
-                "bottom": { "Length": 99.0 }
-            },
-            "padding":
-            {
-                "left": { "Length": 99.0 }
-            },
-            "border":
-            {
-                "bottom": { "Length": 99.0 }
-            },
-            "gap": {
-                "width": { "Length": 99.0 }
-            },
-            "grid_row": { "start": "Auto" },
-            "grid_column": { "end": "Auto" }
+            "display": "grid",
+            "inset": { "left": { "length": 22 }, "right": "auto", "top": "auto", "bottom": "auto" },
+            "size": { "width": { "percent": 0.5 }, "height": "auto" },
+            "min_size": { "width": "auto", "height": { "length": 10 } },
+            "margin": { "left": { "length": 0 }, "right": { "length": 99.0 }, "top": { "length": 0 }, "bottom": "auto" },
+            "grid_template_columns": [
+                { "single": { "min": "min_content", "max": { "fr": 1.0 } } },
+                { "repeat": ["auto_fill", [{ "min": { "length": 20.0 }, "max": { "fit_content_px": 40.0 } }]] }
+            ],
+            "grid_row": { "start": { "line": 2 }, "end": { "span": 3 } },
+            "grid_column": { "end": "auto" }
         }"###;
-        let _: Value = serde_json::from_str(&json).unwrap();
+        let style: Style = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            style,
+            Style {
+                display: Display::Grid,
+                inset: Rect { left: length(22.0), right: auto(), top: auto(), bottom: auto() },
+                size: Size { width: percent(0.5), height: auto() },
+                min_size: Size { width: auto(), height: length(10.0) },
+                margin: Rect { left: zero(), right: length(99.0), top: zero(), bottom: auto() },
+                grid_template_columns: vec![
+                    minmax(min_content(), fr(1.0)),
+                    repeat(GridTrackRepetition::AutoFill, vec![minmax(length(20.0), fit_content(length(40.0)))]),
+                ],
+                grid_row: Line { start: line(2), end: span(3) },
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn serde_round_trips_style() {
+        let style = Style {
+            display: Display::Block,
+            size: Size { width: percent(0.5), height: auto() },
+            min_size: Size { width: length(20.0), height: percent(0.1) },
+            flex_basis: length(30.0),
+            margin: Rect { left: length(10.0), right: auto(), top: zero(), bottom: percent(0.25) },
+            padding: Rect::length(5.0),
+            gap: Size::length(3.0),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&style).unwrap();
+        let round_tripped: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, style);
+    }
+
+    #[test]
+    fn serde_round_trips_every_style_field() {
+        let style = Style {
+            display: Display::Grid,
+            item_is_table: true,
+            item_is_inline: true,
+            box_sizing: BoxSizing::ContentBox,
+            overflow: Point { x: Overflow::Scroll, y: Overflow::Clip },
+            scrollbar_width: 12.0,
+            position: Position::Absolute,
+            inset: Rect { left: length(1.0), right: percent(0.2), top: auto(), bottom: length(-4.0) },
+            size: Size { width: length(100.0), height: percent(0.5) },
+            min_size: Size { width: auto(), height: length(10.0) },
+            max_size: Size { width: percent(1.0), height: length(500.0) },
+            aspect_ratio: Some(1.5),
+            margin: Rect { left: auto(), right: length(2.0), top: percent(0.1), bottom: zero() },
+            padding: Rect { left: length(3.0), right: percent(0.05), top: zero(), bottom: length(4.0) },
+            border: Rect { left: length(1.0), right: length(2.0), top: length(3.0), bottom: percent(0.01) },
+            align_items: Some(AlignItems::Baseline),
+            align_self: Some(AlignSelf::FlexEnd),
+            justify_items: Some(JustifyItems::Center),
+            justify_self: Some(JustifySelf::Stretch),
+            align_content: Some(AlignContent::SpaceEvenly),
+            justify_content: Some(JustifyContent::SpaceBetween),
+            gap: Size { width: length(5.0), height: percent(0.02) },
+            text_align: TextAlign::LegacyCenter,
+            float: Float::Right,
+            clear: Clear::Both,
+            flex_direction: FlexDirection::ColumnReverse,
+            flex_wrap: FlexWrap::WrapReverse,
+            flex_basis: percent(0.25),
+            flex_grow: 2.0,
+            flex_shrink: 0.5,
+            grid_template_rows: vec![
+                length(10.0),
+                fr(2.0),
+                minmax(length(20.0), max_content()),
+                repeat(3, vec![percent(0.1), auto()]),
+            ],
+            grid_template_columns: vec![
+                fit_content(length(40.0)),
+                repeat(GridTrackRepetition::AutoFit, vec![minmax(min_content(), fr(1.0))]),
+                fit_content(percent(0.3)),
+            ],
+            grid_auto_rows: vec![min_content(), fr(1.0)],
+            grid_auto_columns: vec![minmax(length(10.0), fit_content(length(50.0)))],
+            grid_auto_flow: GridAutoFlow::ColumnDense,
+            grid_row: Line { start: line(-2), end: span(2) },
+            grid_column: Line { start: auto(), end: line(4) },
+        };
+        let json = serde_json::to_string(&style).unwrap();
+        let round_tripped: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, style);
+    }
+
+    #[test]
+    fn serde_represents_styles_with_snake_case_externally_tagged_enums() {
+        let style = Style {
+            display: Display::Flex,
+            size: Size { width: length(10.0), height: auto() },
+            grid_template_rows: vec![repeat(GridTrackRepetition::AutoFill, vec![fr(1.0)])],
+            grid_row: Line { start: span(2), end: auto() },
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&style).unwrap();
+        assert_eq!(json["display"], serde_json::json!("flex"));
+        assert_eq!(json["size"], serde_json::json!({ "width": { "length": 10.0 }, "height": "auto" }));
+        assert_eq!(
+            json["grid_template_rows"],
+            serde_json::json!([{ "repeat": ["auto_fill", [{ "min": "auto", "max": { "fr": 1.0 } }]] }])
+        );
+        assert_eq!(json["grid_row"], serde_json::json!({ "start": { "span": 2 }, "end": "auto" }));
+        assert_eq!(json["overflow"], serde_json::json!({ "x": "visible", "y": "visible" }));
+        assert_eq!(json["box_sizing"], serde_json::json!("border_box"));
+    }
+
+    #[test]
+    fn serde_rejects_serializing_calc() {
+        let style = Style { padding: Rect { left: calc(calc_handle()), ..Rect::zero() }, ..Default::default() };
+        assert!(serde_json::to_string(&style).is_err());
+    }
+
+    #[test]
+    fn serde_rejects_deserializing_calc() {
+        let raw = calc_handle() as u64;
+        let result: Result<LengthPercentage, _> = serde_json::from_str(&raw.to_string());
+        assert!(result.is_err());
+
+        let valid = serde_json::to_string(&LengthPercentage::length(10.0)).unwrap();
+        let result: Result<LengthPercentage, _> = serde_json::from_str(&valid);
+        assert_eq!(result.unwrap(), LengthPercentage::length(10.0));
+    }
+
+    /// Measures text of `char_count` 10px wide characters, wrapping it onto 20px lines to fit the available width
+    fn text_measure_function(
+        known_dimensions: Size<Option<Scalar>>,
+        available_space: Size<AvailableSpace>,
+        _node_id: NodeId,
+        char_count: Option<&mut usize>,
+        _style: &Style,
+    ) -> Size<Scalar> {
+        let Some(&mut char_count) = char_count else { return Size::ZERO };
+        let inline_size = char_count as Scalar * 10.0;
+        let width = known_dimensions.width.unwrap_or(match available_space.width {
+            AvailableSpace::MinContent => 10.0,
+            AvailableSpace::MaxContent => inline_size,
+            AvailableSpace::Definite(width) => width.clamp(10.0, inline_size),
+        });
+        let lines = (inline_size / width.max(10.0)).ceil();
+        Size { width, height: known_dimensions.height.unwrap_or(lines * 20.0) }
+    }
+
+    #[test]
+    fn serde_tree_snapshot_restores_to_the_same_layout() {
+        let mut taffy: TaffyTree<usize> = TaffyTree::new();
+        let text = taffy.new_leaf_with_context(Style { padding: Rect::length(3.3), ..Default::default() }, 23).unwrap();
+        let spacer = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let column = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    size: Size { width: percent(0.37), height: auto() },
+                    ..Default::default()
+                },
+                &[text, spacer],
+            )
+            .unwrap();
+        let cell = taffy.new_leaf_with_context(Style::default(), 7).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![fr(1.0), length(33.7)],
+                    padding: Rect::length(1.1),
+                    ..Default::default()
+                },
+                &[cell],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(301.5), height: auto() }, ..Default::default() },
+                &[column, grid],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+        let snapshot = taffy.serialize_tree(root);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot: TreeSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot, taffy.serialize_tree(root));
+        assert_eq!(snapshot.nodes.len(), 6);
+        assert_eq!(snapshot.nodes[0].children, vec![1, 4]);
+        assert!(snapshot.nodes[2].needs_measure);
+        assert!(!snapshot.nodes[3].needs_measure);
+
+        let (mut restored, restored_root) = TaffyTree::from_snapshot(&snapshot);
+        restored.compute_layout(restored_root, Size::MAX_CONTENT).unwrap();
+        let restored_snapshot = restored.serialize_tree(restored_root);
+        for (restored_node, node) in restored_snapshot.nodes.iter().zip(&snapshot.nodes) {
+            assert_eq!(restored_node.children, node.children);
+            assert_eq!(restored_node.needs_measure, node.needs_measure);
+            assert_eq!(restored_node.layout, node.layout);
+        }
     }
 }
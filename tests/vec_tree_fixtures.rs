@@ -0,0 +1,10 @@
+// Runs the generated flexbox, block and leaf tests against `VecTree` rather than `TaffyTree`. The generated tests only
+// build and lay out trees through the functions re-exported below, so they're mounted unchanged from `tests/generated`.
+#[path = "generated/block/mod.rs"]
+mod block;
+#[path = "generated/flex/mod.rs"]
+mod flex;
+#[path = "generated/leaf/mod.rs"]
+mod leaf;
+
+pub use taffy_test_helpers::{new_test_vec_tree as new_test_tree, test_measure_function, TestNodeContext, WritingMode};
@@ -0,0 +1,76 @@
+//! Drives the JavaScript bindings through wasm-bindgen. Run with `wasm-bindgen-test-runner` as the runner for the
+//! `wasm32-unknown-unknown` target.
+#![cfg(all(feature = "wasm-bindgen", target_arch = "wasm32"))]
+
+use js_sys::{Function, Reflect};
+use taffy::wasm::TaffyTree;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Reads a number property of a JavaScript object
+fn number(object: &JsValue, property: &str) -> f64 {
+    Reflect::get(object, &property.into()).unwrap().as_f64().unwrap()
+}
+
+fn position(tree: &TaffyTree, node: u64) -> (f64, f64, f64, f64) {
+    let layout = tree.layout(node).map_err(JsValue::from).unwrap().into();
+    (number(&layout, "x"), number(&layout, "y"), number(&layout, "width"), number(&layout, "height"))
+}
+
+#[wasm_bindgen_test]
+fn wasm_lays_out_a_flex_row() {
+    let mut tree = TaffyTree::new();
+    let fixed = tree.new_leaf(r#"{ "size": { "width": { "length": 50 }, "height": { "length": 20 } } }"#).unwrap();
+    let grow = tree.new_leaf(r#"{ "flex_grow": 1 }"#).unwrap();
+    let root = tree
+        .new_with_children(
+            r#"{ "flex_direction": "row", "size": { "width": { "length": 200 }, "height": "auto" }, "padding": { "left": { "length": 10 }, "right": { "length": 0 }, "top": { "length": 0 }, "bottom": { "length": 0 } } }"#,
+            &[fixed, grow],
+        )
+        .unwrap();
+    tree.compute_layout(root, None, None).unwrap();
+
+    assert_eq!(position(&tree, root), (0.0, 0.0, 200.0, 20.0));
+    assert_eq!(position(&tree, fixed), (10.0, 0.0, 50.0, 20.0));
+    assert_eq!(position(&tree, grow), (60.0, 0.0, 140.0, 20.0));
+
+    tree.set_style(fixed, r#"{ "size": { "width": { "length": 100 }, "height": { "length": 20 } } }"#).unwrap();
+    tree.compute_layout(root, None, None).unwrap();
+    assert_eq!(position(&tree, grow), (110.0, 0.0, 90.0, 20.0));
+}
+
+#[wasm_bindgen_test]
+fn wasm_measures_leaves_with_a_callback() {
+    let mut tree = TaffyTree::new();
+    let text = tree.new_leaf("{}").unwrap();
+    let measure = Function::new_with_args(
+        "known, available",
+        "return { width: known.width ?? 30, height: typeof available.height === 'number' ? 15 : 25 };",
+    );
+    tree.set_measure(text, Some(measure)).unwrap();
+    let root = tree
+        .new_with_children(
+            r#"{ "flex_direction": "column", "size": { "width": { "length": 100 }, "height": "auto" } }"#,
+            &[text],
+        )
+        .unwrap();
+
+    tree.compute_layout(root, None, None).unwrap();
+    assert_eq!(position(&tree, text), (0.0, 0.0, 100.0, 25.0));
+
+    let throws = Function::new_no_args("throw new Error('measure failed');");
+    tree.set_measure(text, Some(throws)).unwrap();
+    tree.mark_dirty(text).unwrap();
+    assert!(tree.compute_layout(root, None, None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn wasm_rejects_invalid_styles_and_nodes() {
+    let mut tree = TaffyTree::new();
+    assert!(tree.new_leaf(r#"{ "display": "table" }"#).is_err());
+
+    let node = tree.new_leaf("{}").unwrap();
+    tree.remove(node).unwrap();
+    assert!(tree.set_style(node, "{}").is_err());
+    assert!(tree.layout(node).is_err());
+}